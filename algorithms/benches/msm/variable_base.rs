@@ -46,6 +46,17 @@ fn variable_base_bls12_377(c: &mut Criterion) {
     }
 }
 
+fn variable_base_glv_bls12_377(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(2000000);
+
+    for size in [10_000, 100_000, 200_000, 300_000, 400_000, 500_000, 1_000_000, 2_000_000] {
+        c.bench_function(&format!("VariableBase GLV MSM on BLS12-377 ({})", size), |b| {
+            b.iter(|| VariableBase::msm_glv(&bases[..size], &scalars[..size]))
+        });
+    }
+}
+
 fn variable_base_edwards_bls12(c: &mut Criterion) {
     use snarkvm_curves::edwards_bls12::{EdwardsAffine, Fr};
     let (bases, scalars) = create_scalar_bases::<EdwardsAffine, Fr>(1_000_000);
@@ -60,7 +71,7 @@ fn variable_base_edwards_bls12(c: &mut Criterion) {
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base_bls12_377, variable_base_edwards_bls12
+    targets = variable_base_bls12_377, variable_base_glv_bls12_377, variable_base_edwards_bls12
 }
 
 criterion_main!(variable_base_group);
@@ -364,8 +364,16 @@ fn batched_window<G: AffineCurve>(
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+    msm_with_num_bits(bases, scalars, <G::ScalarField as PrimeField>::size_in_bits())
+}
+
+/// Returns the MSM of `bases` and `scalars`, where every scalar is less than `2^num_bits`.
+pub fn msm_with_num_bits<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    num_bits: usize,
+) -> G::Projective {
     if bases.len() < 15 {
-        let num_bits = G::ScalarField::size_in_bits();
         let bigint_size = <G::ScalarField as PrimeField>::BigInteger::NUM_LIMBS * 64;
         let mut bits =
             scalars.iter().map(|s| BitIteratorBE::new(s.as_ref()).skip(bigint_size - num_bits)).collect::<Vec<_>>();
@@ -392,8 +400,6 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
             false => crate::msm::ln_without_floats(scalars.len()) + 2,
         };
 
-        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
-
         // Each window is of size `c`.
         // We divide up the bits 0..num_bits into windows of size `c`, and
         // in parallel process each such window.
@@ -23,8 +23,13 @@ mod cuda;
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
-use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
+use snarkvm_curves::{
+    bls12_377::G1Affine,
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{AffineCurve, GLVParameters},
+};
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::BigInteger;
 
 use core::any::TypeId;
 
@@ -58,6 +63,47 @@ impl VariableBase {
         }
     }
 
+    /// Performs variable base MSM on a curve with a GLV endomorphism `phi`.
+    ///
+    /// Each scalar `k` is decomposed as `k1 + k2 * LAMBDA`, and the MSM of `bases` and `scalars`
+    /// is computed as a single MSM of `bases || phi(bases)` and `k1 || k2`, whose scalars are half as wide.
+    /// The `bases` must be in the prime-order subgroup. If any of the `scalars` is not a canonical
+    /// field element, this falls back to `VariableBase::msm`.
+    pub fn msm_glv<P: GLVParameters>(
+        bases: &[Affine<P>],
+        scalars: &[<P::ScalarField as PrimeField>::BigInteger],
+    ) -> Projective<P> {
+        // Decompose the scalars, or fall back to the standard MSM if any of them is not canonical.
+        let decomposed: Option<Vec<_>> =
+            scalars.iter().map(|scalar| P::ScalarField::from_repr(*scalar).map(|k| P::glv_decomposition(&k))).collect();
+        let decomposed = match decomposed {
+            Some(decomposed) => decomposed,
+            None => return Self::msm(bases, scalars),
+        };
+
+        // Returns the given `u128` as a `BigInteger`.
+        let to_bigint = |k: u128| {
+            let mut bigint = <P::ScalarField as PrimeField>::BigInteger::from((k >> 64) as u64);
+            bigint.muln(64);
+            bigint.add_nocarry(&(k as u64).into());
+            bigint
+        };
+
+        let (mut glv_bases, mut glv_scalars) = (bases.to_vec(), Vec::with_capacity(2 * scalars.len()));
+        glv_bases.extend(bases.iter().map(P::glv_endomorphism));
+        let (k1s, k2s): (Vec<_>, Vec<_>) =
+            decomposed.into_iter().map(|(k1, k2)| (to_bigint(k1), to_bigint(k2))).unzip();
+        glv_scalars.extend(k1s);
+        glv_scalars.extend(k2s);
+
+        // The decomposed scalars are at most 128 bits wide.
+        if TypeId::of::<Affine<P>>() == TypeId::of::<G1Affine>() {
+            batched::msm_with_num_bits(&glv_bases, &glv_scalars, 128)
+        } else {
+            standard::msm_with_num_bits(&glv_bases, &glv_scalars, 128)
+        }
+    }
+
     #[cfg(test)]
     fn msm_naive<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
         use itertools::Itertools;
@@ -110,9 +156,30 @@ mod tests {
 
             let candidate = batched::msm(bases.as_slice(), scalars.as_slice()).to_affine();
             assert_eq!(naive_a, candidate, "MSM size: {msm_size}");
+
+            let candidate = VariableBase::msm_glv(bases.as_slice(), scalars.as_slice()).to_affine();
+            assert_eq!(naive_a, candidate, "MSM size: {msm_size}");
         }
     }
 
+    #[test]
+    fn test_msm_glv_non_canonical_scalars() {
+        use snarkvm_curves::ProjectiveCurve;
+        use snarkvm_fields::FieldParameters;
+        use snarkvm_utilities::BigInteger;
+
+        let mut rng = TestRng::default();
+        let (bases, mut scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 10);
+
+        // Replace a scalar with `MODULUS + 1`, which is not a canonical field element.
+        scalars[0] = <Fr as PrimeField>::Parameters::MODULUS;
+        scalars[0].add_nocarry(&1u64.into());
+
+        let naive = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice()).to_affine();
+        let candidate = VariableBase::msm_glv(bases.as_slice(), scalars.as_slice()).to_affine();
+        assert_eq!(naive, candidate);
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
@@ -47,6 +47,9 @@ use rayon::prelude::*;
 mod data_structures;
 pub use data_structures::*;
 
+mod powers_of_tau;
+pub use powers_of_tau::*;
+
 use super::sonic_pc::LabeledPolynomialWithBasis;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -0,0 +1,356 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{KZG10DegreeBoundsConfig, UniversalParams};
+use crate::msm::VariableBase;
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_parameters::testnet3::PowersOfG;
+use snarkvm_utilities::{
+    io::{self, Read},
+    rand::Uniform,
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    Compress,
+    Validate,
+};
+
+use anyhow::{bail, ensure, Result};
+use parking_lot::RwLock;
+use rand_core::RngCore;
+use std::sync::Arc;
+
+/// The number of bytes in the hash that prefixes a powers-of-tau transcript.
+const TRANSCRIPT_HASH_SIZE: usize = 64;
+
+/// `PowersOfTau` is the output of a phase 1 powers-of-tau ceremony, from which
+/// the `UniversalParams` of the KZG10 scheme for Marlin are derived.
+///
+/// A transcript of `N` powers is laid out as a 64-byte hash, followed by the group elements
+/// `{ \tau^i G }` for `i` in `0..2N - 1`, `{ \tau^i H }` for `i` in `0..N`, `{ \alpha \tau^i G }`
+/// for `i` in `0..N`, `{ \beta \tau^i G }` for `i` in `0..N`, and `\beta H`.
+/// The powers of `\beta` are only used by Groth16, and are skipped when reading the transcript.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PowersOfTau<E: PairingEngine> {
+    /// The hash of the ceremony contribution that produced the transcript.
+    pub hash: [u8; TRANSCRIPT_HASH_SIZE],
+    /// Group elements of the form `{ \tau^i G }`, where `i` ranges from 0 to `2N - 2`.
+    pub tau_powers_g1: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \tau^i H }`, where `i` ranges from 0 to `N - 1`.
+    pub tau_powers_g2: Vec<E::G2Affine>,
+    /// Group elements of the form `{ \alpha \tau^i G }`, where `i` ranges from 0 to `N - 1`.
+    pub alpha_tau_powers_g1: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> PowersOfTau<E> {
+    /// Reads a transcript of `num_powers` powers from the given reader,
+    /// checking that all group elements are in the prime-order subgroup.
+    pub fn read<R: Read>(mut reader: R, num_powers: usize, compress: Compress) -> Result<Self> {
+        ensure!(num_powers >= 2, "A transcript must contain at least 2 powers, found {num_powers}");
+
+        let mut hash = [0u8; TRANSCRIPT_HASH_SIZE];
+        reader.read_exact(&mut hash)?;
+
+        let tau_powers_g1 = read_elements::<E::G1Affine, _>(&mut reader, 2 * num_powers - 1, compress)?;
+        let tau_powers_g2 = read_elements::<E::G2Affine, _>(&mut reader, num_powers, compress)?;
+        let alpha_tau_powers_g1 = read_elements::<E::G1Affine, _>(&mut reader, num_powers, compress)?;
+
+        // Skip `{ \beta \tau^i G }` and `\beta H`.
+        let num_beta_bytes =
+            num_powers * E::G1Affine::zero().serialized_size(compress) + E::G2Affine::zero().serialized_size(compress);
+        let num_skipped_bytes = io::copy(&mut reader.by_ref().take(num_beta_bytes as u64), &mut io::sink())?;
+        ensure!(num_skipped_bytes == num_beta_bytes as u64, "The transcript is truncated");
+
+        Ok(Self { hash, tau_powers_g1, tau_powers_g2, alpha_tau_powers_g1 })
+    }
+
+    /// Returns the maximum degree of the universal parameters that can be derived from the transcript.
+    pub fn max_degree(&self) -> usize {
+        // The derivation requires `\tau^{d+1} H` and `\alpha \tau^{d+1} G` for a maximum degree of `d`.
+        self.tau_powers_g2.len().min(self.alpha_tau_powers_g1.len()).min(self.tau_powers_g1.len() + 1).saturating_sub(2)
+    }
+
+    /// Checks that the transcript is well-formed, and that all powers are consistent
+    /// with a single \tau, using a randomized batch of pairing checks.
+    pub fn verify<R: RngCore>(&self, rng: &mut R) -> Result<()> {
+        // Ensure the transcript has the expected shape.
+        ensure!(self.tau_powers_g2.len() >= 2, "The transcript must contain at least 2 powers of tau H");
+        ensure!(
+            self.tau_powers_g1.len() == 2 * self.tau_powers_g2.len() - 1,
+            "The transcript contains {} powers of tau G, but expected {}",
+            self.tau_powers_g1.len(),
+            2 * self.tau_powers_g2.len() - 1
+        );
+        ensure!(
+            self.alpha_tau_powers_g1.len() == self.tau_powers_g2.len(),
+            "The transcript contains {} powers of alpha * tau G, but expected {}",
+            self.alpha_tau_powers_g1.len(),
+            self.tau_powers_g2.len()
+        );
+
+        let (g, tau_g) = (self.tau_powers_g1[0], self.tau_powers_g1[1]);
+        let (h, tau_h) = (self.tau_powers_g2[0], self.tau_powers_g2[1]);
+        ensure!(!g.is_zero(), "The generator of G1 must be non-zero");
+        ensure!(!h.is_zero(), "The generator of G2 must be non-zero");
+        ensure!(!tau_g.is_zero(), "The element tau * G must be non-zero");
+        ensure!(!self.alpha_tau_powers_g1[0].is_zero(), "The element alpha * G must be non-zero");
+
+        // Ensure every power is \tau times the previous power, for both `\tau^i G` and `\alpha \tau^i G`,
+        // by checking `e(\sum_i r_i P_{i+1}, H) == e(\sum_i r_i P_i, \tau H)` for random `r_i`.
+        let (lower, upper) = consecutive_powers(&[&self.tau_powers_g1, &self.alpha_tau_powers_g1]);
+        let randomizers = (0..lower.len()).map(|_| E::Fr::rand(rng).to_repr()).collect::<Vec<_>>();
+        let lower = VariableBase::msm(&lower, &randomizers);
+        let upper = VariableBase::msm(&upper, &randomizers);
+        if E::pairing(upper, h) != E::pairing(lower, tau_h) {
+            bail!("The powers of tau in G1 are inconsistent");
+        }
+
+        // Ensure every power `\tau^i H` is \tau times the previous power,
+        // by checking `e(G, \sum_i r_i Q_{i+1}) == e(\tau G, \sum_i r_i Q_i)` for random `r_i`.
+        let (lower, upper) = consecutive_powers(&[&self.tau_powers_g2]);
+        let randomizers = (0..lower.len()).map(|_| E::Fr::rand(rng).to_repr()).collect::<Vec<_>>();
+        let lower = VariableBase::msm(&lower, &randomizers);
+        let upper = VariableBase::msm(&upper, &randomizers);
+        if E::pairing(g, upper) != E::pairing(tau_g, lower) {
+            bail!("The powers of tau in G2 are inconsistent");
+        }
+
+        Ok(())
+    }
+
+    /// Returns the universal parameters of the given maximum degree for the transcript.
+    /// The transcript must be checked with `PowersOfTau::verify` beforehand.
+    ///
+    /// As the transcript does not contain negative powers of \tau, the generator of G2 is taken
+    /// to be `\tau^d H`, so that `\tau^{-(d - i)}` times the generator is the element `\tau^i H`.
+    /// The generator of G2 only appears in pairings against G2 elements from the same parameters,
+    /// so this does not change the outcome of any pairing check. The element \gamma, used for hiding,
+    /// is taken to be the \alpha of the ceremony.
+    pub fn into_universal_params(self, max_degree: usize) -> Result<UniversalParams<E>> {
+        ensure!(max_degree >= 1, "The maximum degree must be at least 1");
+        ensure!(
+            max_degree <= self.max_degree(),
+            "The transcript supports a maximum degree of {}, but {max_degree} was requested",
+            self.max_degree()
+        );
+
+        let supported_degree_bounds = KZG10DegreeBoundsConfig::MARLIN.get_list::<E::Fr>(max_degree);
+        let h = self.tau_powers_g2[max_degree];
+        let beta_h = self.tau_powers_g2[max_degree + 1];
+        let inverse_neg_powers_of_beta_h = supported_degree_bounds
+            .iter()
+            .map(|degree_bound| (*degree_bound, self.tau_powers_g2[*degree_bound]))
+            .collect();
+
+        let mut powers_of_beta_g = self.tau_powers_g1;
+        powers_of_beta_g.truncate(max_degree + 1);
+        let mut powers_of_beta_times_gamma_g = self.alpha_tau_powers_g1;
+        powers_of_beta_times_gamma_g.truncate(max_degree + 2);
+        let powers =
+            PowersOfG::<E>::setup(powers_of_beta_g, powers_of_beta_times_gamma_g.into_iter().enumerate().collect())?;
+
+        Ok(UniversalParams {
+            powers: Arc::new(RwLock::new(powers)),
+            h,
+            beta_h,
+            supported_degree_bounds,
+            inverse_neg_powers_of_beta_h,
+            prepared_h: h.prepare(),
+            prepared_beta_h: beta_h.prepare(),
+        })
+    }
+}
+
+/// Reads `num_elements` group elements from the given reader, checking that they are in the prime-order subgroup.
+fn read_elements<G: CanonicalDeserialize, R: Read>(
+    reader: &mut R,
+    num_elements: usize,
+    compress: Compress,
+) -> Result<Vec<G>> {
+    Ok((0..num_elements)
+        .map(|_| G::deserialize_with_mode(&mut *reader, compress, Validate::Yes))
+        .collect::<Result<_, _>>()?)
+}
+
+/// Returns the pairs of consecutive powers `(P_i, P_{i+1})` in the given sequences of powers, as two vectors.
+fn consecutive_powers<G: AffineCurve>(sequences: &[&[G]]) -> (Vec<G>, Vec<G>) {
+    let lower = sequences.iter().flat_map(|powers| &powers[..powers.len() - 1]).copied().collect();
+    let upper = sequences.iter().flat_map(|powers| &powers[1..]).copied().collect();
+    (lower, upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        fft::DensePolynomial,
+        polycommit::sonic_pc::{Evaluations, LabeledPolynomial, QuerySet, SonicKZG10},
+        AlgebraicSponge,
+    };
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, Fq, Fr, G1Projective, G2Projective},
+        ProjectiveCurve,
+    };
+    use snarkvm_fields::One;
+    use snarkvm_utilities::rand::TestRng;
+
+    type PowersOfTauBls12_377 = PowersOfTau<Bls12_377>;
+    type Sponge = PoseidonSponge<Fq, 2, 1>;
+    type PC = SonicKZG10<Bls12_377, Sponge>;
+
+    /// The number of powers in the transcript fixture.
+    const FIXTURE_NUM_POWERS: usize = 16;
+    /// The seed of the secrets in the transcript fixture.
+    const FIXTURE_SEED: u64 = 1245897092;
+
+    /// A transcript of 16 powers, uncompressed, as output by the final round of a phase 1 ceremony.
+    const FIXTURE: &[u8] = include_bytes!("resources/powers_of_tau_16");
+
+    /// Returns the bytes of a transcript of `num_powers` powers for randomly-sampled secrets.
+    fn sample_transcript_bytes(num_powers: usize, compress: Compress, rng: &mut TestRng) -> Vec<u8> {
+        let (tau, alpha, beta) = (Fr::rand(rng), Fr::rand(rng), Fr::rand(rng));
+        let (g, h) = (G1Projective::rand(rng), G2Projective::rand(rng));
+        let powers_of_tau = (0..2 * num_powers - 1)
+            .scan(Fr::one(), |power, _| {
+                let current = *power;
+                *power *= tau;
+                Some(current)
+            })
+            .collect::<Vec<_>>();
+
+        let mut bytes = vec![0u8; TRANSCRIPT_HASH_SIZE];
+        rng.fill_bytes(&mut bytes);
+        let g1_elements = powers_of_tau
+            .iter()
+            .map(|power| g * *power)
+            .chain(powers_of_tau[..num_powers].iter().map(|power| g * (alpha * power)))
+            .chain(powers_of_tau[..num_powers].iter().map(|power| g * (beta * power)))
+            .collect::<Vec<_>>();
+        let g2_elements =
+            powers_of_tau[..num_powers].iter().map(|power| h * *power).chain([h * beta]).collect::<Vec<_>>();
+        let g1_elements = G1Projective::batch_normalization_into_affine(g1_elements);
+        let g2_elements = G2Projective::batch_normalization_into_affine(g2_elements);
+
+        // Write the elements in the order of the transcript layout.
+        let (tau_powers_g1, other_g1_elements) = g1_elements.split_at(2 * num_powers - 1);
+        let (tau_powers_g2, beta_h) = g2_elements.split_at(num_powers);
+        let (alpha_tau_powers_g1, beta_tau_powers_g1) = other_g1_elements.split_at(num_powers);
+        tau_powers_g1.iter().for_each(|element| element.serialize_with_mode(&mut bytes, compress).unwrap());
+        tau_powers_g2.iter().for_each(|element| element.serialize_with_mode(&mut bytes, compress).unwrap());
+        alpha_tau_powers_g1.iter().for_each(|element| element.serialize_with_mode(&mut bytes, compress).unwrap());
+        beta_tau_powers_g1.iter().for_each(|element| element.serialize_with_mode(&mut bytes, compress).unwrap());
+        beta_h[0].serialize_with_mode(&mut bytes, compress).unwrap();
+        bytes
+    }
+
+    /// Commits to a hiding polynomial with a degree bound, and checks an opening against the given parameters.
+    fn check_opening(pp: &UniversalParams<Bls12_377>, rng: &mut TestRng) -> bool {
+        let degree_bound = *pp.supported_degree_bounds().iter().rev().find(|bound| **bound < pp.max_degree()).unwrap();
+        let polynomial = LabeledPolynomial::new(
+            "test".to_string(),
+            DensePolynomial::rand(degree_bound, rng),
+            Some(degree_bound),
+            Some(1),
+        );
+        let (ck, vk) = PC::trim(pp, pp.max_degree(), None, 1, Some(&[degree_bound])).unwrap();
+        let (commitments, randomness) = PC::commit(&ck, [(&polynomial).into()], Some(rng)).unwrap();
+
+        let point = Fr::rand(rng);
+        let mut query_set = QuerySet::new();
+        query_set.insert(("test".to_string(), ("point".to_string(), point)));
+        let mut evaluations = Evaluations::new();
+        evaluations.insert(("test".to_string(), point), polynomial.evaluate(point));
+
+        let polynomials = [polynomial];
+        let proof =
+            PC::batch_open(&ck, &polynomials, &commitments, &query_set, &randomness, &mut Sponge::new()).unwrap();
+        PC::batch_check(&vk, &commitments, &query_set, &evaluations, &proof, &mut Sponge::new()).unwrap()
+    }
+
+    #[test]
+    fn test_powers_of_tau_fixture() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the fixture matches the transcript layout.
+        let expected = sample_transcript_bytes(FIXTURE_NUM_POWERS, Compress::No, &mut TestRng::fixed(FIXTURE_SEED));
+        assert_eq!(FIXTURE, expected.as_slice());
+
+        let transcript = PowersOfTauBls12_377::read(FIXTURE, FIXTURE_NUM_POWERS, Compress::No).unwrap();
+        assert_eq!(transcript.tau_powers_g1.len(), 2 * FIXTURE_NUM_POWERS - 1);
+        assert_eq!(transcript.max_degree(), FIXTURE_NUM_POWERS - 2);
+        transcript.verify(rng).unwrap();
+
+        // Ensure the derived parameters support hiding commitments with degree bounds.
+        for max_degree in [1, 7, FIXTURE_NUM_POWERS - 2] {
+            let pp = transcript.clone().into_universal_params(max_degree).unwrap();
+            assert_eq!(pp.max_degree(), max_degree);
+            assert_eq!(pp.supported_degree_bounds(), KZG10DegreeBoundsConfig::MARLIN.get_list::<Fr>(max_degree));
+            if max_degree > 1 {
+                assert!(check_opening(&pp, rng));
+            }
+        }
+        assert!(transcript.into_universal_params(FIXTURE_NUM_POWERS - 1).is_err());
+
+        // Ensure a truncated fixture is rejected.
+        assert!(PowersOfTauBls12_377::read(&FIXTURE[..FIXTURE.len() - 1], FIXTURE_NUM_POWERS, Compress::No).is_err());
+    }
+
+    #[test]
+    fn test_powers_of_tau_compressed() {
+        let rng = &mut TestRng::default();
+
+        for num_powers in [2, 5, 32] {
+            let bytes = sample_transcript_bytes(num_powers, Compress::Yes, rng);
+            let transcript = PowersOfTauBls12_377::read(&bytes[..], num_powers, Compress::Yes).unwrap();
+            transcript.verify(rng).unwrap();
+
+            let pp = transcript.into_universal_params(num_powers - 2).ok();
+            assert_eq!(pp.is_some(), num_powers > 2);
+        }
+    }
+
+    #[test]
+    fn test_powers_of_tau_rejects_inconsistent_powers() {
+        let rng = &mut TestRng::default();
+        let transcript = PowersOfTauBls12_377::read(FIXTURE, FIXTURE_NUM_POWERS, Compress::No).unwrap();
+
+        // Tamper with a power of tau G.
+        let mut candidate = transcript.clone();
+        candidate.tau_powers_g1[5] = (candidate.tau_powers_g1[5] * Fr::from(2u64)).to_affine();
+        assert!(candidate.verify(rng).is_err());
+
+        // Tamper with the highest power of tau G.
+        let mut candidate = transcript.clone();
+        let index = candidate.tau_powers_g1.len() - 1;
+        candidate.tau_powers_g1[index] = (candidate.tau_powers_g1[index] * Fr::from(2u64)).to_affine();
+        assert!(candidate.verify(rng).is_err());
+
+        // Tamper with a power of alpha * tau G.
+        let mut candidate = transcript.clone();
+        candidate.alpha_tau_powers_g1.swap(3, 4);
+        assert!(candidate.verify(rng).is_err());
+
+        // Tamper with a power of tau H.
+        let mut candidate = transcript.clone();
+        candidate.tau_powers_g2[7] = G2Projective::rand(rng).to_affine();
+        assert!(candidate.verify(rng).is_err());
+
+        // Remove a power of alpha * tau G.
+        let mut candidate = transcript;
+        candidate.alpha_tau_powers_g1.pop();
+        assert!(candidate.verify(rng).is_err());
+    }
+}
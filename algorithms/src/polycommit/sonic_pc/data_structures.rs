@@ -17,7 +17,7 @@
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10, Prepare};
 use hashbrown::HashMap;
 use snarkvm_curves::{PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use std::{
@@ -755,6 +755,54 @@ pub fn evaluate_query_set<'a, F: PrimeField>(
     evaluations
 }
 
+/// Accumulates the pairing checks of independent evaluation proofs,
+/// so that all of them can be verified with a single multi-pairing.
+#[derive(Clone, Debug)]
+pub struct PairingAccumulator<E: PairingEngine> {
+    /// The verification key shared by every accumulated check.
+    pub(super) vk: Option<kzg10::VerifierKey<E>>,
+    /// The G1 elements that are paired with the degree-bound shifts.
+    pub(super) g1_projective_elems: Vec<E::G1Projective>,
+    /// The degree-bound shifts (or the generator of G2) for each G1 element.
+    pub(super) g2_prepared_elems: Vec<<E::G2Affine as PairingCurve>::Prepared>,
+    /// The combined witness, which is paired with `beta_h`.
+    pub(super) combined_witness: E::G1Projective,
+    /// The combined adjusted witness, which is paired with `h`.
+    pub(super) combined_adjusted_witness: E::G1Projective,
+    /// The number of accumulated checks.
+    pub(super) num_checks: usize,
+}
+
+impl<E: PairingEngine> Default for PairingAccumulator<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: PairingEngine> PairingAccumulator<E> {
+    /// Initializes an empty accumulator.
+    pub fn new() -> Self {
+        Self {
+            vk: None,
+            g1_projective_elems: Vec::new(),
+            g2_prepared_elems: Vec::new(),
+            combined_witness: E::G1Projective::zero(),
+            combined_adjusted_witness: E::G1Projective::zero(),
+            num_checks: 0,
+        }
+    }
+
+    /// Returns the number of accumulated checks.
+    pub fn num_checks(&self) -> usize {
+        self.num_checks
+    }
+
+    /// Returns `true` if no checks have been accumulated.
+    pub fn is_empty(&self) -> bool {
+        self.num_checks == 0
+    }
+}
+
 /// A proof of satisfaction of linear combinations.
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct BatchLCProof<E: PairingEngine> {
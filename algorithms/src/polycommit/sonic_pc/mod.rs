@@ -20,6 +20,7 @@ use crate::{
     polycommit::{kzg10, optional_rng::OptionalRng, PCError},
     AlgebraicSponge,
 };
+use anyhow::anyhow;
 use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
@@ -411,56 +412,30 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     where
         Commitment<E>: 'a,
     {
-        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
-        let batch_check_time = start_timer!(|| format!(
-            "Checking {} commitments at query set of size {}",
-            commitments.len(),
-            query_set.len(),
-        ));
-        let mut query_to_labels_map = BTreeMap::new();
-
-        for (label, (point_name, point)) in query_set.iter() {
-            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
-            labels.1.insert(label);
-        }
-
-        assert_eq!(proof.0.len(), query_to_labels_map.len());
-
-        let mut randomizer = E::Fr::one();
+        let batch_check_time =
+            start_timer!(|| format!("Checking commitments at query set of size {}", query_set.len()));
 
         let mut combined_comms = BTreeMap::new();
         let mut combined_witness = E::G1Projective::zero();
         let mut combined_adjusted_witness = E::G1Projective::zero();
+        let mut randomizer = E::Fr::one();
 
-        for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
-            let mut comms_to_combine: Vec<&'_ LabeledCommitment<_>> = Vec::new();
-            let mut values_to_combine = Vec::new();
-            for label in labels.into_iter() {
-                let commitment =
-                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
-
-                let v_i = values
-                    .get(&(label.clone(), *query))
-                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
-
-                comms_to_combine.push(commitment);
-                values_to_combine.push(*v_i);
-            }
-
-            Self::accumulate_elems(
-                &mut combined_comms,
-                &mut combined_witness,
-                &mut combined_adjusted_witness,
-                vk,
-                comms_to_combine.into_iter(),
-                *query,
-                values_to_combine.into_iter(),
-                p,
-                Some(randomizer),
-                fs_rng,
-            );
-
-            randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+        let mut proofs = proof.0.iter();
+        Self::accumulate_query_set(
+            &mut combined_comms,
+            &mut combined_witness,
+            &mut combined_adjusted_witness,
+            &mut randomizer,
+            vk,
+            commitments,
+            query_set,
+            values,
+            &mut proofs,
+            fs_rng,
+        )?;
+        // Ensure every evaluation proof was consumed.
+        if proofs.next().is_some() {
+            return Ok(false);
         }
 
         let result = Self::check_elems(combined_comms, combined_witness, combined_adjusted_witness, vk);
@@ -561,6 +536,227 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Commitment<E>: 'a,
     {
         let BatchLCProof { proof, .. } = proof;
+        let (lc_commitments, evaluations) =
+            Self::combine_lc_commitments(linear_combinations, commitments, evaluations)?;
+        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Opens the linear combinations of several independent sets of polynomials (e.g. those of distinct circuits)
+    /// in a single proof. Each set is opened under its own committer key, and labels need only be unique within a set.
+    #[allow(clippy::type_complexity)]
+    pub fn open_combinations_batch<'a, LCs, Polys, Comms, Rands>(
+        sets: impl IntoIterator<Item = (&'a CommitterKey<E>, LCs, Polys, Comms, &'a QuerySet<'a, E::Fr>, Rands)>,
+        fs_rng: &mut S,
+    ) -> Result<BatchLCProof<E>, PCError>
+    where
+        LCs: IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        Polys: IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        Comms: IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        Rands: IntoIterator<Item = &'a Randomness<E>>,
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        let mut proofs = Vec::new();
+        for (ck, linear_combinations, polynomials, commitments, query_set, rands) in sets {
+            let BatchLCProof { proof, .. } =
+                Self::open_combinations(ck, linear_combinations, polynomials, commitments, query_set, rands, fs_rng)?;
+            proofs.extend(proof.0);
+        }
+        Ok(BatchLCProof { proof: BatchProof(proofs), evaluations: None })
+    }
+
+    /// Checks a proof produced by `open_combinations_batch`, using a single pairing product for all sets.
+    /// Every verifier key must be derived from the same universal parameters.
+    #[allow(clippy::type_complexity)]
+    pub fn check_combinations_batch<'a, LCs, Comms>(
+        sets: impl IntoIterator<
+            Item = (&'a VerifierKey<E>, LCs, Comms, &'a QuerySet<'a, E::Fr>, &'a Evaluations<'a, E::Fr>),
+        >,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError>
+    where
+        LCs: IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        Comms: IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        Commitment<E>: 'a,
+    {
+        let batch_check_time = start_timer!(|| "Checking a batch of linear combinations");
+        let BatchLCProof { proof, .. } = proof;
+
+        let mut g1_projective_elems = Vec::new();
+        let mut g2_prepared_elems = Vec::new();
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
+        let mut randomizer = E::Fr::one();
+
+        let mut proofs = proof.0.iter();
+        let mut reference_vk: Option<&VerifierKey<E>> = None;
+        for (vk, linear_combinations, commitments, query_set, evaluations) in sets {
+            // Ensure the verifier keys share the same generators.
+            match reference_vk {
+                Some(reference_vk) if reference_vk.vk != vk.vk => return Ok(false),
+                Some(_) => (),
+                None => reference_vk = Some(vk),
+            }
+
+            let (lc_commitments, evaluations) =
+                Self::combine_lc_commitments(linear_combinations, commitments, evaluations)?;
+
+            // The degree bounds are enforced with the shifts of each set's own verifier key.
+            let mut combined_comms = BTreeMap::new();
+            Self::accumulate_query_set(
+                &mut combined_comms,
+                &mut combined_witness,
+                &mut combined_adjusted_witness,
+                &mut randomizer,
+                vk,
+                &lc_commitments,
+                query_set,
+                &evaluations,
+                &mut proofs,
+                fs_rng,
+            )?;
+            Self::pair_combined_comms(&mut g1_projective_elems, &mut g2_prepared_elems, combined_comms, vk)?;
+        }
+
+        // Ensure every evaluation proof was consumed.
+        let result = match (reference_vk, proofs.next()) {
+            (Some(vk), None) => Self::check_paired_elems(
+                g1_projective_elems,
+                g2_prepared_elems,
+                combined_witness,
+                combined_adjusted_witness,
+                vk,
+            ),
+            _ => Ok(false),
+        };
+        end_timer!(batch_check_time);
+        result
+    }
+
+    /// Accumulates the check of a proof produced by `open_combinations` into `accumulator`, scaled by `scalar`.
+    /// The accumulated checks are then verified together with `check_accumulator`.
+    ///
+    /// The `scalar` must be sampled by the verifier independently of the proof,
+    /// so that an invalid proof cannot cancel out against the other accumulated checks.
+    #[allow(clippy::too_many_arguments)]
+    pub fn accumulate_combinations<'a>(
+        accumulator: &mut PairingAccumulator<E>,
+        vk: &VerifierKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        scalar: E::Fr,
+        fs_rng: &mut S,
+    ) -> Result<(), PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        // Ensure the verifier key shares the generators of the previously accumulated checks.
+        match &accumulator.vk {
+            Some(reference_vk) if reference_vk != &vk.vk => {
+                return Err(anyhow!("Cannot accumulate checks from different universal parameters").into());
+            }
+            Some(_) => (),
+            None => accumulator.vk = Some(vk.vk.clone()),
+        }
+
+        let BatchLCProof { proof, .. } = proof;
+        let (lc_commitments, evaluations) =
+            Self::combine_lc_commitments(linear_combinations, commitments, evaluations)?;
+
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
+        let mut randomizer = E::Fr::one();
+
+        let mut proofs = proof.0.iter();
+        Self::accumulate_query_set(
+            &mut combined_comms,
+            &mut combined_witness,
+            &mut combined_adjusted_witness,
+            &mut randomizer,
+            vk,
+            &lc_commitments,
+            query_set,
+            &evaluations,
+            &mut proofs,
+            fs_rng,
+        )?;
+        if proofs.next().is_some() {
+            return Err(anyhow!("Found more evaluation proofs than points in the query set").into());
+        }
+
+        // Scale the check by the given scalar, and add it to the accumulator.
+        combined_comms.values_mut().for_each(|comm| *comm *= scalar);
+        Self::pair_combined_comms(
+            &mut accumulator.g1_projective_elems,
+            &mut accumulator.g2_prepared_elems,
+            combined_comms,
+            vk,
+        )?;
+        accumulator.combined_witness += combined_witness * scalar;
+        accumulator.combined_adjusted_witness += combined_adjusted_witness * scalar;
+        accumulator.num_checks += 1;
+        Ok(())
+    }
+
+    /// Verifies every check in `accumulator` with a single multi-pairing.
+    pub fn check_accumulator(accumulator: PairingAccumulator<E>) -> Result<bool, PCError> {
+        let PairingAccumulator {
+            vk,
+            g1_projective_elems,
+            g2_prepared_elems,
+            combined_witness,
+            combined_adjusted_witness,
+            ..
+        } = accumulator;
+
+        match vk {
+            Some(vk) => {
+                let check_time = start_timer!(|| "Checking the accumulated pairings");
+                let mut g1_prepared_elems = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
+                    .into_iter()
+                    .map(|a| a.prepare())
+                    .collect::<Vec<_>>();
+                let mut g2_prepared_elems = g2_prepared_elems;
+
+                let [adjusted_witness, witness] =
+                    <[_; 2]>::try_from(E::G1Projective::batch_normalization_into_affine(vec![
+                        -combined_adjusted_witness,
+                        -combined_witness,
+                    ]))
+                    .map_err(|_| anyhow!("Failed to normalize the accumulated witnesses"))?;
+                g1_prepared_elems.push(adjusted_witness.prepare());
+                g2_prepared_elems.push(vk.prepared_h.clone());
+                g1_prepared_elems.push(witness.prepare());
+                g2_prepared_elems.push(vk.prepared_beta_h);
+
+                let g1_g2_prepared = g1_prepared_elems.iter().zip_eq(g2_prepared_elems.iter());
+                let is_one: bool = E::product_of_pairings(g1_g2_prepared).is_one();
+                end_timer!(check_time);
+                Ok(is_one)
+            }
+            // There is nothing to check.
+            None => Ok(true),
+        }
+    }
+}
+
+impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
+    /// Combines the commitments of each linear combination, and adjusts the given evaluations
+    /// to account for the constant terms of the linear combinations.
+    #[allow(clippy::type_complexity)]
+    fn combine_lc_commitments<'a>(
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        evaluations: &Evaluations<E::Fr>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Evaluations<'a, E::Fr>), PCError>
+    where
+        Commitment<E>: 'a,
+    {
         let label_comm_map = commitments.into_iter().map(|c| (c.label(), c)).collect::<BTreeMap<_, _>>();
 
         let mut lc_commitments = Vec::new();
@@ -612,11 +808,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Ok((lc_commitments, evaluations))
     }
-}
 
-impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     fn combine_polynomials<'a>(
         coeffs_polys_rands: impl IntoIterator<Item = (E::Fr, &'a DensePolynomial<E::Fr>, &'a Randomness<E>)>,
     ) -> (DensePolynomial<E::Fr>, Randomness<E>) {
@@ -703,17 +897,76 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         end_timer!(acc_time);
     }
 
+    /// Accumulates the openings of `commitments` at every point in `query_set`,
+    /// consuming one evaluation proof from `proofs` per point.
+    #[allow(clippy::too_many_arguments)]
+    fn accumulate_query_set<'a, 'b>(
+        combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
+        combined_witness: &mut E::G1Projective,
+        combined_adjusted_witness: &mut E::G1Projective,
+        randomizer: &mut E::Fr,
+        vk: &VerifierKey<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proofs: &mut impl Iterator<Item = &'b kzg10::Proof<E>>,
+        fs_rng: &mut S,
+    ) -> Result<(), PCError>
+    where
+        Commitment<E>: 'a,
+        kzg10::Proof<E>: 'b,
+    {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
+        let mut query_to_labels_map = BTreeMap::new();
+
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        for (_query_name, (query, labels)) in query_to_labels_map.into_iter() {
+            let p = proofs.next().ok_or_else(|| anyhow!("Missing an evaluation proof for the query set"))?;
+
+            let mut comms_to_combine: Vec<&'_ LabeledCommitment<_>> = Vec::new();
+            let mut values_to_combine = Vec::new();
+            for label in labels.into_iter() {
+                let commitment =
+                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+
+                let v_i = values
+                    .get(&(label.clone(), *query))
+                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+
+                comms_to_combine.push(commitment);
+                values_to_combine.push(*v_i);
+            }
+
+            Self::accumulate_elems(
+                combined_comms,
+                combined_witness,
+                combined_adjusted_witness,
+                vk,
+                comms_to_combine.into_iter(),
+                *query,
+                values_to_combine.into_iter(),
+                p,
+                Some(*randomizer),
+                fs_rng,
+            );
+
+            *randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+        }
+        Ok(())
+    }
+
+    /// Pairs each of the `combined_comms` with the G2 element that enforces its degree bound.
     #[allow(clippy::type_complexity)]
-    fn check_elems(
+    fn pair_combined_comms(
+        g1_projective_elems: &mut Vec<E::G1Projective>,
+        g2_prepared_elems: &mut Vec<<E::G2Affine as PairingCurve>::Prepared>,
         combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: E::G1Projective,
-        combined_adjusted_witness: E::G1Projective,
         vk: &VerifierKey<E>,
-    ) -> Result<bool, PCError> {
-        let check_time = start_timer!(|| "Checking elems");
-        let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
-        let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
-
+    ) -> Result<(), PCError> {
         for (degree_bound, comm) in combined_comms.into_iter() {
             let shift_power = if let Some(degree_bound) = degree_bound {
                 vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
@@ -724,7 +977,36 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             g1_projective_elems.push(comm);
             g2_prepared_elems.push(shift_power);
         }
+        Ok(())
+    }
+
+    fn check_elems(
+        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+        combined_witness: E::G1Projective,
+        combined_adjusted_witness: E::G1Projective,
+        vk: &VerifierKey<E>,
+    ) -> Result<bool, PCError> {
+        let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
+        let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
+        Self::pair_combined_comms(&mut g1_projective_elems, &mut g2_prepared_elems, combined_comms, vk)?;
+        Self::check_paired_elems(
+            g1_projective_elems,
+            g2_prepared_elems,
+            combined_witness,
+            combined_adjusted_witness,
+            vk,
+        )
+    }
 
+    #[allow(clippy::type_complexity)]
+    fn check_paired_elems(
+        mut g1_projective_elems: Vec<E::G1Projective>,
+        mut g2_prepared_elems: Vec<<E::G2Affine as PairingCurve>::Prepared>,
+        combined_witness: E::G1Projective,
+        combined_adjusted_witness: E::G1Projective,
+        vk: &VerifierKey<E>,
+    ) -> Result<bool, PCError> {
+        let check_time = start_timer!(|| "Checking elems");
         g1_projective_elems.push(-combined_adjusted_witness);
         g2_prepared_elems.push(vk.vk.prepared_h.clone());
 
@@ -748,7 +1030,7 @@ mod tests {
     #![allow(non_camel_case_types)]
 
     use super::{CommitterKey, SonicKZG10};
-    use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::*};
+    use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::*, AlgebraicSponge};
     use snarkvm_curves::bls12_377::{Bls12_377, Fq};
     use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
 
@@ -781,6 +1063,25 @@ mod tests {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_batch_check_rejects_extra_proofs() {
+        for component in single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377") {
+            let TestComponents { verification_key, commitments, query_set, evaluations, batch_proof, .. } = component;
+            let mut proof = batch_proof.unwrap();
+            proof.0.push(proof.0[0]);
+
+            let result = PC_Bls12_377::batch_check(
+                &verification_key,
+                &commitments,
+                &query_set,
+                &evaluations,
+                &proof,
+                &mut Sponge::new(),
+            );
+            assert!(!result.unwrap());
+        }
+    }
+
     #[test]
     fn test_quadratic_poly_degree_bound_multiple_queries() {
         quadratic_poly_degree_bound_multiple_queries_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{
+    errors::SynthesisError,
+    ConstraintSynthesizer,
+    ConstraintSystem as CS,
+    Index as VarIndex,
+    LinearCombination,
+    Variable,
+};
+
+/// Stores the constraints of a circuit and, when proving, the assignment to its variables.
+pub(crate) struct ConstraintSystem<F: Field> {
+    pub(crate) a: Vec<Vec<(F, VarIndex)>>,
+    pub(crate) b: Vec<Vec<(F, VarIndex)>>,
+    pub(crate) c: Vec<Vec<(F, VarIndex)>>,
+    pub(crate) public_variables: Vec<F>,
+    pub(crate) private_variables: Vec<F>,
+    pub(crate) num_public_variables: usize,
+    pub(crate) num_private_variables: usize,
+    setup_mode: bool,
+}
+
+impl<F: Field> ConstraintSystem<F> {
+    /// Synthesizes the given circuit. In setup mode, no assignments are computed.
+    ///
+    /// After synthesis, a constraint `input_i * 0 = 0` is added for every public input,
+    /// which makes the polynomials of the public inputs linearly independent.
+    pub(crate) fn synthesize<C: ConstraintSynthesizer<F>>(
+        circuit: &C,
+        setup_mode: bool,
+    ) -> Result<Self, SynthesisError> {
+        let mut cs = Self {
+            a: Vec::new(),
+            b: Vec::new(),
+            c: Vec::new(),
+            public_variables: if setup_mode { Vec::new() } else { vec![F::one()] },
+            private_variables: Vec::new(),
+            num_public_variables: 1,
+            num_private_variables: 0,
+            setup_mode,
+        };
+        circuit.generate_constraints(&mut cs)?;
+
+        for i in 0..cs.num_public_variables {
+            cs.a.push(vec![(F::one(), VarIndex::Public(i))]);
+            cs.b.push(Vec::new());
+            cs.c.push(Vec::new());
+        }
+        Ok(cs)
+    }
+
+    /// Returns the total number of variables, including the constant `one` variable.
+    pub(crate) fn num_variables(&self) -> usize {
+        self.num_public_variables + self.num_private_variables
+    }
+
+    /// Returns the position of the given variable, where public variables precede private ones.
+    pub(crate) fn variable_position(&self, index: VarIndex) -> usize {
+        match index {
+            VarIndex::Public(i) => i,
+            VarIndex::Private(i) => self.num_public_variables + i,
+        }
+    }
+
+    /// Evaluates the given row under the assignment of the constraint system.
+    pub(crate) fn evaluate_row(&self, row: &[(F, VarIndex)]) -> F {
+        row.iter()
+            .map(|(coeff, index)| match index {
+                VarIndex::Public(i) => self.public_variables[*i] * coeff,
+                VarIndex::Private(i) => self.private_variables[*i] * coeff,
+            })
+            .sum()
+    }
+
+    #[inline]
+    fn make_row(l: &LinearCombination<F>) -> Vec<(F, VarIndex)> {
+        l.as_ref().iter().map(|(var, coeff)| (*coeff, var.get_unchecked())).collect()
+    }
+}
+
+impl<F: Field> CS<F> for ConstraintSystem<F> {
+    type Root = Self;
+
+    #[inline]
+    fn alloc<Fn, A, AR>(&mut self, _: A, f: Fn) -> Result<Variable, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        if !self.setup_mode {
+            self.private_variables.push(f()?);
+        }
+        let index = self.num_private_variables;
+        self.num_private_variables += 1;
+
+        Ok(Variable::new_unchecked(VarIndex::Private(index)))
+    }
+
+    #[inline]
+    fn alloc_input<Fn, A, AR>(&mut self, _: A, f: Fn) -> Result<Variable, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        if !self.setup_mode {
+            self.public_variables.push(f()?);
+        }
+        let index = self.num_public_variables;
+        self.num_public_variables += 1;
+
+        Ok(Variable::new_unchecked(VarIndex::Public(index)))
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.a.push(Self::make_row(&a(LinearCombination::zero())));
+        self.b.push(Self::make_row(&b(LinearCombination::zero())));
+        self.c.push(Self::make_row(&c(LinearCombination::zero())));
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn pop_namespace(&mut self) {
+        // Do nothing; we don't care about namespaces in this context.
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.a.len()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.num_public_variables
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.num_private_variables
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        self.setup_mode
+    }
+}
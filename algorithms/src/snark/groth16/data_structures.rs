@@ -0,0 +1,226 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Prepare;
+use snarkvm_curves::{PairingCurve, PairingEngine};
+use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+    ToMinimalBits,
+};
+
+/// A Groth16 proof.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<E: PairingEngine> {
+    pub a: E::G1Affine,
+    pub b: E::G2Affine,
+    pub c: E::G1Affine,
+}
+
+impl<E: PairingEngine> ToBytes for Proof<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize Groth16 proof"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Proof<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize Groth16 proof"))
+    }
+}
+
+/// A batch of Groth16 proofs, one for each instance that was proven together.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchProof<E: PairingEngine> {
+    proofs: Vec<Proof<E>>,
+}
+
+impl<E: PairingEngine> BatchProof<E> {
+    /// Initializes a new batch proof, ensuring it contains at least one proof.
+    pub fn new(proofs: Vec<Proof<E>>) -> Result<Self, crate::SNARKError> {
+        match proofs.is_empty() {
+            true => Err(crate::SNARKError::EmptyBatch),
+            false => Ok(Self { proofs }),
+        }
+    }
+
+    /// Returns the proofs in the batch.
+    pub fn proofs(&self) -> &[Proof<E>] {
+        &self.proofs
+    }
+
+    /// Returns the number of proofs in the batch.
+    pub fn len(&self) -> usize {
+        self.proofs.len()
+    }
+
+    /// Returns `true` if the batch has no proofs.
+    pub fn is_empty(&self) -> bool {
+        self.proofs.is_empty()
+    }
+}
+
+impl<E: PairingEngine> From<Proof<E>> for BatchProof<E> {
+    fn from(proof: Proof<E>) -> Self {
+        Self { proofs: vec![proof] }
+    }
+}
+
+impl<E: PairingEngine> ToBytes for BatchProof<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize Groth16 batch proof"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for BatchProof<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        let batch = Self::deserialize_compressed(r).map_err(|_| error("could not deserialize Groth16 batch proof"))?;
+        match batch.is_empty() {
+            true => Err(error("a Groth16 batch proof must contain at least one proof")),
+            false => Ok(batch),
+        }
+    }
+}
+
+/// A verifying key for a specific circuit.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct VerifyingKey<E: PairingEngine> {
+    pub alpha_g1: E::G1Affine,
+    pub beta_g2: E::G2Affine,
+    pub gamma_g2: E::G2Affine,
+    pub delta_g2: E::G2Affine,
+    /// The `(beta * a_i(t) + alpha * b_i(t) + c_i(t)) / gamma` terms for the public inputs,
+    /// starting with the constant `one` variable.
+    pub gamma_abc_g1: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> Prepare for VerifyingKey<E> {
+    type Prepared = PreparedVerifyingKey<E>;
+
+    /// Prepare the verifying key.
+    fn prepare(&self) -> Self::Prepared {
+        PreparedVerifyingKey {
+            alpha_g1_beta_g2: E::pairing(self.alpha_g1, self.beta_g2),
+            gamma_g2_neg_pc: (-self.gamma_g2).prepare(),
+            delta_g2_neg_pc: (-self.delta_g2).prepare(),
+            orig_vk: self.clone(),
+        }
+    }
+}
+
+impl<E: PairingEngine> From<ProvingKey<E>> for VerifyingKey<E> {
+    fn from(other: ProvingKey<E>) -> Self {
+        other.vk
+    }
+}
+
+impl<'a, E: PairingEngine> From<&'a ProvingKey<E>> for VerifyingKey<E> {
+    fn from(other: &'a ProvingKey<E>) -> Self {
+        other.vk.clone()
+    }
+}
+
+impl<E: PairingEngine> From<PreparedVerifyingKey<E>> for VerifyingKey<E> {
+    fn from(other: PreparedVerifyingKey<E>) -> Self {
+        other.orig_vk
+    }
+}
+
+impl<E: PairingEngine> ToBytes for VerifyingKey<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize Groth16 VerifyingKey"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for VerifyingKey<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize Groth16 VerifyingKey"))
+    }
+}
+
+impl<E: PairingEngine> ToMinimalBits for VerifyingKey<E> {
+    fn to_minimal_bits(&self) -> Vec<bool> {
+        [
+            self.alpha_g1.to_minimal_bits(),
+            self.beta_g2.to_minimal_bits(),
+            self.gamma_g2.to_minimal_bits(),
+            self.delta_g2.to_minimal_bits(),
+            self.gamma_abc_g1.to_minimal_bits(),
+        ]
+        .concat()
+    }
+}
+
+impl<E: PairingEngine> ToConstraintField<E::Fq> for VerifyingKey<E> {
+    fn to_field_elements(&self) -> Result<Vec<E::Fq>, ConstraintFieldError> {
+        let mut res = Vec::new();
+        res.append(&mut self.alpha_g1.to_field_elements()?);
+        res.append(&mut self.beta_g2.to_field_elements()?);
+        res.append(&mut self.gamma_g2.to_field_elements()?);
+        res.append(&mut self.delta_g2.to_field_elements()?);
+        for point in self.gamma_abc_g1.iter() {
+            res.append(&mut point.to_field_elements()?);
+        }
+        Ok(res)
+    }
+}
+
+/// A verifying key with its pairing-friendly precomputations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedVerifyingKey<E: PairingEngine> {
+    /// The result of `e(alpha_g1, beta_g2)`.
+    pub alpha_g1_beta_g2: E::Fqk,
+    /// The prepared form of `-gamma_g2`.
+    pub gamma_g2_neg_pc: <E::G2Affine as PairingCurve>::Prepared,
+    /// The prepared form of `-delta_g2`.
+    pub delta_g2_neg_pc: <E::G2Affine as PairingCurve>::Prepared,
+    /// The original verifying key.
+    pub orig_vk: VerifyingKey<E>,
+}
+
+/// A proving key for a specific circuit.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ProvingKey<E: PairingEngine> {
+    pub vk: VerifyingKey<E>,
+    pub beta_g1: E::G1Affine,
+    pub delta_g1: E::G1Affine,
+    /// The `a_i(t)` terms, for every variable.
+    pub a_query: Vec<E::G1Affine>,
+    /// The `b_i(t)` terms in G1, for every variable.
+    pub b_g1_query: Vec<E::G1Affine>,
+    /// The `b_i(t)` terms in G2, for every variable.
+    pub b_g2_query: Vec<E::G2Affine>,
+    /// The `t^i * z(t) / delta` terms, for the coefficients of the quotient polynomial.
+    pub h_query: Vec<E::G1Affine>,
+    /// The `(beta * a_i(t) + alpha * b_i(t) + c_i(t)) / delta` terms for the private variables.
+    pub l_query: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> ToBytes for ProvingKey<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize Groth16 ProvingKey"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for ProvingKey<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize Groth16 ProvingKey"))
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    msm::FixedBase,
+    snark::groth16::{
+        constraint_system::ConstraintSystem,
+        r1cs_to_qap::{constraint_domain, evaluate_qap_at},
+        Groth16,
+        ProvingKey,
+        VerifyingKey,
+    },
+    AlgebraicSponge,
+    SNARKError,
+};
+use rand::{CryptoRng, Rng};
+use snarkvm_curves::{PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, FieldParameters, PrimeField, ToConstraintField};
+use snarkvm_r1cs::{ConstraintSynthesizer, SynthesisError};
+use snarkvm_utilities::{cfg_into_iter, Uniform};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, Input: ToConstraintField<E::Fr> + ?Sized> Groth16<E, FS, Input> {
+    /// Generates a proving key for the given circuit, sampling the trapdoor from `rng`.
+    ///
+    /// The trapdoor must be discarded after this call; anyone who knows it can forge proofs.
+    pub fn circuit_specific_setup<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        circuit: &C,
+        rng: &mut R,
+    ) -> Result<ProvingKey<E>, SNARKError> {
+        let setup_time = start_timer!(|| "Groth16::Setup");
+
+        let g1 = E::G1Projective::rand(rng);
+        let g2 = E::G2Projective::rand(rng);
+        let alpha = E::Fr::rand(rng);
+        let beta = E::Fr::rand(rng);
+        let gamma = E::Fr::rand(rng);
+        let delta = E::Fr::rand(rng);
+
+        let synthesis_time = start_timer!(|| "Constraint synthesis");
+        let cs = ConstraintSystem::synthesize(circuit, true)?;
+        end_timer!(synthesis_time);
+
+        let domain = constraint_domain(&cs)?;
+        let t = domain.sample_element_outside_domain(rng);
+
+        let reduction_time = start_timer!(|| "R1CS to QAP");
+        let qap = evaluate_qap_at(&cs, &domain, t);
+        end_timer!(reduction_time);
+
+        let gamma_inverse = gamma.inverse().ok_or(SynthesisError::UnexpectedIdentity)?;
+        let delta_inverse = delta.inverse().ok_or(SynthesisError::UnexpectedIdentity)?;
+
+        // Compute `(beta * a_i(t) + alpha * b_i(t) + c_i(t))` for every variable.
+        let abc = cfg_into_iter!(0..cs.num_variables())
+            .map(|i| beta * qap.a[i] + alpha * qap.b[i] + qap.c[i])
+            .collect::<Vec<_>>();
+        let (gamma_abc, l) = abc.split_at(cs.num_public_variables);
+        let gamma_abc = gamma_abc.iter().map(|x| *x * gamma_inverse).collect::<Vec<_>>();
+        let l = l.iter().map(|x| *x * delta_inverse).collect::<Vec<_>>();
+
+        // Compute `t^i * z(t) / delta` for the coefficients of the quotient polynomial.
+        let h = {
+            let mut h = Vec::with_capacity(domain.size() - 1);
+            let mut current = qap.z_t * delta_inverse;
+            for _ in 0..domain.size() - 1 {
+                h.push(current);
+                current *= &t;
+            }
+            h
+        };
+
+        let msm_time = start_timer!(|| "Compute the proving key queries");
+        let a_query = Self::batch_mul(g1, &qap.a);
+        let b_g1_query = Self::batch_mul(g1, &qap.b);
+        let b_g2_query = Self::batch_mul(g2, &qap.b);
+        let h_query = Self::batch_mul(g1, &h);
+        let l_query = Self::batch_mul(g1, &l);
+        let gamma_abc_g1 = Self::batch_mul(g1, &gamma_abc);
+        end_timer!(msm_time);
+
+        let vk = VerifyingKey::<E> {
+            alpha_g1: (g1 * alpha).to_affine(),
+            beta_g2: (g2 * beta).to_affine(),
+            gamma_g2: (g2 * gamma).to_affine(),
+            delta_g2: (g2 * delta).to_affine(),
+            gamma_abc_g1,
+        };
+
+        let proving_key = ProvingKey {
+            vk,
+            beta_g1: (g1 * beta).to_affine(),
+            delta_g1: (g1 * delta).to_affine(),
+            a_query,
+            b_g1_query,
+            b_g2_query,
+            h_query,
+            l_query,
+        };
+
+        end_timer!(setup_time);
+        Ok(proving_key)
+    }
+
+    /// Returns `base * scalar` for every scalar, using a fixed-base window table.
+    fn batch_mul<G: ProjectiveCurve>(base: G, scalars: &[G::ScalarField]) -> Vec<G::Affine> {
+        let scalar_bits = <G::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize;
+        let window = FixedBase::get_mul_window_size(scalars.len());
+        let table = FixedBase::get_window_table(scalar_bits, window, base);
+        G::batch_normalization_into_affine(FixedBase::msm(scalar_bits, window, &table, scalars))
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    snark::groth16::{BatchProof, PreparedVerifyingKey, ProvingKey, VerifyingKey},
+    AlgebraicSponge,
+    SNARKError,
+    SNARK,
+    SRS,
+};
+use rand::{CryptoRng, Rng};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_r1cs::ConstraintSynthesizer;
+
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use std::borrow::Borrow;
+
+/// The Groth16 proof system.
+///
+/// Groth16 requires a circuit-specific setup, and produces constant-size proofs
+/// that are verified with three pairings.
+#[derive(Clone, Debug)]
+pub struct Groth16<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, Input: ToConstraintField<E::Fr> + ?Sized>(
+    #[doc(hidden)] PhantomData<(E, FS, Input)>,
+);
+
+impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, Input: ToConstraintField<E::Fr> + ?Sized> Groth16<E, FS, Input> {
+    fn terminate(terminator: &AtomicBool) -> Result<(), SNARKError> {
+        if terminator.load(Ordering::Relaxed) {
+            Err(SNARKError::Terminated)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Samples the randomizers for batch verification, by absorbing the verifying key,
+    /// the public inputs, and the proofs into the Fiat-Shamir sponge.
+    fn batch_randomizers(
+        fs_parameters: &FS::Parameters,
+        verifying_key: &VerifyingKey<E>,
+        public_inputs: &[Vec<E::Fr>],
+        proof: &BatchProof<E>,
+    ) -> Result<Vec<E::Fr>, SNARKError> {
+        let mut sponge = FS::new_with_parameters(fs_parameters);
+        sponge.absorb_native_field_elements(&[verifying_key.to_field_elements()?]);
+        for (inputs, proof) in public_inputs.iter().zip(proof.proofs()) {
+            sponge.absorb_nonnative_field_elements(inputs.iter().copied());
+            sponge.absorb_native_field_elements(&[proof.a, proof.c]);
+            sponge.absorb_native_field_elements(&[proof.b]);
+        }
+        Ok(sponge.squeeze_short_nonnative_field_elements(proof.len()).into_vec())
+    }
+}
+
+impl<E: PairingEngine, FS, Input> SNARK for Groth16<E, FS, Input>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    Input: ToConstraintField<E::Fr> + ?Sized,
+{
+    type BaseField = E::Fq;
+    /// Groth16 verifying keys are produced by a trusted, circuit-specific setup,
+    /// so there is no certificate that a verifying key was derived correctly.
+    type Certificate = ();
+    type FSParameters = FS::Parameters;
+    type FiatShamirRng = FS;
+    type Proof = BatchProof<E>;
+    type ProvingKey = ProvingKey<E>;
+    type ScalarField = E::Fr;
+    type UniversalSetupConfig = ();
+    type UniversalSetupParameters = ();
+    type VerifierInput = Input;
+    type VerifyingKey = VerifyingKey<E>;
+
+    fn universal_setup<R: Rng + CryptoRng>(_: &(), _: &mut R) -> Result<(), SNARKError> {
+        Err(SNARKError::ExpectedCircuitSpecificSRS)
+    }
+
+    fn setup<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        circuit: &C,
+        srs: &mut SRS<R, Self::UniversalSetupParameters>,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
+        match srs {
+            SRS::CircuitSpecific(rng) => {
+                let proving_key = Self::circuit_specific_setup(circuit, rng)?;
+                let verifying_key = proving_key.vk.clone();
+                Ok((proving_key, verifying_key))
+            }
+            SRS::Universal(_) => Err(SNARKError::ExpectedCircuitSpecificSRS),
+        }
+    }
+
+    fn prove_vk(_: &Self::FSParameters, _: &Self::VerifyingKey, _: &Self::ProvingKey) -> Result<(), SNARKError> {
+        Err(SNARKError::Message("Groth16 does not support verifying key certificates".to_string()))
+    }
+
+    fn verify_vk<C: ConstraintSynthesizer<E::Fr>>(
+        _: &Self::FSParameters,
+        _: &C,
+        _: &Self::VerifyingKey,
+        _: &Self::Certificate,
+    ) -> Result<bool, SNARKError> {
+        Err(SNARKError::Message("Groth16 does not support verifying key certificates".to_string()))
+    }
+
+    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        _: &Self::FSParameters,
+        proving_key: &Self::ProvingKey,
+        circuits: &[C],
+        terminator: &AtomicBool,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        if circuits.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+
+        let mut proofs = Vec::with_capacity(circuits.len());
+        for circuit in circuits {
+            Self::terminate(terminator)?;
+            proofs.push(Self::create_proof(proving_key, circuit, rng)?);
+        }
+        BatchProof::new(proofs)
+    }
+
+    fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
+        fs_parameters: &Self::FSParameters,
+        prepared_verifying_key: &PreparedVerifyingKey<E>,
+        public_inputs: &[B],
+        proof: &Self::Proof,
+    ) -> Result<bool, SNARKError> {
+        if public_inputs.len() != proof.len() {
+            return Err(SNARKError::Message(format!(
+                "Expected {} public inputs for the Groth16 batch, found {}",
+                proof.len(),
+                public_inputs.len()
+            )));
+        }
+        let public_inputs =
+            public_inputs.iter().map(|input| input.borrow().to_field_elements()).collect::<Result<Vec<_>, _>>()?;
+
+        match proof.proofs() {
+            [proof] => Self::verify_proof(prepared_verifying_key, proof, &public_inputs[0]),
+            proofs => {
+                let randomizers =
+                    Self::batch_randomizers(fs_parameters, &prepared_verifying_key.orig_vk, &public_inputs, proof)?;
+                let proofs_and_inputs = proofs
+                    .iter()
+                    .zip(&public_inputs)
+                    .map(|(proof, inputs)| (proof, inputs.as_slice()))
+                    .collect::<Vec<_>>();
+                Self::verify_proofs_batched(prepared_verifying_key, &proofs_and_inputs, &randomizers)
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! An implementation of the [Groth16](https://eprint.iacr.org/2016/260.pdf) zkSNARK for R1CS.
+//!
+//! # Note
+//!
+//! Groth16 requires a trusted setup for every circuit, and the setup of one circuit
+//! cannot be reused for another. In exchange, proofs have a constant size and are verified
+//! with three pairings, which makes Groth16 a good fit for fixed, performance-critical circuits.
+#![forbid(unsafe_code)]
+#![allow(clippy::module_inception)]
+
+/// The R1CS constraint system used for both setup and proving.
+mod constraint_system;
+
+/// Implements the reduction from R1CS to a quadratic arithmetic program (QAP).
+mod r1cs_to_qap;
+
+mod data_structures;
+pub use data_structures::*;
+
+/// Implements the Groth16 zkSNARK.
+mod groth16;
+pub use groth16::*;
+
+/// Implements the circuit-specific setup.
+mod generator;
+
+/// Implements the prover.
+mod prover;
+
+/// Implements the verifier, including batch verification.
+mod verifier;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    msm::VariableBase,
+    snark::groth16::{
+        constraint_system::ConstraintSystem,
+        r1cs_to_qap::{constraint_domain, witness_map},
+        Groth16,
+        Proof,
+        ProvingKey,
+    },
+    AlgebraicSponge,
+    SNARKError,
+};
+use rand::{CryptoRng, Rng};
+use snarkvm_curves::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_r1cs::{ConstraintSynthesizer, SynthesisError};
+use snarkvm_utilities::{cfg_iter, Uniform};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, Input: ToConstraintField<E::Fr> + ?Sized> Groth16<E, FS, Input> {
+    /// Creates a proof for the given circuit, which must have the same shape as the circuit
+    /// that `proving_key` was generated for.
+    pub fn create_proof<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        proving_key: &ProvingKey<E>,
+        circuit: &C,
+        rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        let prover_time = start_timer!(|| "Groth16::Prover");
+
+        let synthesis_time = start_timer!(|| "Constraint synthesis");
+        let cs = ConstraintSystem::synthesize(circuit, false)?;
+        end_timer!(synthesis_time);
+
+        if cs.num_public_variables != proving_key.vk.gamma_abc_g1.len() {
+            return Err(SynthesisError::MalformedVerifyingKey(
+                cs.num_public_variables,
+                proving_key.vk.gamma_abc_g1.len(),
+            )
+            .into());
+        }
+        if cs.num_variables() != proving_key.a_query.len() || cs.num_private_variables != proving_key.l_query.len() {
+            return Err(SNARKError::Message("The circuit does not match the Groth16 proving key".to_string()));
+        }
+
+        let domain = constraint_domain(&cs)?;
+        if domain.size() - 1 != proving_key.h_query.len() {
+            return Err(SNARKError::Message("The circuit does not match the Groth16 proving key".to_string()));
+        }
+
+        let witness_map_time = start_timer!(|| "R1CS to QAP witness map");
+        let h = witness_map(&cs, &domain);
+        end_timer!(witness_map_time);
+
+        let r = E::Fr::rand(rng);
+        let s = E::Fr::rand(rng);
+
+        let to_bigints = |values: &[E::Fr]| cfg_iter!(values).map(|v| v.to_repr()).collect::<Vec<_>>();
+        let private_assignment = to_bigints(&cs.private_variables);
+        let assignment = [to_bigints(&cs.public_variables), private_assignment.clone()].concat();
+
+        let msm_time = start_timer!(|| "Compute the proof elements");
+        let delta_g1 = proving_key.delta_g1.to_projective();
+
+        // A = alpha + sum_i w_i * a_i(t) + r * delta
+        let g_a = proving_key.vk.alpha_g1.to_projective()
+            + VariableBase::msm(&proving_key.a_query, &assignment)
+            + delta_g1 * r;
+
+        // B = beta + sum_i w_i * b_i(t) + s * delta, in both G1 and G2.
+        let g1_b = proving_key.beta_g1.to_projective()
+            + VariableBase::msm(&proving_key.b_g1_query, &assignment)
+            + delta_g1 * s;
+        let g2_b = proving_key.vk.beta_g2.to_projective()
+            + VariableBase::msm(&proving_key.b_g2_query, &assignment)
+            + proving_key.vk.delta_g2.to_projective() * s;
+
+        // C = sum_i w_i * l_i(t) + h(t) * z(t) / delta + s * A + r * B - r * s * delta
+        let g_c = VariableBase::msm(&proving_key.l_query, &private_assignment)
+            + VariableBase::msm(&proving_key.h_query, &to_bigints(&h))
+            + g_a * s
+            + g1_b * r
+            - delta_g1 * (r * s);
+        end_timer!(msm_time);
+
+        end_timer!(prover_time);
+        Ok(Proof { a: g_a.to_affine(), b: g2_b.to_affine(), c: g_c.to_affine() })
+    }
+}
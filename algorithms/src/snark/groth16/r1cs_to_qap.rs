@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{fft::EvaluationDomain, snark::groth16::constraint_system::ConstraintSystem};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::errors::SynthesisError;
+use snarkvm_utilities::cfg_iter_mut;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Returns the evaluation domain for the constraints of the given constraint system.
+pub(crate) fn constraint_domain<F: PrimeField>(
+    cs: &ConstraintSystem<F>,
+) -> Result<EvaluationDomain<F>, SynthesisError> {
+    EvaluationDomain::new(cs.a.len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)
+}
+
+/// The QAP of a constraint system, evaluated at a point `t` outside of the constraint domain.
+pub(crate) struct QAPEvaluations<F: PrimeField> {
+    /// The evaluations `a_i(t)`, for every variable.
+    pub(crate) a: Vec<F>,
+    /// The evaluations `b_i(t)`, for every variable.
+    pub(crate) b: Vec<F>,
+    /// The evaluations `c_i(t)`, for every variable.
+    pub(crate) c: Vec<F>,
+    /// The evaluation `z(t)` of the vanishing polynomial of the constraint domain.
+    pub(crate) z_t: F,
+}
+
+/// Evaluates the QAP of the given constraint system at `t`.
+pub(crate) fn evaluate_qap_at<F: PrimeField>(
+    cs: &ConstraintSystem<F>,
+    domain: &EvaluationDomain<F>,
+    t: F,
+) -> QAPEvaluations<F> {
+    let lagrange_coefficients = domain.evaluate_all_lagrange_coefficients(t);
+
+    let evaluate = |matrix: &[Vec<(F, snarkvm_r1cs::Index)>]| {
+        let mut evaluations = vec![F::zero(); cs.num_variables()];
+        for (row, u_i) in matrix.iter().zip(&lagrange_coefficients) {
+            for (coeff, index) in row {
+                evaluations[cs.variable_position(*index)] += *u_i * coeff;
+            }
+        }
+        evaluations
+    };
+
+    QAPEvaluations {
+        a: evaluate(&cs.a),
+        b: evaluate(&cs.b),
+        c: evaluate(&cs.c),
+        z_t: domain.evaluate_vanishing_polynomial(t),
+    }
+}
+
+/// Computes the coefficients of the quotient `h(X) = (a(X) * b(X) - c(X)) / z(X)`
+/// for the assignment of the given constraint system.
+pub(crate) fn witness_map<F: PrimeField>(cs: &ConstraintSystem<F>, domain: &EvaluationDomain<F>) -> Vec<F> {
+    let evaluate = |matrix: &[Vec<(F, snarkvm_r1cs::Index)>]| {
+        let mut evaluations = vec![F::zero(); domain.size()];
+        for (evaluation, row) in evaluations.iter_mut().zip(matrix) {
+            *evaluation = cs.evaluate_row(row);
+        }
+        evaluations
+    };
+    let mut a = evaluate(&cs.a);
+    let mut b = evaluate(&cs.b);
+    let mut c = evaluate(&cs.c);
+
+    // Interpolate, and evaluate over a coset of the domain, where `z(X)` does not vanish.
+    for evaluations in [&mut a, &mut b, &mut c] {
+        domain.ifft_in_place(evaluations);
+        domain.coset_fft_in_place(evaluations);
+    }
+
+    cfg_iter_mut!(a).zip(b).zip(c).for_each(|((a, b), c)| {
+        *a *= b;
+        *a -= c;
+    });
+    domain.divide_by_vanishing_poly_on_coset_in_place(&mut a);
+    domain.coset_ifft_in_place(&mut a);
+
+    // The quotient has degree at most `domain.size() - 2`.
+    a.truncate(domain.size() - 1);
+    a
+}
@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::PoseidonSponge,
+    snark::groth16::{BatchProof, Groth16, Proof, ProvingKey, VerifyingKey},
+    AlgebraicSponge,
+    SNARKError,
+    SNARK,
+    SRS,
+};
+use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::Bw6_761, PairingEngine};
+use snarkvm_fields::{Field, One};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_utilities::{
+    rand::{TestRng, Uniform},
+    FromBytes,
+    ToBytes,
+};
+
+/// Proves knowledge of `a` and `b` such that `a * b = c` and `a * b * b = d`, for public `c` and `d`.
+#[derive(Copy, Clone)]
+struct Circuit<F: Field> {
+    a: Option<F>,
+    b: Option<F>,
+    num_constraints: usize,
+}
+
+impl<F: Field> Circuit<F> {
+    fn new(a: F, b: F, num_constraints: usize) -> (Self, [F; 2]) {
+        (Self { a: Some(a), b: Some(b), num_constraints }, [a * b, a * b * b])
+    }
+}
+
+impl<F: Field> ConstraintSynthesizer<F> for Circuit<F> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
+        let c = cs.alloc_input(
+            || "c",
+            || Ok(self.a.ok_or(SynthesisError::AssignmentMissing)? * self.b.ok_or(SynthesisError::AssignmentMissing)?),
+        )?;
+        let d = cs.alloc_input(
+            || "d",
+            || {
+                let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+                Ok(self.a.ok_or(SynthesisError::AssignmentMissing)? * b * b)
+            },
+        )?;
+
+        for i in 0..(self.num_constraints - 1) {
+            cs.enforce(|| format!("constraint {i}"), |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        }
+        cs.enforce(|| "constraint_final", |lc| lc + c, |lc| lc + b, |lc| lc + d);
+
+        Ok(())
+    }
+}
+
+type FS<E> = PoseidonSponge<<E as PairingEngine>::Fq, 2, 1>;
+type TestGroth16<E> = Groth16<E, FS<E>, [<E as PairingEngine>::Fr]>;
+
+fn test_prove_and_verify<E: PairingEngine>(num_constraints: usize) {
+    let rng = &mut TestRng::default();
+    let fs_parameters = FS::<E>::sample_parameters();
+
+    let (circuit, _) = Circuit::new(E::Fr::rand(rng), E::Fr::rand(rng), num_constraints);
+    let (proving_key, verifying_key) = TestGroth16::<E>::setup(&circuit, &mut SRS::CircuitSpecific(rng)).unwrap();
+
+    for _ in 0..5 {
+        let (circuit, public_inputs) = Circuit::new(E::Fr::rand(rng), E::Fr::rand(rng), num_constraints);
+        let proof = TestGroth16::<E>::prove(&fs_parameters, &proving_key, &circuit, rng).unwrap();
+        assert!(TestGroth16::<E>::verify(&fs_parameters, &verifying_key, public_inputs, &proof).unwrap());
+
+        // Check that the proof does not verify against different public inputs.
+        let [c, d] = public_inputs;
+        assert!(!TestGroth16::<E>::verify(&fs_parameters, &verifying_key, [c, d + E::Fr::one()], &proof).unwrap());
+        assert!(!TestGroth16::<E>::verify(&fs_parameters, &verifying_key, [d, c], &proof).unwrap());
+    }
+}
+
+#[test]
+fn test_prove_and_verify_bls12_377() {
+    test_prove_and_verify::<Bls12_377>(1);
+    test_prove_and_verify::<Bls12_377>(25);
+}
+
+#[test]
+fn test_prove_and_verify_bw6_761() {
+    test_prove_and_verify::<Bw6_761>(25);
+}
+
+#[test]
+fn test_batch_prove_and_verify() {
+    let rng = &mut TestRng::default();
+    let fs_parameters = FS::<Bls12_377>::sample_parameters();
+
+    let (circuit, _) = Circuit::new(Uniform::rand(rng), Uniform::rand(rng), 50);
+    let (proving_key, verifying_key) =
+        TestGroth16::<Bls12_377>::setup(&circuit, &mut SRS::CircuitSpecific(rng)).unwrap();
+
+    let (circuits, public_inputs): (Vec<_>, Vec<_>) =
+        (0..4).map(|_| Circuit::new(Uniform::rand(rng), Uniform::rand(rng), 50)).unzip();
+    let proof = TestGroth16::<Bls12_377>::prove_batch(&fs_parameters, &proving_key, &circuits, rng).unwrap();
+    assert_eq!(proof.len(), 4);
+    assert!(TestGroth16::<Bls12_377>::verify_batch(&fs_parameters, &verifying_key, &public_inputs, &proof).unwrap());
+
+    // Check that the batch does not verify if any of the public inputs are changed.
+    let mut bad_public_inputs = public_inputs.clone();
+    bad_public_inputs[2][1].double_in_place();
+    assert!(
+        !TestGroth16::<Bls12_377>::verify_batch(&fs_parameters, &verifying_key, &bad_public_inputs, &proof).unwrap()
+    );
+
+    // Check that the batch does not verify if two proofs are swapped.
+    let mut proofs = proof.proofs().to_vec();
+    proofs.swap(0, 1);
+    let swapped = BatchProof::new(proofs).unwrap();
+    assert!(!TestGroth16::<Bls12_377>::verify_batch(&fs_parameters, &verifying_key, &public_inputs, &swapped).unwrap());
+
+    // Check that the number of public inputs must match the number of proofs.
+    assert!(
+        TestGroth16::<Bls12_377>::verify_batch(&fs_parameters, &verifying_key, &public_inputs[..3], &proof).is_err()
+    );
+}
+
+#[test]
+fn test_universal_srs_is_rejected() {
+    let rng = &mut TestRng::default();
+
+    let (circuit, _) = Circuit::<<Bls12_377 as PairingEngine>::Fr>::new(Uniform::rand(rng), Uniform::rand(rng), 1);
+    assert!(matches!(
+        TestGroth16::<Bls12_377>::setup(&circuit, &mut SRS::<TestRng, _>::Universal(&())),
+        Err(SNARKError::ExpectedCircuitSpecificSRS)
+    ));
+    assert!(matches!(TestGroth16::<Bls12_377>::universal_setup(&(), rng), Err(SNARKError::ExpectedCircuitSpecificSRS)));
+}
+
+#[test]
+fn test_mismatched_circuit_is_rejected() {
+    let rng = &mut TestRng::default();
+    let fs_parameters = FS::<Bls12_377>::sample_parameters();
+
+    let (circuit, _) = Circuit::new(Uniform::rand(rng), Uniform::rand(rng), 10);
+    let (proving_key, _) = TestGroth16::<Bls12_377>::setup(&circuit, &mut SRS::CircuitSpecific(rng)).unwrap();
+
+    let (circuit, _) = Circuit::new(Uniform::rand(rng), Uniform::rand(rng), 40);
+    assert!(TestGroth16::<Bls12_377>::prove(&fs_parameters, &proving_key, &circuit, rng).is_err());
+}
+
+#[test]
+fn test_serialization() {
+    let rng = &mut TestRng::default();
+    let fs_parameters = FS::<Bls12_377>::sample_parameters();
+
+    let (circuit, public_inputs) = Circuit::new(Uniform::rand(rng), Uniform::rand(rng), 10);
+    let (proving_key, verifying_key) =
+        TestGroth16::<Bls12_377>::setup(&circuit, &mut SRS::CircuitSpecific(rng)).unwrap();
+    let proof = TestGroth16::<Bls12_377>::prove(&fs_parameters, &proving_key, &circuit, rng).unwrap();
+
+    let proving_key = ProvingKey::<Bls12_377>::read_le(&proving_key.to_bytes_le().unwrap()[..]).unwrap();
+    let verifying_key = VerifyingKey::<Bls12_377>::read_le(&verifying_key.to_bytes_le().unwrap()[..]).unwrap();
+    let proof = BatchProof::<Bls12_377>::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+    assert_eq!(VerifyingKey::from(&proving_key), verifying_key);
+    assert!(TestGroth16::<Bls12_377>::verify(&fs_parameters, &verifying_key, public_inputs, &proof).unwrap());
+
+    let single = Proof::<Bls12_377>::read_le(&proof.proofs()[0].to_bytes_le().unwrap()[..]).unwrap();
+    assert_eq!(BatchProof::from(single), proof);
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    msm::VariableBase,
+    snark::groth16::{Groth16, PreparedVerifyingKey, Proof},
+    AlgebraicSponge,
+    SNARKError,
+};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, PrimeField, ToConstraintField, Zero};
+use snarkvm_r1cs::SynthesisError;
+
+impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, Input: ToConstraintField<E::Fr> + ?Sized> Groth16<E, FS, Input> {
+    /// Returns `gamma_abc_g1[0] + sum_i public_inputs[i] * gamma_abc_g1[i + 1]`.
+    pub fn prepare_inputs(
+        prepared_verifying_key: &PreparedVerifyingKey<E>,
+        public_inputs: &[E::Fr],
+    ) -> Result<E::G1Projective, SNARKError> {
+        let gamma_abc_g1 = &prepared_verifying_key.orig_vk.gamma_abc_g1;
+        if public_inputs.len() + 1 != gamma_abc_g1.len() {
+            return Err(SynthesisError::MalformedVerifyingKey(public_inputs.len() + 1, gamma_abc_g1.len()).into());
+        }
+
+        let public_inputs = public_inputs.iter().map(|input| input.to_repr()).collect::<Vec<_>>();
+        Ok(gamma_abc_g1[0].to_projective() + VariableBase::msm(&gamma_abc_g1[1..], &public_inputs))
+    }
+
+    /// Verifies the given proof against the given public inputs, checking that
+    /// `e(A, B) = e(alpha, beta) * e(prepared_inputs, gamma) * e(C, delta)`.
+    pub fn verify_proof(
+        prepared_verifying_key: &PreparedVerifyingKey<E>,
+        proof: &Proof<E>,
+        public_inputs: &[E::Fr],
+    ) -> Result<bool, SNARKError> {
+        let verifier_time = start_timer!(|| "Groth16::Verify");
+        let prepared_inputs = Self::prepare_inputs(prepared_verifying_key, public_inputs)?.to_affine();
+
+        let a = proof.a.prepare();
+        let b = proof.b.prepare();
+        let prepared_inputs = prepared_inputs.prepare();
+        let c = proof.c.prepare();
+
+        let miller_loop = E::miller_loop(
+            [
+                (&a, &b),
+                (&prepared_inputs, &prepared_verifying_key.gamma_g2_neg_pc),
+                (&c, &prepared_verifying_key.delta_g2_neg_pc),
+            ]
+            .into_iter(),
+        );
+        let is_valid = E::final_exponentiation(&miller_loop) == Some(prepared_verifying_key.alpha_g1_beta_g2);
+
+        end_timer!(verifier_time);
+        Ok(is_valid)
+    }
+
+    /// Verifies many proofs under the same verifying key with a single final exponentiation,
+    /// by checking a random linear combination of their pairing equations:
+    /// `prod_j e(r_j * A_j, B_j) = e(alpha, beta)^(sum_j r_j) * e(sum_j r_j * prepared_inputs_j, gamma) * e(sum_j r_j * C_j, delta)`.
+    ///
+    /// The `randomizers` must be sampled after the proofs and public inputs are fixed.
+    pub fn verify_proofs_batched(
+        prepared_verifying_key: &PreparedVerifyingKey<E>,
+        proofs_and_inputs: &[(&Proof<E>, &[E::Fr])],
+        randomizers: &[E::Fr],
+    ) -> Result<bool, SNARKError> {
+        if proofs_and_inputs.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+        if proofs_and_inputs.len() != randomizers.len() {
+            return Err(SNARKError::Message(format!(
+                "Expected {} randomizers for the Groth16 batch, found {}",
+                proofs_and_inputs.len(),
+                randomizers.len()
+            )));
+        }
+        let verifier_time = start_timer!(|| format!("Groth16::VerifyBatch with {} proofs", proofs_and_inputs.len()));
+
+        let mut prepared_inputs = E::G1Projective::zero();
+        let mut c = E::G1Projective::zero();
+        let mut randomizer_sum = E::Fr::zero();
+        let mut pairs = Vec::with_capacity(proofs_and_inputs.len());
+        for ((proof, public_inputs), randomizer) in proofs_and_inputs.iter().zip(randomizers) {
+            prepared_inputs += Self::prepare_inputs(prepared_verifying_key, public_inputs)? * *randomizer;
+            c += proof.c * *randomizer;
+            randomizer_sum += randomizer;
+            pairs.push(((proof.a * *randomizer).to_affine().prepare(), proof.b.prepare()));
+        }
+        let prepared_inputs = prepared_inputs.to_affine().prepare();
+        let c = c.to_affine().prepare();
+
+        let miller_loop = E::miller_loop(pairs.iter().map(|(a, b)| (a, b)).chain([
+            (&prepared_inputs, &prepared_verifying_key.gamma_g2_neg_pc),
+            (&c, &prepared_verifying_key.delta_g2_neg_pc),
+        ]));
+        let is_valid = E::final_exponentiation(&miller_loop)
+            == Some(prepared_verifying_key.alpha_g1_beta_g2.pow(randomizer_sum.to_repr()));
+
+        end_timer!(verifier_time);
+        Ok(is_valid)
+    }
+}
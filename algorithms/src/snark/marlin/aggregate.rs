@@ -0,0 +1,591 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{
+    fft::EvaluationDomain,
+    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, Randomness, SonicKZG10},
+    snark::marlin::{
+        ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
+        proof,
+        prover,
+        AggregatedProof,
+        CircuitProvingKey,
+        CircuitVerifyingKey,
+        MarlinMode,
+        MarlinSNARK,
+    },
+    AlgebraicSponge,
+    SNARKError,
+};
+use itertools::Itertools;
+use rand::{CryptoRng, Rng};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
+use snarkvm_r1cs::ConstraintSynthesizer;
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+use std::{borrow::Borrow, sync::Arc};
+
+impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode, Input: ToConstraintField<E::Fr> + ?Sized>
+    MarlinSNARK<E, FS, MM, Input>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+{
+    /// The personalization string for aggregated proofs.
+    /// Used to personalize the Fiat-Shamir RNG.
+    pub const AGGREGATE_PROTOCOL_NAME: &'static [u8] = b"MARLIN-2019-AGGREGATE";
+
+    /// Initializes the sponge for an aggregated proof, absorbing every circuit and its instances.
+    fn init_sponge_for_aggregate<'a>(
+        fs_parameters: &FS::Parameters,
+        circuits: impl ExactSizeIterator<Item = (&'a [Commitment<E>], &'a [Vec<E::Fr>])>,
+    ) -> FS {
+        let mut sponge = FS::new_with_parameters(fs_parameters);
+        sponge.absorb_bytes(&to_bytes_le![&Self::AGGREGATE_PROTOCOL_NAME].unwrap());
+        sponge.absorb_bytes(&circuits.len().to_le_bytes());
+        for (circuit_commitments, inputs) in circuits {
+            sponge.absorb_bytes(&inputs.len().to_le_bytes());
+            sponge.absorb_native_field_elements(circuit_commitments);
+            for input in inputs {
+                sponge.absorb_nonnative_field_elements(input.iter().copied());
+            }
+        }
+        sponge
+    }
+
+    /// Proves a batch of instances spanning several distinct circuits, producing a single proof.
+    /// The circuits share one Fiat-Shamir transcript, and all of their polynomials are opened
+    /// with one batched polynomial commitment proof.
+    pub fn prove_batch_aggregate<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        keys_to_circuits: &[(&CircuitProvingKey<E, MM>, &[C])],
+        zk_rng: &mut R,
+    ) -> Result<AggregatedProof<E>, SNARKError> {
+        let prover_time = start_timer!(|| "Marlin::AggregateProver");
+        if keys_to_circuits.is_empty() || keys_to_circuits.iter().any(|(_, circuits)| circuits.is_empty()) {
+            return Err(SNARKError::EmptyBatch);
+        }
+
+        let batch_sizes = keys_to_circuits.iter().map(|(_, circuits)| circuits.len()).collect::<Vec<_>>();
+        let has_lookups = keys_to_circuits.iter().map(|(pk, _)| pk.circuit.lookup.is_some()).collect::<Vec<_>>();
+
+        let mut prover_states = Vec::with_capacity(keys_to_circuits.len());
+        let mut public_inputs = Vec::with_capacity(keys_to_circuits.len());
+        let mut padded_public_inputs = Vec::with_capacity(keys_to_circuits.len());
+        for (circuit_proving_key, circuits) in keys_to_circuits {
+            let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)?;
+            public_inputs.push(prover_state.public_inputs());
+            padded_public_inputs.push(prover_state.padded_public_inputs());
+            prover_states.push(prover_state);
+        }
+
+        let mut sponge = Self::init_sponge_for_aggregate(
+            fs_parameters,
+            keys_to_circuits
+                .iter()
+                .zip_eq(&padded_public_inputs)
+                .map(|((pk, _), inputs)| (&pk.circuit_verifying_key.circuit_commitments[..], &inputs[..])),
+        );
+
+        // --------------------------------------------------------------------
+        // First round
+
+        let mut first_commitments = Vec::with_capacity(keys_to_circuits.len());
+        let mut first_commitment_randomnesses = Vec::with_capacity(keys_to_circuits.len());
+        let prover_states = prover_states
+            .into_iter()
+            .zip_eq(keys_to_circuits)
+            .map(|(prover_state, (circuit_proving_key, _))| {
+                let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, zk_rng)?;
+                let (commitments, randomnesses) = {
+                    let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
+                    SonicKZG10::<E, FS>::commit(
+                        &circuit_proving_key.committer_key,
+                        first_round_oracles.iter_for_commit(),
+                        Some(zk_rng),
+                    )?
+                };
+                first_commitments.push(commitments);
+                first_commitment_randomnesses.push(randomnesses);
+                Ok(prover_state)
+            })
+            .collect::<Result<Vec<_>, SNARKError>>()?;
+
+        first_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
+
+        let (verifier_first_messages, verifier_states): (Vec<_>, Vec<_>) = keys_to_circuits
+            .iter()
+            .zip_eq(&has_lookups)
+            .map(|((circuit_proving_key, circuits), has_lookups)| {
+                AHPForR1CS::<_, MM>::verifier_first_round(
+                    circuit_proving_key.circuit_verifying_key.circuit_info,
+                    circuits.len(),
+                    *has_lookups,
+                    &mut sponge,
+                )
+            })
+            .collect::<Result<Vec<_>, AHPError>>()?
+            .into_iter()
+            .unzip();
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+
+        let mut second_oracles = Vec::with_capacity(keys_to_circuits.len());
+        let mut second_commitments = Vec::with_capacity(keys_to_circuits.len());
+        let mut second_commitment_randomnesses = Vec::with_capacity(keys_to_circuits.len());
+        let prover_states = prover_states
+            .into_iter()
+            .zip_eq(&verifier_first_messages)
+            .zip_eq(keys_to_circuits)
+            .map(|((prover_state, verifier_message), (circuit_proving_key, _))| {
+                let (oracles, prover_state) =
+                    AHPForR1CS::<_, MM>::prover_second_round(verifier_message, prover_state, zk_rng);
+                let (commitments, randomnesses) = SonicKZG10::<E, FS>::commit(
+                    &circuit_proving_key.committer_key,
+                    oracles.iter().map(Into::into),
+                    Some(zk_rng),
+                )?;
+                second_oracles.push(oracles);
+                second_commitments.push(commitments);
+                second_commitment_randomnesses.push(randomnesses);
+                Ok(prover_state)
+            })
+            .collect::<Result<Vec<_>, SNARKError>>()?;
+
+        second_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
+
+        let (verifier_second_messages, verifier_states): (Vec<_>, Vec<_>) = verifier_states
+            .into_iter()
+            .map(|verifier_state| AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge))
+            .collect::<Result<Vec<_>, AHPError>>()?
+            .into_iter()
+            .unzip();
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+
+        let mut prover_third_messages = Vec::with_capacity(keys_to_circuits.len());
+        let mut third_oracles = Vec::with_capacity(keys_to_circuits.len());
+        let mut third_commitments = Vec::with_capacity(keys_to_circuits.len());
+        let mut third_commitment_randomnesses = Vec::with_capacity(keys_to_circuits.len());
+        let prover_states = prover_states
+            .into_iter()
+            .zip_eq(&verifier_second_messages)
+            .zip_eq(keys_to_circuits)
+            .map(|((prover_state, verifier_message), (circuit_proving_key, _))| {
+                let (message, oracles, prover_state) =
+                    AHPForR1CS::<_, MM>::prover_third_round(verifier_message, prover_state, zk_rng)?;
+                let (commitments, randomnesses) = SonicKZG10::<E, FS>::commit(
+                    &circuit_proving_key.committer_key,
+                    oracles.iter().map(Into::into),
+                    Some(zk_rng),
+                )?;
+                prover_third_messages.push(message);
+                third_oracles.push(oracles);
+                third_commitments.push(commitments);
+                third_commitment_randomnesses.push(randomnesses);
+                Ok(prover_state)
+            })
+            .collect::<Result<Vec<_>, SNARKError>>()?;
+
+        third_commitments
+            .iter()
+            .zip_eq(&prover_third_messages)
+            .for_each(|(commitments, message)| Self::absorb_labeled_with_msg(commitments, message, &mut sponge));
+
+        let (verifier_third_messages, verifier_states): (Vec<_>, Vec<_>) = verifier_states
+            .into_iter()
+            .map(|verifier_state| AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge))
+            .collect::<Result<Vec<_>, AHPError>>()?
+            .into_iter()
+            .unzip();
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Fourth round
+
+        let mut first_round_oracles = Vec::with_capacity(keys_to_circuits.len());
+        let mut fourth_oracles = Vec::with_capacity(keys_to_circuits.len());
+        let mut fourth_commitments = Vec::with_capacity(keys_to_circuits.len());
+        let mut fourth_commitment_randomnesses = Vec::with_capacity(keys_to_circuits.len());
+        for ((prover_state, verifier_message), (circuit_proving_key, _)) in
+            prover_states.into_iter().zip_eq(&verifier_third_messages).zip_eq(keys_to_circuits)
+        {
+            first_round_oracles.push(Arc::clone(prover_state.first_round_oracles.as_ref().unwrap()));
+            let oracles = AHPForR1CS::<_, MM>::prover_fourth_round(verifier_message, prover_state, zk_rng)?;
+            let (commitments, randomnesses) = SonicKZG10::<E, FS>::commit(
+                &circuit_proving_key.committer_key,
+                oracles.iter().map(Into::into),
+                Some(zk_rng),
+            )?;
+            fourth_oracles.push(oracles);
+            fourth_commitments.push(commitments);
+            fourth_commitment_randomnesses.push(randomnesses);
+        }
+
+        fourth_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
+
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|verifier_state| AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge))
+            .collect::<Result<Vec<_>, AHPError>>()?;
+        // --------------------------------------------------------------------
+
+        let num_circuits = keys_to_circuits.len();
+        let mut all_polynomials = Vec::with_capacity(num_circuits);
+        let mut all_commitments = Vec::with_capacity(num_circuits);
+        let mut all_labeled_commitments = Vec::with_capacity(num_circuits);
+        let mut all_randomnesses = Vec::with_capacity(num_circuits);
+        let mut all_query_sets = Vec::with_capacity(num_circuits);
+        let mut all_lc_s = Vec::with_capacity(num_circuits);
+        let mut all_evaluations = Vec::with_capacity(num_circuits);
+
+        for (i, (((circuit_proving_key, _), verifier_state), public_input)) in
+            keys_to_circuits.iter().zip_eq(verifier_states).zip_eq(&public_inputs).enumerate()
+        {
+            // Gather prover polynomials in one vector.
+            let polynomials: Vec<_> = circuit_proving_key
+                .circuit
+                .iter()
+                .chain(first_round_oracles[i].iter_for_open())
+                .chain(second_oracles[i].iter())
+                .chain(third_oracles[i].iter())
+                .chain(fourth_oracles[i].iter())
+                .collect();
+
+            // Gather commitments in one vector.
+            let commitments = Self::proof_commitments(
+                batch_sizes[i],
+                has_lookups[i],
+                &first_commitments[i],
+                &second_commitments[i],
+                &third_commitments[i],
+                &fourth_commitments[i],
+            );
+
+            let labeled_commitments: Vec<_> = circuit_proving_key
+                .circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(has_lookups[i]).values())
+                .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+                .chain(first_commitments[i].iter().cloned())
+                .chain(second_commitments[i].iter().cloned())
+                .chain(third_commitments[i].iter().cloned())
+                .chain(fourth_commitments[i].iter().cloned())
+                .collect();
+
+            // Gather commitment randomness together.
+            let commitment_randomnesses: Vec<Randomness<E>> = circuit_proving_key
+                .circuit_commitment_randomness
+                .iter()
+                .chain(&first_commitment_randomnesses[i])
+                .chain(&second_commitment_randomnesses[i])
+                .chain(&third_commitment_randomnesses[i])
+                .chain(&fourth_commitment_randomnesses[i])
+                .cloned()
+                .collect();
+
+            // Compute the AHP verifier's query set.
+            let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+            let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+                public_input,
+                &polynomials,
+                &prover_third_messages[i],
+                &verifier_state,
+            )?;
+
+            let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
+            let mut evaluations = std::collections::BTreeMap::new();
+            for (label, (_, point)) in query_set.to_set() {
+                if !AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
+                    let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
+                    let evaluation = polynomials.get_lc_eval(lc, point)?;
+                    evaluations.insert(label, evaluation);
+                }
+            }
+            end_timer!(eval_time);
+
+            all_polynomials.push(polynomials);
+            all_commitments.push(commitments);
+            all_labeled_commitments.push(labeled_commitments);
+            all_randomnesses.push(commitment_randomnesses);
+            all_query_sets.push(query_set.to_set());
+            all_lc_s.push(lc_s);
+            all_evaluations.push(proof::Evaluations::from_map(&evaluations, batch_sizes[i]));
+        }
+
+        for evaluations in &all_evaluations {
+            sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
+        }
+
+        let pc_proof = SonicKZG10::<E, FS>::open_combinations_batch(
+            keys_to_circuits
+                .iter()
+                .zip_eq(&all_lc_s)
+                .zip_eq(&all_polynomials)
+                .zip_eq(&all_labeled_commitments)
+                .zip_eq(&all_query_sets)
+                .zip_eq(&all_randomnesses)
+                .map(|((((((pk, _), lc_s), polynomials), commitments), query_set), randomnesses)| {
+                    (
+                        &*pk.committer_key,
+                        lc_s.values(),
+                        polynomials.iter().copied(),
+                        commitments,
+                        query_set,
+                        randomnesses,
+                    )
+                }),
+            &mut sponge,
+        )?;
+
+        let proof =
+            AggregatedProof::new(batch_sizes, all_commitments, all_evaluations, prover_third_messages, pc_proof);
+        assert_eq!(proof.pc_proof.is_hiding(), MM::ZK);
+        end_timer!(prover_time);
+
+        Ok(proof)
+    }
+
+    /// Verifies an aggregated proof, given the verifying key and the public inputs of every circuit in the batch.
+    /// The circuits must be given in the same order as they were proven.
+    pub fn verify_batch_aggregate<B: Borrow<Input>>(
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &[(&CircuitVerifyingKey<E, MM>, &[B])],
+        proof: &AggregatedProof<E>,
+    ) -> Result<bool, SNARKError> {
+        if keys_to_inputs.is_empty() || keys_to_inputs.iter().any(|(_, inputs)| inputs.is_empty()) {
+            return Err(SNARKError::EmptyBatch);
+        }
+        let has_lookups = keys_to_inputs.iter().map(|(vk, _)| vk.has_lookups()).collect::<Vec<_>>();
+
+        // Ensure the proof covers exactly the given circuits and instances.
+        let proof_has_correct_shape = proof.batch_sizes().len() == keys_to_inputs.len()
+            && proof.commitments.len() == keys_to_inputs.len()
+            && proof.evaluations.len() == keys_to_inputs.len()
+            && proof.msgs.len() == keys_to_inputs.len()
+            && keys_to_inputs.iter().zip(proof.batch_sizes()).all(|((_, inputs), size)| inputs.len() == *size)
+            && proof
+                .commitments
+                .iter()
+                .zip(proof.batch_sizes())
+                .all(|(comms, size)| comms.witness_commitments.len() == *size)
+            && proof.evaluations.iter().zip(proof.batch_sizes()).all(|(evals, size)| evals.z_b_evals.len() == *size)
+            && proof.commitments.iter().zip(proof.batch_sizes()).zip(&has_lookups).all(|((comms, size), has_lookups)| {
+                comms.h_lookup.is_some() == *has_lookups
+                    && comms.lookup_commitments.len() == if *has_lookups { *size } else { 0 }
+            });
+        if !proof_has_correct_shape {
+            eprintln!("The aggregated proof does not match the given circuits and inputs");
+            return Ok(false);
+        }
+
+        let proof_has_correct_zk_mode = if MM::ZK {
+            proof.pc_proof.is_hiding() & proof.commitments.iter().all(|comms| comms.mask_poly.is_some())
+        } else {
+            !proof.pc_proof.is_hiding() & proof.commitments.iter().all(|comms| comms.mask_poly.is_none())
+        };
+        if !proof_has_correct_zk_mode {
+            eprintln!(
+                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
+                proof.pc_proof.is_hiding()
+            );
+            return Ok(false);
+        }
+
+        let verifier_time = start_timer!(|| format!("Marlin::VerifyAggregate with {} circuits", keys_to_inputs.len()));
+
+        let mut first_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut second_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut third_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut fourth_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut padded_public_inputs = Vec::with_capacity(keys_to_inputs.len());
+        let mut public_inputs = Vec::with_capacity(keys_to_inputs.len());
+
+        for (((circuit_verifying_key, inputs), comms), &vk_has_lookups) in
+            keys_to_inputs.iter().zip_eq(&proof.commitments).zip_eq(&has_lookups)
+        {
+            let batch_size = inputs.len();
+
+            let [first, second, third, fourth] = Self::labeled_commitments_by_round(
+                &circuit_verifying_key.circuit_info,
+                batch_size,
+                vk_has_lookups,
+                comms,
+            );
+            first_commitments.push(first);
+            second_commitments.push(second);
+            third_commitments.push(third);
+            fourth_commitments.push(fourth);
+
+            let input_domain =
+                EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
+
+            let (padded, unformatted): (Vec<_>, Vec<_>) = inputs
+                .iter()
+                .map(|input| {
+                    let input = input.borrow().to_field_elements()?;
+                    let mut new_input = vec![E::Fr::one()];
+                    new_input.extend_from_slice(&input);
+                    new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
+                    let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
+                    Ok((new_input, unformatted))
+                })
+                .collect::<Result<Vec<_>, SNARKError>>()?
+                .into_iter()
+                .unzip();
+            padded_public_inputs.push(padded);
+            public_inputs.push(unformatted);
+        }
+
+        let mut sponge = Self::init_sponge_for_aggregate(
+            fs_parameters,
+            keys_to_inputs
+                .iter()
+                .zip_eq(&padded_public_inputs)
+                .map(|((vk, _), inputs)| (&vk.circuit_commitments[..], &inputs[..])),
+        );
+
+        // --------------------------------------------------------------------
+        // First round
+        first_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
+        let verifier_states = keys_to_inputs
+            .iter()
+            .zip_eq(&has_lookups)
+            .map(|((circuit_verifying_key, inputs), has_lookups)| {
+                AHPForR1CS::<_, MM>::verifier_first_round(
+                    circuit_verifying_key.circuit_info,
+                    inputs.len(),
+                    *has_lookups,
+                    &mut sponge,
+                )
+                .map(|(_, verifier_state)| verifier_state)
+            })
+            .collect::<Result<Vec<_>, AHPError>>()?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+        second_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|verifier_state| {
+                AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge).map(|(_, state)| state)
+            })
+            .collect::<Result<Vec<_>, AHPError>>()?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+        third_commitments
+            .iter()
+            .zip_eq(&proof.msgs)
+            .for_each(|(commitments, message)| Self::absorb_labeled_with_msg(commitments, message, &mut sponge));
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|verifier_state| {
+                AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge).map(|(_, state)| state)
+            })
+            .collect::<Result<Vec<_>, AHPError>>()?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Fourth round
+        fourth_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|verifier_state| AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge))
+            .collect::<Result<Vec<_>, AHPError>>()?;
+        // --------------------------------------------------------------------
+
+        for evaluations in &proof.evaluations {
+            sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
+        }
+
+        let num_circuits = keys_to_inputs.len();
+        let mut all_commitments = Vec::with_capacity(num_circuits);
+        let mut all_query_sets = Vec::with_capacity(num_circuits);
+        let mut all_evaluations = Vec::with_capacity(num_circuits);
+        let mut all_lc_s = Vec::with_capacity(num_circuits);
+
+        for (i, ((circuit_verifying_key, _), verifier_state)) in
+            keys_to_inputs.iter().zip_eq(verifier_states).enumerate()
+        {
+            // Gather commitments in one vector.
+            let commitments: Vec<_> = circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(has_lookups[i]).values())
+                .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+                .chain(first_commitments[i].iter().cloned())
+                .chain(second_commitments[i].iter().cloned())
+                .chain(third_commitments[i].iter().cloned())
+                .chain(fourth_commitments[i].iter().cloned())
+                .collect();
+
+            let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+
+            let mut evaluations = Evaluations::new();
+            for (label, (_point_name, q)) in query_set.to_set() {
+                if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
+                    evaluations.insert((label, q), E::Fr::zero());
+                } else {
+                    let eval = proof.evaluations[i].get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
+                    evaluations.insert((label, q), eval);
+                }
+            }
+
+            let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+                &public_inputs[i],
+                &evaluations,
+                &proof.msgs[i],
+                &verifier_state,
+            )?;
+
+            all_commitments.push(commitments);
+            all_query_sets.push(query_set.to_set());
+            all_evaluations.push(evaluations);
+            all_lc_s.push(lc_s);
+        }
+
+        let pc_time = start_timer!(|| "Checking linear combinations with PC");
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations_batch(
+            keys_to_inputs
+                .iter()
+                .zip_eq(&all_lc_s)
+                .zip_eq(&all_commitments)
+                .zip_eq(&all_query_sets)
+                .zip_eq(&all_evaluations)
+                .map(|(((((vk, _), lc_s), commitments), query_set), evaluations)| {
+                    (&vk.verifier_key, lc_s.values(), commitments, query_set, evaluations)
+                }),
+            &proof.pc_proof,
+            &mut sponge,
+        )?;
+        end_timer!(pc_time);
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::Check failed");
+        }
+        end_timer!(verifier_time);
+        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+    }
+}
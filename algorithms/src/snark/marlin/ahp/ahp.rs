@@ -47,7 +47,7 @@ pub(crate) fn witness_label(poly: &str, i: usize) -> String {
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// The linear combinations that are statically known to evaluate to zero.
     #[rustfmt::skip]
-    pub const LC_WITH_ZERO_EVAL: [&'static str; 2] = ["matrix_sumcheck", "lincheck_sumcheck"];
+    pub const LC_WITH_ZERO_EVAL: [&'static str; 3] = ["matrix_sumcheck", "lincheck_sumcheck", "lookup_check"];
 
     pub fn zk_bound() -> Option<usize> {
         MM::ZK.then_some(1)
@@ -176,6 +176,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let eta_b = first_round_msg.eta_b;
         let eta_c = first_round_msg.eta_c;
         let batch_combiners = &first_round_msg.batch_combiners;
+        let lookup_challenges = first_round_msg.lookup_challenges;
         let prover::ThirdMessage { sum_a, sum_b, sum_c } = prover_third_message;
 
         #[rustfmt::skip]
@@ -236,9 +237,19 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 lincheck_sumcheck.add(F::one(), "mask_poly");
             }
             for (i, (z_b_i_at_beta, combiner)) in z_b_s_at_beta.iter().zip_eq(batch_combiners).enumerate() {
-                lincheck_sumcheck
-                    .add(r_alpha_at_beta * combiner * (eta_a + eta_c * z_b_i_at_beta), witness_label("z_a", i))
-                    .add(-t_at_beta * v_X_at_beta * combiner, witness_label("w", i));
+                if lookup_challenges.is_some() {
+                    // Lookup rows do not satisfy `z_a * z_b = z_c`, so `z_c` is committed to explicitly,
+                    // and the lookup sumcheck is folded into the lincheck sumcheck.
+                    lincheck_sumcheck
+                        .add(r_alpha_at_beta * combiner * eta_a, witness_label("z_a", i))
+                        .add(r_alpha_at_beta * combiner * eta_c, witness_label("z_c", i))
+                        .add(*combiner, witness_label("h_f", i))
+                        .add(-*combiner, witness_label("h_t", i));
+                } else {
+                    lincheck_sumcheck
+                        .add(r_alpha_at_beta * combiner * (eta_a + eta_c * z_b_i_at_beta), witness_label("z_a", i));
+                }
+                lincheck_sumcheck.add(-t_at_beta * v_X_at_beta * combiner, witness_label("w", i));
             }
             lincheck_sumcheck
                 .add(r_alpha_at_beta * eta_b * batch_z_b_at_beta, LCTerm::One)
@@ -289,6 +300,83 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         linear_combinations.insert("g_c".into(), g_c);
         linear_combinations.insert("matrix_sumcheck".into(), matrix_sumcheck);
 
+        if let Some(verifier::LookupChallenges { theta, delta }) = lookup_challenges {
+            let lambda = state.second_round_message.unwrap().lambda.unwrap();
+            let v_H_at_gamma = constraint_domain.evaluate_vanishing_polynomial(gamma);
+            let lookup_lcs = Self::construct_lookup_lcs(state.batch_size, evals, theta, delta, lambda, gamma, v_H_at_gamma)?;
+            linear_combinations.extend(lookup_lcs.into_iter().map(|lc| (lc.label.clone(), lc)));
+        }
+
+        Ok(linear_combinations)
+    }
+
+    /// Construct the linear combinations that check the lookup argument at `gamma`:
+    /// for every instance, on every row of the constraint domain,
+    /// 1) `(1 - s) * (z_a * z_b - z_c) = 0`, i.e. rows that are not lookups satisfy the R1CS relation,
+    /// 2) `h_f * (delta - f) = s`, where `f = z_a + theta * z_b + theta^2 * z_c + theta^3 * id`,
+    /// 3) `h_t * (delta - t) = m`, where `t = t_a + theta * t_b + theta^2 * t_c + theta^3 * t_id`.
+    /// These are combined with powers of `lambda` and checked against `h_lookup * v_H`.
+    #[allow(non_snake_case)]
+    fn construct_lookup_lcs<E: EvaluationsProvider<F>>(
+        batch_size: usize,
+        evals: &E,
+        theta: F,
+        delta: F,
+        lambda: F,
+        gamma: F,
+        v_H_at_gamma: F,
+    ) -> Result<Vec<LinearCombination<F>>, AHPError> {
+        let lookup_time = start_timer!(|| "Lookup check");
+        let [theta_2, theta_3] = [theta.square(), theta.square() * theta];
+        let s = LinearCombination::new("lookup_s", [(F::one(), "lookup_s")]);
+        let s_at_gamma = evals.get_lc_eval(&s, gamma)?;
+
+        let mut linear_combinations = vec![];
+        let mut lookup_check = LinearCombination::empty("lookup_check");
+        let mut lambda_power = F::one();
+        for i in 0..batch_size {
+            let [z_a, h_f, h_t] = ["z_a", "h_f", "h_t"].map(|label| {
+                let label = witness_label(label, i);
+                LinearCombination::new(label.clone(), [(F::one(), label)])
+            });
+            let z_a_at_gamma = evals.get_lc_eval(&z_a, gamma)?;
+            let h_f_at_gamma = evals.get_lc_eval(&h_f, gamma)?;
+            let h_t_at_gamma = evals.get_lc_eval(&h_t, gamma)?;
+            let [z_b, z_c, m] = [witness_label("z_b", i), witness_label("z_c", i), witness_label("m", i)];
+
+            #[rustfmt::skip]
+            let r1cs_check = LinearCombination::new("r1cs_check", [
+                ((F::one() - s_at_gamma) * z_a_at_gamma, LCTerm::from(z_b.clone())),
+                (s_at_gamma - F::one(), z_c.clone().into()),
+            ]);
+            #[rustfmt::skip]
+            let f_check = LinearCombination::new("f_check", [
+                (h_f_at_gamma * (delta - z_a_at_gamma), LCTerm::One),
+                (-h_f_at_gamma * theta, z_b.into()),
+                (-h_f_at_gamma * theta_2, z_c.into()),
+                (-h_f_at_gamma * theta_3, "lookup_id".into()),
+                (-F::one(), "lookup_s".into()),
+            ]);
+            #[rustfmt::skip]
+            let t_check = LinearCombination::new("t_check", [
+                (h_t_at_gamma * delta, LCTerm::One),
+                (-h_t_at_gamma, "lookup_t_a".into()),
+                (-h_t_at_gamma * theta, "lookup_t_b".into()),
+                (-h_t_at_gamma * theta_2, "lookup_t_c".into()),
+                (-h_t_at_gamma * theta_3, "lookup_t_id".into()),
+                (-F::one(), m.into()),
+            ]);
+            for check in [r1cs_check, f_check, t_check] {
+                lookup_check += (lambda_power, &check);
+                lambda_power *= lambda;
+            }
+            linear_combinations.extend([z_a, h_f, h_t]);
+        }
+        lookup_check -= &LinearCombination::new("h_lookup", [(v_H_at_gamma, "h_lookup")]);
+        debug_assert!(evals.get_lc_eval(&lookup_check, gamma)?.is_zero());
+
+        linear_combinations.extend([s, lookup_check]);
+        end_timer!(lookup_time);
         Ok(linear_combinations)
     }
 
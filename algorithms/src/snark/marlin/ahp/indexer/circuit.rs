@@ -22,7 +22,13 @@ use crate::{
         EvaluationDomain,
     },
     polycommit::sonic_pc::LabeledPolynomial,
-    snark::marlin::{ahp::matrices::MatrixArithmetization, AHPForR1CS, CircuitInfo, MarlinMode, Matrix},
+    snark::marlin::{
+        ahp::{indexer::LookupIndex, matrices::MatrixArithmetization},
+        AHPForR1CS,
+        CircuitInfo,
+        MarlinMode,
+        Matrix,
+    },
 };
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{serialize::*, SerializationError};
@@ -35,6 +41,7 @@ use snarkvm_utilities::{serialize::*, SerializationError};
 /// 2) `{a,b,c}` are the matrices defining the R1CS instance
 /// 3) `{a,b,c}_star_arith` are structs containing information about A^*, B^*, and C^*,
 /// which are matrices defined as `M^*(i, j) = M(j, i) * u_H(j, j)`.
+/// 4) `lookup` contains the lookup tables of the constraint system, if it has any.
 pub struct Circuit<F: PrimeField, MM: MarlinMode> {
     /// Information about the indexed circuit.
     pub index_info: CircuitInfo<F>,
@@ -51,6 +58,10 @@ pub struct Circuit<F: PrimeField, MM: MarlinMode> {
     pub b_arith: MatrixArithmetization<F>,
     pub c_arith: MatrixArithmetization<F>,
 
+    /// The indexed lookup tables, if the constraint system has lookup constraints.
+    /// This is not included in the serialization of the circuit.
+    pub lookup: Option<LookupIndex<F>>,
+
     pub fft_precomputation: FFTPrecomputation<F>,
     pub ifft_precomputation: IFFTPrecomputation<F>,
 
@@ -75,6 +86,10 @@ impl<F: PrimeField, MM: MarlinMode> Circuit<F, MM> {
             &self.a_arith.col,
             &self.b_arith.col,
             &self.c_arith.col,
+        ]
+        .into_iter()
+        .chain(self.lookup.iter().flat_map(|lookup| lookup.iter()))
+        .chain([
             &self.a_arith.row,
             &self.b_arith.row,
             &self.c_arith.row,
@@ -84,8 +99,7 @@ impl<F: PrimeField, MM: MarlinMode> Circuit<F, MM> {
             &self.a_arith.val,
             &self.b_arith.val,
             &self.c_arith.val,
-        ]
-        .into_iter()
+        ])
     }
 }
 
@@ -162,6 +176,7 @@ impl<F: PrimeField, MM: MarlinMode> CanonicalDeserialize for Circuit<F, MM> {
             a_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             b_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             c_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            lookup: None,
             fft_precomputation,
             ifft_precomputation,
             mode: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
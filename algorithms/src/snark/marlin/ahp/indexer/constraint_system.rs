@@ -14,9 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::snark::marlin::ahp::matrices::{make_matrices_square, padded_matrix_dim, to_matrix_helper};
+use crate::snark::marlin::ahp::matrices::{
+    make_matrices_square,
+    pad_constraints_for_lookups,
+    padded_matrix_dim,
+    to_matrix_helper,
+};
 use snarkvm_fields::Field;
-use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem as CS, Index as VarIndex, LinearCombination, Variable};
+use snarkvm_r1cs::{
+    errors::SynthesisError,
+    ConstraintSystem as CS,
+    Index as VarIndex,
+    LinearCombination,
+    LookupTable,
+    Variable,
+};
 use snarkvm_utilities::serialize::*;
 
 /// Stores constraints during index generation.
@@ -27,6 +39,9 @@ pub(crate) struct ConstraintSystem<F: Field> {
     pub(crate) num_public_variables: usize,
     pub(crate) num_private_variables: usize,
     pub(crate) num_constraints: usize,
+    pub(crate) lookup_tables: Vec<LookupTable<F>>,
+    /// The rows that are lookup constraints, along with the lookup table they are resolved against.
+    pub(crate) lookup_rows: Vec<(usize, usize)>,
 }
 
 impl<F: Field> ConstraintSystem<F> {
@@ -39,9 +54,17 @@ impl<F: Field> ConstraintSystem<F> {
             num_public_variables: 1,
             num_private_variables: 0,
             num_constraints: 0,
+            lookup_tables: Vec::new(),
+            lookup_rows: Vec::new(),
         }
     }
 
+    /// Returns the total number of rows across all lookup tables.
+    #[inline]
+    pub(crate) fn num_lookup_table_entries(&self) -> usize {
+        self.lookup_tables.iter().map(|table| table.len()).sum()
+    }
+
     #[inline]
     pub(crate) fn a_matrix(&self) -> Vec<Vec<(F, usize)>> {
         to_matrix_helper(&self.a, self.num_public_variables)
@@ -59,6 +82,7 @@ impl<F: Field> ConstraintSystem<F> {
 
     #[inline]
     pub(crate) fn make_matrices_square(&mut self) {
+        pad_constraints_for_lookups(self, self.num_lookup_table_entries());
         let num_variables = self.num_public_variables + self.num_private_variables;
         let matrix_dim = padded_matrix_dim(num_variables, self.num_constraints);
         make_matrices_square(self, num_variables);
@@ -126,6 +150,38 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         self.num_constraints += 1;
     }
 
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> Result<usize, SynthesisError> {
+        self.lookup_tables.push(table);
+        Ok(self.lookup_tables.len() - 1)
+    }
+
+    fn enforce_lookup<A, AR, LA, LB, LC>(
+        &mut self,
+        _: A,
+        table_index: usize,
+        a: LA,
+        b: LB,
+        c: LC,
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        if table_index >= self.lookup_tables.len() {
+            return Err(SynthesisError::MissingLookupTable(table_index));
+        }
+        self.a.push(Self::make_row(&a(LinearCombination::zero())));
+        self.b.push(Self::make_row(&b(LinearCombination::zero())));
+        self.c.push(Self::make_row(&c(LinearCombination::zero())));
+        self.lookup_rows.push((self.num_constraints, table_index));
+
+        self.num_constraints += 1;
+        Ok(())
+    }
+
     fn push_namespace<NR, N>(&mut self, _: N)
     where
         NR: AsRef<str>,
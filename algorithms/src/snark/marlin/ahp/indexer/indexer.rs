@@ -19,7 +19,13 @@ use crate::{
     polycommit::sonic_pc::{PolynomialInfo, PolynomialLabel},
     snark::marlin::{
         ahp::{
-            indexer::{Circuit, CircuitInfo, ConstraintSystem as IndexerConstraintSystem},
+            indexer::{
+                Circuit,
+                CircuitInfo,
+                ConstraintSystem as IndexerConstraintSystem,
+                LookupIndex,
+                LOOKUP_POLYNOMIAL_LABELS,
+            },
             matrices::arithmetize_matrix,
             AHPError,
             AHPForR1CS,
@@ -61,6 +67,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             non_zero_c_domain,
             c_evals,
 
+            lookup,
+
             index_info,
         } = Self::index_helper(c)?;
         let joint_arithmetization_time = start_timer!(|| "Arithmetizing A");
@@ -93,13 +101,15 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             a_arith,
             b_arith,
             c_arith,
+            lookup,
             fft_precomputation,
             ifft_precomputation,
             mode: PhantomData,
         })
     }
 
-    pub fn index_polynomial_info() -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+    /// Output the info of the indexed polynomials, including the lookup polynomials if `has_lookups` is set.
+    pub fn index_polynomial_info(has_lookups: bool) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
         let mut map = BTreeMap::new();
         for matrix in ["a", "b", "c"] {
             map.insert(format!("row_{matrix}"), PolynomialInfo::new(format!("row_{matrix}"), None, None));
@@ -107,13 +117,21 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             map.insert(format!("val_{matrix}"), PolynomialInfo::new(format!("val_{matrix}"), None, None));
             map.insert(format!("row_col_{matrix}"), PolynomialInfo::new(format!("row_col_{matrix}"), None, None));
         }
+        if has_lookups {
+            for label in LOOKUP_POLYNOMIAL_LABELS {
+                map.insert(label.to_string(), PolynomialInfo::new(label.to_string(), None, None));
+            }
+        }
         map
     }
 
-    pub fn index_polynomial_labels() -> impl Iterator<Item = PolynomialLabel> {
-        ["a", "b", "c"].into_iter().flat_map(|matrix| {
-            [format!("row_{matrix}"), format!("col_{matrix}"), format!("val_{matrix}"), format!("row_col_{matrix}")]
-        })
+    pub fn index_polynomial_labels(has_lookups: bool) -> impl Iterator<Item = PolynomialLabel> {
+        ["a", "b", "c"]
+            .into_iter()
+            .flat_map(|matrix| {
+                [format!("row_{matrix}"), format!("col_{matrix}"), format!("val_{matrix}"), format!("row_col_{matrix}")]
+            })
+            .chain(LOOKUP_POLYNOMIAL_LABELS.into_iter().filter(move |_| has_lookups).map(ToString::to_string))
     }
 
     fn index_helper<C: ConstraintSynthesizer<F>>(c: &C) -> Result<IndexerState<F>, AHPError> {
@@ -186,6 +204,15 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let non_zero_c_domain =
             EvaluationDomain::new(num_non_zero_c).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
+        let lookup = match ics.lookup_tables.is_empty() {
+            true => None,
+            false => Some(LookupIndex::new(
+                core::mem::take(&mut ics.lookup_tables),
+                core::mem::take(&mut ics.lookup_rows),
+                constraint_domain,
+            )?),
+        };
+
         let (constraint_domain_elements, constraint_domain_eq_poly_vals) =
             precomputation_for_matrix_evals(&constraint_domain);
 
@@ -220,6 +247,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             non_zero_c_domain,
             c_evals,
 
+            lookup,
+
             index_info,
         });
         end_timer!(index_time);
@@ -247,6 +276,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             let lagrange_coefficients_at_point = domain.evaluate_all_lagrange_coefficients(point);
             labels.into_iter().zip(evals.evaluate(&lagrange_coefficients_at_point))
         })
+        .chain(state.lookup.iter().flat_map(|lookup| lookup.iter().map(|p| (p.label().to_string(), p.evaluate(point)))))
         .collect::<Vec<_>>();
         evals.sort_by(|(l1, _), (l2, _)| l1.cmp(l2));
         Ok(evals.into_iter().map(|(_, eval)| eval))
@@ -268,5 +298,7 @@ struct IndexerState<F: PrimeField> {
     non_zero_c_domain: EvaluationDomain<F>,
     c_evals: MatrixEvals<F>,
 
+    lookup: Option<LookupIndex<F>>,
+
     index_info: CircuitInfo<F>,
 }
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    fft::{EvaluationDomain, Evaluations as EvaluationsOnDomain},
+    polycommit::sonic_pc::LabeledPolynomial,
+    snark::marlin::ahp::AHPError,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::LookupTable;
+use snarkvm_utilities::{serialize::*, SerializationError};
+
+/// The labels of the lookup polynomials, in alphabetical order.
+pub(crate) const LOOKUP_POLYNOMIAL_LABELS: [&str; 6] =
+    ["lookup_id", "lookup_s", "lookup_t_a", "lookup_t_b", "lookup_t_c", "lookup_t_id"];
+
+/// The indexed lookup tables of a constraint system.
+/// This struct contains the lookup tables and the constraint rows that are resolved against them,
+/// along with their low-degree extensions over the constraint domain:
+/// 1) `s` is the selector for lookup rows, and `id` is the table that each lookup row refers to.
+/// 2) `t_{a,b,c}` are the concatenated table columns, and `t_id` is the table that each entry belongs to.
+///
+/// Unused table entries are padded with `(0, 0, 0, num_tables)`, which no lookup row can match.
+#[derive(Clone, Debug)]
+pub struct LookupIndex<F: PrimeField> {
+    /// The lookup tables of the constraint system.
+    pub tables: Vec<LookupTable<F>>,
+    /// The rows that are lookup constraints, along with the table they are resolved against.
+    pub rows: Vec<(usize, usize)>,
+    /// The position of the first entry of each table in the concatenated table columns.
+    offsets: Vec<usize>,
+
+    /// The LDE of the table identifier of each lookup row.
+    pub id: LabeledPolynomial<F>,
+    /// The LDE of the lookup row selector.
+    pub s: LabeledPolynomial<F>,
+    /// The LDEs of the concatenated table columns.
+    pub t_a: LabeledPolynomial<F>,
+    pub t_b: LabeledPolynomial<F>,
+    pub t_c: LabeledPolynomial<F>,
+    /// The LDE of the table identifier of each table entry.
+    pub t_id: LabeledPolynomial<F>,
+}
+
+impl<F: PrimeField> LookupIndex<F> {
+    /// Indexes the given lookup tables and lookup rows over the constraint domain.
+    pub fn new(
+        tables: Vec<LookupTable<F>>,
+        rows: Vec<(usize, usize)>,
+        constraint_domain: EvaluationDomain<F>,
+    ) -> Result<Self, AHPError> {
+        let size = constraint_domain.size();
+        let num_table_entries = tables.iter().map(|table| table.len()).sum::<usize>();
+        if num_table_entries > size {
+            return Err(AHPError::PolynomialDegreeTooLarge);
+        }
+
+        let mut id = vec![F::zero(); size];
+        let mut s = vec![F::zero(); size];
+        for &(row, table) in &rows {
+            if row >= size || table >= tables.len() {
+                return Err(AHPError::InstanceDoesNotMatchIndex);
+            }
+            id[row] = F::from(table as u64);
+            s[row] = F::one();
+        }
+
+        let padding = F::from(tables.len() as u64);
+        let (mut t_a, mut t_b, mut t_c, mut t_id) =
+            (vec![F::zero(); size], vec![F::zero(); size], vec![F::zero(); size], vec![padding; size]);
+        let mut offsets = Vec::with_capacity(tables.len());
+        let mut position = 0;
+        for (i, table) in tables.iter().enumerate() {
+            offsets.push(position);
+            for (a, b, c) in table.iter() {
+                t_a[position] = *a;
+                t_b[position] = *b;
+                t_c[position] = *c;
+                t_id[position] = F::from(i as u64);
+                position += 1;
+            }
+        }
+
+        let [id, s, t_a, t_b, t_c, t_id]: [_; 6] = [id, s, t_a, t_b, t_c, t_id]
+            .into_iter()
+            .zip(LOOKUP_POLYNOMIAL_LABELS)
+            .map(|(evals, label)| {
+                let poly = EvaluationsOnDomain::from_vec_and_domain(evals, constraint_domain).interpolate();
+                LabeledPolynomial::new(label.to_string(), poly, None, None)
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        Ok(Self { tables, rows, offsets, id, s, t_a, t_b, t_c, t_id })
+    }
+
+    /// Returns the total number of entries across all lookup tables.
+    pub fn num_table_entries(&self) -> usize {
+        self.tables.iter().map(|table| table.len()).sum()
+    }
+
+    /// Returns the position of the given entry of the given table in the concatenated table columns.
+    pub fn position(&self, table: usize, a: &F, b: &F, c: &F) -> Option<usize> {
+        Some(self.offsets.get(table)? + self.tables[table].lookup(a, b, c)?)
+    }
+
+    /// Iterate over the lookup polynomials, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
+        [&self.id, &self.s, &self.t_a, &self.t_b, &self.t_c, &self.t_id].into_iter()
+    }
+
+    /// Writes the lookup tables and lookup rows; the polynomials are recomputed on deserialization.
+    pub(crate) fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        let tables = self.tables.iter().map(|table| table.iter().copied().collect::<Vec<_>>()).collect::<Vec<_>>();
+        tables.serialize_with_mode(&mut writer, compress)?;
+        self.rows.serialize_with_mode(&mut writer, compress)
+    }
+
+    /// Reads the lookup tables and lookup rows, and indexes them over the given constraint domain.
+    pub(crate) fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        constraint_domain: EvaluationDomain<F>,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let tables: Vec<Vec<(F, F, F)>> = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let rows = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let tables = tables.into_iter().map(|table| table.into_iter().collect()).collect();
+        Self::new(tables, rows, constraint_domain).map_err(|_| SerializationError::InvalidData)
+    }
+}
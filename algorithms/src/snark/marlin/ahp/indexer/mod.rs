@@ -27,6 +27,9 @@ pub(crate) use constraint_system::*;
 
 mod indexer;
 
+mod lookup;
+pub(crate) use lookup::*;
+
 /// Represents a matrix.
 pub(crate) type Matrix<F> = Vec<Vec<(F, usize)>>;
 
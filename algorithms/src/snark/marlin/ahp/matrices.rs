@@ -72,6 +72,19 @@ pub(crate) fn pad_input_for_indexer_and_prover<F: PrimeField, CS: ConstraintSyst
     }
 }
 
+/// Adds dummy constraints until the constraint domain is large enough to hold every lookup table entry.
+/// This must be called before `make_matrices_square`, and identically by the indexer and the prover.
+pub(crate) fn pad_constraints_for_lookups<F: Field, CS: ConstraintSystem<F>>(cs: &mut CS, num_table_entries: usize) {
+    let num_constraints = cs.num_constraints();
+    if num_table_entries > num_constraints {
+        use core::convert::identity as iden;
+        // Add dummy constraints of the form 0 * 0 == 0
+        for i in 0..(num_table_entries - num_constraints) {
+            cs.enforce(|| format!("pad_lookup_constraint_{}", i), iden, iden, iden);
+        }
+    }
+}
+
 pub(crate) fn make_matrices_square<F: Field, CS: ConstraintSystem<F>>(cs: &mut CS, num_formatted_variables: usize) {
     let num_constraints = cs.num_constraints();
     let matrix_padding = ((num_formatted_variables as isize) - (num_constraints as isize)).abs();
@@ -14,9 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::snark::marlin::ahp::matrices::make_matrices_square;
+use crate::snark::marlin::ahp::matrices::{make_matrices_square, pad_constraints_for_lookups};
 use snarkvm_fields::Field;
-use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem as CS, Index as VarIndex, LinearCombination, Variable};
+use snarkvm_r1cs::{
+    errors::SynthesisError,
+    ConstraintSystem as CS,
+    Index as VarIndex,
+    LinearCombination,
+    LookupTable,
+    Variable,
+};
 
 pub(crate) struct ConstraintSystem<F: Field> {
     pub(crate) public_variables: Vec<F>,
@@ -24,6 +31,8 @@ pub(crate) struct ConstraintSystem<F: Field> {
     pub(crate) num_public_variables: usize,
     pub(crate) num_private_variables: usize,
     pub(crate) num_constraints: usize,
+    pub(crate) num_lookup_tables: usize,
+    pub(crate) num_lookup_table_entries: usize,
 }
 
 impl<F: Field> ConstraintSystem<F> {
@@ -34,6 +43,8 @@ impl<F: Field> ConstraintSystem<F> {
             num_public_variables: 1usize,
             num_private_variables: 0usize,
             num_constraints: 0usize,
+            num_lookup_tables: 0usize,
+            num_lookup_table_entries: 0usize,
         }
     }
 
@@ -52,6 +63,7 @@ impl<F: Field> ConstraintSystem<F> {
     }
 
     pub(crate) fn make_matrices_square(&mut self) {
+        pad_constraints_for_lookups(self, self.num_lookup_table_entries);
         let num_variables = self.num_public_variables + self.num_private_variables;
         make_matrices_square(self, num_variables);
         assert_eq!(self.num_public_variables + self.num_private_variables, self.num_constraints, "padding failed!");
@@ -101,6 +113,35 @@ impl<F: Field> CS<F> for ConstraintSystem<F> {
         self.num_constraints += 1;
     }
 
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> Result<usize, SynthesisError> {
+        self.num_lookup_tables += 1;
+        self.num_lookup_table_entries += table.len();
+        Ok(self.num_lookup_tables - 1)
+    }
+
+    #[inline]
+    fn enforce_lookup<A, AR, LA, LB, LC>(
+        &mut self,
+        _: A,
+        table_index: usize,
+        _: LA,
+        _: LB,
+        _: LC,
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        if table_index >= self.num_lookup_tables {
+            return Err(SynthesisError::MissingLookupTable(table_index));
+        }
+        self.num_constraints += 1;
+        Ok(())
+    }
+
     fn push_namespace<NR, N>(&mut self, _: N)
     where
         NR: AsRef<str>,
@@ -24,6 +24,8 @@ use crate::polycommit::sonic_pc::{LabeledPolynomial, LabeledPolynomialWithBasis,
 #[derive(Debug, Clone)]
 pub struct FirstOracles<'a, F: PrimeField> {
     pub(in crate::snark::marlin) batches: Vec<SingleEntry<'a, F>>,
+    /// The lookup polynomials of each instance, if the circuit has lookups.
+    pub(in crate::snark::marlin) lookup_batches: Vec<LookupEntry<'a, F>>,
     /// The sum-check hiding polynomial.
     pub mask_poly: Option<LabeledPolynomial<F>>,
 }
@@ -33,18 +35,24 @@ impl<'a, F: PrimeField> FirstOracles<'a, F> {
     /// Intended for use when committing.
     #[allow(clippy::needless_collect)]
     pub fn iter_for_commit(&mut self) -> impl Iterator<Item = LabeledPolynomialWithBasis<'a, F>> {
-        let t = self.batches.iter_mut().flat_map(|b| b.iter_for_commit()).collect::<Vec<_>>();
+        let mut t = self.batches.iter_mut().flat_map(|b| b.iter_for_commit()).collect::<Vec<_>>();
+        t.extend(self.lookup_batches.iter_mut().flat_map(|b| b.iter_for_commit()));
         t.into_iter().chain(self.mask_poly.clone().map(Into::into))
     }
 
     /// Iterate over the polynomials output by the prover in the first round.
     /// Intended for use when opening.
     pub fn iter_for_open(&'a self) -> impl Iterator<Item = &'a LabeledPolynomial<F>> {
-        self.batches.iter().flat_map(|b| b.iter_for_open()).chain(self.mask_poly.as_ref())
+        self.batches
+            .iter()
+            .flat_map(|b| b.iter_for_open())
+            .chain(self.lookup_batches.iter().flat_map(|b| b.iter_for_open()))
+            .chain(self.mask_poly.as_ref())
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
         self.batches.iter().all(|b| b.matches_info(info))
+            && self.lookup_batches.iter().all(|b| b.matches_info(info))
             && self.mask_poly.as_ref().map_or(true, |p| Some(p.info()) == info.get(p.label()))
     }
 }
@@ -92,6 +100,44 @@ impl<'a, F: PrimeField> SingleEntry<'a, F> {
     }
 }
 
+#[derive(Debug, Clone)]
+pub(in crate::snark::marlin) struct LookupEntry<'a, F: PrimeField> {
+    /// The evaluations of `Cz`.
+    pub(super) z_c: LabeledPolynomialWithBasis<'a, F>,
+    /// The multiplicities of the table entries.
+    pub(super) m: LabeledPolynomialWithBasis<'a, F>,
+    /// The LDE of `Cz`.
+    pub(super) z_c_poly: LabeledPolynomial<F>,
+    /// The LDE of the multiplicities of the table entries.
+    pub(super) m_poly: LabeledPolynomial<F>,
+}
+
+impl<'a, F: PrimeField> LookupEntry<'a, F> {
+    /// Iterate over the polynomials output by the prover in the first round.
+    /// Intended for use when committing.
+    pub fn iter_for_commit(&mut self) -> impl Iterator<Item = LabeledPolynomialWithBasis<'a, F>> {
+        let z_c = self.z_c.clone();
+        self.z_c = LabeledPolynomialWithBasis { polynomial: vec![], info: z_c.info().clone() };
+
+        let m = self.m.clone();
+        self.m = LabeledPolynomialWithBasis { polynomial: vec![], info: m.info().clone() };
+        [z_c, m].into_iter()
+    }
+
+    /// Iterate over the polynomials output by the prover in the first round.
+    /// Intended for use when opening.
+    pub fn iter_for_open(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
+        [&self.z_c_poly, &self.m_poly].into_iter()
+    }
+
+    pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
+        Some(self.z_c.info()) == info.get(self.z_c.label())
+            && Some(self.m.info()) == info.get(self.m.label())
+            && Some(self.z_c_poly.info()) == info.get(self.z_c_poly.label())
+            && Some(self.m_poly.info()) == info.get(self.m_poly.label())
+    }
+}
+
 /// The second set of prover oracles.
 #[derive(Debug)]
 pub struct SecondOracles<F: PrimeField> {
@@ -99,16 +145,20 @@ pub struct SecondOracles<F: PrimeField> {
     pub g_1: LabeledPolynomial<F>,
     /// The polynomial `h` resulting from the first sumcheck.
     pub h_1: LabeledPolynomial<F>,
+    /// The polynomials `h_f` and `h_t` of the lookup argument for each instance, if the circuit has lookups.
+    pub lookup: Vec<[LabeledPolynomial<F>; 2]>,
 }
 
 impl<F: PrimeField> SecondOracles<F> {
     /// Iterate over the polynomials output by the prover in the second round.
     pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
-        [&self.g_1, &self.h_1].into_iter()
+        [&self.g_1, &self.h_1].into_iter().chain(self.lookup.iter().flatten())
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
-        Some(self.h_1.info()) == info.get(self.h_1.label()) && Some(self.g_1.info()) == info.get(self.g_1.label())
+        Some(self.h_1.info()) == info.get(self.h_1.label())
+            && Some(self.g_1.info()) == info.get(self.g_1.label())
+            && self.lookup.iter().flatten().all(|p| Some(p.info()) == info.get(p.label()))
     }
 }
 
@@ -121,18 +171,21 @@ pub struct ThirdOracles<F: PrimeField> {
     pub g_b: LabeledPolynomial<F>,
     /// The polynomial `g_c` resulting from the second sumcheck.
     pub g_c: LabeledPolynomial<F>,
+    /// The polynomial `h_lookup` resulting from the lookup argument, if the circuit has lookups.
+    pub h_lookup: Option<LabeledPolynomial<F>>,
 }
 
 impl<F: PrimeField> ThirdOracles<F> {
     /// Iterate over the polynomials output by the prover in the third round.
     pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
-        [&self.g_a, &self.g_b, &self.g_c].into_iter()
+        [&self.g_a, &self.g_b, &self.g_c].into_iter().chain(self.h_lookup.as_ref())
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
         Some(self.g_a.info()) == info.get(self.g_a.label())
             && Some(self.g_b.info()) == info.get(self.g_b.label())
             && Some(self.g_c.info()) == info.get(self.g_c.label())
+            && self.h_lookup.as_ref().map_or(true, |p| Some(p.info()) == info.get(p.label()))
     }
 }
 
@@ -43,12 +43,15 @@ use rayon::prelude::*;
 
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Output the number of oracles sent by the prover in the first round.
-    pub fn num_first_round_oracles(batch_size: usize) -> usize {
-        3 * batch_size + (MM::ZK as usize)
+    pub fn num_first_round_oracles(batch_size: usize, has_lookups: bool) -> usize {
+        (3 + 2 * has_lookups as usize) * batch_size + (MM::ZK as usize)
     }
 
     /// Output the degree bounds of oracles in the first round.
-    pub fn first_round_polynomial_info(batch_size: usize) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+    pub fn first_round_polynomial_info(
+        batch_size: usize,
+        has_lookups: bool,
+    ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
         let mut polynomials = Vec::new();
 
         for i in 0..batch_size {
@@ -56,6 +59,12 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             polynomials.push(PolynomialInfo::new(witness_label("z_a", i), None, Self::zk_bound()));
             polynomials.push(PolynomialInfo::new(witness_label("z_b", i), None, Self::zk_bound()));
         }
+        if has_lookups {
+            for i in 0..batch_size {
+                polynomials.push(PolynomialInfo::new(witness_label("z_c", i), None, Self::zk_bound()));
+                polynomials.push(PolynomialInfo::new(witness_label("m", i), None, Self::zk_bound()));
+            }
+        }
         if MM::ZK {
             polynomials.push(PolynomialInfo::new("mask_poly".to_string(), None, None));
         }
@@ -74,19 +83,25 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let z_a = state.z_a.take().unwrap();
         let z_b = state.z_b.take().unwrap();
+        let z_c = state.z_c.take();
+        let multiplicities = state.multiplicities.take();
         let private_variables = core::mem::take(&mut state.private_variables);
         assert_eq!(z_a.len(), batch_size);
         assert_eq!(z_b.len(), batch_size);
         assert_eq!(private_variables.len(), batch_size);
         let mut r_b_s = Vec::with_capacity(batch_size);
 
-        let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(3 * batch_size);
+        // With lookups, `z_a` is also evaluated on its own in the lookup argument, and so must be hidden.
+        let has_lookups = state.index.lookup.is_some();
+        let num_jobs = Self::num_first_round_oracles(batch_size, has_lookups) - (MM::ZK as usize);
+        let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(num_jobs);
         let state_ref = &state;
         for (i, (z_a, z_b, private_variables, x_poly)) in
             itertools::izip!(z_a, z_b, private_variables, &state.x_poly).enumerate()
         {
             job_pool.add_job(move || Self::calculate_w(witness_label("w", i), private_variables, x_poly, state_ref));
-            job_pool.add_job(move || Self::calculate_z_m(witness_label("z_a", i), z_a, false, state_ref, None));
+            let r_a = has_lookups.then(|| F::rand(rng));
+            job_pool.add_job(move || Self::calculate_z_m(witness_label("z_a", i), z_a, has_lookups, state_ref, r_a));
             let r_b = F::rand(rng);
             job_pool.add_job(move || Self::calculate_z_m(witness_label("z_b", i), z_b, true, state_ref, Some(r_b)));
             if MM::ZK {
@@ -94,8 +109,18 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             }
         }
 
-        let batches = job_pool
-            .execute_all()
+        if let (Some(z_c), Some(multiplicities)) = (z_c, multiplicities) {
+            assert_eq!(z_c.len(), batch_size);
+            assert_eq!(multiplicities.len(), batch_size);
+            for (i, (z_c, m)) in z_c.into_iter().zip_eq(multiplicities).enumerate() {
+                job_pool.add_job(move || Self::calculate_z_m(witness_label("z_c", i), z_c, false, state_ref, None));
+                job_pool.add_job(move || Self::calculate_z_m(witness_label("m", i), m, false, state_ref, None));
+            }
+        }
+
+        let mut results = job_pool.execute_all();
+        let lookup_results = results.split_off(3 * batch_size);
+        let batches = results
             .into_iter()
             .tuples()
             .map(|(w, z_a, z_b)| {
@@ -108,10 +133,22 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             .collect::<Vec<_>>();
         assert_eq!(batches.len(), batch_size);
 
+        let lookup_batches = lookup_results
+            .into_iter()
+            .tuples()
+            .map(|(z_c, m)| {
+                let (z_c_poly, z_c) = z_c.z_m().unwrap();
+                let (m_poly, m) = m.z_m().unwrap();
+
+                prover::LookupEntry { z_c, m, z_c_poly, m_poly }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(lookup_batches.len(), if has_lookups { batch_size } else { 0 });
+
         let mask_poly = Self::calculate_mask_poly(constraint_domain, rng);
 
-        let oracles = prover::FirstOracles { batches, mask_poly };
-        assert!(oracles.matches_info(&Self::first_round_polynomial_info(batch_size)));
+        let oracles = prover::FirstOracles { batches, lookup_batches, mask_poly };
+        assert!(oracles.matches_info(&Self::first_round_polynomial_info(batch_size, has_lookups)));
         state.first_round_oracles = Some(Arc::new(oracles));
         state.mz_poly_randomizer = MM::ZK.then_some(r_b_s);
         end_timer!(round_time);
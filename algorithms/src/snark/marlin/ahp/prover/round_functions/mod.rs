@@ -44,7 +44,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let init_time = start_timer!(|| "AHP::Prover::Init");
 
         // Perform matrix multiplications.
-        let (padded_public_variables, private_variables, z_a, z_b) = cfg_iter!(circuits)
+        let (padded_public_variables, private_variables, z_a, z_b, z_c, multiplicities): (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>) = cfg_iter!(circuits)
             .map(|circuit| {
                 let constraint_time = start_timer!(|| "Generating constraints and witnesses");
                 let mut pcs = prover::ConstraintSystem::new();
@@ -91,18 +91,42 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 Self::formatted_public_input_is_admissible(&padded_public_variables)?;
 
                 let eval_z_a_time = start_timer!(|| "Evaluating z_A");
-                let z_a = cfg_iter!(index.a)
+                let z_a: Vec<F> = cfg_iter!(index.a)
                     .map(|row| inner_product(&padded_public_variables, &private_variables, row, num_public_variables))
                     .collect();
                 end_timer!(eval_z_a_time);
 
                 let eval_z_b_time = start_timer!(|| "Evaluating z_B");
-                let z_b = cfg_iter!(index.b)
+                let z_b: Vec<F> = cfg_iter!(index.b)
                     .map(|row| inner_product(&padded_public_variables, &private_variables, row, num_public_variables))
                     .collect();
                 end_timer!(eval_z_b_time);
+
+                // If the circuit has lookups, `z_c` is committed to explicitly, along with the
+                // number of times that each table entry is looked up.
+                let (z_c, multiplicities) = match &index.lookup {
+                    Some(lookup) => {
+                        let eval_z_c_time = start_timer!(|| "Evaluating z_C");
+                        let z_c: Vec<F> = cfg_iter!(index.c)
+                            .map(|row| {
+                                inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
+                            })
+                            .collect();
+                        end_timer!(eval_z_c_time);
+
+                        let mut multiplicities = vec![F::zero(); num_constraints];
+                        for &(row, table) in &lookup.rows {
+                            // Lookups that are not in the table are left unaccounted for, and fail the lookup argument.
+                            if let Some(position) = lookup.position(table, &z_a[row], &z_b[row], &z_c[row]) {
+                                multiplicities[position] += F::one();
+                            }
+                        }
+                        (Some(z_c), Some(multiplicities))
+                    }
+                    None => (None, None),
+                };
                 end_timer!(init_time);
-                Ok((padded_public_variables, private_variables, z_a, z_b))
+                Ok((padded_public_variables, private_variables, z_a, z_b, z_c, multiplicities))
             })
             .collect::<Result<Vec<_>, _>>()?
             .into_iter()
@@ -111,6 +135,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let mut state = prover::State::initialize(padded_public_variables, private_variables, index)?;
         state.z_a = Some(z_a);
         state.z_b = Some(z_b);
+        state.z_c = z_c.into_iter().collect();
+        state.multiplicities = multiplicities.into_iter().collect();
 
         Ok(state)
     }
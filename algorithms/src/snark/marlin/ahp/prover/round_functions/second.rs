@@ -35,6 +35,7 @@ use crate::{
             UnnormalizedBivariateLagrangePoly,
         },
         prover,
+        witness_label,
         MarlinMode,
     },
 };
@@ -49,37 +50,51 @@ use rayon::prelude::*;
 
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Output the number of oracles sent by the prover in the second round.
-    pub fn num_second_round_oracles() -> usize {
-        2
+    pub fn num_second_round_oracles(batch_size: usize, has_lookups: bool) -> usize {
+        2 + 2 * has_lookups as usize * batch_size
     }
 
     /// Output the degree bounds of oracles in the first round.
-    pub fn second_round_polynomial_info(info: &CircuitInfo<F>) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+    pub fn second_round_polynomial_info(
+        info: &CircuitInfo<F>,
+        batch_size: usize,
+        has_lookups: bool,
+    ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
         let constraint_domain_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_constraints).unwrap();
-        [
+        let mut polynomials = vec![
             PolynomialInfo::new("g_1".into(), Some(constraint_domain_size - 2), Self::zk_bound()),
             PolynomialInfo::new("h_1".into(), None, None),
-        ]
-        .into_iter()
-        .map(|info| (info.label().into(), info))
-        .collect()
+        ];
+        if has_lookups {
+            for i in 0..batch_size {
+                polynomials.push(PolynomialInfo::new(witness_label("h_f", i), None, Self::zk_bound()));
+                polynomials.push(PolynomialInfo::new(witness_label("h_t", i), None, Self::zk_bound()));
+            }
+        }
+        polynomials.into_iter().map(|info| (info.label().into(), info)).collect()
     }
 
     /// Output the second round message and the next state.
     pub fn prover_second_round<'a, R: RngCore>(
         verifier_message: &verifier::FirstMessage<F>,
         mut state: prover::State<'a, F, MM>,
-        _r: &mut R,
+        rng: &mut R,
     ) -> (prover::SecondOracles<F>, prover::State<'a, F, MM>) {
         let round_time = start_timer!(|| "AHP::Prover::SecondRound");
 
         let constraint_domain = state.constraint_domain;
         let zk_bound = Self::zk_bound();
 
-        let verifier::FirstMessage { alpha, eta_b, eta_c, batch_combiners } = verifier_message;
+        let verifier::FirstMessage { alpha, eta_b, eta_c, batch_combiners, lookup_challenges } = verifier_message;
 
         let (summed_z_m, t) = Self::calculate_summed_z_m_and_t(&state, *alpha, *eta_b, *eta_c, batch_combiners);
 
+        // The lookup sumcheck is folded into the lincheck sumcheck, as `sum_i c_i * (h_f_i - h_t_i)`.
+        let lookup = match lookup_challenges {
+            Some(challenges) => Self::calculate_lookup_polynomials(&state, challenges, rng),
+            None => vec![],
+        };
+
         let z_time = start_timer!(|| "Compute z poly");
         let z = cfg_iter!(state.first_round_oracles.as_ref().unwrap().batches)
             .zip_eq(batch_combiners)
@@ -96,7 +111,11 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         end_timer!(z_time);
 
-        let sumcheck_lhs = Self::calculate_lhs(&state, t, summed_z_m, z, *alpha);
+        let mut sumcheck_lhs = Self::calculate_lhs(&state, t, summed_z_m, z, *alpha);
+        for ([h_f, h_t], combiner) in lookup.iter().zip(batch_combiners) {
+            sumcheck_lhs += (*combiner, h_f.polynomial().as_dense().unwrap());
+            sumcheck_lhs += (-*combiner, h_t.polynomial().as_dense().unwrap());
+        }
 
         debug_assert!(
             sumcheck_lhs.evaluate_over_domain_by_ref(constraint_domain).evaluations.into_iter().sum::<F>().is_zero()
@@ -114,10 +133,16 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let oracles = prover::SecondOracles {
             g_1: LabeledPolynomial::new("g_1".into(), g_1, Some(constraint_domain.size() - 2), zk_bound),
             h_1: LabeledPolynomial::new("h_1".into(), h_1, None, None),
+            lookup: lookup.clone(),
         };
-        assert!(oracles.matches_info(&Self::second_round_polynomial_info(&state.index.index_info)));
+        assert!(oracles.matches_info(&Self::second_round_polynomial_info(
+            &state.index.index_info,
+            state.batch_size,
+            lookup_challenges.is_some()
+        )));
 
         state.verifier_first_message = Some(verifier_message.clone());
+        state.lookup_polynomials = lookup_challenges.is_some().then_some(lookup);
         end_timer!(round_time);
 
         (oracles, state)
@@ -161,6 +186,62 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         lhs
     }
 
+    /// Computes the `h_f` and `h_t` polynomials of the lookup argument for each instance, which are the LDEs of
+    /// `s / (delta - f)` and `m / (delta - t)` over the constraint domain, where
+    /// `f = z_a + theta * z_b + theta^2 * z_c + theta^3 * id` and `t = t_a + theta * t_b + theta^2 * t_c + theta^3 * t_id`.
+    fn calculate_lookup_polynomials<R: RngCore>(
+        state: &prover::State<F, MM>,
+        challenges: &verifier::LookupChallenges<F>,
+        rng: &mut R,
+    ) -> Vec<[LabeledPolynomial<F>; 2]> {
+        let lookup_time = start_timer!(|| "Compute lookup polys");
+        let constraint_domain = state.constraint_domain;
+        let v_H = constraint_domain.vanishing_polynomial();
+        let lookup = state.index.lookup.as_ref().unwrap();
+        let first_msg = state.first_round_oracles.as_ref().unwrap();
+        let verifier::LookupChallenges { theta, delta } = *challenges;
+        let compress = |columns: [&[F]; 4]| -> Vec<F> {
+            (0..constraint_domain.size())
+                .map(|k| columns[0][k] + theta * (columns[1][k] + theta * (columns[2][k] + theta * columns[3][k])))
+                .collect()
+        };
+        let evaluate = |poly: &LabeledPolynomial<F>| {
+            poly.polynomial().as_dense().unwrap().evaluate_over_domain_by_ref(constraint_domain).evaluations
+        };
+        // Computes the LDE of `numerator / (delta - denominator)`, masked if necessary.
+        let mut interpolate = |label: String, numerator: Vec<F>, mut denominator: Vec<F>| {
+            cfg_iter_mut!(denominator).for_each(|d| *d = delta - *d);
+            snarkvm_fields::batch_inversion(&mut denominator);
+            let evals = numerator.into_iter().zip_eq(denominator).map(|(n, d)| n * d).collect();
+            let mut poly = fft::Evaluations::from_vec_and_domain(evals, constraint_domain)
+                .interpolate_with_pc(state.ifft_precomputation());
+            if MM::ZK {
+                poly += &(&v_H * F::rand(rng));
+            }
+            LabeledPolynomial::new(label, poly, None, Self::zk_bound())
+        };
+
+        let [id, s, t_a, t_b, t_c, t_id] = [&lookup.id, &lookup.s, &lookup.t_a, &lookup.t_b, &lookup.t_c, &lookup.t_id]
+            .map(|poly| evaluate(poly));
+        let t = compress([&t_a, &t_b, &t_c, &t_id]);
+        let lookup_polynomials = first_msg
+            .batches
+            .iter()
+            .zip_eq(&first_msg.lookup_batches)
+            .enumerate()
+            .map(|(i, (entry, lookup_entry))| {
+                let [z_a, z_b, z_c, m] = [&entry.z_a_poly, &entry.z_b_poly, &lookup_entry.z_c_poly, &lookup_entry.m_poly]
+                    .map(|poly| evaluate(poly));
+                let f = compress([&z_a, &z_b, &z_c, &id]);
+                let h_f = interpolate(witness_label("h_f", i), s.clone(), f);
+                let h_t = interpolate(witness_label("h_t", i), m, t.clone());
+                [h_f, h_t]
+            })
+            .collect();
+        end_timer!(lookup_time);
+        lookup_polynomials
+    }
+
     fn calculate_summed_z_m_and_t(
         state: &prover::State<F, MM>,
         alpha: F,
@@ -177,6 +258,20 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let mut job_pool = ExecutionPool::with_capacity(2 * state.batch_size);
         let eta_b_over_eta_c = eta_b * eta_c.inverse().unwrap();
         job_pool.add_job(|| {
+            if !first_msg.lookup_batches.is_empty() {
+                // With lookups, `z_c` is committed to explicitly, and so the sum is linear.
+                return cfg_iter!(first_msg.batches)
+                    .zip_eq(&first_msg.lookup_batches)
+                    .zip_eq(batch_combiners)
+                    .map(|((entry, lookup_entry), combiner)| {
+                        let mut summed_z_m = entry.z_a_poly.polynomial().as_dense().unwrap().clone();
+                        summed_z_m += (eta_b, entry.z_b_poly.polynomial().as_dense().unwrap());
+                        summed_z_m += (eta_c, lookup_entry.z_c_poly.polynomial().as_dense().unwrap());
+                        cfg_iter_mut!(summed_z_m.coeffs).for_each(|c| *c *= *combiner);
+                        summed_z_m
+                    })
+                    .sum::<DensePolynomial<_>>();
+            }
             cfg_iter!(first_msg.batches)
                 .zip_eq(batch_combiners)
                 .map(|(entry, combiner)| {
@@ -38,19 +38,21 @@ use snarkvm_utilities::{cfg_iter, cfg_iter_mut, ExecutionPool};
 
 use rand_core::RngCore;
 
-#[cfg(not(feature = "parallel"))]
 use itertools::Itertools;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// Output the number of oracles sent by the prover in the third round.
-    pub fn num_third_round_oracles() -> usize {
-        3
+    pub fn num_third_round_oracles(has_lookups: bool) -> usize {
+        3 + has_lookups as usize
     }
 
     /// Output the degree bounds of oracles in the first round.
-    pub fn third_round_polynomial_info(info: &CircuitInfo<F>) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+    pub fn third_round_polynomial_info(
+        info: &CircuitInfo<F>,
+        has_lookups: bool,
+    ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
         let non_zero_a_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_a).unwrap();
         let non_zero_b_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_b).unwrap();
         let non_zero_c_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_c).unwrap();
@@ -61,6 +63,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             PolynomialInfo::new("g_c".into(), Some(non_zero_c_size - 2), None),
         ]
         .into_iter()
+        .chain(has_lookups.then(|| PolynomialInfo::new("h_lookup".into(), None, None)))
         .map(|info| (info.label().into(), info))
         .collect()
     }
@@ -132,11 +135,16 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let [(sum_a, lhs_a, g_a), (sum_b, lhs_b, g_b), (sum_c, lhs_c, g_c)]: [_; 3] =
             pool.execute_all().try_into().unwrap();
 
+        let h_lookup = verifier_message.lambda.map(|lambda| Self::calculate_h_lookup(&state, lambda));
+
         let msg = prover::ThirdMessage { sum_a, sum_b, sum_c };
-        let oracles = prover::ThirdOracles { g_a, g_b, g_c };
+        let oracles = prover::ThirdOracles { g_a, g_b, g_c, h_lookup };
         state.lhs_polynomials = Some([lhs_a, lhs_b, lhs_c]);
         state.sums = Some([sum_a, sum_b, sum_c]);
-        assert!(oracles.matches_info(&Self::third_round_polynomial_info(&state.index.index_info)));
+        assert!(oracles.matches_info(&Self::third_round_polynomial_info(
+            &state.index.index_info,
+            state.index.lookup.is_some()
+        )));
 
         end_timer!(round_time);
 
@@ -232,4 +240,54 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         assert!(g.degree() <= non_zero_domain.size() - 2);
         (f.coeffs[0], h, g)
     }
+
+    /// Computes the `h_lookup` polynomial, which is the quotient of the lookup constraints by `v_H`.
+    /// For every instance, the lookup constraints are
+    /// 1) `(1 - s) * (z_a * z_b - z_c)`,
+    /// 2) `h_f * (delta - f) - s`, where `f = z_a + theta * z_b + theta^2 * z_c + theta^3 * id`,
+    /// 3) `h_t * (delta - t) - m`, where `t = t_a + theta * t_b + theta^2 * t_c + theta^3 * t_id`,
+    /// and these are combined with successive powers of `lambda`.
+    fn calculate_h_lookup(state: &prover::State<F, MM>, lambda: F) -> LabeledPolynomial<F> {
+        let h_lookup_time = start_timer!(|| "Computing h_lookup poly");
+        let constraint_domain = state.constraint_domain;
+        let lookup = state.index.lookup.as_ref().unwrap();
+        let first_msg = state.first_round_oracles.as_ref().unwrap();
+        let lookup_polynomials = state.lookup_polynomials.as_ref().unwrap();
+        let verifier::LookupChallenges { theta, delta } =
+            state.verifier_first_message.as_ref().unwrap().lookup_challenges.unwrap();
+
+        // The lookup constraints have degree less than `3 * |H|`.
+        let domain = EvaluationDomain::new(3 * constraint_domain.size()).unwrap();
+        let evaluate = |poly: &LabeledPolynomial<F>| {
+            poly.polynomial().as_dense().unwrap().evaluate_over_domain_by_ref(domain).evaluations
+        };
+        let compress = |columns: [F; 4]| columns[0] + theta * (columns[1] + theta * (columns[2] + theta * columns[3]));
+
+        let [id, s, t_a, t_b, t_c, t_id] =
+            [&lookup.id, &lookup.s, &lookup.t_a, &lookup.t_b, &lookup.t_c, &lookup.t_id].map(|poly| evaluate(poly));
+        let mut numerator = vec![F::zero(); domain.size()];
+        let mut lambda_power = F::one();
+        for ((entry, lookup_entry), [h_f, h_t]) in
+            first_msg.batches.iter().zip_eq(&first_msg.lookup_batches).zip_eq(lookup_polynomials)
+        {
+            let [z_a, z_b, z_c, m, h_f, h_t] =
+                [&entry.z_a_poly, &entry.z_b_poly, &lookup_entry.z_c_poly, &lookup_entry.m_poly, h_f, h_t]
+                    .map(|poly| evaluate(poly));
+            let [w_0, w_1, w_2] = [lambda_power, lambda_power * lambda, lambda_power * lambda.square()];
+            cfg_iter_mut!(numerator).enumerate().for_each(|(k, n)| {
+                let f = compress([z_a[k], z_b[k], z_c[k], id[k]]);
+                let t = compress([t_a[k], t_b[k], t_c[k], t_id[k]]);
+                *n += w_0 * (F::one() - s[k]) * (z_a[k] * z_b[k] - z_c[k])
+                    + w_1 * (h_f[k] * (delta - f) - s[k])
+                    + w_2 * (h_t[k] * (delta - t) - m[k]);
+            });
+            lambda_power = w_2 * lambda;
+        }
+
+        let numerator = EvaluationsOnDomain::from_vec_and_domain(numerator, domain).interpolate();
+        let (h_lookup, remainder) = numerator.divide_by_vanishing_poly(constraint_domain).unwrap();
+        assert!(remainder.is_zero());
+        end_timer!(h_lookup_time);
+        LabeledPolynomial::new("h_lookup".into(), h_lookup, None, None)
+    }
 }
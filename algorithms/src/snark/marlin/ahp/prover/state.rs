@@ -23,6 +23,7 @@ use crate::{
         EvaluationDomain,
         Evaluations as EvaluationsOnDomain,
     },
+    polycommit::sonic_pc::LabeledPolynomial,
     snark::marlin::{
         ahp::{indexer::Circuit, verifier},
         AHPError,
@@ -68,6 +69,14 @@ pub struct State<'a, F: PrimeField, MM: MarlinMode> {
     /// The length of this list must be equal to the batch size.
     pub(super) z_b: Option<Vec<Vec<F>>>,
 
+    /// The list of Cz vectors for each instance in the batch, if the circuit has lookups.
+    /// The length of this list must be equal to the batch size.
+    pub(super) z_c: Option<Vec<Vec<F>>>,
+
+    /// The list of table multiplicity vectors for each instance in the batch, if the circuit has lookups.
+    /// The length of this list must be equal to the batch size.
+    pub(super) multiplicities: Option<Vec<Vec<F>>>,
+
     /// A list of polynomials corresponding to the interpolation of the public input.
     /// The length of this list must be equal to the batch size.
     pub(super) x_poly: Vec<DensePolynomial<F>>,
@@ -83,6 +92,9 @@ pub struct State<'a, F: PrimeField, MM: MarlinMode> {
     /// The challenges sent by the verifier in the first round
     pub(super) verifier_first_message: Option<verifier::FirstMessage<F>>,
 
+    /// The `h_f` and `h_t` polynomials of the lookup argument, for each instance in the batch.
+    pub(super) lookup_polynomials: Option<Vec<[LabeledPolynomial<F>; 2]>>,
+
     /// Polynomials involved in the holographic sumcheck.
     pub(super) lhs_polynomials: Option<[DensePolynomial<F>; 3]>,
     /// Polynomials involved in the holographic sumcheck.
@@ -131,9 +143,12 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
             private_variables,
             z_a: None,
             z_b: None,
+            z_c: None,
+            multiplicities: None,
             first_round_oracles: None,
             mz_poly_randomizer: None,
             verifier_first_message: None,
+            lookup_polynomials: None,
             lhs_polynomials: None,
             sums: None,
         })
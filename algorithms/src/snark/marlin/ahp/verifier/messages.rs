@@ -29,6 +29,17 @@ pub struct FirstMessage<F> {
     pub eta_c: F,
     /// Randomizers for combining vectors from the batch.
     pub batch_combiners: Vec<F>,
+    /// Challenges for the lookup argument, if the circuit has lookups.
+    pub lookup_challenges: Option<LookupChallenges<F>>,
+}
+
+/// Challenges for the lookup argument.
+#[derive(Copy, Clone, Debug)]
+pub struct LookupChallenges<F> {
+    /// Randomizer for compressing the columns of a lookup row into a single value.
+    pub theta: F,
+    /// Point at which the logarithmic derivatives of the lookups and the tables are evaluated.
+    pub delta: F,
 }
 
 /// Second verifier message.
@@ -36,6 +47,8 @@ pub struct FirstMessage<F> {
 pub struct SecondMessage<F> {
     /// Query for the second round of polynomials.
     pub beta: F,
+    /// Randomizer for combining the lookup constraints, if the circuit has lookups.
+    pub lambda: Option<F>,
 }
 
 /// Third message of the verifier.
@@ -59,6 +72,8 @@ pub struct QuerySet<F> {
     pub g_b_query: (String, F),
     pub g_c_query: (String, F),
     pub matrix_sumcheck_query: (String, F),
+
+    pub lookup_query: Option<(String, F)>,
 }
 
 impl<F: PrimeField> QuerySet<F> {
@@ -83,6 +98,8 @@ impl<F: PrimeField> QuerySet<F> {
             g_b_query: ("gamma".into(), gamma),
             g_c_query: ("gamma".into(), gamma),
             matrix_sumcheck_query: ("gamma".into(), gamma),
+
+            lookup_query: state.second_round_message.unwrap().lambda.map(|_| ("gamma".into(), gamma)),
         }
     }
 
@@ -100,6 +117,17 @@ impl<F: PrimeField> QuerySet<F> {
         query_set.insert(("g_b".into(), self.g_b_query.clone()));
         query_set.insert(("g_c".into(), self.g_c_query.clone()));
         query_set.insert(("matrix_sumcheck".into(), self.matrix_sumcheck_query.clone()));
+
+        // The lookup argument is checked at `gamma`, using the evaluations of `z_a`, `h_f`, `h_t`, and `s`.
+        if let Some(lookup_query) = &self.lookup_query {
+            for i in 0..self.batch_size {
+                query_set.insert((witness_label("z_a", i), lookup_query.clone()));
+                query_set.insert((witness_label("h_f", i), lookup_query.clone()));
+                query_set.insert((witness_label("h_t", i), lookup_query.clone()));
+            }
+            query_set.insert(("lookup_s".into(), lookup_query.clone()));
+            query_set.insert(("lookup_check".into(), lookup_query.clone()));
+        }
         query_set
     }
 }
@@ -21,7 +21,7 @@ use crate::{
     snark::marlin::{
         ahp::{
             indexer::CircuitInfo,
-            verifier::{FirstMessage, QuerySet, SecondMessage, State, ThirdMessage},
+            verifier::{FirstMessage, LookupChallenges, QuerySet, SecondMessage, State, ThirdMessage},
             AHPError,
             AHPForR1CS,
         },
@@ -33,9 +33,11 @@ use snarkvm_fields::PrimeField;
 
 impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
     /// Output the first message and next round state.
+    /// If `has_lookups` is set, the message also contains the challenges for the lookup argument.
     pub fn verifier_first_round<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
         index_info: CircuitInfo<TargetField>,
         batch_size: usize,
+        has_lookups: bool,
         fs_rng: &mut R,
     ) -> Result<(FirstMessage<TargetField>, State<TargetField, MM>), AHPError> {
         // Check that the R1CS is a square matrix.
@@ -74,13 +76,17 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
         let [alpha, eta_b, eta_c]: [_; 3] = first.try_into().unwrap();
         let mut batch_combiners = vec![TargetField::one()];
         batch_combiners.extend_from_slice(rest);
+        let lookup_challenges = has_lookups.then(|| {
+            let elems = fs_rng.squeeze_nonnative_field_elements(2);
+            LookupChallenges { theta: elems[0], delta: elems[1] }
+        });
         end_timer!(squeeze_time);
 
         let check_vanish_poly_time = start_timer!(|| "Evaluating vanishing polynomial");
         assert!(!constraint_domain.evaluate_vanishing_polynomial(alpha).is_zero());
         end_timer!(check_vanish_poly_time);
 
-        let message = FirstMessage { alpha, eta_b, eta_c, batch_combiners };
+        let message = FirstMessage { alpha, eta_b, eta_c, batch_combiners, lookup_challenges };
 
         let new_state = State {
             batch_size,
@@ -104,11 +110,13 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
         mut state: State<TargetField, MM>,
         fs_rng: &mut R,
     ) -> Result<(SecondMessage<TargetField>, State<TargetField, MM>), AHPError> {
-        let elems = fs_rng.squeeze_nonnative_field_elements(1);
+        let has_lookups = state.first_round_message.as_ref().unwrap().lookup_challenges.is_some();
+        let elems = fs_rng.squeeze_nonnative_field_elements(1 + has_lookups as usize);
         let beta = elems[0];
         assert!(!state.constraint_domain.evaluate_vanishing_polynomial(beta).is_zero());
+        let lambda = elems.get(1).copied();
 
-        let message = SecondMessage { beta };
+        let message = SecondMessage { beta, lambda };
         state.second_round_message = Some(message);
 
         Ok((message, state))
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    polycommit::sonic_pc::{PairingAccumulator, SonicKZG10},
+    snark::marlin::{marlin::PCCheck, CircuitVerifyingKey, MarlinMode, MarlinSNARK, Proof},
+    AlgebraicSponge,
+    SNARKError,
+};
+use rand::Rng;
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_utilities::Uniform;
+
+use std::borrow::Borrow;
+
+impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode, Input: ToConstraintField<E::Fr> + ?Sized>
+    MarlinSNARK<E, FS, MM, Input>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+{
+    /// Runs the AHP verifier on the given proof, and accumulates its polynomial commitment check
+    /// into `accumulator` with a random coefficient sampled from `rng`.
+    /// Returns `false` if the proof is malformed, in which case nothing is accumulated.
+    ///
+    /// The accumulated checks of many proofs are verified together with [`Self::verify_accumulator`].
+    pub fn accumulate<B: Borrow<Input>, R: Rng>(
+        accumulator: &mut PairingAccumulator<E>,
+        fs_parameters: &FS::Parameters,
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        public_inputs: &[B],
+        proof: &Proof<E>,
+        rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        let PCCheck { mut sponge, lc_s, commitments, query_set, evaluations } =
+            match Self::verify_ahp(fs_parameters, circuit_verifying_key, public_inputs, proof)? {
+                Some(pc_check) => pc_check,
+                None => return Ok(false),
+            };
+
+        SonicKZG10::<E, FS>::accumulate_combinations(
+            accumulator,
+            &circuit_verifying_key.verifier_key,
+            lc_s.values(),
+            &commitments,
+            &query_set,
+            &evaluations,
+            &proof.pc_proof,
+            E::Fr::rand(rng),
+            &mut sponge,
+        )?;
+        Ok(true)
+    }
+
+    /// Verifies every proof accumulated into `accumulator` with a single multi-pairing.
+    pub fn verify_accumulator(accumulator: PairingAccumulator<E>) -> Result<bool, SNARKError> {
+        let verifier_time =
+            start_timer!(|| format!("Marlin::VerifyAccumulator with {} proofs", accumulator.num_checks()));
+        let is_valid = SonicKZG10::<E, FS>::check_accumulator(accumulator)?;
+        end_timer!(verifier_time);
+        Ok(is_valid)
+    }
+
+    /// Verifies many independent proofs, each under its own verifying key and public inputs,
+    /// with a single multi-pairing. The proofs may be for the same circuit or for different circuits.
+    pub fn verify_many<B: Borrow<Input>, R: Rng>(
+        fs_parameters: &FS::Parameters,
+        instances: &[(&CircuitVerifyingKey<E, MM>, &[B], &Proof<E>)],
+        rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        if instances.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+
+        let mut accumulator = PairingAccumulator::new();
+        for (circuit_verifying_key, public_inputs, proof) in instances {
+            if !Self::accumulate(&mut accumulator, fs_parameters, circuit_verifying_key, public_inputs, proof, rng)? {
+                return Ok(false);
+            }
+        }
+        Self::verify_accumulator(accumulator)
+    }
+}
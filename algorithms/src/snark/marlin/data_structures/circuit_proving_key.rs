@@ -15,11 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    fft::EvaluationDomain,
     polycommit::sonic_pc,
     snark::marlin::{ahp::indexer::*, CircuitVerifyingKey, MarlinMode},
 };
 use snarkvm_curves::PairingEngine;
 use snarkvm_utilities::{
+    error,
     io::{self, Read, Write},
     serialize::*,
     FromBytes,
@@ -46,6 +48,13 @@ impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitProvingKey<E, MM> {
         CanonicalSerialize::serialize_compressed(&self.circuit_verifying_key, &mut writer)?;
         CanonicalSerialize::serialize_compressed(&self.circuit_commitment_randomness, &mut writer)?;
         CanonicalSerialize::serialize_compressed(&self.circuit, &mut writer)?;
+        // The lookup tables are only written if the verifying key commits to them.
+        if self.circuit_verifying_key.has_lookups() {
+            match &self.circuit.lookup {
+                Some(lookup) => lookup.serialize_with_mode(&mut writer, Compress::Yes)?,
+                None => return Err(error("missing lookup tables in CircuitProvingKey")),
+            }
+        }
 
         self.committer_key.write_le(&mut writer)
     }
@@ -54,9 +63,16 @@ impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitProvingKey<E, MM> {
 impl<E: PairingEngine, MM: MarlinMode> FromBytes for CircuitProvingKey<E, MM> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
-        let circuit_verifying_key = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
+        let circuit_verifying_key: CircuitVerifyingKey<E, MM> = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
         let circuit_commitment_randomness = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
-        let circuit = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
+        let mut circuit: Circuit<E::Fr, MM> = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
+        if circuit_verifying_key.has_lookups() {
+            let constraint_domain = EvaluationDomain::new(circuit.index_info.num_constraints)
+                .ok_or_else(|| error("invalid constraint domain in CircuitProvingKey"))?;
+            circuit.lookup =
+                Some(LookupIndex::deserialize_with_mode(&mut reader, constraint_domain, Compress::Yes, Validate::Yes)?);
+        }
+        let circuit = Arc::new(circuit);
         let committer_key = Arc::new(FromBytes::read_le(&mut reader)?);
 
         Ok(Self { circuit_verifying_key, circuit_commitment_randomness, circuit, committer_key })
@@ -17,7 +17,7 @@
 use crate::{
     fft::EvaluationDomain,
     polycommit::sonic_pc,
-    snark::marlin::{ahp::indexer::*, CircuitProvingKey, MarlinMode, PreparedCircuitVerifyingKey},
+    snark::marlin::{ahp::indexer::*, AHPForR1CS, CircuitProvingKey, MarlinMode, PreparedCircuitVerifyingKey},
     Prepare,
 };
 use snarkvm_curves::PairingEngine;
@@ -170,6 +170,11 @@ impl<E: PairingEngine, MM: MarlinMode> CircuitVerifyingKey<E, MM> {
     pub fn iter(&self) -> impl Iterator<Item = &sonic_pc::Commitment<E>> {
         self.circuit_commitments.iter()
     }
+
+    /// Returns `true` if the indexed circuit contains lookup tables.
+    pub fn has_lookups(&self) -> bool {
+        self.circuit_commitments.len() == AHPForR1CS::<E::Fr, MM>::index_polynomial_labels(true).count()
+    }
 }
 
 impl<E: PairingEngine, MM: MarlinMode> ToConstraintField<E::Fq> for CircuitVerifyingKey<E, MM> {
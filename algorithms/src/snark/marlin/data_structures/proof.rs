@@ -43,6 +43,10 @@ pub struct Commitments<E: PairingEngine> {
     pub g_c: sonic_pc::Commitment<E>,
     /// Commitment to the `h_2` polynomial.
     pub h_2: sonic_pc::Commitment<E>,
+    /// Commitments to the lookup polynomials of each instance, if the circuit has lookups.
+    pub lookup_commitments: Vec<LookupCommitments<E>>,
+    /// Commitment to the `h_lookup` polynomial, if the circuit has lookups.
+    pub h_lookup: Option<sonic_pc::Commitment<E>>,
 }
 
 impl<E: PairingEngine> Commitments<E> {
@@ -61,6 +65,12 @@ impl<E: PairingEngine> Commitments<E> {
         CanonicalSerialize::serialize_with_mode(&self.g_b, &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.g_c, &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.h_2, &mut writer, compress)?;
+        if let Some(h_lookup) = &self.h_lookup {
+            for comm in &self.lookup_commitments {
+                comm.serialize_with_mode(&mut writer, compress)?;
+            }
+            CanonicalSerialize::serialize_with_mode(h_lookup, &mut writer, compress)?;
+        }
         Ok(())
     }
 
@@ -75,11 +85,16 @@ impl<E: PairingEngine> Commitments<E> {
         size += CanonicalSerialize::serialized_size(&self.g_b, compress);
         size += CanonicalSerialize::serialized_size(&self.g_c, compress);
         size += CanonicalSerialize::serialized_size(&self.h_2, compress);
+        if let Some(h_lookup) = &self.h_lookup {
+            size += self.lookup_commitments.iter().map(|c| c.serialized_size(compress)).sum::<usize>();
+            size += CanonicalSerialize::serialized_size(h_lookup, compress);
+        }
         size
     }
 
     fn deserialize_with_mode<R: snarkvm_utilities::Read>(
         batch_size: usize,
+        has_lookups: bool,
         mut reader: R,
         compress: Compress,
         validate: Validate,
@@ -88,16 +103,22 @@ impl<E: PairingEngine> Commitments<E> {
         for _ in 0..batch_size {
             witness_commitments.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
         }
-        Ok(Commitments {
-            witness_commitments,
-            mask_poly: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_1: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            h_1: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_a: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_b: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_c: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            h_2: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-        })
+        let mask_poly = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let g_1 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let h_1 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let g_a = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let g_b = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let g_c = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let h_2 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let mut lookup_commitments = Vec::new();
+        let mut h_lookup = None;
+        if has_lookups {
+            for _ in 0..batch_size {
+                lookup_commitments.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+            }
+            h_lookup = Some(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+        }
+        Ok(Commitments { witness_commitments, mask_poly, g_1, h_1, g_a, g_b, g_c, h_2, lookup_commitments, h_lookup })
     }
 }
 /// Commitments to the `w`, `z_a`, and `z_b` polynomials.
@@ -111,6 +132,19 @@ pub struct WitnessCommitments<E: PairingEngine> {
     pub z_b: sonic_pc::Commitment<E>,
 }
 
+/// Commitments to the `z_c`, `m`, `h_f`, and `h_t` polynomials of the lookup argument.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct LookupCommitments<E: PairingEngine> {
+    /// Commitment to the `z_c` polynomial.
+    pub z_c: sonic_pc::Commitment<E>,
+    /// Commitment to the `m` polynomial, which holds the multiplicity of each table entry.
+    pub m: sonic_pc::Commitment<E>,
+    /// Commitment to the `h_f` polynomial.
+    pub h_f: sonic_pc::Commitment<E>,
+    /// Commitment to the `h_t` polynomial.
+    pub h_t: sonic_pc::Commitment<E>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Evaluations<F: PrimeField> {
     /// Evaluation of `z_b_i`'s at `beta`.
@@ -123,6 +157,14 @@ pub struct Evaluations<F: PrimeField> {
     pub g_b_eval: F,
     /// Evaluation of `g_c` at `gamma`.
     pub g_c_eval: F,
+    /// Evaluation of `z_a_i`'s at `gamma`, if the circuit has lookups.
+    pub z_a_evals: Vec<F>,
+    /// Evaluation of `h_f_i`'s at `gamma`, if the circuit has lookups.
+    pub h_f_evals: Vec<F>,
+    /// Evaluation of `h_t_i`'s at `gamma`, if the circuit has lookups.
+    pub h_t_evals: Vec<F>,
+    /// Evaluation of `lookup_s` at `gamma`, if the circuit has lookups.
+    pub lookup_s_eval: Option<F>,
 }
 
 impl<F: PrimeField> Evaluations<F> {
@@ -138,6 +180,12 @@ impl<F: PrimeField> Evaluations<F> {
         CanonicalSerialize::serialize_with_mode(&self.g_a_eval, &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.g_b_eval, &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.g_c_eval, &mut writer, compress)?;
+        if let Some(lookup_s_eval) = &self.lookup_s_eval {
+            for eval in self.z_a_evals.iter().chain(&self.h_f_evals).chain(&self.h_t_evals) {
+                CanonicalSerialize::serialize_with_mode(eval, &mut writer, compress)?;
+            }
+            CanonicalSerialize::serialize_with_mode(lookup_s_eval, &mut writer, compress)?;
+        }
         Ok(())
     }
 
@@ -148,11 +196,22 @@ impl<F: PrimeField> Evaluations<F> {
         size += CanonicalSerialize::serialized_size(&self.g_a_eval, compress);
         size += CanonicalSerialize::serialized_size(&self.g_b_eval, compress);
         size += CanonicalSerialize::serialized_size(&self.g_c_eval, compress);
+        if let Some(lookup_s_eval) = &self.lookup_s_eval {
+            size += self
+                .z_a_evals
+                .iter()
+                .chain(&self.h_f_evals)
+                .chain(&self.h_t_evals)
+                .map(|s| s.serialized_size(compress))
+                .sum::<usize>();
+            size += CanonicalSerialize::serialized_size(lookup_s_eval, compress);
+        }
         size
     }
 
     fn deserialize_with_mode<R: snarkvm_utilities::Read>(
         batch_size: usize,
+        has_lookups: bool,
         mut reader: R,
         compress: Compress,
         validate: Validate,
@@ -161,32 +220,75 @@ impl<F: PrimeField> Evaluations<F> {
         for _ in 0..batch_size {
             z_b_evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
         }
+        let g_1_eval = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let g_a_eval = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let g_b_eval = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let g_c_eval = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let (mut z_a_evals, mut h_f_evals, mut h_t_evals, mut lookup_s_eval) = (vec![], vec![], vec![], None);
+        if has_lookups {
+            for evals in [&mut z_a_evals, &mut h_f_evals, &mut h_t_evals] {
+                for _ in 0..batch_size {
+                    evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+                }
+            }
+            lookup_s_eval = Some(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+        }
         Ok(Evaluations {
             z_b_evals,
-            g_1_eval: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_a_eval: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_b_eval: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            g_c_eval: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            g_1_eval,
+            g_a_eval,
+            g_b_eval,
+            g_c_eval,
+            z_a_evals,
+            h_f_evals,
+            h_t_evals,
+            lookup_s_eval,
         })
     }
 }
 
 impl<F: PrimeField> Evaluations<F> {
     pub(crate) fn from_map(map: &std::collections::BTreeMap<String, F>, batch_size: usize) -> Self {
-        let z_b_evals = map.iter().filter_map(|(k, v)| k.starts_with("z_b_").then_some(*v)).collect::<Vec<_>>();
+        let evals_with_prefix = |prefix: &str| {
+            map.iter().filter_map(|(k, v)| k.starts_with(prefix).then_some(*v)).collect::<Vec<_>>()
+        };
+        let z_b_evals = evals_with_prefix("z_b_");
         assert_eq!(z_b_evals.len(), batch_size);
-        Self { z_b_evals, g_1_eval: map["g_1"], g_a_eval: map["g_a"], g_b_eval: map["g_b"], g_c_eval: map["g_c"] }
+        let lookup_s_eval = map.get("lookup_s").copied();
+        let [z_a_evals, h_f_evals, h_t_evals] = ["z_a_", "h_f_", "h_t_"].map(|prefix| {
+            let evals = evals_with_prefix(prefix);
+            assert_eq!(evals.len(), if lookup_s_eval.is_some() { batch_size } else { 0 });
+            evals
+        });
+        Self {
+            z_b_evals,
+            g_1_eval: map["g_1"],
+            g_a_eval: map["g_a"],
+            g_b_eval: map["g_b"],
+            g_c_eval: map["g_c"],
+            z_a_evals,
+            h_f_evals,
+            h_t_evals,
+            lookup_s_eval,
+        }
     }
 
     pub(crate) fn get(&self, label: &str) -> Option<F> {
         if let Some(index) = label.strip_prefix("z_b_") {
             self.z_b_evals.get(index.parse::<usize>().unwrap()).copied()
+        } else if let Some(index) = label.strip_prefix("z_a_") {
+            self.z_a_evals.get(index.parse::<usize>().ok()?).copied()
+        } else if let Some(index) = label.strip_prefix("h_f_") {
+            self.h_f_evals.get(index.parse::<usize>().ok()?).copied()
+        } else if let Some(index) = label.strip_prefix("h_t_") {
+            self.h_t_evals.get(index.parse::<usize>().ok()?).copied()
         } else {
             match label {
                 "g_1" => Some(self.g_1_eval),
                 "g_a" => Some(self.g_a_eval),
                 "g_b" => Some(self.g_b_eval),
                 "g_c" => Some(self.g_c_eval),
+                "lookup_s" => self.lookup_s_eval,
                 _ => None,
             }
         }
@@ -199,7 +301,11 @@ impl<F: PrimeField> Valid for Evaluations<F> {
         self.g_1_eval.check()?;
         self.g_a_eval.check()?;
         self.g_b_eval.check()?;
-        self.g_c_eval.check()
+        self.g_c_eval.check()?;
+        self.z_a_evals.check()?;
+        self.h_f_evals.check()?;
+        self.h_t_evals.check()?;
+        self.lookup_s_eval.check()
     }
 }
 
@@ -207,6 +313,7 @@ impl<F: PrimeField> Evaluations<F> {
     pub fn to_field_elements(&self) -> Vec<F> {
         let mut result = self.z_b_evals.clone();
         result.extend([self.g_1_eval, self.g_a_eval, self.g_b_eval, self.g_c_eval]);
+        result.extend(self.z_a_evals.iter().chain(&self.h_f_evals).chain(&self.h_t_evals).chain(&self.lookup_s_eval));
         result
     }
 }
@@ -241,11 +348,25 @@ impl<E: PairingEngine> Proof<E> {
     ) -> Self {
         Self { batch_size, commitments, evaluations, msg, pc_proof }
     }
+
+    /// Returns the number of instances being proven in this proof.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Returns `true` if this proof contains a lookup argument.
+    pub fn has_lookups(&self) -> bool {
+        self.commitments.h_lookup.is_some()
+    }
 }
 
+/// The flag that is set in the serialized batch size of proofs that contain a lookup argument.
+const LOOKUP_FLAG: usize = 1 << (usize::BITS - 1);
+
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
     fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
-        CanonicalSerialize::serialize_with_mode(&self.batch_size, &mut writer, compress)?;
+        let batch_size = if self.has_lookups() { self.batch_size | LOOKUP_FLAG } else { self.batch_size };
+        CanonicalSerialize::serialize_with_mode(&batch_size, &mut writer, compress)?;
         Commitments::serialize_with_mode(&self.commitments, &mut writer, compress)?;
         Evaluations::serialize_with_mode(&self.evaluations, &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.msg, &mut writer, compress)?;
@@ -280,11 +401,12 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
         compress: Compress,
         validate: Validate,
     ) -> Result<Self, SerializationError> {
-        let batch_size = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let batch_size: usize = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let (batch_size, has_lookups) = (batch_size & !LOOKUP_FLAG, batch_size & LOOKUP_FLAG != 0);
         Ok(Proof {
             batch_size,
-            commitments: Commitments::deserialize_with_mode(batch_size, &mut reader, compress, validate)?,
-            evaluations: Evaluations::deserialize_with_mode(batch_size, &mut reader, compress, validate)?,
+            commitments: Commitments::deserialize_with_mode(batch_size, has_lookups, &mut reader, compress, validate)?,
+            evaluations: Evaluations::deserialize_with_mode(batch_size, has_lookups, &mut reader, compress, validate)?,
             msg: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             pc_proof: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
         })
@@ -302,3 +424,137 @@ impl<E: PairingEngine> FromBytes for Proof<E> {
         Self::deserialize_compressed(&mut r).map_err(|_| error("could not deserialize Proof"))
     }
 }
+
+/// A zkSNARK proof for a batch of instances that spans several distinct circuits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregatedProof<E: PairingEngine> {
+    /// The number of instances being proven for each circuit.
+    batch_sizes: Vec<usize>,
+
+    /// Commitments to prover polynomials, for each circuit.
+    pub commitments: Vec<Commitments<E>>,
+
+    /// Evaluations of some of the committed polynomials, for each circuit.
+    pub evaluations: Vec<Evaluations<E::Fr>>,
+
+    /// Prover messages: sum_a, sum_b, sum_c, for each circuit.
+    pub msgs: Vec<ahp::prover::ThirdMessage<E::Fr>>,
+
+    /// A single evaluation proof from the polynomial commitment, shared by all circuits.
+    pub pc_proof: sonic_pc::BatchLCProof<E>,
+}
+
+impl<E: PairingEngine> AggregatedProof<E> {
+    /// Construct a new aggregated proof.
+    pub fn new(
+        batch_sizes: Vec<usize>,
+        commitments: Vec<Commitments<E>>,
+        evaluations: Vec<Evaluations<E::Fr>>,
+        msgs: Vec<ahp::prover::ThirdMessage<E::Fr>>,
+        pc_proof: sonic_pc::BatchLCProof<E>,
+    ) -> Self {
+        Self { batch_sizes, commitments, evaluations, msgs, pc_proof }
+    }
+
+    /// Returns the number of instances proven for each circuit.
+    pub fn batch_sizes(&self) -> &[usize] {
+        &self.batch_sizes
+    }
+
+    /// Returns the serialized batch size of each circuit, flagged if the circuit contains a lookup argument.
+    fn flagged_batch_sizes(&self) -> Vec<usize> {
+        self.batch_sizes
+            .iter()
+            .zip(&self.commitments)
+            .map(|(batch_size, comms)| if comms.h_lookup.is_some() { batch_size | LOOKUP_FLAG } else { *batch_size })
+            .collect()
+    }
+}
+
+impl<E: PairingEngine> CanonicalSerialize for AggregatedProof<E> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize_with_mode(&self.flagged_batch_sizes(), &mut writer, compress)?;
+        for ((commitments, evaluations), msg) in self.commitments.iter().zip(&self.evaluations).zip(&self.msgs) {
+            Commitments::serialize_with_mode(commitments, &mut writer, compress)?;
+            Evaluations::serialize_with_mode(evaluations, &mut writer, compress)?;
+            CanonicalSerialize::serialize_with_mode(msg, &mut writer, compress)?;
+        }
+        CanonicalSerialize::serialize_with_mode(&self.pc_proof, &mut writer, compress)?;
+        Ok(())
+    }
+
+    fn serialized_size(&self, mode: Compress) -> usize {
+        let mut size = 0;
+        size += CanonicalSerialize::serialized_size(&self.batch_sizes, mode);
+        for ((commitments, evaluations), msg) in self.commitments.iter().zip(&self.evaluations).zip(&self.msgs) {
+            size += Commitments::serialized_size(commitments, mode);
+            size += Evaluations::serialized_size(evaluations, mode);
+            size += CanonicalSerialize::serialized_size(msg, mode);
+        }
+        size += CanonicalSerialize::serialized_size(&self.pc_proof, mode);
+        size
+    }
+}
+
+impl<E: PairingEngine> Valid for AggregatedProof<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.batch_sizes.check()?;
+        self.commitments.check()?;
+        self.evaluations.check()?;
+        self.msgs.check()?;
+        self.pc_proof.check()
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for AggregatedProof<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let flagged_batch_sizes: Vec<usize> =
+            CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let mut batch_sizes = Vec::with_capacity(flagged_batch_sizes.len());
+        let mut commitments = Vec::with_capacity(flagged_batch_sizes.len());
+        let mut evaluations = Vec::with_capacity(flagged_batch_sizes.len());
+        let mut msgs = Vec::with_capacity(flagged_batch_sizes.len());
+        for batch_size in flagged_batch_sizes {
+            let (batch_size, has_lookups) = (batch_size & !LOOKUP_FLAG, batch_size & LOOKUP_FLAG != 0);
+            batch_sizes.push(batch_size);
+            commitments.push(Commitments::deserialize_with_mode(
+                batch_size,
+                has_lookups,
+                &mut reader,
+                compress,
+                validate,
+            )?);
+            evaluations.push(Evaluations::deserialize_with_mode(
+                batch_size,
+                has_lookups,
+                &mut reader,
+                compress,
+                validate,
+            )?);
+            msgs.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+        }
+        Ok(AggregatedProof {
+            batch_sizes,
+            commitments,
+            evaluations,
+            msgs,
+            pc_proof: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for AggregatedProof<E> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("could not serialize AggregatedProof"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for AggregatedProof<E> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("could not deserialize AggregatedProof"))
+    }
+}
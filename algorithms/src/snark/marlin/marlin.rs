@@ -16,9 +16,17 @@
 
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, QuerySet, Randomness, SonicKZG10},
+    polycommit::sonic_pc::{
+        Commitment,
+        Evaluations,
+        LabeledCommitment,
+        LinearCombination,
+        QuerySet,
+        Randomness,
+        SonicKZG10,
+    },
     snark::marlin::{
-        ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
+        ahp::{AHPError, AHPForR1CS, CircuitInfo, EvaluationsProvider},
         proof,
         prover,
         witness_label,
@@ -43,7 +51,7 @@ use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
 use snarkvm_r1cs::ConstraintSynthesizer;
 use snarkvm_utilities::{to_bytes_le, ToBytes};
 
-use std::{borrow::Borrow, sync::Arc};
+use std::{borrow::Borrow, collections::BTreeMap, sync::Arc};
 
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
@@ -64,6 +72,20 @@ pub struct MarlinSNARK<
     Input: ToConstraintField<E::Fr> + ?Sized,
 >(#[doc(hidden)] PhantomData<(E, FS, MM, Input)>);
 
+/// The polynomial commitment checks that remain after running the AHP verifier on a proof.
+pub(super) struct PCCheck<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>> {
+    /// The Fiat-Shamir sponge, after absorbing the proof evaluations.
+    pub(super) sponge: FS,
+    /// The linear combinations to check.
+    pub(super) lc_s: BTreeMap<String, LinearCombination<E::Fr>>,
+    /// The labeled commitments of the circuit and the proof.
+    pub(super) commitments: Vec<LabeledCommitment<Commitment<E>>>,
+    /// The points at which the linear combinations are evaluated.
+    pub(super) query_set: QuerySet<'static, E::Fr>,
+    /// The claimed evaluations of the linear combinations.
+    pub(super) evaluations: Evaluations<'static, E::Fr>,
+}
+
 impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode, Input: ToConstraintField<E::Fr> + ?Sized>
     MarlinSNARK<E, FS, MM, Input>
 {
@@ -145,8 +167,164 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode, Input: ToC
         Ok((circuit_proving_key, circuit_verifying_key))
     }
 
+    /// Runs the AHP verifier on the given proof, returning the linear combinations that remain
+    /// to be checked with the polynomial commitment scheme, or `None` if the proof is malformed.
+    pub(super) fn verify_ahp<B: Borrow<Input>>(
+        fs_parameters: &FS::Parameters,
+        circuit_verifying_key: &CircuitVerifyingKey<E, MM>,
+        public_inputs: &[B],
+        proof: &Proof<E>,
+    ) -> Result<Option<PCCheck<E, FS>>, SNARKError> {
+        if public_inputs.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+
+        let comms = &proof.commitments;
+        let proof_has_correct_zk_mode = if MM::ZK {
+            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
+        } else {
+            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
+        };
+        if !proof_has_correct_zk_mode {
+            eprintln!(
+                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
+                proof.pc_proof.is_hiding()
+            );
+            return Ok(None);
+        }
+
+        let batch_size = public_inputs.len();
+
+        let has_lookups = circuit_verifying_key.has_lookups();
+        let expected_lookup_commitments = if has_lookups { batch_size } else { 0 };
+        if proof.has_lookups() != has_lookups
+            || comms.lookup_commitments.len() != expected_lookup_commitments
+            || comms.h_lookup.is_some() != has_lookups
+        {
+            eprintln!("Found lookup commitments in the proof that do not match the circuit verifying key");
+            return Ok(None);
+        }
+
+        let [first_commitments, second_commitments, third_commitments, fourth_commitments] =
+            Self::labeled_commitments_by_round(&circuit_verifying_key.circuit_info, batch_size, has_lookups, comms);
+
+        let input_domain =
+            EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
+
+        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
+            public_inputs
+                .iter()
+                .map(|input| {
+                    let input = input.borrow().to_field_elements().unwrap();
+                    let mut new_input = vec![E::Fr::one()];
+                    new_input.extend_from_slice(&input);
+                    new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
+                    if cfg!(debug_assertions) {
+                        println!("Number of padded public variables: {}", new_input.len());
+                    }
+                    let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
+                    (new_input, unformatted)
+                })
+                .unzip()
+        };
+
+        let mut sponge = Self::init_sponge(
+            fs_parameters,
+            batch_size,
+            &circuit_verifying_key.circuit_commitments,
+            &padded_public_inputs,
+        );
+
+        // --------------------------------------------------------------------
+        // First round
+        let first_round_time = start_timer!(|| "First round");
+        Self::absorb_labeled(&first_commitments, &mut sponge);
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
+            circuit_verifying_key.circuit_info,
+            batch_size,
+            has_lookups,
+            &mut sponge,
+        )?;
+        end_timer!(first_round_time);
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+        let second_round_time = start_timer!(|| "Second round");
+        Self::absorb_labeled(&second_commitments, &mut sponge);
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        end_timer!(second_round_time);
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+        let third_round_time = start_timer!(|| "Third round");
+
+        Self::absorb_labeled_with_msg(&third_commitments, &proof.msg, &mut sponge);
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        end_timer!(third_round_time);
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Fourth round
+        let fourth_round_time = start_timer!(|| "Fourth round");
+
+        Self::absorb_labeled(&fourth_commitments, &mut sponge);
+        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        end_timer!(fourth_round_time);
+        // --------------------------------------------------------------------
+
+        // Collect degree bounds for commitments. Indexed polynomials have *no*
+        // degree bounds because we know the committed index polynomial has the
+        // correct degree.
+
+        // Gather commitments in one vector.
+        let commitments: Vec<_> = circuit_verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(has_lookups).values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .chain(first_commitments)
+            .chain(second_commitments)
+            .chain(third_commitments)
+            .chain(fourth_commitments)
+            .collect();
+
+        let query_set_time = start_timer!(|| "Constructing query set");
+        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+        end_timer!(query_set_time);
+
+        sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements());
+
+        let mut evaluations = Evaluations::new();
+
+        for (label, (_point_name, q)) in query_set.to_set() {
+            if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
+                evaluations.insert((label, q), E::Fr::zero());
+            } else {
+                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
+                evaluations.insert((label, q), eval);
+            }
+        }
+
+        let lc_time = start_timer!(|| "Constructing linear combinations");
+        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+            &public_inputs,
+            &evaluations,
+            &proof.msg,
+            &verifier_state,
+        )?;
+        end_timer!(lc_time);
+
+        Ok(Some(PCCheck { sponge, lc_s, commitments, query_set: query_set.to_set(), evaluations }))
+    }
+
     fn terminate(terminator: &AtomicBool) -> Result<(), MarlinError> {
-        if terminator.load(Ordering::Relaxed) { Err(MarlinError::Terminated) } else { Ok(()) }
+        if terminator.load(Ordering::Relaxed) {
+            Err(MarlinError::Terminated)
+        } else {
+            Ok(())
+        }
     }
 
     fn init_sponge(
@@ -175,7 +353,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode, Input: ToC
         sponge
     }
 
-    fn absorb_labeled_with_msg(
+    pub(super) fn absorb_labeled_with_msg(
         comms: &[LabeledCommitment<Commitment<E>>],
         message: &prover::ThirdMessage<E::Fr>,
         sponge: &mut FS,
@@ -184,7 +362,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode, Input: ToC
         Self::absorb_with_msg(&commitments, message, sponge)
     }
 
-    fn absorb_labeled(comms: &[LabeledCommitment<Commitment<E>>], sponge: &mut FS) {
+    pub(super) fn absorb_labeled(comms: &[LabeledCommitment<Commitment<E>>], sponge: &mut FS) {
         let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
         Self::absorb(&commitments, sponge);
     }
@@ -201,6 +379,114 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode, Input: ToC
         sponge.absorb_nonnative_field_elements([msg.sum_a, msg.sum_b, msg.sum_c]);
         end_timer!(sponge_time);
     }
+
+    /// Gathers the commitments of each round of the prover into the commitments of a proof.
+    pub(super) fn proof_commitments(
+        batch_size: usize,
+        has_lookups: bool,
+        first_commitments: &[LabeledCommitment<Commitment<E>>],
+        second_commitments: &[LabeledCommitment<Commitment<E>>],
+        third_commitments: &[LabeledCommitment<Commitment<E>>],
+        fourth_commitments: &[LabeledCommitment<Commitment<E>>],
+    ) -> proof::Commitments<E> {
+        let (witness_commitments, remainder) = first_commitments.split_at(3 * batch_size);
+        let (lookup_first_commitments, mask_commitment) =
+            remainder.split_at(if has_lookups { 2 * batch_size } else { 0 });
+        let mask_poly = MM::ZK.then(|| *mask_commitment[0].commitment());
+        let witness_commitments = witness_commitments
+            .chunks_exact(3)
+            .map(|c| proof::WitnessCommitments {
+                w: *c[0].commitment(),
+                z_a: *c[1].commitment(),
+                z_b: *c[2].commitment(),
+            })
+            .collect();
+        let lookup_commitments = lookup_first_commitments
+            .chunks_exact(2)
+            .zip_eq(second_commitments[2..].chunks_exact(2))
+            .map(|(first, second)| proof::LookupCommitments {
+                z_c: *first[0].commitment(),
+                m: *first[1].commitment(),
+                h_f: *second[0].commitment(),
+                h_t: *second[1].commitment(),
+            })
+            .collect();
+        proof::Commitments {
+            witness_commitments,
+            mask_poly,
+            g_1: *second_commitments[0].commitment(),
+            h_1: *second_commitments[1].commitment(),
+            g_a: *third_commitments[0].commitment(),
+            g_b: *third_commitments[1].commitment(),
+            g_c: *third_commitments[2].commitment(),
+            h_2: *fourth_commitments[0].commitment(),
+            lookup_commitments,
+            h_lookup: third_commitments.get(3).map(|c| *c.commitment()),
+        }
+    }
+
+    /// Labels the commitments of a proof for a circuit with the given info, grouped by round.
+    /// This method assumes the commitments match the batch size and the lookups of the circuit.
+    pub(super) fn labeled_commitments_by_round(
+        circuit_info: &CircuitInfo<E::Fr>,
+        batch_size: usize,
+        has_lookups: bool,
+        comms: &proof::Commitments<E>,
+    ) -> [Vec<LabeledCommitment<Commitment<E>>>; 4] {
+        let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size, has_lookups);
+        let mut first_commitments = comms
+            .witness_commitments
+            .iter()
+            .enumerate()
+            .flat_map(|(i, c)| {
+                [
+                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
+                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
+                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
+                ]
+            })
+            .collect::<Vec<_>>();
+        first_commitments.extend(comms.lookup_commitments.iter().enumerate().flat_map(|(i, c)| {
+            [
+                LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_c", i)], c.z_c),
+                LabeledCommitment::new_with_info(&first_round_info[&witness_label("m", i)], c.m),
+            ]
+        }));
+        if MM::ZK {
+            first_commitments.push(LabeledCommitment::new_with_info(
+                first_round_info.get("mask_poly").unwrap(),
+                comms.mask_poly.unwrap(),
+            ));
+        }
+
+        let second_round_info =
+            AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(circuit_info, batch_size, has_lookups);
+        let mut second_commitments = vec![
+            LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
+            LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
+        ];
+        second_commitments.extend(comms.lookup_commitments.iter().enumerate().flat_map(|(i, c)| {
+            [
+                LabeledCommitment::new_with_info(&second_round_info[&witness_label("h_f", i)], c.h_f),
+                LabeledCommitment::new_with_info(&second_round_info[&witness_label("h_t", i)], c.h_t),
+            ]
+        }));
+
+        let third_round_info = AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(circuit_info, has_lookups);
+        let mut third_commitments = vec![
+            LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
+            LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
+            LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
+        ];
+        if let Some(h_lookup) = comms.h_lookup {
+            third_commitments.push(LabeledCommitment::new_with_info(&third_round_info["h_lookup"], h_lookup));
+        }
+
+        let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
+        let fourth_commitments = vec![LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
+
+        [first_commitments, second_commitments, third_commitments, fourth_commitments]
+    }
 }
 
 impl<E: PairingEngine, FS, MM, Input> SNARK for MarlinSNARK<E, FS, MM, Input>
@@ -270,7 +556,7 @@ where
         let commitments = verifying_key
             .iter()
             .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(verifying_key.has_lookups()).values())
             .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
             .collect::<Vec<_>>();
 
@@ -293,7 +579,7 @@ where
         verifying_key: &Self::VerifyingKey,
         certificate: &Self::Certificate,
     ) -> Result<bool, SNARKError> {
-        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info();
+        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info(verifying_key.has_lookups());
         // Initialize sponge.
         let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
         // Compute challenges for linear combination, and the point to evaluate the polynomials at.
@@ -348,6 +634,7 @@ where
         if batch_size == 0 {
             return Err(SNARKError::EmptyBatch);
         }
+        let has_lookups = circuit_proving_key.circuit.lookup.is_some();
 
         Self::terminate(terminator)?;
 
@@ -387,6 +674,7 @@ where
         let (verifier_first_message, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
             circuit_proving_key.circuit_verifying_key.circuit_info,
             batch_size,
+            has_lookups,
             &mut sponge,
         )?;
         // --------------------------------------------------------------------
@@ -467,46 +755,30 @@ where
         // Gather prover polynomials in one vector.
         let polynomials: Vec<_> = circuit_proving_key
             .circuit
-            .iter() // 12 items
-            .chain(first_round_oracles.iter_for_open()) // 3 * batch_size + (MM::ZK as usize) items
-            .chain(second_oracles.iter())// 2 items
-            .chain(third_oracles.iter())// 3 items
+            .iter() // 12 items, or 18 items with lookups
+            .chain(first_round_oracles.iter_for_open()) // (3 or 5) * batch_size + (MM::ZK as usize) items
+            .chain(second_oracles.iter())// 2 items, plus 2 * batch_size items with lookups
+            .chain(third_oracles.iter())// 3 items, or 4 items with lookups
             .chain(fourth_oracles.iter())// 1 item
             .collect();
 
         Self::terminate(terminator)?;
 
         // Gather commitments in one vector.
-        let witness_commitments = first_commitments.chunks_exact(3);
-        let mask_poly = MM::ZK.then(|| *witness_commitments.remainder()[0].commitment());
-        let witness_commitments = witness_commitments
-            .map(|c| proof::WitnessCommitments {
-                w: *c[0].commitment(),
-                z_a: *c[1].commitment(),
-                z_b: *c[2].commitment(),
-            })
-            .collect();
-        #[rustfmt::skip]
-        let commitments = proof::Commitments {
-            witness_commitments,
-            mask_poly,
-
-            g_1: *second_commitments[0].commitment(),
-            h_1: *second_commitments[1].commitment(),
-
-
-            g_a: *third_commitments[0].commitment(),
-            g_b: *third_commitments[1].commitment(),
-            g_c: *third_commitments[2].commitment(),
-
-            h_2: *fourth_commitments[0].commitment(),
-        };
+        let commitments = Self::proof_commitments(
+            batch_size,
+            has_lookups,
+            &first_commitments,
+            &second_commitments,
+            &third_commitments,
+            &fourth_commitments,
+        );
 
         let labeled_commitments: Vec<_> = circuit_proving_key
             .circuit_verifying_key
             .iter()
             .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(has_lookups).values())
             .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
             .chain(first_commitments.into_iter())
             .chain(second_commitments.into_iter())
@@ -584,175 +856,20 @@ where
         proof: &Self::Proof,
     ) -> Result<bool, SNARKError> {
         let circuit_verifying_key = &prepared_verifying_key.orig_vk;
-        if public_inputs.is_empty() {
-            return Err(SNARKError::EmptyBatch);
-        }
-
-        let comms = &proof.commitments;
-        let proof_has_correct_zk_mode = if MM::ZK {
-            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
-        } else {
-            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
-        };
-        if !proof_has_correct_zk_mode {
-            eprintln!(
-                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
-                proof.pc_proof.is_hiding()
-            );
-            return Ok(false);
-        }
-
-        let batch_size = public_inputs.len();
-        let verifier_time = start_timer!(|| format!("Marlin::Verify with batch size {batch_size}"));
-
-        let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
-        let mut first_commitments = comms
-            .witness_commitments
-            .iter()
-            .enumerate()
-            .flat_map(|(i, c)| {
-                [
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
-                ]
-            })
-            .collect::<Vec<_>>();
-        if MM::ZK {
-            first_commitments.push(LabeledCommitment::new_with_info(
-                first_round_info.get("mask_poly").unwrap(),
-                comms.mask_poly.unwrap(),
-            ));
-        }
-
-        let second_round_info =
-            AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let second_commitments = [
-            LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
-            LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
-        ];
-
-        let third_round_info =
-            AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let third_commitments = [
-            LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
-            LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
-            LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
-        ];
-
-        let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
-        let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
-
-        let input_domain =
-            EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
-
-        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
-            public_inputs
-                .iter()
-                .map(|input| {
-                    let input = input.borrow().to_field_elements().unwrap();
-                    let mut new_input = vec![E::Fr::one()];
-                    new_input.extend_from_slice(&input);
-                    new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
-                    if cfg!(debug_assertions) {
-                        println!("Number of padded public variables: {}", new_input.len());
-                    }
-                    let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
-                    (new_input, unformatted)
-                })
-                .unzip()
-        };
-
-        let mut sponge = Self::init_sponge(
-            fs_parameters,
-            batch_size,
-            &circuit_verifying_key.circuit_commitments,
-            &padded_public_inputs,
-        );
-
-        // --------------------------------------------------------------------
-        // First round
-        let first_round_time = start_timer!(|| "First round");
-        Self::absorb_labeled(&first_commitments, &mut sponge);
-        let (_, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, batch_size, &mut sponge)?;
-        end_timer!(first_round_time);
-        // --------------------------------------------------------------------
+        let verifier_time = start_timer!(|| format!("Marlin::Verify with batch size {}", public_inputs.len()));
 
-        // --------------------------------------------------------------------
-        // Second round
-        let second_round_time = start_timer!(|| "Second round");
-        Self::absorb_labeled(&second_commitments, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
-        end_timer!(second_round_time);
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Third round
-        let third_round_time = start_timer!(|| "Third round");
-
-        Self::absorb_labeled_with_msg(&third_commitments, &proof.msg, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
-        end_timer!(third_round_time);
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Fourth round
-        let fourth_round_time = start_timer!(|| "Fourth round");
-
-        Self::absorb_labeled(&fourth_commitments, &mut sponge);
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
-        end_timer!(fourth_round_time);
-        // --------------------------------------------------------------------
-
-        // Collect degree bounds for commitments. Indexed polynomials have *no*
-        // degree bounds because we know the committed index polynomial has the
-        // correct degree.
-
-        // Gather commitments in one vector.
-        let commitments: Vec<_> = circuit_verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .chain(first_commitments)
-            .chain(second_commitments)
-            .chain(third_commitments)
-            .chain(fourth_commitments)
-            .collect();
-
-        let query_set_time = start_timer!(|| "Constructing query set");
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
-        end_timer!(query_set_time);
-
-        sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements());
-
-        let mut evaluations = Evaluations::new();
-
-        for (label, (_point_name, q)) in query_set.to_set() {
-            if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
-                evaluations.insert((label, q), E::Fr::zero());
-            } else {
-                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
-                evaluations.insert((label, q), eval);
-            }
-        }
-
-        let lc_time = start_timer!(|| "Constructing linear combinations");
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
-            &public_inputs,
-            &evaluations,
-            &proof.msg,
-            &verifier_state,
-        )?;
-        end_timer!(lc_time);
+        let PCCheck { mut sponge, lc_s, commitments, query_set, evaluations } =
+            match Self::verify_ahp(fs_parameters, circuit_verifying_key, public_inputs, proof)? {
+                Some(pc_check) => pc_check,
+                None => return Ok(false),
+            };
 
         let pc_time = start_timer!(|| "Checking linear combinations with PC");
         let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
             &circuit_verifying_key.verifier_key,
             lc_s.values(),
             &commitments,
-            &query_set.to_set(),
+            &query_set,
             &evaluations,
             &proof.pc_proof,
             &mut sponge,
@@ -765,9 +882,9 @@ where
         }
         end_timer!(verifier_time, || format!(
             " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
+            evaluations_are_correct
         ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        Ok(evaluations_are_correct)
     }
 }
 
@@ -41,6 +41,12 @@ pub use errors::*;
 mod marlin;
 pub use marlin::*;
 
+/// Implements proving and verifying batches that span several circuits.
+mod aggregate;
+
+/// Implements verifying many independent proofs with a single pairing check.
+mod batch_verify;
+
 /// Specifies the Marlin mode.
 mod mode;
 pub use mode::*;
@@ -16,7 +16,7 @@
 
 use crate::traits::{AlgebraicSponge, SNARK};
 use snarkvm_fields::Field;
-use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem, LookupTable};
 
 #[derive(Copy, Clone)]
 pub struct Circuit<F: Field> {
@@ -65,12 +65,58 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<Constrai
     }
 }
 
+/// A circuit that checks `a XOR b = c` for 2-bit values `a` and `b` with a lookup table.
+#[derive(Clone)]
+pub struct LookupCircuit {
+    pub pairs: Vec<(u8, u8)>,
+}
+
+impl LookupCircuit {
+    /// Returns the public inputs of the circuit, i.e. the XOR of each pair.
+    pub fn public_inputs<F: Field>(&self) -> Vec<F> {
+        self.pairs.iter().map(|(a, b)| F::from((a ^ b) as u64)).collect()
+    }
+}
+
+impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for LookupCircuit {
+    fn generate_constraints<CS: ConstraintSystem<ConstraintF>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let table = (0..4u64)
+            .flat_map(|a| (0..4u64).map(move |b| (a.into(), b.into(), (a ^ b).into())))
+            .collect::<LookupTable<_>>();
+        let xor = cs.add_lookup_table(table)?;
+
+        for (i, &(a, b)) in self.pairs.iter().enumerate() {
+            let a_var = cs.alloc(|| format!("a_{i}"), || Ok(ConstraintF::from(a as u64)))?;
+            let b_var = cs.alloc(|| format!("b_{i}"), || Ok(ConstraintF::from(b as u64)))?;
+            let c_var = cs.alloc_input(|| format!("c_{i}"), || Ok(ConstraintF::from((a ^ b) as u64)))?;
+            let sum = cs.alloc(|| format!("sum_{i}"), || Ok(ConstraintF::from((a + b) as u64)))?;
+
+            cs.enforce_lookup(|| format!("xor_{i}"), xor, |lc| lc + a_var, |lc| lc + b_var, |lc| lc + c_var)?;
+            cs.enforce(|| format!("sum_{i}"), |lc| lc + a_var + b_var, |lc| lc + CS::one(), |lc| lc + sum);
+        }
+
+        Ok(())
+    }
+}
+
 mod marlin {
     use super::*;
-    use crate::snark::marlin::{AHPForR1CS, CircuitVerifyingKey, MarlinHidingMode, MarlinNonHidingMode, MarlinSNARK};
+    use crate::snark::marlin::{
+        AHPForR1CS,
+        AggregatedProof,
+        CircuitProvingKey,
+        CircuitVerifyingKey,
+        MarlinHidingMode,
+        MarlinNonHidingMode,
+        MarlinSNARK,
+        Proof,
+    };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_fields::One;
     use snarkvm_utilities::rand::{TestRng, Uniform};
 
+    use rand::Rng;
+
     use core::ops::MulAssign;
 
     type MarlinSonicInst = MarlinSNARK<Bls12_377, FS, MarlinHidingMode, [Fr]>;
@@ -144,19 +190,215 @@ mod marlin {
                             );
                             println!("Called verifier");
                             println!("\nShould not verify (i.e. verifier messages should print below):");
-                            assert!(
-                                !$marlin_inst::verify_batch(
-                                    &fs_parameters,
-                                    &index_vk,
-                                    &vec![[Fr::rand(rng), Fr::rand(rng)]; batch_size],
-                                    &proof
-                                )
-                                .unwrap()
-                            );
+                            assert!(!$marlin_inst::verify_batch(
+                                &fs_parameters,
+                                &index_vk,
+                                &vec![[Fr::rand(rng), Fr::rand(rng)]; batch_size],
+                                &proof
+                            )
+                            .unwrap());
                         }
                     }
                 }
 
+                pub(crate) fn test_aggregate() {
+                    use snarkvm_utilities::{FromBytes, ToBytes};
+
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    // Sample a batch of instances for a circuit of the given size.
+                    let sample_batch =
+                        |rng: &mut TestRng, num_constraints: usize, num_variables: usize, batch_size: usize| {
+                            (0..batch_size)
+                                .map(|_| {
+                                    let a = Fr::rand(rng);
+                                    let b = Fr::rand(rng);
+                                    let mut c = a;
+                                    c.mul_assign(&b);
+                                    let mut d = c;
+                                    d.mul_assign(&b);
+                                    (Circuit { a: Some(a), b: Some(b), num_constraints, num_variables }, [c, d])
+                                })
+                                .unzip::<_, _, Vec<_>, Vec<_>>()
+                        };
+                    let (circuits_0, inputs_0) = sample_batch(rng, 100, 25, 2);
+                    let (circuits_1, inputs_1) = sample_batch(rng, 25, 26, 1);
+                    let (circuits_2, inputs_2) = sample_batch(rng, 26, 25, 3);
+
+                    let (pk_0, vk_0) = $marlin_inst::circuit_setup(&universal_srs, &circuits_0[0]).unwrap();
+                    let (pk_1, vk_1) = $marlin_inst::circuit_setup(&universal_srs, &circuits_1[0]).unwrap();
+                    let (pk_2, vk_2) = $marlin_inst::circuit_setup(&universal_srs, &circuits_2[0]).unwrap();
+
+                    let keys_to_circuits =
+                        [(&pk_0, &circuits_0[..]), (&pk_1, &circuits_1[..]), (&pk_2, &circuits_2[..])];
+                    let proof = $marlin_inst::prove_batch_aggregate(&fs_parameters, &keys_to_circuits, rng).unwrap();
+                    assert_eq!(proof.batch_sizes(), &[2, 1, 3]);
+
+                    let keys_to_inputs = [(&vk_0, &inputs_0[..]), (&vk_1, &inputs_1[..]), (&vk_2, &inputs_2[..])];
+                    assert!($marlin_inst::verify_batch_aggregate(&fs_parameters, &keys_to_inputs, &proof).unwrap());
+
+                    // Ensure the proof survives a serialization round trip.
+                    let proof_bytes = proof.to_bytes_le().unwrap();
+                    let candidate = AggregatedProof::read_le(&proof_bytes[..]).unwrap();
+                    assert_eq!(proof, candidate);
+                    assert!($marlin_inst::verify_batch_aggregate(&fs_parameters, &keys_to_inputs, &candidate).unwrap());
+
+                    // Ensure the proof fails to verify on incorrect inputs.
+                    let (_, wrong_inputs) = sample_batch(rng, 25, 26, 1);
+                    let keys_to_inputs = [(&vk_0, &inputs_0[..]), (&vk_1, &wrong_inputs[..]), (&vk_2, &inputs_2[..])];
+                    assert!(!$marlin_inst::verify_batch_aggregate(&fs_parameters, &keys_to_inputs, &proof).unwrap());
+
+                    // Ensure the proof fails to verify for a subset of the circuits.
+                    let keys_to_inputs = [(&vk_0, &inputs_0[..]), (&vk_1, &inputs_1[..])];
+                    assert!(!$marlin_inst::verify_batch_aggregate(&fs_parameters, &keys_to_inputs, &proof).unwrap());
+
+                    // Ensure the proof fails to verify when the circuits are reordered.
+                    let keys_to_inputs = [(&vk_1, &inputs_1[..]), (&vk_0, &inputs_0[..]), (&vk_2, &inputs_2[..])];
+                    assert!(!$marlin_inst::verify_batch_aggregate(&fs_parameters, &keys_to_inputs, &proof).unwrap());
+
+                    // Ensure a malformed proof is rejected instead of panicking the verifier.
+                    let keys_to_inputs = [(&vk_0, &inputs_0[..]), (&vk_1, &inputs_1[..]), (&vk_2, &inputs_2[..])];
+                    let mut malformed = proof.clone();
+                    malformed.commitments[2].witness_commitments.pop();
+                    let is_valid = $marlin_inst::verify_batch_aggregate(&fs_parameters, &keys_to_inputs, &malformed);
+                    assert!(!is_valid.unwrap());
+                    let mut malformed = proof;
+                    malformed.evaluations[0].z_b_evals.pop();
+                    let is_valid = $marlin_inst::verify_batch_aggregate(&fs_parameters, &keys_to_inputs, &malformed);
+                    assert!(!is_valid.unwrap());
+                }
+
+                pub(crate) fn test_verify_many() {
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    // Sample a satisfied circuit of the given size, along with its public inputs.
+                    let sample_circuit = |rng: &mut TestRng, num_constraints: usize, num_variables: usize| {
+                        let a = Fr::rand(rng);
+                        let b = Fr::rand(rng);
+                        let mut c = a;
+                        c.mul_assign(&b);
+                        let mut d = c;
+                        d.mul_assign(&b);
+                        (Circuit { a: Some(a), b: Some(b), num_constraints, num_variables }, vec![c, d])
+                    };
+
+                    let (circuit_0, _) = sample_circuit(rng, 100, 25);
+                    let (circuit_1, _) = sample_circuit(rng, 25, 26);
+                    let (pk_0, vk_0) = $marlin_inst::circuit_setup(&universal_srs, &circuit_0).unwrap();
+                    let (pk_1, vk_1) = $marlin_inst::circuit_setup(&universal_srs, &circuit_1).unwrap();
+
+                    // Prove several instances of each circuit, each in its own proof.
+                    let mut instances = Vec::new();
+                    for i in 0..6 {
+                        let (pk, vk, (num_constraints, num_variables)) =
+                            if i % 2 == 0 { (&pk_0, &vk_0, (100, 25)) } else { (&pk_1, &vk_1, (25, 26)) };
+                        let (circuit, inputs) = sample_circuit(rng, num_constraints, num_variables);
+                        let proof = $marlin_inst::prove(&fs_parameters, pk, &circuit, rng).unwrap();
+                        instances.push((vk, vec![inputs], proof));
+                    }
+
+                    #[allow(clippy::type_complexity)]
+                    fn to_instances<'a, V, P>(instances: &'a [(&'a V, Vec<Vec<Fr>>, P)]) -> Vec<(&'a V, &'a [Vec<Fr>], &'a P)> {
+                        instances.iter().map(|(vk, inputs, proof)| (*vk, &inputs[..], proof)).collect()
+                    }
+                    assert!($marlin_inst::verify_many(&fs_parameters, &to_instances(&instances), rng).unwrap());
+
+                    // Ensure a single proof with incorrect inputs fails the batch.
+                    let mut invalid_instances = instances.clone();
+                    invalid_instances[3].1 = vec![vec![Fr::rand(rng), Fr::rand(rng)]];
+                    assert!(!$marlin_inst::verify_many(&fs_parameters, &to_instances(&invalid_instances), rng).unwrap());
+
+                    // Ensure a proof checked against the wrong circuit fails the batch.
+                    let mut invalid_instances = instances.clone();
+                    invalid_instances[2].0 = &vk_1;
+                    assert!(!$marlin_inst::verify_many(&fs_parameters, &to_instances(&invalid_instances), rng).unwrap());
+                }
+
+                pub(crate) fn test_lookup_circuit() {
+                    use snarkvm_utilities::{FromBytes, ToBytes};
+
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree, rng).unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    let sample_circuit = |rng: &mut TestRng| LookupCircuit {
+                        pairs: (0..5).map(|_| (rng.gen_range(0..4), rng.gen_range(0..4))).collect(),
+                    };
+
+                    let circuit = sample_circuit(rng);
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circuit).unwrap();
+                    assert!(index_vk.has_lookups());
+
+                    let certificate = $marlin_inst::prove_vk(&fs_parameters, &index_vk, &index_pk).unwrap();
+                    assert!($marlin_inst::verify_vk(&fs_parameters, &circuit, &index_vk, &certificate).unwrap());
+
+                    for batch_size in [1, 2, 3] {
+                        let circuits = (0..batch_size).map(|_| sample_circuit(rng)).collect::<Vec<_>>();
+                        let inputs = circuits.iter().map(|circuit| circuit.public_inputs::<Fr>()).collect::<Vec<_>>();
+
+                        let proof = $marlin_inst::prove_batch(&fs_parameters, &index_pk, &circuits, rng).unwrap();
+                        assert!(proof.has_lookups());
+                        assert!($marlin_inst::verify_batch(&fs_parameters, &index_vk, &inputs, &proof).unwrap());
+
+                        // Ensure the proof survives a serialization round trip.
+                        let candidate = Proof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+                        assert_eq!(proof, candidate);
+                        assert!($marlin_inst::verify_batch(&fs_parameters, &index_vk, &inputs, &candidate).unwrap());
+
+                        // Ensure the proof fails to verify on incorrect inputs.
+                        let mut wrong_inputs = inputs.clone();
+                        wrong_inputs[0][0] += Fr::one();
+                        assert!(!$marlin_inst::verify_batch(&fs_parameters, &index_vk, &wrong_inputs, &proof).unwrap());
+                    }
+
+                    // Ensure the lookup tables survive a serialization round trip of the proving key.
+                    let index_pk = CircuitProvingKey::read_le(&index_pk.to_bytes_le().unwrap()[..]).unwrap();
+                    assert!(index_pk.circuit.lookup.is_some());
+                    let circuit = sample_circuit(rng);
+                    let proof = $marlin_inst::prove(&fs_parameters, &index_pk, &circuit, rng).unwrap();
+                    assert!($marlin_inst::verify(&fs_parameters, &index_vk, circuit.public_inputs::<Fr>(), &proof).unwrap());
+
+                    // Ensure circuits with lookups can be aggregated into one proof.
+                    let other_circuit = LookupCircuit { pairs: vec![(1, 2), (3, 3), (0, 1)] };
+                    let (other_pk, other_vk) = $marlin_inst::circuit_setup(&universal_srs, &other_circuit).unwrap();
+                    let circuits = (0..2).map(|_| sample_circuit(rng)).collect::<Vec<_>>();
+                    let inputs = circuits.iter().map(|circuit| circuit.public_inputs::<Fr>()).collect::<Vec<_>>();
+                    let other_inputs = [other_circuit.public_inputs::<Fr>()];
+                    let keys_to_circuits = [(&index_pk, &circuits[..]), (&other_pk, &[other_circuit][..])];
+                    let proof = $marlin_inst::prove_batch_aggregate(&fs_parameters, &keys_to_circuits, rng).unwrap();
+                    let keys_to_inputs = [(&index_vk, &inputs[..]), (&other_vk, &other_inputs[..])];
+                    assert!($marlin_inst::verify_batch_aggregate(&fs_parameters, &keys_to_inputs, &proof).unwrap());
+
+                    // Ensure the aggregated proof survives a serialization round trip.
+                    let candidate = AggregatedProof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+                    assert_eq!(proof, candidate);
+                    assert!($marlin_inst::verify_batch_aggregate(&fs_parameters, &keys_to_inputs, &candidate).unwrap());
+
+                    // Ensure the aggregated proof fails to verify on incorrect inputs.
+                    let mut wrong_inputs = other_inputs.clone();
+                    wrong_inputs[0][1] += Fr::one();
+                    let keys_to_inputs = [(&index_vk, &inputs[..]), (&other_vk, &wrong_inputs[..])];
+                    assert!(!$marlin_inst::verify_batch_aggregate(&fs_parameters, &keys_to_inputs, &proof).unwrap());
+
+                    // Ensure proofs for circuits without lookups are rejected by a verifying key with lookups.
+                    let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+                    let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 25, num_variables: 25 };
+                    let (pk, _) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    let proof = $marlin_inst::prove(&fs_parameters, &pk, &circ, rng).unwrap();
+                    assert!(!proof.has_lookups());
+                    assert!(!$marlin_inst::verify(&fs_parameters, &index_vk, [a * b, a * b * b], &proof).unwrap());
+                }
+
                 pub(crate) fn test_serde_json(num_constraints: usize, num_variables: usize) {
                     use std::str::FromStr;
 
@@ -215,6 +457,24 @@ mod marlin {
     impl_marlin_test!(SonicPCTest, MarlinSonicInst, MarlinHidingMode);
     impl_marlin_test!(SonicPCPoswTest, MarlinSonicPoswInst, MarlinNonHidingMode);
 
+    #[test]
+    fn prove_and_verify_many() {
+        SonicPCTest::test_verify_many();
+        SonicPCPoswTest::test_verify_many();
+    }
+
+    #[test]
+    fn prove_and_verify_aggregate() {
+        SonicPCTest::test_aggregate();
+        SonicPCPoswTest::test_aggregate();
+    }
+
+    #[test]
+    fn prove_and_verify_with_lookups() {
+        SonicPCTest::test_lookup_circuit();
+        SonicPCPoswTest::test_lookup_circuit();
+    }
+
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
         let num_constraints = 100;
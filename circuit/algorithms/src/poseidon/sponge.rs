@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A duplex sponge over the Poseidon permutation, for deriving Fiat-Shamir challenges in a circuit.
+/// The sponge matches the native `PoseidonSponge` for the same parameters.
+#[derive(Clone)]
+pub struct PoseidonSponge<E: Environment, const RATE: usize> {
+    /// The Poseidon parameters.
+    poseidon: Poseidon<E, RATE>,
+    /// The sponge state.
+    state: Vec<Field<E>>,
+    /// The sponge mode.
+    mode: DuplexSpongeMode,
+}
+
+impl<E: Environment, const RATE: usize> PoseidonSponge<E, RATE> {
+    /// Initializes a new sponge with the given Poseidon parameters.
+    pub fn new(poseidon: &Poseidon<E, RATE>) -> Self {
+        Self {
+            poseidon: poseidon.clone(),
+            state: vec![Field::zero(); RATE + CAPACITY],
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+        }
+    }
+
+    /// Absorbs the given field elements into the sponge.
+    pub fn absorb(&mut self, input: &[Field<E>]) {
+        self.poseidon.absorb(&mut self.state, &mut self.mode, input)
+    }
+
+    /// Squeezes the given number of field elements from the sponge.
+    pub fn squeeze(&mut self, num_outputs: u16) -> Vec<Field<E>> {
+        self.poseidon.squeeze(&mut self.state, &mut self.mode, num_outputs)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{crypto_hash::PoseidonSponge as NativeSponge, AlgebraicSponge};
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const ITERATIONS: usize = 10;
+    const RATE: usize = 4;
+
+    type NativeField = <Circuit as Environment>::BaseField;
+
+    fn check_sponge(mode: Mode, schedule: &[(usize, u16)], rng: &mut TestRng) -> Result<()> {
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Initialize the native and circuit sponges.
+            let mut expected_sponge = NativeSponge::<NativeField, RATE, 1>::new_with_parameters(native.parameters());
+            let mut candidate_sponge = PoseidonSponge::new(&poseidon);
+
+            Circuit::scope(format!("PoseidonSponge {mode} {i}"), || {
+                // Interleave absorbing and squeezing according to the schedule.
+                for (num_inputs, num_outputs) in schedule {
+                    let native_input = (0..*num_inputs).map(|_| NativeField::rand(rng)).collect::<Vec<_>>();
+                    let input = native_input
+                        .iter()
+                        .map(|v| Field::<Circuit>::new(mode, console::Field::new(*v)))
+                        .collect::<Vec<_>>();
+
+                    expected_sponge.absorb_native_field_elements(&native_input);
+                    candidate_sponge.absorb(&input);
+
+                    let expected = expected_sponge.squeeze_native_field_elements(*num_outputs as usize);
+                    let candidate = candidate_sponge.squeeze(*num_outputs);
+                    for (expected_element, candidate_element) in expected.iter().zip_eq(&candidate) {
+                        assert_eq!(*expected_element, *candidate_element.eject_value());
+                    }
+                }
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sponge_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+        check_sponge(Mode::Constant, &[(1, 1), (RATE, 2), (RATE + 1, 0), (0, RATE as u16 + 1)], &mut rng)
+    }
+
+    #[test]
+    fn test_sponge_public() -> Result<()> {
+        let mut rng = TestRng::default();
+        check_sponge(Mode::Public, &[(1, 1), (RATE, 2), (RATE + 1, 0), (0, RATE as u16 + 1)], &mut rng)
+    }
+
+    #[test]
+    fn test_sponge_private() -> Result<()> {
+        let mut rng = TestRng::default();
+        check_sponge(Mode::Private, &[(2, 3), (2 * RATE + 1, 1), (0, 2 * RATE as u16), (3, 1)], &mut rng)
+    }
+}
@@ -14,7 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{helpers::Constraint, Mode, *};
+use crate::{
+    helpers::{Constraint, LookupConstraint},
+    Mode,
+    *,
+};
+use snarkvm_r1cs::LookupTable;
 
 use core::{cell::RefCell, fmt};
 use std::rc::Rc;
@@ -182,6 +187,58 @@ impl Environment for Circuit {
         })
     }
 
+    /// Registers a lookup table under the given name, and returns its index.
+    /// The table is only constructed if no table with the given name has been registered yet.
+    fn add_lookup_table<S: Into<String>, Fn: FnOnce() -> LookupTable<Self::BaseField>>(name: S, table: Fn) -> usize {
+        CIRCUIT.with(|circuit| (**circuit).borrow_mut().add_lookup_table(name, table))
+    }
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row in the given lookup table.
+    fn enforce_lookup<Fn, A, B, C>(table_index: usize, lookup: Fn)
+    where
+        Fn: FnOnce() -> (A, B, C),
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>,
+    {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !(*(**in_witness).borrow()) {
+                CIRCUIT.with(|circuit| {
+                    let (a, b, c) = lookup();
+                    let (a, b, c) = (a.into(), b.into(), c.into());
+
+                    // Ensure the lookup table exists.
+                    let is_constant_lookup = match (**circuit).borrow().get_lookup_table(table_index) {
+                        Some(table) => match a.is_constant() && b.is_constant() && c.is_constant() {
+                            true => {
+                                // Evaluate the constant lookup.
+                                assert!(
+                                    table.contains(&a.value(), &b.value(), &c.value()),
+                                    "Constant lookup failed: ({}, {}, {}) is not in table {}",
+                                    a,
+                                    b,
+                                    c,
+                                    table_index
+                                );
+                                true
+                            }
+                            false => false,
+                        },
+                        None => Self::halt(format!("Lookup table {table_index} does not exist")),
+                    };
+
+                    if !is_constant_lookup {
+                        // Construct the lookup constraint object.
+                        let lookup = LookupConstraint((**circuit).borrow().scope(), table_index, a, b, c);
+                        // Append the lookup constraint.
+                        (**circuit).borrow_mut().enforce_lookup(lookup)
+                    }
+                });
+            }
+        })
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied())
@@ -192,6 +249,16 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied_in_scope())
     }
 
+    /// Returns the first unsatisfied constraint in the environment, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>> {
+        CIRCUIT.with(|circuit| (**circuit).borrow().unsatisfied_constraints().next())
+    }
+
+    /// Returns all unsatisfied constraints in the environment, followed by all unsatisfied lookups.
+    fn unsatisfied_constraints() -> Vec<UnsatisfiedConstraint<Self::BaseField>> {
+        CIRCUIT.with(|circuit| (**circuit).borrow().unsatisfied_constraints().collect())
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_constants())
@@ -212,6 +279,11 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_constraints())
     }
 
+    /// Returns the number of lookup constraints in the entire circuit.
+    fn num_lookups() -> u64 {
+        CIRCUIT.with(|circuit| (**circuit).borrow().num_lookups())
+    }
+
     /// Returns the number of gates in the entire circuit.
     fn num_gates() -> u64 {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_gates())
@@ -249,6 +321,35 @@ impl Environment for Circuit {
         panic!("{}", &error)
     }
 
+    /// Runs the given logic with the given session as the current environment,
+    /// and restores the enclosing environment afterwards (even if the logic halts).
+    fn enter<Fn, Output>(session: &mut Session<Self::BaseField>, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        /// Swaps the state of the current environment with the state of the given session.
+        fn swap(session: &mut Session<Field>) {
+            CIRCUIT.with(|circuit| core::mem::swap(&mut *(**circuit).borrow_mut(), &mut session.r1cs));
+            IN_WITNESS.with(|in_witness| core::mem::swap(&mut *(**in_witness).borrow_mut(), &mut session.in_witness));
+        }
+
+        /// Restores the enclosing environment when dropped.
+        struct Guard<'a>(&'a mut Session<Field>);
+
+        impl Drop for Guard<'_> {
+            fn drop(&mut self) {
+                swap(self.0)
+            }
+        }
+
+        // Set the given session as the current environment.
+        swap(session);
+        let _guard = Guard(session);
+
+        // Run the logic.
+        logic()
+    }
+
     /// TODO (howardwu): Abstraction - Refactor this into an appropriate design.
     ///  Circuits should not have easy access to this during synthesis.
     /// Returns the R1CS circuit, resetting the circuit.
@@ -369,4 +470,87 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_unsatisfied_constraints() {
+        Circuit::reset();
+
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let (two, three) = (one + one, one + one + one);
+
+        Circuit::scope("outer", || {
+            let a = Field::<Circuit>::new(Mode::Private, two);
+            let b = Field::<Circuit>::new(Mode::Private, three);
+            // Enforce a satisfied constraint.
+            Circuit::enforce(|| (&a, &b, &a + &a + &a));
+            // Enforce an unsatisfied constraint.
+            Circuit::scope("inner", || Circuit::enforce(|| (&a, &b, &b)));
+        });
+        assert!(!Circuit::is_satisfied());
+
+        let unsatisfied = Circuit::first_unsatisfied_constraint().unwrap();
+        assert_eq!(1, unsatisfied.index());
+        assert_eq!("outer.inner", unsatisfied.scope());
+        assert_eq!("inner", unsatisfied.gadget());
+        assert_eq!(None, unsatisfied.table());
+        assert_eq!((*two, *three, *three), unsatisfied.values());
+        assert_eq!(vec![unsatisfied], Circuit::unsatisfied_constraints());
+
+        Circuit::reset();
+        assert_eq!(None, Circuit::first_unsatisfied_constraint());
+    }
+
+    #[test]
+    fn test_circuit_session() {
+        Circuit::reset();
+
+        // Synthesize a circuit in the current environment.
+        let _candidate = create_example_circuit::<Circuit>();
+        let expected = Circuit::count();
+
+        // Synthesize the same circuit in a new session, nested in the current environment.
+        let mut session = Session::new();
+        Circuit::enter(&mut session, || {
+            assert_eq!((0, 1, 0, 0, 0), Circuit::count());
+            let _candidate = create_example_circuit::<Circuit>();
+            assert_eq!(expected, Circuit::count());
+
+            // Ensure sessions can be nested.
+            Circuit::enter(&mut Session::new(), || assert_eq!((0, 1, 0, 0, 0), Circuit::count()));
+            assert_eq!(expected, Circuit::count());
+        });
+
+        // Ensure the current environment is restored.
+        assert_eq!(expected, Circuit::count());
+
+        // Ensure the session retains its circuit, when re-entered.
+        Circuit::enter(&mut session, || {
+            assert_eq!(expected, Circuit::count());
+            assert!(Circuit::is_satisfied());
+        });
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_circuit_session_restores_on_halt() {
+        Circuit::reset();
+
+        let _candidate = create_example_circuit::<Circuit>();
+        let expected = Circuit::count();
+
+        // Halt in the middle of a session.
+        let result = std::panic::catch_unwind(|| {
+            Circuit::enter(&mut Session::new(), || {
+                let _candidate = create_example_circuit::<Circuit>();
+                Circuit::halt::<_, ()>("Halted in a session")
+            })
+        });
+        assert!(result.is_err());
+
+        // Ensure the current environment is restored.
+        assert_eq!(expected, Circuit::count());
+
+        Circuit::reset();
+    }
 }
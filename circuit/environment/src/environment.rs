@@ -14,7 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{Assignment, Inject, LinearCombination, Mode, Session, UnsatisfiedConstraint, Variable, R1CS};
+use snarkvm_r1cs::LookupTable;
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -83,12 +84,30 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         Self::enforce(|| (a, Self::one(), b))
     }
 
+    /// Registers a lookup table under the given name, and returns its index.
+    /// The table is only constructed if no table with the given name has been registered yet.
+    fn add_lookup_table<S: Into<String>, Fn: FnOnce() -> LookupTable<Self::BaseField>>(name: S, table: Fn) -> usize;
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row in the given lookup table.
+    fn enforce_lookup<Fn, A, B, C>(table_index: usize, lookup: Fn)
+    where
+        Fn: FnOnce() -> (A, B, C),
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>;
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool;
 
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
+    /// Returns the first unsatisfied constraint in the environment, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>>;
+
+    /// Returns all unsatisfied constraints in the environment, followed by all unsatisfied lookups.
+    fn unsatisfied_constraints() -> Vec<UnsatisfiedConstraint<Self::BaseField>>;
+
     /// Returns the number of constants in the entire environment.
     fn num_constants() -> u64;
 
@@ -101,6 +120,9 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns the number of constraints in the entire environment.
     fn num_constraints() -> u64;
 
+    /// Returns the number of lookup constraints in the entire environment.
+    fn num_lookups() -> u64;
+
     /// Returns the number of gates in the entire environment.
     fn num_gates() -> u64;
 
@@ -140,6 +162,12 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         <Self::Network as console::Environment>::halt(message)
     }
 
+    /// Runs the given logic with the given session as the current environment,
+    /// and restores the enclosing environment afterwards (even if the logic halts).
+    fn enter<Fn, Output>(session: &mut Session<Self::BaseField>, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output;
+
     /// Returns the R1CS circuit, resetting the circuit.
     fn inject_r1cs(r1cs: R1CS<Self::BaseField>);
 
@@ -16,6 +16,7 @@
 
 use crate::Index;
 use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::LookupTable;
 
 use indexmap::IndexMap;
 
@@ -61,6 +62,8 @@ pub struct Assignment<F: PrimeField> {
     public: IndexMap<Index, F>,
     private: IndexMap<Index, F>,
     constraints: Vec<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)>,
+    lookup_tables: Vec<LookupTable<F>>,
+    lookups: Vec<(usize, AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)>,
 }
 
 impl<F: PrimeField> From<crate::R1CS<F>> for Assignment<F> {
@@ -77,6 +80,11 @@ impl<F: PrimeField> From<crate::R1CS<F>> for Assignment<F> {
                 let (a, b, c) = constraint.to_terms();
                 (a.into(), b.into(), c.into())
             })),
+            lookup_tables: r1cs.to_lookup_tables().values().cloned().collect(),
+            lookups: FromIterator::from_iter(r1cs.to_lookups().iter().map(|lookup| {
+                let (a, b, c) = lookup.to_terms();
+                (lookup.table_index(), a.into(), b.into(), c.into())
+            })),
         }
     }
 }
@@ -97,9 +105,14 @@ impl<F: PrimeField> Assignment<F> {
         self.private.len() as u64
     }
 
-    /// Returns the number of constraints in the assignment.
+    /// Returns the number of constraints (including lookups) in the assignment.
     pub fn num_constraints(&self) -> u64 {
-        self.constraints.len() as u64
+        (self.constraints.len() + self.lookups.len()) as u64
+    }
+
+    /// Returns the number of lookup constraints in the assignment.
+    pub fn num_lookups(&self) -> u64 {
+        self.lookups.len() as u64
     }
 }
 
@@ -156,50 +169,50 @@ impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for Assignment<F> {
             assert!(result.is_none(), "Overwrote an existing private variable in the converter");
         }
 
-        // Enforce all of the constraints.
-        for (i, (a, b, c)) in self.constraints.iter().enumerate() {
-            // Converts terms from one linear combination in the first system to the second system.
-            let convert_linear_combination = |lc: &AssignmentLC<F>| -> snarkvm_r1cs::LinearCombination<F> {
-                // Initialize a linear combination for the second system.
-                let mut linear_combination = snarkvm_r1cs::LinearCombination::<F>::zero();
-
-                // Keep an accumulator for constant values in the linear combination.
-                let mut constant_accumulator = lc.constant;
-                // Process every term in the linear combination.
-                for (variable, coefficient) in lc.terms.iter() {
-                    match variable {
-                        AssignmentVariable::Constant(value) => {
-                            constant_accumulator += *value;
-                        }
-                        AssignmentVariable::Public(index) => {
-                            let gadget = converter.public.get(index).unwrap();
-                            assert_eq!(
-                                snarkvm_r1cs::Index::Public((index + 1) as usize),
-                                gadget.get_unchecked(),
-                                "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
-                            );
-                            linear_combination += (*coefficient, *gadget);
-                        }
-                        AssignmentVariable::Private(index) => {
-                            let gadget = converter.private.get(index).unwrap();
-                            assert_eq!(
-                                snarkvm_r1cs::Index::Private(*index as usize),
-                                gadget.get_unchecked(),
-                                "Failed during constraint translation. The private variable in the second system must match the first system"
-                            );
-                            linear_combination += (*coefficient, *gadget);
-                        }
+        // Converts terms from one linear combination in the first system to the second system.
+        let convert_linear_combination = |lc: &AssignmentLC<F>| -> snarkvm_r1cs::LinearCombination<F> {
+            // Initialize a linear combination for the second system.
+            let mut linear_combination = snarkvm_r1cs::LinearCombination::<F>::zero();
+
+            // Keep an accumulator for constant values in the linear combination.
+            let mut constant_accumulator = lc.constant;
+            // Process every term in the linear combination.
+            for (variable, coefficient) in lc.terms.iter() {
+                match variable {
+                    AssignmentVariable::Constant(value) => {
+                        constant_accumulator += *value;
+                    }
+                    AssignmentVariable::Public(index) => {
+                        let gadget = converter.public.get(index).unwrap();
+                        assert_eq!(
+                            snarkvm_r1cs::Index::Public((index + 1) as usize),
+                            gadget.get_unchecked(),
+                            "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
+                        );
+                        linear_combination += (*coefficient, *gadget);
+                    }
+                    AssignmentVariable::Private(index) => {
+                        let gadget = converter.private.get(index).unwrap();
+                        assert_eq!(
+                            snarkvm_r1cs::Index::Private(*index as usize),
+                            gadget.get_unchecked(),
+                            "Failed during constraint translation. The private variable in the second system must match the first system"
+                        );
+                        linear_combination += (*coefficient, *gadget);
                     }
                 }
+            }
 
-                // Finally, add the accumulated constant value to the linear combination.
-                linear_combination +=
-                    (constant_accumulator, snarkvm_r1cs::Variable::new_unchecked(snarkvm_r1cs::Index::Public(0)));
+            // Finally, add the accumulated constant value to the linear combination.
+            linear_combination +=
+                (constant_accumulator, snarkvm_r1cs::Variable::new_unchecked(snarkvm_r1cs::Index::Public(0)));
 
-                // Return the linear combination of the second system.
-                linear_combination
-            };
+            // Return the linear combination of the second system.
+            linear_combination
+        };
 
+        // Enforce all of the constraints.
+        for (i, (a, b, c)) in self.constraints.iter().enumerate() {
             cs.enforce(
                 || format!("Constraint {i}"),
                 |lc| lc + convert_linear_combination(a),
@@ -208,6 +221,23 @@ impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for Assignment<F> {
             );
         }
 
+        // Register all of the lookup tables.
+        for (i, table) in self.lookup_tables.iter().enumerate() {
+            let index = cs.add_lookup_table(table.clone())?;
+            assert_eq!(i, index, "Lookup tables in the second system must match the first system");
+        }
+
+        // Enforce all of the lookup constraints.
+        for (i, (table_index, a, b, c)) in self.lookups.iter().enumerate() {
+            cs.enforce_lookup(
+                || format!("Lookup {i}"),
+                *table_index,
+                |lc| lc + convert_linear_combination(a),
+                |lc| lc + convert_linear_combination(b),
+                |lc| lc + convert_linear_combination(c),
+            )?;
+        }
+
         // Ensure the given `cs` matches in size with the first system.
         assert_eq!(self.num_public() + 1, cs.num_public_variables() as u64);
         assert_eq!(self.num_private(), cs.num_private_variables() as u64);
@@ -307,4 +337,60 @@ mod tests {
         println!("\nShould not verify (i.e. verifier messages should print below):");
         assert!(!MarlinInst::verify(&fs_pp, &index_vk, [one, one + one], &proof).unwrap());
     }
+
+    #[test]
+    fn test_marlin_with_lookups() {
+        // Multiply two integers, which range checks the carry of the product with lookups.
+        let a = U64::<Circuit>::new(Mode::Private, snarkvm_console_types::U64::new(u64::MAX - 1));
+        let b = U64::<Circuit>::new(Mode::Private, snarkvm_console_types::U64::new(3));
+        let (num_constraints, num_lookups) = (Circuit::num_constraints(), Circuit::num_lookups());
+        let product = a.mul_wrapped(&b);
+        assert_eq!((u64::MAX - 1).wrapping_mul(3), *product.eject_value());
+
+        // Ensure the 33-bit carry is range checked with 5 lookups, instead of being decomposed into 33 bits.
+        assert_eq!(5, Circuit::num_lookups() - num_lookups);
+        assert_eq!(64 + 5 + 4, Circuit::num_constraints() - num_constraints);
+        assert!(Circuit::num_constraints() - num_constraints < 64 + 33 + 5);
+        assert!(Circuit::is_satisfied());
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        // Marlin setup, prove, and verify.
+
+        use snarkvm_algorithms::{
+            crypto_hash::PoseidonSponge,
+            snark::marlin::{ahp::AHPForR1CS, MarlinHidingMode, MarlinSNARK},
+        };
+        use snarkvm_curves::bls12_377::{Bls12_377, Fq};
+        use snarkvm_utilities::rand::TestRng;
+
+        type FS = PoseidonSponge<Fq, 2, 1>;
+        type MarlinInst = MarlinSNARK<Bls12_377, FS, MarlinHidingMode, [Fr]>;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(600, 600, 1200).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree, rng).unwrap();
+        let fs_pp = FS::sample_parameters();
+
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &assignment).unwrap();
+        assert!(index_vk.has_lookups());
+
+        let one = <Circuit as Environment>::BaseField::one();
+        let proof = MarlinInst::prove(&fs_pp, &index_pk, &assignment, rng).unwrap();
+        assert!(proof.has_lookups());
+        assert!(MarlinInst::verify(&fs_pp, &index_vk, [one], &proof).unwrap());
+
+        // Ensure the circuit can be aggregated with a circuit without lookups.
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let other_assignment = Circuit::eject_assignment_and_reset();
+        let (other_pk, other_vk) = MarlinInst::circuit_setup(&universal_srs, &other_assignment).unwrap();
+        assert!(!other_vk.has_lookups());
+
+        let keys_to_circuits = [(&index_pk, &[assignment][..]), (&other_pk, &[other_assignment][..])];
+        let proof = MarlinInst::prove_batch_aggregate(&fs_pp, &keys_to_circuits, rng).unwrap();
+        let keys_to_inputs = [(&index_vk, &[vec![one]][..]), (&other_vk, &[vec![one, one]][..])];
+        assert!(MarlinInst::verify_batch_aggregate(&fs_pp, &keys_to_inputs, &proof).unwrap());
+        let keys_to_inputs = [(&index_vk, &[vec![one]][..]), (&other_vk, &[vec![one, one + one]][..])];
+        assert!(!MarlinInst::verify_batch_aggregate(&fs_pp, &keys_to_inputs, &proof).unwrap());
+    }
 }
@@ -67,3 +67,50 @@ impl<F: PrimeField> Display for Constraint<F> {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub(crate) struct LookupConstraint<F: PrimeField>(
+    pub(crate) Scope,
+    pub(crate) usize,
+    pub(crate) LinearCombination<F>,
+    pub(crate) LinearCombination<F>,
+    pub(crate) LinearCombination<F>,
+);
+
+impl<F: PrimeField> LookupConstraint<F> {
+    /// Returns the number of gates consumed by this lookup constraint.
+    pub(crate) fn num_gates(&self) -> u64 {
+        let (a, b, c) = (&self.2, &self.3, &self.4);
+        1 + a.num_additions() + b.num_additions() + c.num_additions()
+    }
+
+    /// Returns `true` if the row `(a, b, c)` is contained in the lookup table.
+    pub(crate) fn is_satisfied(&self, tables: &IndexMap<String, LookupTable<F>>) -> bool {
+        let (scope, table_index, a, b, c) = (&self.0, self.1, &self.2, &self.3, &self.4);
+        let a = a.value();
+        let b = b.value();
+        let c = c.value();
+
+        match tables.get_index(table_index) {
+            Some((_, table)) if table.contains(&a, &b, &c) => true,
+            Some((name, _)) => {
+                eprintln!("Failed lookup at {scope}:\n\t({a}, {b}, {c}) is not in table '{name}'");
+                false
+            }
+            None => {
+                eprintln!("Failed lookup at {scope}:\n\tTable {table_index} does not exist");
+                false
+            }
+        }
+    }
+
+    /// Returns the index of the lookup table.
+    pub(crate) fn table_index(&self) -> usize {
+        self.1
+    }
+
+    /// Returns a reference to the terms `(a, b, c)`.
+    pub(crate) fn to_terms(&self) -> (&LinearCombination<F>, &LinearCombination<F>, &LinearCombination<F>) {
+        (&self.2, &self.3, &self.4)
+    }
+}
@@ -99,50 +99,50 @@ impl<F: PrimeField> R1CS<F> {
             }
         }
 
-        // Enforce all of the constraints.
-        for (i, constraint) in self.to_constraints().iter().enumerate() {
-            // Converts terms from one linear combination in the first system to the second system.
-            let convert_linear_combination = |lc: &LinearCombination<F>| -> snarkvm_r1cs::LinearCombination<F> {
-                // Initialize a linear combination for the second system.
-                let mut linear_combination = snarkvm_r1cs::LinearCombination::<F>::zero();
-
-                // Keep an accumulator for constant values in the linear combination.
-                let mut constant_accumulator = lc.to_constant();
-                // Process every term in the linear combination.
-                for (variable, coefficient) in lc.to_terms() {
-                    match variable {
-                        Variable::Constant(value) => {
-                            constant_accumulator += **value;
-                        }
-                        Variable::Public(index, _) => {
-                            let gadget = converter.public.get(index).unwrap();
-                            assert_eq!(
-                                snarkvm_r1cs::Index::Public((index + 1) as usize),
-                                gadget.get_unchecked(),
-                                "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
-                            );
-                            linear_combination += (*coefficient, *gadget);
-                        }
-                        Variable::Private(index, _) => {
-                            let gadget = converter.private.get(index).unwrap();
-                            assert_eq!(
-                                snarkvm_r1cs::Index::Private(*index as usize),
-                                gadget.get_unchecked(),
-                                "Failed during constraint translation. The private variable in the second system must match the first system"
-                            );
-                            linear_combination += (*coefficient, *gadget);
-                        }
+        // Converts terms from one linear combination in the first system to the second system.
+        let convert_linear_combination = |lc: &LinearCombination<F>| -> snarkvm_r1cs::LinearCombination<F> {
+            // Initialize a linear combination for the second system.
+            let mut linear_combination = snarkvm_r1cs::LinearCombination::<F>::zero();
+
+            // Keep an accumulator for constant values in the linear combination.
+            let mut constant_accumulator = lc.to_constant();
+            // Process every term in the linear combination.
+            for (variable, coefficient) in lc.to_terms() {
+                match variable {
+                    Variable::Constant(value) => {
+                        constant_accumulator += **value;
+                    }
+                    Variable::Public(index, _) => {
+                        let gadget = converter.public.get(index).unwrap();
+                        assert_eq!(
+                            snarkvm_r1cs::Index::Public((index + 1) as usize),
+                            gadget.get_unchecked(),
+                            "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
+                        );
+                        linear_combination += (*coefficient, *gadget);
+                    }
+                    Variable::Private(index, _) => {
+                        let gadget = converter.private.get(index).unwrap();
+                        assert_eq!(
+                            snarkvm_r1cs::Index::Private(*index as usize),
+                            gadget.get_unchecked(),
+                            "Failed during constraint translation. The private variable in the second system must match the first system"
+                        );
+                        linear_combination += (*coefficient, *gadget);
                     }
                 }
+            }
 
-                // Finally, add the accumulated constant value to the linear combination.
-                linear_combination +=
-                    (constant_accumulator, snarkvm_r1cs::Variable::new_unchecked(snarkvm_r1cs::Index::Public(0)));
+            // Finally, add the accumulated constant value to the linear combination.
+            linear_combination +=
+                (constant_accumulator, snarkvm_r1cs::Variable::new_unchecked(snarkvm_r1cs::Index::Public(0)));
 
-                // Return the linear combination of the second system.
-                linear_combination
-            };
+            // Return the linear combination of the second system.
+            linear_combination
+        };
 
+        // Enforce all of the constraints.
+        for (i, constraint) in self.to_constraints().iter().enumerate() {
             let (a, b, c) = constraint.to_terms();
 
             cs.enforce(
@@ -153,6 +153,25 @@ impl<F: PrimeField> R1CS<F> {
             );
         }
 
+        // Register all of the lookup tables.
+        for (i, table) in self.to_lookup_tables().values().enumerate() {
+            let index = cs.add_lookup_table(table.clone())?;
+            assert_eq!(i, index, "Lookup tables in the second system must match the first system");
+        }
+
+        // Enforce all of the lookup constraints.
+        for (i, lookup) in self.to_lookups().iter().enumerate() {
+            let (a, b, c) = lookup.to_terms();
+
+            cs.enforce_lookup(
+                || format!("Lookup {i}"),
+                lookup.table_index(),
+                |lc| lc + convert_linear_combination(a),
+                |lc| lc + convert_linear_combination(b),
+                |lc| lc + convert_linear_combination(c),
+            )?;
+        }
+
         // Ensure the given `cs` matches in size with the first system.
         assert_eq!(self.num_public() + 1, cs.num_public_variables() as u64);
         assert_eq!(self.num_private(), cs.num_private_variables() as u64);
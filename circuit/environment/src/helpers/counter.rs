@@ -14,18 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::*;
+use crate::{prelude::*, *};
 use snarkvm_fields::PrimeField;
 
 #[derive(Debug, Default)]
 pub(crate) struct Counter<F: PrimeField> {
     scope: Scope,
     constraints: Vec<Constraint<F>>,
+    lookups: Vec<LookupConstraint<F>>,
     constants: u64,
     public: u64,
     private: u64,
     gates: u64,
-    parents: Vec<(Scope, Vec<Constraint<F>>, u64, u64, u64, u64)>,
+    parents: Vec<(Scope, Vec<Constraint<F>>, Vec<LookupConstraint<F>>, u64, u64, u64, u64)>,
 }
 
 impl<F: PrimeField> Counter<F> {
@@ -41,19 +42,16 @@ impl<F: PrimeField> Counter<F> {
                     false => format!("{}.{}", self.scope, name),
                 };
 
-                // Save the current scope members.
+                // Save the current scope members, and initialize the new scope members.
                 self.parents.push((
-                    self.scope.clone(),
-                    self.constraints.clone(),
+                    core::mem::replace(&mut self.scope, scope),
+                    core::mem::take(&mut self.constraints),
+                    core::mem::take(&mut self.lookups),
                     self.constants,
                     self.public,
                     self.private,
                     self.gates,
                 ));
-
-                // Initialize the new scope members.
-                self.scope = scope;
-                self.constraints = Default::default();
                 self.constants = 0;
                 self.public = 0;
                 self.private = 0;
@@ -75,9 +73,10 @@ impl<F: PrimeField> Counter<F> {
         // Ensure the current scope is the last pushed scope.
         match current_scope == name.into() {
             true => {
-                if let Some((scope, constraints, constants, public, private, gates)) = self.parents.pop() {
+                if let Some((scope, constraints, lookups, constants, public, private, gates)) = self.parents.pop() {
                     self.scope = scope;
                     self.constraints = constraints;
+                    self.lookups = lookups;
                     self.constants = constants;
                     self.public = public;
                     self.private = private;
@@ -98,9 +97,16 @@ impl<F: PrimeField> Counter<F> {
         self.constraints.push(constraint);
     }
 
-    /// Returns `true` if all constraints in the scope are satisfied.
-    pub(crate) fn is_satisfied_in_scope(&self) -> bool {
+    /// Increments the number of constraints by 1, for the given lookup constraint.
+    pub(crate) fn add_lookup(&mut self, lookup: LookupConstraint<F>) {
+        self.gates += lookup.num_gates();
+        self.lookups.push(lookup);
+    }
+
+    /// Returns `true` if all constraints and lookups in the scope are satisfied.
+    pub(crate) fn is_satisfied_in_scope(&self, tables: &IndexMap<String, LookupTable<F>>) -> bool {
         self.constraints.iter().all(|constraint| constraint.is_satisfied())
+            && self.lookups.iter().all(|lookup| lookup.is_satisfied(tables))
     }
 
     /// Returns the current scope.
@@ -140,7 +146,7 @@ impl<F: PrimeField> Counter<F> {
 
     /// Returns the number of constraints in scope.
     pub(crate) fn num_constraints_in_scope(&self) -> u64 {
-        self.constraints.len() as u64
+        (self.constraints.len() + self.lookups.len()) as u64
     }
 
     /// Returns the number of gates in scope.
@@ -42,3 +42,9 @@ pub use variable::*;
 
 pub(super) mod r1cs;
 pub use r1cs::*;
+
+mod session;
+pub use session::Session;
+
+mod unsatisfied;
+pub use unsatisfied::UnsatisfiedConstraint;
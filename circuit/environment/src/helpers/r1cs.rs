@@ -15,8 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    helpers::{Constraint, Counter},
+    helpers::{Constraint, Counter, LookupConstraint},
     prelude::*,
+    UnsatisfiedConstraint,
 };
 use snarkvm_fields::PrimeField;
 
@@ -30,6 +31,8 @@ pub struct R1CS<F: PrimeField> {
     public: Vec<Variable<F>>,
     private: Vec<Variable<F>>,
     constraints: Vec<Constraint<F>>,
+    lookup_tables: IndexMap<String, LookupTable<F>>,
+    lookups: Vec<LookupConstraint<F>>,
     counter: Counter<F>,
     gates: u64,
 }
@@ -42,6 +45,8 @@ impl<F: PrimeField> R1CS<F> {
             public: vec![Variable::Public(0u64, Rc::new(F::one()))],
             private: Default::default(),
             constraints: Default::default(),
+            lookup_tables: Default::default(),
+            lookups: Default::default(),
             counter: Default::default(),
             gates: 0,
         }
@@ -88,14 +93,58 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.add_constraint(constraint);
     }
 
+    /// Registers the lookup table with the given name, and returns its index.
+    /// If a table with the given name already exists, the existing index is returned.
+    pub(crate) fn add_lookup_table<S: Into<String>, Fn: FnOnce() -> LookupTable<F>>(
+        &mut self,
+        name: S,
+        table: Fn,
+    ) -> usize {
+        let entry = self.lookup_tables.entry(name.into());
+        let index = entry.index();
+        entry.or_insert_with(table);
+        index
+    }
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row in the lookup table.
+    pub(crate) fn enforce_lookup(&mut self, lookup: LookupConstraint<F>) {
+        self.gates += lookup.num_gates();
+        self.lookups.push(lookup.clone());
+        self.counter.add_lookup(lookup);
+    }
+
+    /// Returns the lookup table at the given index, if it exists.
+    pub(crate) fn get_lookup_table(&self, index: usize) -> Option<&LookupTable<F>> {
+        self.lookup_tables.get_index(index).map(|(_, table)| table)
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     pub(crate) fn is_satisfied(&self) -> bool {
         self.constraints.iter().all(|constraint| constraint.is_satisfied())
+            && self.lookups.iter().all(|lookup| lookup.is_satisfied(&self.lookup_tables))
+    }
+
+    /// Returns an iterator over the unsatisfied constraints in the environment, followed by the unsatisfied lookups.
+    pub(crate) fn unsatisfied_constraints(&self) -> impl Iterator<Item = UnsatisfiedConstraint<F>> + '_ {
+        let constraints = self.constraints.iter().enumerate().filter_map(|(index, Constraint(scope, a, b, c))| {
+            let (a, b, c) = (a.value(), b.value(), c.value());
+            (a * b != c).then(|| UnsatisfiedConstraint::constraint(index, scope.clone(), (a, b, c)))
+        });
+        let lookups = self.lookups.iter().enumerate().filter_map(|(index, lookup)| {
+            let LookupConstraint(scope, table_index, a, b, c) = lookup;
+            let (a, b, c) = (a.value(), b.value(), c.value());
+            match self.lookup_tables.get_index(*table_index) {
+                Some((_, table)) if table.contains(&a, &b, &c) => None,
+                Some((name, _)) => Some(UnsatisfiedConstraint::lookup(index, scope.clone(), name.clone(), (a, b, c))),
+                None => Some(UnsatisfiedConstraint::lookup(index, scope.clone(), table_index.to_string(), (a, b, c))),
+            }
+        });
+        constraints.chain(lookups)
     }
 
     /// Returns `true` if all constraints in the current scope are satisfied.
     pub(crate) fn is_satisfied_in_scope(&self) -> bool {
-        self.counter.is_satisfied_in_scope()
+        self.counter.is_satisfied_in_scope(&self.lookup_tables)
     }
 
     /// Returns the current scope.
@@ -118,9 +167,14 @@ impl<F: PrimeField> R1CS<F> {
         self.private.len() as u64
     }
 
-    /// Returns the number of constraints in the constraint system.
+    /// Returns the number of constraints (including lookups) in the constraint system.
     pub(crate) fn num_constraints(&self) -> u64 {
-        self.constraints.len() as u64
+        (self.constraints.len() + self.lookups.len()) as u64
+    }
+
+    /// Returns the number of lookup constraints in the constraint system.
+    pub(crate) fn num_lookups(&self) -> u64 {
+        self.lookups.len() as u64
     }
 
     /// Returns the number of gates in the constraint system.
@@ -167,6 +221,16 @@ impl<F: PrimeField> R1CS<F> {
     pub(crate) fn to_constraints(&self) -> &Vec<Constraint<F>> {
         &self.constraints
     }
+
+    /// Returns the lookup tables in the constraint system.
+    pub(crate) fn to_lookup_tables(&self) -> &IndexMap<String, LookupTable<F>> {
+        &self.lookup_tables
+    }
+
+    /// Returns the lookup constraints in the constraint system.
+    pub(crate) fn to_lookups(&self) -> &Vec<LookupConstraint<F>> {
+        &self.lookups
+    }
 }
 
 impl<F: PrimeField> Display for R1CS<F> {
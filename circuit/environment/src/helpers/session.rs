@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assignment, R1CS};
+use snarkvm_fields::PrimeField;
+
+/// An isolated circuit environment, which owns its own R1CS instance and witness mode.
+///
+/// A session is activated with `Environment::enter`, which makes it the current environment
+/// for the duration of the given logic, and restores the enclosing environment afterwards.
+/// This allows a circuit to be synthesized without resetting (or contaminating) any synthesis
+/// that is already in progress, such as the caller of a `call` instruction.
+#[derive(Debug)]
+pub struct Session<F: PrimeField> {
+    /// The R1CS instance of the session.
+    pub(crate) r1cs: R1CS<F>,
+    /// A flag indicating if the session is in witness mode.
+    pub(crate) in_witness: bool,
+}
+
+impl<F: PrimeField> Session<F> {
+    /// Initializes a new session with an empty R1CS instance.
+    pub fn new() -> Self {
+        Self { r1cs: R1CS::new(), in_witness: false }
+    }
+
+    /// Returns the R1CS instance of the session.
+    pub fn r1cs(&self) -> &R1CS<F> {
+        &self.r1cs
+    }
+
+    /// Returns the R1CS instance of the session, consuming the session.
+    pub fn into_r1cs(self) -> R1CS<F> {
+        self.r1cs
+    }
+
+    /// Returns the R1CS assignment of the session, consuming the session.
+    pub fn into_assignment(self) -> Assignment<F> {
+        Assignment::from(self.r1cs)
+    }
+}
+
+impl<F: PrimeField> Default for Session<F> {
+    /// Initializes a new session with an empty R1CS instance.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PrimeField> From<R1CS<F>> for Session<F> {
+    /// Initializes a new session from the given R1CS instance.
+    fn from(r1cs: R1CS<F>) -> Self {
+        Self { r1cs, in_witness: false }
+    }
+}
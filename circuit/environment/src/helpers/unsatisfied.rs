@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Scope;
+use snarkvm_fields::PrimeField;
+
+use core::fmt;
+
+/// A report of a constraint that is not satisfied by the current assignment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsatisfiedConstraint<F: PrimeField> {
+    /// The position of the constraint, among the constraints (or lookups) of the circuit.
+    index: usize,
+    /// The scope path in which the constraint was enforced.
+    scope: Scope,
+    /// The name of the lookup table, if the constraint is a lookup.
+    table: Option<String>,
+    /// The values of the linear combinations `(a, b, c)`.
+    values: (F, F, F),
+}
+
+impl<F: PrimeField> UnsatisfiedConstraint<F> {
+    /// Initializes a new report for an unsatisfied constraint `(a * b) == c`.
+    pub(crate) fn constraint(index: usize, scope: Scope, values: (F, F, F)) -> Self {
+        Self { index, scope, table: None, values }
+    }
+
+    /// Initializes a new report for an unsatisfied lookup of `(a, b, c)` in the given table.
+    pub(crate) fn lookup(index: usize, scope: Scope, table: String, values: (F, F, F)) -> Self {
+        Self { index, scope, table: Some(table), values }
+    }
+
+    /// Returns the position of the constraint, among the constraints (or lookups) of the circuit.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the scope path in which the constraint was enforced.
+    pub fn scope(&self) -> &Scope {
+        &self.scope
+    }
+
+    /// Returns the innermost scope in which the constraint was enforced, i.e. the originating gadget.
+    pub fn gadget(&self) -> &str {
+        self.scope.rsplit('.').next().unwrap_or_default()
+    }
+
+    /// Returns the name of the lookup table, if the constraint is a lookup.
+    pub fn table(&self) -> Option<&str> {
+        self.table.as_deref()
+    }
+
+    /// Returns the values of the linear combinations `(a, b, c)`.
+    pub fn values(&self) -> (F, F, F) {
+        self.values
+    }
+}
+
+impl<F: PrimeField> fmt::Display for UnsatisfiedConstraint<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (a, b, c) = &self.values;
+        let scope = match self.scope.is_empty() {
+            true => "the root scope".to_string(),
+            false => format!("'{}'", self.scope),
+        };
+        match &self.table {
+            None => write!(f, "Constraint {} in {scope} is not satisfied: ({a} * {b}) != {c}", self.index),
+            Some(table) => write!(
+                f,
+                "Lookup {} in {scope} is not satisfied: ({a}, {b}, {c}) is not in table '{table}'",
+                self.index
+            ),
+        }
+    }
+}
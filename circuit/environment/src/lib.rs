@@ -82,6 +82,7 @@ pub mod prelude {
         TypeName,
     };
     pub use snarkvm_fields::{Field as _, PrimeField, Zero as _};
+    pub use snarkvm_r1cs::LookupTable;
     pub use snarkvm_utilities::ToBits as _;
 
     #[cfg(debug_assertions)]
@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+
+aleo_environment!(
+    /// The circuit environment for the local development network.
+    AleoDevnet,
+    console::Devnet,
+    console::devnet
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AleoV0;
+
+    type CurrentAleo = AleoDevnet;
+
+    #[test]
+    fn test_generator() {
+        let expected = <console::Devnet as console::Network>::g_powers()[0];
+        let candidate = CurrentAleo::g_scalar_multiply(&Scalar::one()).eject_value();
+        assert_eq!(expected, candidate);
+
+        // Ensure the devnet does not share its generator with testnet3.
+        let testnet3 = AleoV0::g_scalar_multiply(&Scalar::one()).eject_value();
+        assert_ne!(candidate.to_string(), testnet3.to_string());
+    }
+}
@@ -17,6 +17,12 @@
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 
+#[macro_use]
+mod macros;
+
+pub mod devnet;
+pub use devnet::*;
+
 pub mod v0;
 pub use v0::*;
 
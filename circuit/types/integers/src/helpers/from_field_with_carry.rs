@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Casts an integer from the lower bits of a base field element, discarding a carry of up to `num_carry_bits`.
+    /// Rather than decomposing the carry into bits, the carry is split into bytes,
+    /// and each byte is range checked with a single lookup into the `u8` table.
+    pub(crate) fn from_field_with_carry(field: &Field<E>, num_carry_bits: u64) -> Self {
+        // Determine the number of bytes in the carry.
+        let num_carry_bytes = num_carry_bits.div_ceil(8);
+
+        // Ensure the integer and its carry fit within a single base field element.
+        if I::BITS + 8 * num_carry_bytes >= E::BaseField::size_in_data_bits() as u64 {
+            E::halt(format!("Attempted to extract a {}-bit integer with a {num_carry_bits}-bit carry", I::BITS))
+        }
+
+        // If the field element is a constant, the carry is checked on its bits.
+        if field.is_constant() {
+            let mut bits_le = field.to_lower_bits_le((I::BITS + num_carry_bits) as usize);
+            bits_le.truncate(I::BITS as usize);
+            return Integer { bits_le, phantom: Default::default() };
+        }
+
+        // Construct a vector of `Boolean`s comprising the integer bits of the field value.
+        let bits_le = witness!(|field| field.to_bits_le().into_iter().take(I::BITS as usize).collect::<Vec<_>>());
+
+        // Construct a vector of `Field`s comprising the carry bytes of the field value.
+        let carry_bytes: Vec<Field<E>> = witness!(|field| {
+            field.to_bits_le()[I::BITS as usize..]
+                .chunks(8)
+                .take(num_carry_bytes as usize)
+                .map(|byte| console::Field::from_u8(byte.iter().rev().fold(0u8, |acc, bit| (acc << 1) | *bit as u8)))
+                .collect::<Vec<_>>()
+        });
+
+        // Ensure each carry byte is in `[0, 256)`.
+        let u8_table = E::add_lookup_table("u8", || {
+            (0..=u8::MAX).map(|byte| (byte.into(), E::BaseField::zero(), E::BaseField::zero())).collect()
+        });
+        for byte in &carry_bytes {
+            E::enforce_lookup(u8_table, || (byte, E::zero(), E::zero()));
+        }
+
+        // Reconstruct the bits and the carry bytes as a linear combination representing the original field value.
+        let mut accumulator = Field::zero();
+        let mut coefficient = Field::one();
+        for bit in &bits_le {
+            accumulator += Field::from_boolean(bit) * &coefficient;
+            coefficient = coefficient.double();
+        }
+        for byte in &carry_bytes {
+            accumulator += byte * &coefficient;
+            for _ in 0..8 {
+                coefficient = coefficient.double();
+            }
+        }
+
+        // Ensure value * 1 == (2^(k + 8j) * c_j + ... + 2^k * c_0) + (2^(k-1) * b_{k-1} + ... + 2^0 * b_0).
+        // As the field value is less than the modulus, this ensures the decomposition is unique.
+        E::assert_eq(field, accumulator);
+
+        Integer { bits_le, phantom: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_from_field_with_carry<I: IntegerType>(mode: Mode, rng: &mut TestRng) {
+        let num_carry_bits = I::BITS / 2 + 1;
+        let num_carry_bytes = num_carry_bits.div_ceil(8);
+        // Compute 2^I::BITS, the position of the carry.
+        let one = console::Field::<<Circuit as Environment>::Network>::one();
+        let shift = (0..I::BITS).fold(one, |shift, _| shift.double());
+
+        for i in 0..ITERATIONS {
+            // Sample a random integer and carry.
+            let expected = Uniform::rand(rng);
+            let carry: u8 = Uniform::rand(rng);
+            let carry = console::Field::from_u8(carry >> 8u64.saturating_sub(num_carry_bits)) * shift;
+            let candidate = Integer::<Circuit, I>::new(mode, expected).to_field() + Field::new(mode, carry);
+
+            Circuit::scope(format!("{mode} {expected} {i}"), || {
+                // Perform the operation.
+                let candidate = Integer::<Circuit, I>::from_field_with_carry(&candidate, num_carry_bits);
+                assert_eq!(expected, candidate.eject_value());
+                match mode {
+                    Mode::Constant => assert_scope!(I::BITS + num_carry_bits, 0, 0, 0),
+                    _ => assert_scope!(0, 0, I::BITS + num_carry_bytes, I::BITS + num_carry_bytes + 1),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_u8_from_field_with_carry() {
+        let mut rng = TestRng::default();
+
+        type I = u8;
+        check_from_field_with_carry::<I>(Mode::Constant, &mut rng);
+        check_from_field_with_carry::<I>(Mode::Public, &mut rng);
+        check_from_field_with_carry::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_i16_from_field_with_carry() {
+        let mut rng = TestRng::default();
+
+        type I = i16;
+        check_from_field_with_carry::<I>(Mode::Constant, &mut rng);
+        check_from_field_with_carry::<I>(Mode::Public, &mut rng);
+        check_from_field_with_carry::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_u64_from_field_with_carry() {
+        let mut rng = TestRng::default();
+
+        type I = u64;
+        check_from_field_with_carry::<I>(Mode::Constant, &mut rng);
+        check_from_field_with_carry::<I>(Mode::Public, &mut rng);
+        check_from_field_with_carry::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_u128_from_field_with_carry() {
+        let mut rng = TestRng::default();
+
+        type I = u128;
+        check_from_field_with_carry::<I>(Mode::Constant, &mut rng);
+        check_from_field_with_carry::<I>(Mode::Public, &mut rng);
+        check_from_field_with_carry::<I>(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_from_field_with_carry_rejects_large_carry() {
+        // A carry of 2^8 does not fit in a single byte.
+        let value = console::Field::<<Circuit as Environment>::Network>::from_u32(1 << 16);
+        let candidate = Field::<Circuit>::new(Mode::Private, value);
+        let _ = Integer::<Circuit, u8>::from_field_with_carry(&candidate, 5);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
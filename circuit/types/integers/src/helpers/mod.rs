@@ -18,6 +18,7 @@ use super::*;
 
 pub mod from_bits;
 pub mod from_field;
+pub mod from_field_with_carry;
 pub mod msb;
 pub mod one;
 pub mod to_bits;
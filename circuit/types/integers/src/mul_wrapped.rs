@@ -45,13 +45,9 @@ impl<E: Environment, I: IntegerType> MulWrapped<Self> for Integer<E, I> {
             let b_m = Field::from_bits_le(&b_m_bits);
             let z_0_plus_z_1 = &z_0 + (&z_1 * &b_m);
 
-            let mut bits_le = z_0_plus_z_1.to_lower_bits_le(I::BITS as usize + I::BITS as usize / 2 + 1);
-
-            // Remove any carry bits.
-            bits_le.truncate(I::BITS as usize);
-
             // Return the product of `self` and `other`, without the carry bits.
-            Integer { bits_le, phantom: Default::default() }
+            // The carry bits are range checked with lookups, as they are discarded.
+            Self::from_field_with_carry(&z_0_plus_z_1, I::BITS / 2 + 1)
         }
     }
 }
@@ -60,12 +56,15 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulWrapped<Integer<E, I>, Outpu
     type Case = (Mode, Mode);
 
     fn count(case: &Self::Case) -> Count {
+        // The carry of `I::BITS / 2 + 1` bits is range checked with one lookup per byte.
+        let num_carry_bytes = (I::BITS / 2 + 1).div_ceil(8);
+
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
             (Mode::Constant, _) | (_, Mode::Constant) => {
-                Count::is(0, 0, I::BITS + (I::BITS / 2) + 1, I::BITS + (I::BITS / 2) + 2)
+                Count::is(0, 0, I::BITS + num_carry_bytes, I::BITS + num_carry_bytes + 1)
             }
-            (_, _) => Count::is(0, 0, I::BITS + (I::BITS / 2) + 4, I::BITS + (I::BITS / 2) + 5),
+            (_, _) => Count::is(0, 0, I::BITS + num_carry_bytes + 3, I::BITS + num_carry_bytes + 4),
         }
     }
 }
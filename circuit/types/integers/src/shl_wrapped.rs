@@ -79,20 +79,23 @@ impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn ShlWrapped<Intege
             None => E::halt(format!("Integer of {num_bits} bits is not supported")),
         };
 
+        // The number of carry bytes range checked by the multiplication.
+        let num_carry_bytes = (I::BITS / 2 + 1).div_ceil(8);
+
         match (case.0, case.1) {
             (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
             (_, Mode::Constant) => Count::is(0, 0, 0, 0),
             (Mode::Constant, _) => Count::is(
                 0,
                 0,
-                (2 * I::BITS) + (I::BITS / 2) + (2 * index(I::BITS)) + 5,
-                (2 * I::BITS) + (I::BITS / 2) + (2 * index(I::BITS)) + 7,
+                (2 * I::BITS) + num_carry_bytes + (2 * index(I::BITS)) + 4,
+                (2 * I::BITS) + num_carry_bytes + (2 * index(I::BITS)) + 6,
             ),
             (_, _) => Count::is(
                 0,
                 0,
-                (2 * I::BITS) + (I::BITS / 2) + (2 * index(I::BITS)) + 8,
-                (2 * I::BITS) + (I::BITS / 2) + (2 * index(I::BITS)) + 10,
+                (2 * I::BITS) + num_carry_bytes + (2 * index(I::BITS)) + 7,
+                (2 * I::BITS) + num_carry_bytes + (2 * index(I::BITS)) + 9,
             ),
         }
     }
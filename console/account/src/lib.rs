@@ -33,6 +33,16 @@ pub mod graph_key;
 #[cfg(feature = "graph_key")]
 pub use graph_key::*;
 
+#[cfg(feature = "message")]
+pub mod message;
+#[cfg(feature = "message")]
+pub use message::*;
+
+#[cfg(feature = "multisig")]
+pub mod multisig;
+#[cfg(feature = "multisig")]
+pub use multisig::*;
+
 #[cfg(feature = "private_key")]
 pub mod private_key;
 #[cfg(feature = "private_key")]
@@ -48,6 +58,11 @@ pub mod view_key;
 #[cfg(feature = "view_key")]
 pub use view_key::*;
 
+#[cfg(feature = "watch_key")]
+pub mod watch_key;
+#[cfg(feature = "watch_key")]
+pub use watch_key::*;
+
 // #[cfg(test)]
 // mod tests {
 //     use crate::{testnet2::Testnet2, Account, Address, Network, PrivateKey, ViewKey};
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for Message<N> {
+    /// Reads the message from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the network ID.
+        let network_id = u16::read_le(&mut reader)?;
+        // Ensure the network ID matches.
+        if network_id != N::ID {
+            return Err(error(format!("Invalid network ID for the message: found {network_id}, expected {}", N::ID)));
+        }
+        // Read the payload.
+        let payload_length = u32::read_le(&mut reader)? as usize;
+        // Ensure the payload length is within bounds, before allocating the payload.
+        if payload_length > Self::MAX_PAYLOAD_SIZE {
+            return Err(error(format!("Message payload exceeds {} bytes", Self::MAX_PAYLOAD_SIZE)));
+        }
+        let mut payload = vec![0u8; payload_length];
+        reader.read_exact(&mut payload)?;
+        // Return the message.
+        Self::new(&payload).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for Message<N> {
+    /// Writes the message to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the network ID.
+        self.network_id().write_le(&mut writer)?;
+        // Ensure the payload length is within bounds.
+        if self.payload.len() > Self::MAX_PAYLOAD_SIZE {
+            return Err(error(format!("Message payload exceeds {} bytes", Self::MAX_PAYLOAD_SIZE)));
+        }
+        // Write the payload.
+        u32::try_from(self.payload.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        writer.write_all(&self.payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new message.
+            let payload: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let expected = Message::<CurrentNetwork>::new(&payload)?;
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Message::read_le(&expected_bytes[..])?);
+            assert!(Message::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_exceeds_max_payload_size() {
+        // Check that an oversized payload length is rejected before the payload is read.
+        let mut bytes = CurrentNetwork::ID.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(Message::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+    }
+}
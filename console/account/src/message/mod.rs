@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod sign;
+mod verify;
+
+use crate::{Address, PrivateKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use core::marker::PhantomData;
+
+/// The domain tag of a signed message.
+const MESSAGE_DOMAIN: &str = "AleoSignedMessage0";
+
+/// A domain-separated message envelope, for signing arbitrary payloads off-chain.
+/// The signed fields of the envelope are `(domain, network ID, payload length, Hash(payload))`,
+/// which ensures a message signature is never valid as a transaction signature or on another network.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Message<N: Network> {
+    /// The message payload.
+    payload: Vec<u8>,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> Message<N> {
+    /// The maximum number of bytes in a serialized message payload.
+    pub const MAX_PAYLOAD_SIZE: usize = 1 << 20;
+
+    /// Initializes a new message from the given payload.
+    pub fn new(payload: &[u8]) -> Result<Self> {
+        // Ensure the payload is within bounds, so the message can be serialized and verified by others.
+        ensure!(payload.len() <= Self::MAX_PAYLOAD_SIZE, "Message payload exceeds {} bytes", Self::MAX_PAYLOAD_SIZE);
+        Ok(Self { payload: payload.to_vec(), _phantom: PhantomData })
+    }
+
+    /// Returns the network ID of the message.
+    pub const fn network_id(&self) -> u16 {
+        N::ID
+    }
+
+    /// Returns the message payload.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Returns the field elements of the envelope, as `(domain, network ID, payload length, Hash(payload))`.
+    pub fn to_fields(&self) -> Result<Vec<Field<N>>> {
+        // Pack the payload bits into field elements.
+        let payload = self
+            .payload
+            .to_bits_le()
+            .chunks(Field::<N>::size_in_data_bits())
+            .map(Field::from_bits_le)
+            .collect::<Result<Vec<_>>>()?;
+        // Hash the payload, as the payload may exceed the maximum size of a signed message.
+        let payload_hash = N::hash_psd8(&payload)?;
+        // Construct the envelope.
+        Ok(vec![
+            Field::new_domain_separator(MESSAGE_DOMAIN),
+            Field::from_u16(self.network_id()),
+            Field::from_u64(self.payload.len() as u64),
+            payload_hash,
+        ])
+    }
+}
+
+impl<N: Network> TryFrom<&str> for Message<N> {
+    type Error = Error;
+
+    /// Initializes a new message from the given string.
+    fn try_from(payload: &str) -> Result<Self> {
+        Self::new(payload.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_fields() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..100 {
+            // Sample a random payload.
+            let payload: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let message = Message::<CurrentNetwork>::new(&payload)?;
+
+            // Check the envelope.
+            let fields = message.to_fields()?;
+            assert_eq!(4, fields.len());
+            assert_eq!(Field::new_domain_separator(MESSAGE_DOMAIN), fields[0]);
+            assert_eq!(Field::from_u16(CurrentNetwork::ID), fields[1]);
+            assert_eq!(Field::from_u64(i), fields[2]);
+
+            // Check that a trailing zero byte changes the envelope.
+            let mut padded = payload.clone();
+            padded.push(0u8);
+            assert_ne!(fields, Message::<CurrentNetwork>::new(&padded)?.to_fields()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_exceeds_max_payload_size() {
+        let max_size = Message::<CurrentNetwork>::MAX_PAYLOAD_SIZE;
+        assert!(Message::<CurrentNetwork>::new(&vec![0u8; max_size]).is_ok());
+        assert!(Message::<CurrentNetwork>::new(&vec![0u8; max_size + 1]).is_err());
+    }
+}
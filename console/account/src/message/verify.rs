@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Message<N> {
+    /// Returns `true` if the signature is valid for the message envelope and the given address.
+    pub fn verify(&self, signature: &Signature<N>, address: &Address<N>) -> bool {
+        match self.to_fields() {
+            Ok(fields) => signature.verify(address, &fields),
+            Err(error) => {
+                eprintln!("Failed to verify the message signature: {error}");
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the signature is valid for the message.
+            let payload: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let message = Message::<CurrentNetwork>::new(&payload)?;
+            let signature = message.sign(&private_key, rng)?;
+            assert!(message.verify(&signature, &address));
+
+            // Check that the signature is invalid for an incorrect message.
+            let failure_payload: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            if payload != failure_payload {
+                assert!(!Message::<CurrentNetwork>::new(&failure_payload)?.verify(&signature, &address));
+            }
+
+            // Check that the signature is invalid for an incorrect address.
+            let failure_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!message.verify(&signature, &failure_address));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_and_verify_string() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an address and a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Check that the signature is valid for the string message.
+        let message = Message::<CurrentNetwork>::try_from("I am the owner of this address.")?;
+        let signature = message.sign(&private_key, rng)?;
+        assert!(message.verify(&signature, &address));
+
+        // Check that the signature survives a round trip through its string representation.
+        assert!(message.verify(&Signature::from_str(&signature.to_string())?, &address));
+        Ok(())
+    }
+}
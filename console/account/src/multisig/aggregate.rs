@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The maximum number of signers in an aggregate key.
+const MAX_SIGNERS: usize = u16::MAX as usize;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregateKey<N: Network> {
+    /// The compute keys of the signers, in signing order.
+    compute_keys: Vec<ComputeKey<N>>,
+    /// The key aggregation coefficients `a_i` of the signers.
+    coefficients: Vec<Scalar<N>>,
+    /// The aggregate compute key.
+    compute_key: ComputeKey<N>,
+}
+
+impl<N: Network> AggregateKey<N> {
+    /// Returns the aggregate key for the given compute keys, where:
+    ///     a_i := HashToScalar(domain, pk_sig_1, pr_sig_1, ..., pk_sig_n, pr_sig_n, pk_sig_i, pr_sig_i)
+    ///     pk_sig := Σ a_i * pk_sig_i
+    ///     pr_sig := Σ a_i * pr_sig_i
+    pub fn new(compute_keys: &[ComputeKey<N>]) -> Result<Self> {
+        // Ensure there is at least one signer.
+        ensure!(!compute_keys.is_empty(), "Cannot aggregate an empty list of compute keys");
+        // Ensure the number of signers does not exceed the maximum.
+        ensure!(compute_keys.len() <= MAX_SIGNERS, "Cannot aggregate more than {MAX_SIGNERS} compute keys");
+        // Ensure there are no duplicate signers.
+        ensure!(!has_duplicates(compute_keys.iter()), "Cannot aggregate duplicate compute keys");
+
+        // Construct the common preimage as (domain, pk_sig_1, pr_sig_1, ..., pk_sig_n, pr_sig_n).
+        let mut preimage = Vec::with_capacity(3 + 2 * compute_keys.len());
+        preimage.push(Field::new_domain_separator("AleoMultiSigKeyAggregation0"));
+        preimage.extend(compute_keys.iter().flat_map(|key| [key.pk_sig(), key.pr_sig()].map(|g| g.to_x_coordinate())));
+
+        // Compute the key aggregation coefficient for each signer.
+        let coefficients = compute_keys
+            .iter()
+            .map(|key| {
+                let mut input = preimage.clone();
+                input.extend([key.pk_sig(), key.pr_sig()].map(|point| point.to_x_coordinate()));
+                N::hash_to_scalar_psd8(&input)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the aggregate pk_sig and pr_sig.
+        let (pk_sig, pr_sig) = compute_keys.iter().zip_eq(&coefficients).fold(
+            (Group::zero(), Group::zero()),
+            |(pk_sig, pr_sig), (key, coefficient)| {
+                (pk_sig + key.pk_sig() * *coefficient, pr_sig + key.pr_sig() * *coefficient)
+            },
+        );
+
+        // Derive the aggregate compute key.
+        let compute_key = ComputeKey::try_from((pk_sig, pr_sig))?;
+        Ok(Self { compute_keys: compute_keys.to_vec(), coefficients, compute_key })
+    }
+
+    /// Returns the compute keys of the signers.
+    pub fn compute_keys(&self) -> &[ComputeKey<N>] {
+        &self.compute_keys
+    }
+
+    /// Returns the number of signers.
+    pub fn num_signers(&self) -> usize {
+        self.compute_keys.len()
+    }
+
+    /// Returns the index of the given signer compute key, if it is part of the aggregate key.
+    pub fn index_of(&self, compute_key: &ComputeKey<N>) -> Option<u16> {
+        self.compute_keys.iter().position(|key| key == compute_key).and_then(|index| u16::try_from(index).ok())
+    }
+
+    /// Returns the key aggregation coefficient of the signer at the given index.
+    pub fn coefficient(&self, index: u16) -> Result<Scalar<N>> {
+        match self.coefficients.get(index as usize) {
+            Some(coefficient) => Ok(*coefficient),
+            None => bail!("Signer index {index} is out of bounds for the aggregate key"),
+        }
+    }
+
+    /// Returns the aggregate compute key.
+    pub const fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key
+    }
+
+    /// Returns the address jointly controlled by the signers.
+    pub fn to_address(&self) -> Address<N> {
+        self.compute_key.to_address()
+    }
+
+    /// Returns the aggregate nonce and the verifier challenge for the given public nonces and message, where:
+    ///     g_r := Σ g_r_i
+    ///     challenge := HashToScalar(g_r, pk_sig, pr_sig, address, message)
+    pub(super) fn challenge(
+        &self,
+        public_nonces: &[PublicNonce<N>],
+        message: &[Field<N>],
+    ) -> Result<(Group<N>, Scalar<N>)> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Ensure there is one public nonce per signer.
+        ensure!(public_nonces.len() == self.num_signers(), "Expected one public nonce per signer");
+
+        // Compute the aggregate nonce `g_r`.
+        let g_r = public_nonces.iter().map(|nonce| nonce.g_r()).sum::<Group<N>>();
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend(
+            [g_r, self.compute_key.pk_sig(), self.compute_key.pr_sig(), *self.to_address()]
+                .map(|point| point.to_x_coordinate()),
+        );
+        preimage.extend(message);
+
+        // Compute the verifier challenge.
+        Ok((g_r, N::hash_to_scalar_psd8(&preimage)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_signers in 1..ITERATIONS {
+            let (private_keys, aggregate_key) = test_helpers::sample_signers(num_signers, &mut rng);
+            assert_eq!(num_signers, aggregate_key.num_signers());
+
+            // Check that every signer is found at its index.
+            for (index, private_key) in private_keys.iter().enumerate() {
+                let compute_key = ComputeKey::try_from(private_key)?;
+                assert_eq!(Some(u16::try_from(index)?), aggregate_key.index_of(&compute_key));
+            }
+
+            // Check that the aggregate address is distinct from every signer address.
+            for private_key in &private_keys {
+                assert_ne!(Address::try_from(private_key)?, aggregate_key.to_address());
+            }
+
+            // Check that the aggregation is deterministic.
+            assert_eq!(aggregate_key, AggregateKey::new(aggregate_key.compute_keys())?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_fails() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Check that an empty list of compute keys fails.
+        assert!(AggregateKey::<CurrentNetwork>::new(&[]).is_err());
+
+        // Check that duplicate compute keys fail.
+        let compute_key = ComputeKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        assert!(AggregateKey::new(&[compute_key, compute_key]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_signer_order() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Check that the aggregate address depends on the order of the signers.
+        let (_, aggregate_key) = test_helpers::sample_signers(3, &mut rng);
+        let mut compute_keys = aggregate_key.compute_keys().to_vec();
+        compute_keys.reverse();
+        assert_ne!(aggregate_key.to_address(), AggregateKey::new(&compute_keys)?.to_address());
+        Ok(())
+    }
+}
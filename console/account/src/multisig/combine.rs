@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> AggregateKey<N> {
+    /// Returns the signature `(challenge, response, compute_key)` for the aggregate address, where:
+    ///     challenge := HashToScalar(Σ g_r_i, pk_sig, pr_sig, address, message)
+    ///     response := Σ response_i
+    ///
+    /// The resulting signature is verified with `Signature::verify` against `AggregateKey::to_address`.
+    pub fn combine(
+        &self,
+        partial_signatures: &[PartialSignature<N>],
+        public_nonces: &[PublicNonce<N>],
+        message: &[Field<N>],
+    ) -> Result<Signature<N>> {
+        // Ensure there is one partial signature per signer.
+        ensure!(partial_signatures.len() == self.num_signers(), "Expected one partial signature per signer");
+        // Ensure there are no duplicate signers.
+        ensure!(
+            !has_duplicates(partial_signatures.iter().map(|partial| partial.index())),
+            "Found duplicate partial signatures for a signer"
+        );
+
+        // Ensure every partial signature is valid.
+        for partial_signature in partial_signatures {
+            ensure!(
+                partial_signature.verify(self, public_nonces, message),
+                "The partial signature of signer {} is invalid",
+                partial_signature.index()
+            );
+        }
+
+        // Compute the verifier challenge.
+        let (_, challenge) = self.challenge(public_nonces, message)?;
+        // Compute the prover response.
+        let response = partial_signatures.iter().map(|partial| partial.response()).sum();
+
+        // Output the signature.
+        Ok(Signature::from((challenge, response, self.compute_key())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_combine_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_signers in 1..ITERATIONS {
+            let (private_keys, aggregate_key) = test_helpers::sample_signers(num_signers, rng);
+            let address = aggregate_key.to_address();
+
+            // Check that the combined signature is valid for the aggregate address.
+            let message: Vec<_> = (0..num_signers).map(|_| Uniform::rand(rng)).collect();
+            let signature = test_helpers::sample_multisig(&private_keys, &aggregate_key, &message, rng);
+            assert!(signature.verify(&address, &message));
+            assert_eq!(address, signature.to_address());
+
+            // Check that the combined signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..num_signers).map(|_| Uniform::rand(rng)).collect();
+            if message != failure_message {
+                assert!(!signature.verify(&address, &failure_message));
+            }
+
+            // Check that the combined signature is invalid for any individual signer.
+            for private_key in &private_keys {
+                assert!(!signature.verify(&Address::try_from(private_key)?, &message));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_combine_fails() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (private_keys, aggregate_key) = test_helpers::sample_signers(3, rng);
+        let message: Vec<_> = (0..3).map(|_| Uniform::rand(rng)).collect();
+
+        let nonces: Vec<_> = (0..3).map(|_| SigningNonce::new(rng)).collect();
+        let commitments = nonces.iter().map(|nonce| nonce.to_commitment()).collect::<Result<Vec<_>>>()?;
+        let public_nonces: Vec<_> = nonces.iter().map(|nonce| nonce.to_public_nonce()).collect();
+        let partial_signatures = private_keys
+            .iter()
+            .zip_eq(nonces)
+            .map(|(private_key, nonce)| {
+                PartialSignature::sign(private_key, nonce, &aggregate_key, &commitments, &public_nonces, &message)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Check that a missing partial signature fails.
+        assert!(aggregate_key.combine(&partial_signatures[1..], &public_nonces, &message).is_err());
+
+        // Check that a duplicate partial signature fails.
+        let duplicates = [partial_signatures[0], partial_signatures[0], partial_signatures[1]];
+        assert!(aggregate_key.combine(&duplicates, &public_nonces, &message).is_err());
+
+        // Check that a tampered partial signature fails.
+        let mut tampered = partial_signatures.clone();
+        tampered[2] = PartialSignature { index: 2, response: tampered[2].response() + Scalar::one() };
+        assert!(aggregate_key.combine(&tampered, &public_nonces, &message).is_err());
+
+        // Check that the untampered partial signatures succeed.
+        let signature = aggregate_key.combine(&partial_signatures, &public_nonces, &message)?;
+        assert!(signature.verify(&aggregate_key.to_address(), &message));
+        Ok(())
+    }
+}
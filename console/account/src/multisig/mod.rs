@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod aggregate;
+mod combine;
+mod nonce;
+mod sign;
+
+pub use aggregate::AggregateKey;
+pub use nonce::{NonceCommitment, PublicNonce, SigningNonce};
+
+use crate::{ComputeKey, PrivateKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+/// The partial signature of one signer in a multi-party Schnorr signing session.
+///
+/// The signing protocol between `n` signers proceeds as follows:
+///     1. Each signer derives the same `AggregateKey` from the compute keys of all signers.
+///     2. Each signer samples a `SigningNonce`, and broadcasts its `NonceCommitment`.
+///     3. Once all commitments are received, each signer broadcasts its `PublicNonce`.
+///     4. Each signer checks the public nonces against the commitments, and broadcasts its `PartialSignature`.
+///     5. Any party combines the partial signatures into a `Signature` for the aggregate address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PartialSignature<N: Network> {
+    /// The index of the signer in the aggregate key.
+    index: u16,
+    /// The partial prover response of the signer.
+    response: Scalar<N>,
+}
+
+impl<N: Network> PartialSignature<N> {
+    /// Returns the index of the signer in the aggregate key.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the partial prover response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples the given number of private keys, and their aggregate key.
+    pub(super) fn sample_signers(
+        num_signers: usize,
+        rng: &mut TestRng,
+    ) -> (Vec<PrivateKey<CurrentNetwork>>, AggregateKey<CurrentNetwork>) {
+        // Sample the private keys.
+        let private_keys: Vec<_> = (0..num_signers).map(|_| PrivateKey::new(rng).unwrap()).collect();
+        // Derive the compute keys.
+        let compute_keys: Vec<_> = private_keys.iter().map(|key| ComputeKey::try_from(key).unwrap()).collect();
+        // Aggregate the compute keys.
+        let aggregate_key = AggregateKey::new(&compute_keys).unwrap();
+        (private_keys, aggregate_key)
+    }
+
+    /// Runs a full signing session for the given signers and message, returning the combined signature.
+    pub(super) fn sample_multisig(
+        private_keys: &[PrivateKey<CurrentNetwork>],
+        aggregate_key: &AggregateKey<CurrentNetwork>,
+        message: &[Field<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> Signature<CurrentNetwork> {
+        // Round 1: Sample the nonces, and share the nonce commitments.
+        let nonces: Vec<_> = private_keys.iter().map(|_| SigningNonce::new(rng)).collect();
+        let commitments: Vec<_> = nonces.iter().map(|nonce| nonce.to_commitment().unwrap()).collect();
+        // Round 2: Share the public nonces.
+        let public_nonces: Vec<_> = nonces.iter().map(|nonce| nonce.to_public_nonce()).collect();
+        // Round 3: Compute and share the partial signatures.
+        let partial_signatures: Vec<_> = private_keys
+            .iter()
+            .zip_eq(nonces)
+            .map(|(private_key, nonce)| {
+                PartialSignature::sign(private_key, nonce, aggregate_key, &commitments, &public_nonces, message)
+                    .unwrap()
+            })
+            .collect();
+        // Combine the partial signatures.
+        aggregate_key.combine(&partial_signatures, &public_nonces, message).unwrap()
+    }
+}
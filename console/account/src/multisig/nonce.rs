@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The secret nonce of one signer in a multi-party signing session.
+/// A signing nonce must never be reused, and is therefore consumed when producing a partial signature.
+#[derive(PartialEq, Eq)]
+pub struct SigningNonce<N: Network> {
+    /// The secret nonce `r_i`.
+    nonce: Scalar<N>,
+    /// The public nonce `g_r_i` := G^r_i.
+    g_r: Group<N>,
+}
+
+/// The public nonce `g_r_i` of one signer in a multi-party signing session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PublicNonce<N: Network> {
+    /// The public nonce `g_r_i` := G^r_i.
+    g_r: Group<N>,
+}
+
+/// The commitment to the public nonce of one signer, which is shared before any public nonce is revealed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonceCommitment<N: Network> {
+    /// The commitment `HashPSD4(domain, g_r_i.x, g_r_i.y)`.
+    commitment: Field<N>,
+}
+
+impl<N: Network> SigningNonce<N> {
+    /// Samples a new random signing nonce.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        // Sample a random nonce from the scalar field.
+        let nonce = Scalar::rand(rng);
+        // Compute `g_r` as `nonce * G`.
+        Self { nonce, g_r: N::g_scalar_multiply(&nonce) }
+    }
+
+    /// Returns the public nonce.
+    pub const fn to_public_nonce(&self) -> PublicNonce<N> {
+        PublicNonce { g_r: self.g_r }
+    }
+
+    /// Returns the commitment to the public nonce.
+    pub fn to_commitment(&self) -> Result<NonceCommitment<N>> {
+        self.to_public_nonce().to_commitment()
+    }
+
+    /// Returns the secret nonce.
+    pub(super) const fn nonce(&self) -> Scalar<N> {
+        self.nonce
+    }
+}
+
+impl<N: Network> PublicNonce<N> {
+    /// Returns the public nonce `g_r_i`.
+    pub const fn g_r(&self) -> Group<N> {
+        self.g_r
+    }
+
+    /// Returns the commitment to the public nonce.
+    pub fn to_commitment(&self) -> Result<NonceCommitment<N>> {
+        // Compute the commitment as HashPSD4(domain, g_r.x, g_r.y).
+        let (x, y) = self.g_r.to_xy_coordinate();
+        let commitment = N::hash_psd4(&[Field::new_domain_separator("AleoMultiSigNonce0"), x, y])?;
+        Ok(NonceCommitment { commitment })
+    }
+
+    /// Returns `true` if the public nonce opens the given commitment.
+    pub fn is_committed_by(&self, commitment: &NonceCommitment<N>) -> bool {
+        match self.to_commitment() {
+            Ok(candidate) => candidate == *commitment,
+            Err(_) => false,
+        }
+    }
+}
+
+impl<N: Network> From<Group<N>> for PublicNonce<N> {
+    /// Initializes a public nonce from a group element.
+    fn from(g_r: Group<N>) -> Self {
+        Self { g_r }
+    }
+}
+
+impl<N: Network> NonceCommitment<N> {
+    /// Returns the commitment.
+    pub const fn commitment(&self) -> Field<N> {
+        self.commitment
+    }
+}
+
+impl<N: Network> From<Field<N>> for NonceCommitment<N> {
+    /// Initializes a nonce commitment from a field element.
+    fn from(commitment: Field<N>) -> Self {
+        Self { commitment }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_commitment() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two signing nonces.
+            let nonce = SigningNonce::<CurrentNetwork>::new(&mut rng);
+            let other = SigningNonce::<CurrentNetwork>::new(&mut rng);
+
+            // Check that the public nonce opens its own commitment.
+            let commitment = nonce.to_commitment()?;
+            assert!(nonce.to_public_nonce().is_committed_by(&commitment));
+            assert_eq!(commitment, NonceCommitment::from(commitment.commitment()));
+
+            // Check that a different public nonce does not open the commitment.
+            assert!(!other.to_public_nonce().is_committed_by(&commitment));
+            // Check that the negated public nonce does not open the commitment.
+            assert!(!PublicNonce::from(-nonce.to_public_nonce().g_r()).is_committed_by(&commitment));
+        }
+        Ok(())
+    }
+}
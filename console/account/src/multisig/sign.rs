@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> PartialSignature<N> {
+    /// Returns the partial signature of the given signer, where:
+    ///     challenge := HashToScalar(Σ g_r_i, pk_sig, pr_sig, address, message)
+    ///     response_i := r_i - challenge * a_i * private_key.sk_sig()
+    ///
+    /// The `commitments` and `public_nonces` must be ordered by signer index.
+    pub fn sign(
+        private_key: &PrivateKey<N>,
+        nonce: SigningNonce<N>,
+        aggregate_key: &AggregateKey<N>,
+        commitments: &[NonceCommitment<N>],
+        public_nonces: &[PublicNonce<N>],
+        message: &[Field<N>],
+    ) -> Result<Self> {
+        // Retrieve the index of the signer.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        let index = match aggregate_key.index_of(&compute_key) {
+            Some(index) => index,
+            None => bail!("Cannot sign the message: the signer is not part of the aggregate key"),
+        };
+
+        // Ensure there is one nonce commitment per signer.
+        ensure!(commitments.len() == aggregate_key.num_signers(), "Expected one nonce commitment per signer");
+        // Ensure there is one public nonce per signer.
+        ensure!(public_nonces.len() == aggregate_key.num_signers(), "Expected one public nonce per signer");
+        // Ensure every public nonce opens the commitment of its signer.
+        for (i, (public_nonce, commitment)) in public_nonces.iter().zip_eq(commitments).enumerate() {
+            ensure!(
+                public_nonce.is_committed_by(commitment),
+                "The public nonce of signer {i} does not match its commitment"
+            );
+        }
+        // Ensure the public nonce of the signer is its own.
+        ensure!(
+            public_nonces[index as usize] == nonce.to_public_nonce(),
+            "The public nonce of signer {index} does not match its signing nonce"
+        );
+
+        // Compute the verifier challenge.
+        let (_, challenge) = aggregate_key.challenge(public_nonces, message)?;
+        // Compute the partial prover response.
+        let response = nonce.nonce() - (challenge * aggregate_key.coefficient(index)? * private_key.sk_sig());
+
+        // Output the partial signature.
+        Ok(Self { index, response })
+    }
+
+    /// Returns `true` if the partial signature is valid for the given public nonces and message, where:
+    ///     g_r_i == (response_i * G) + (challenge * a_i * pk_sig_i)
+    pub fn verify(
+        &self,
+        aggregate_key: &AggregateKey<N>,
+        public_nonces: &[PublicNonce<N>],
+        message: &[Field<N>],
+    ) -> bool {
+        // Retrieve the compute key and the coefficient of the signer.
+        let (compute_key, coefficient) =
+            match (aggregate_key.compute_keys().get(self.index as usize), aggregate_key.coefficient(self.index)) {
+                (Some(compute_key), Ok(coefficient)) => (compute_key, coefficient),
+                _ => return false,
+            };
+
+        // Compute the verifier challenge, and return `false` if this operation fails.
+        let challenge = match aggregate_key.challenge(public_nonces, message) {
+            Ok((_, challenge)) => challenge,
+            Err(_) => return false,
+        };
+
+        // Compute the candidate `g_r_i` := (response_i * G) + (challenge * a_i * pk_sig_i).
+        let candidate = N::g_scalar_multiply(&self.response) + (compute_key.pk_sig() * (challenge * coefficient));
+        // Return `true` if the candidate matches the public nonce of the signer.
+        match public_nonces.get(self.index as usize) {
+            Some(public_nonce) => candidate == public_nonce.g_r(),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (private_keys, aggregate_key) = test_helpers::sample_signers(3, rng);
+        let message: Vec<_> = (0..5).map(|_| Uniform::rand(rng)).collect();
+
+        // Sample the nonces.
+        let nonces: Vec<_> = (0..3).map(|_| SigningNonce::<CurrentNetwork>::new(rng)).collect();
+        let commitments = nonces.iter().map(|nonce| nonce.to_commitment()).collect::<Result<Vec<_>>>()?;
+        let public_nonces: Vec<_> = nonces.iter().map(|nonce| nonce.to_public_nonce()).collect();
+
+        for (private_key, nonce) in private_keys.iter().zip_eq(nonces) {
+            // Check that the partial signature is valid.
+            let partial =
+                PartialSignature::sign(private_key, nonce, &aggregate_key, &commitments, &public_nonces, &message)?;
+            assert!(partial.verify(&aggregate_key, &public_nonces, &message));
+
+            // Check that the partial signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..5).map(|_| Uniform::rand(rng)).collect();
+            assert!(!partial.verify(&aggregate_key, &public_nonces, &failure_message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_fails() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (private_keys, aggregate_key) = test_helpers::sample_signers(2, rng);
+        let message: Vec<_> = (0..5).map(|_| Uniform::rand(rng)).collect();
+
+        // Samples the nonces, commitments, and public nonces of a signing session.
+        let sample_session = |rng: &mut TestRng| -> Result<_> {
+            let nonces: Vec<_> = (0..2).map(|_| SigningNonce::<CurrentNetwork>::new(rng)).collect();
+            let commitments = nonces.iter().map(|nonce| nonce.to_commitment()).collect::<Result<Vec<_>>>()?;
+            let public_nonces: Vec<_> = nonces.iter().map(|nonce| nonce.to_public_nonce()).collect();
+            Ok((nonces, commitments, public_nonces))
+        };
+
+        // Check that an outside signer cannot sign.
+        let (mut nonces, commitments, public_nonces) = sample_session(rng)?;
+        let outsider = PrivateKey::new(rng)?;
+        let result =
+            PartialSignature::sign(&outsider, nonces.remove(0), &aggregate_key, &commitments, &public_nonces, &message);
+        assert!(result.is_err());
+
+        // Check that a public nonce which does not match its commitment is rejected.
+        let (mut nonces, commitments, mut public_nonces) = sample_session(rng)?;
+        public_nonces.swap(0, 1);
+        let result = PartialSignature::sign(
+            &private_keys[0],
+            nonces.remove(0),
+            &aggregate_key,
+            &commitments,
+            &public_nonces,
+            &message,
+        );
+        assert!(result.is_err());
+
+        // Check that a signer cannot use the nonce of another signer.
+        let (mut nonces, commitments, public_nonces) = sample_session(rng)?;
+        let result = PartialSignature::sign(
+            &private_keys[0],
+            nonces.remove(1),
+            &aggregate_key,
+            &commitments,
+            &public_nonces,
+            &message,
+        );
+        assert!(result.is_err());
+
+        // Check that the wrong number of public nonces is rejected.
+        let (mut nonces, commitments, public_nonces) = sample_session(rng)?;
+        let result = PartialSignature::sign(
+            &private_keys[0],
+            nonces.remove(0),
+            &aggregate_key,
+            &commitments,
+            &public_nonces[..1],
+            &message,
+        );
+        assert!(result.is_err());
+        Ok(())
+    }
+}
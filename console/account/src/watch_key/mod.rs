@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod serialize;
+mod string;
+mod tag;
+mod try_from;
+
+#[cfg(feature = "private_key")]
+use crate::PrivateKey;
+use crate::{GraphKey, ViewKey};
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field};
+
+use base58::{FromBase58, ToBase58};
+
+/// The account watch key, which bundles the keys to monitor an account without spending authority.
+/// A watch key can decrypt the records of the account and determine whether they are spent.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WatchKey<N: Network> {
+    /// The account view key.
+    view_key: ViewKey<N>,
+    /// The account graph key.
+    graph_key: GraphKey<N>,
+    /// The account address.
+    address: Address<N>,
+}
+
+impl<N: Network> WatchKey<N> {
+    /// Returns the account view key.
+    pub const fn view_key(&self) -> &ViewKey<N> {
+        &self.view_key
+    }
+
+    /// Returns the account graph key.
+    pub const fn graph_key(&self) -> &GraphKey<N> {
+        &self.graph_key
+    }
+
+    /// Returns the account address.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+}
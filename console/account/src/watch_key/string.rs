@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+static WATCH_KEY_PREFIX: [u8; 8] = [3, 76, 202, 77, 7, 151, 211, 138]; // AWatchKey1
+
+impl<N: Network> FromStr for WatchKey<N> {
+    type Err = Error;
+
+    /// Reads in an account watch key from a base58 string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Encode the string into base58.
+        let data = s.from_base58().map_err(|err| anyhow!("{:?}", err))?;
+        if data.len() != 40 {
+            bail!("Invalid account watch key length: found {}, expected 40", data.len())
+        } else if data[0..8] != WATCH_KEY_PREFIX {
+            bail!("Invalid account watch key prefix: found {:?}, expected {:?}", &data[0..8], WATCH_KEY_PREFIX)
+        }
+        // Output the watch key.
+        Self::try_from(ViewKey::read_le(&data[8..40])?)
+    }
+}
+
+impl<N: Network> fmt::Display for WatchKey<N> {
+    /// Writes the account watch key as a base58 string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Write the watch key bytes.
+        let mut watch_key = [0u8; 40];
+        watch_key[0..8].copy_from_slice(&WATCH_KEY_PREFIX);
+        self.view_key.write_le(&mut watch_key[8..40]).map_err(|_| fmt::Error)?;
+        // Encode the watch key into base58.
+        write!(f, "{}", watch_key.to_base58())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new watch key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let expected = WatchKey::try_from(private_key)?;
+
+            // Check the string representation.
+            let candidate = format!("{expected}");
+            assert_eq!(expected, WatchKey::from_str(&candidate)?);
+            assert_eq!("AWatchKey", candidate.split('1').next().unwrap());
+
+            // Check that a view key string is not a valid watch key string.
+            assert!(WatchKey::<CurrentNetwork>::from_str(&expected.view_key().to_string()).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> WatchKey<N> {
+    /// Returns the tag for the record with the given commitment, where:
+    ///     tag := Hash(sk_tag || commitment)
+    pub fn to_tag(&self, commitment: Field<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[self.graph_key.sk_tag(), commitment])
+    }
+
+    /// Returns `true` if the record with the given commitment is spent,
+    /// where `contains_tag` returns `true` if the given tag has been published on-chain.
+    pub fn is_spent(&self, commitment: Field<N>, contains_tag: impl Fn(&Field<N>) -> Result<bool>) -> Result<bool> {
+        contains_tag(&self.to_tag(commitment)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use std::collections::HashSet;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_is_spent() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new watch key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let watch_key = WatchKey::try_from(private_key)?;
+
+            // Sample a spent and an unspent commitment.
+            let spent: Field<CurrentNetwork> = Uniform::rand(rng);
+            let unspent: Field<CurrentNetwork> = Uniform::rand(rng);
+
+            // Check that the tag is the hash of `sk_tag` and the commitment.
+            let tag = watch_key.to_tag(spent)?;
+            assert_eq!(tag, CurrentNetwork::hash_psd2(&[watch_key.graph_key().sk_tag(), spent])?);
+
+            // Check the spent status against the set of published tags.
+            let tags = HashSet::from([tag]);
+            assert!(watch_key.is_spent(spent, |tag| Ok(tags.contains(tag)))?);
+            assert!(!watch_key.is_spent(unspent, |tag| Ok(tags.contains(tag)))?);
+
+            // Check that the tags of different accounts differ.
+            let other = WatchKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!other.is_spent(spent, |tag| Ok(tags.contains(tag)))?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[cfg(feature = "private_key")]
+impl<N: Network> TryFrom<PrivateKey<N>> for WatchKey<N> {
+    type Error = Error;
+
+    /// Derives the account watch key from an account private key.
+    fn try_from(private_key: PrivateKey<N>) -> Result<Self, Self::Error> {
+        Self::try_from(&private_key)
+    }
+}
+
+#[cfg(feature = "private_key")]
+impl<N: Network> TryFrom<&PrivateKey<N>> for WatchKey<N> {
+    type Error = Error;
+
+    /// Derives the account watch key from an account private key.
+    fn try_from(private_key: &PrivateKey<N>) -> Result<Self, Self::Error> {
+        Self::try_from(ViewKey::try_from(private_key)?)
+    }
+}
+
+impl<N: Network> TryFrom<ViewKey<N>> for WatchKey<N> {
+    type Error = Error;
+
+    /// Derives the account watch key from an account view key.
+    fn try_from(view_key: ViewKey<N>) -> Result<Self, Self::Error> {
+        Self::try_from(&view_key)
+    }
+}
+
+impl<N: Network> TryFrom<&ViewKey<N>> for WatchKey<N> {
+    type Error = Error;
+
+    /// Derives the account watch key from an account view key.
+    fn try_from(view_key: &ViewKey<N>) -> Result<Self, Self::Error> {
+        // Derive the graph key.
+        let graph_key = GraphKey::try_from(view_key)?;
+        // Derive the address.
+        let address = view_key.to_address();
+        // Output the watch key.
+        Ok(Self { view_key: *view_key, graph_key, address })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new watch key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let watch_key = WatchKey::try_from(private_key)?;
+
+            // Check that the watch key matches the individually-derived keys.
+            assert_eq!(&ViewKey::try_from(private_key)?, watch_key.view_key());
+            assert_eq!(&GraphKey::try_from(ViewKey::try_from(private_key)?)?, watch_key.graph_key());
+            assert_eq!(Address::try_from(private_key)?, watch_key.address());
+
+            // Check that the watch key is derived identically from the view key.
+            assert_eq!(watch_key, WatchKey::try_from(watch_key.view_key())?);
+        }
+        Ok(())
+    }
+}
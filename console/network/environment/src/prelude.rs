@@ -25,6 +25,7 @@ pub use snarkvm_utilities::{
     error,
     has_duplicates,
     io::{Read, Result as IoResult, Write},
+    BigInteger as _,
     FromBits as _,
     FromBytes,
     FromBytesDeserializer,
@@ -0,0 +1,414 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_algorithms::{
+    Blake2Xs,
+    Pedersen128,
+    Pedersen64,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+    BHP1024,
+    BHP256,
+    BHP512,
+    BHP768,
+};
+
+lazy_static! {
+    /// The group bases for the Aleo signature and encryption schemes.
+    pub static ref GENERATOR_G: Vec<Group<Devnet>> = Devnet::new_bases("AleoDevnetAccountEncryptionAndSignatureScheme0");
+
+    /// The Marlin sponge parameters.
+    pub static ref MARLIN_FS_PARAMETERS: FiatShamirParameters<Devnet> = FiatShamir::<Devnet>::sample_parameters();
+
+    /// The balance commitment domain as a constant field element.
+    pub static ref BCM_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoBalanceCommitment0");
+    /// The encryption domain as a constant field element.
+    pub static ref ENCRYPTION_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoSymmetricEncryption0");
+    /// The graph key domain as a constant field element.
+    pub static ref GRAPH_KEY_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoGraphKey0");
+    /// The randomizer domain as a constant field element.
+    pub static ref RANDOMIZER_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoRandomizer0");
+    /// The balance commitment randomizer domain as a constant field element.
+    pub static ref R_BCM_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoBalanceRandomizer0");
+    /// The serial number domain as a constant field element.
+    pub static ref SERIAL_NUMBER_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoSerialNumber0");
+
+    /// The BHP hash function, which can take an input of up to 256 bits.
+    pub static ref BHP_256: BHP256<Devnet> = BHP256::<Devnet>::setup("AleoBHP256").expect("Failed to setup BHP256");
+    /// The BHP hash function, which can take an input of up to 512 bits.
+    pub static ref BHP_512: BHP512<Devnet> = BHP512::<Devnet>::setup("AleoBHP512").expect("Failed to setup BHP512");
+    /// The BHP hash function, which can take an input of up to 768 bits.
+    pub static ref BHP_768: BHP768<Devnet> = BHP768::<Devnet>::setup("AleoBHP768").expect("Failed to setup BHP768");
+    /// The BHP hash function, which can take an input of up to 1024 bits.
+    pub static ref BHP_1024: BHP1024<Devnet> = BHP1024::<Devnet>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
+
+    /// The Pedersen hash function, which can take an input of up to 64 bits.
+    pub static ref PEDERSEN_64: Pedersen64<Devnet> = Pedersen64::<Devnet>::setup("AleoPedersen64");
+    /// The Pedersen hash function, which can take an input of up to 128 bits.
+    pub static ref PEDERSEN_128: Pedersen128<Devnet> = Pedersen128::<Devnet>::setup("AleoPedersen128");
+
+    /// The Poseidon hash function, using a rate of 2.
+    pub static ref POSEIDON_2: Poseidon2<Devnet> = Poseidon2::<Devnet>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
+    /// The Poseidon hash function, using a rate of 4.
+    pub static ref POSEIDON_4: Poseidon4<Devnet> = Poseidon4::<Devnet>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
+    /// The Poseidon hash function, using a rate of 8.
+    pub static ref POSEIDON_8: Poseidon8<Devnet> = Poseidon8::<Devnet>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
+}
+
+/// Returns the given build-time value as a decimal number, or the default if it is not set.
+const fn value_or(value: Option<&str>, default: usize) -> usize {
+    let bytes = match value {
+        Some(value) => value.as_bytes(),
+        None => return default,
+    };
+    assert!(!bytes.is_empty(), "Expected a decimal number");
+
+    let mut result = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(bytes[i].is_ascii_digit(), "Expected a decimal number");
+        result = result * 10 + (bytes[i] - b'0') as usize;
+        i += 1;
+    }
+    result
+}
+
+/// A network for local development.
+///
+/// The devnet has its own account generators, and does not depend on any downloaded parameters:
+/// 1) the universal SRS is sampled locally from an RNG seeded with the network ID,
+/// 2) the `credits.aleo` circuit keys are synthesized on demand, and
+/// 3) the genesis block must be created locally.
+///
+/// **Warning:** As the seed of the universal SRS is public, so is its trapdoor, and anyone can forge proofs.
+/// The devnet must never be used to secure real value.
+///
+/// The following limits can be tuned by setting environment variables when building snarkVM:
+/// `SNARKVM_DEVNET_MAX_INPUTS`, `SNARKVM_DEVNET_MAX_OUTPUTS`, `SNARKVM_DEVNET_MAX_DATA_ENTRIES`,
+/// and `SNARKVM_DEVNET_SRS_DEGREE`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Devnet;
+
+impl Devnet {
+    /// Initializes a new instance of group bases from a given input domain message.
+    fn new_bases(message: &str) -> Vec<Group<Self>> {
+        // Hash the given message to a point on the curve, to initialize the starting base.
+        let (base, _, _) = Blake2Xs::hash_to_curve::<<Self as Environment>::Affine>(message);
+
+        // Compute the bases up to the size of the scalar field (in bits).
+        let mut g = Group::<Self>::new(base);
+        let mut g_bases = Vec::with_capacity(Scalar::<Self>::size_in_bits());
+        for _ in 0..Scalar::<Self>::size_in_bits() {
+            g_bases.push(g);
+            g = g.double();
+        }
+        g_bases
+    }
+}
+
+impl Environment for Devnet {
+    type Affine = <Console as Environment>::Affine;
+    type BigInteger = <Console as Environment>::BigInteger;
+    type Field = <Console as Environment>::Field;
+    type PairingCurve = <Console as Environment>::PairingCurve;
+    type Projective = <Console as Environment>::Projective;
+    type Scalar = <Console as Environment>::Scalar;
+
+    /// The coefficient `A` of the twisted Edwards curve.
+    const EDWARDS_A: Self::Field = Console::EDWARDS_A;
+    /// The coefficient `D` of the twisted Edwards curve.
+    const EDWARDS_D: Self::Field = Console::EDWARDS_D;
+    /// The coefficient `A` of the Montgomery curve.
+    const MONTGOMERY_A: Self::Field = Console::MONTGOMERY_A;
+    /// The coefficient `B` of the Montgomery curve.
+    const MONTGOMERY_B: Self::Field = Console::MONTGOMERY_B;
+}
+
+impl Network for Devnet {
+    /// The block hash type.
+    type BlockHash = AleoID<Field<Self>, { hrp2!("ab") }>;
+    /// The state root type.
+    type StateRoot = AleoID<Field<Self>, { hrp2!("ar") }>;
+    /// The transaction ID type.
+    type TransactionID = AleoID<Field<Self>, { hrp2!("at") }>;
+    /// The transition ID type.
+    type TransitionID = AleoID<Field<Self>, { hrp2!("as") }>;
+
+    /// The network edition.
+    const EDITION: u16 = 0;
+    /// The network ID.
+    const ID: u16 = 0;
+    /// The maximum degree of the locally-sampled universal SRS, which defaults to the smallest degree
+    /// that supports the `credits.aleo` circuits, so that it is fast to sample.
+    /// Note: The universal SRS is sampled from an RNG seeded with the network ID, so its trapdoor is public.
+    const LOCAL_SRS_DEGREE: usize = value_or(option_env!("SNARKVM_DEVNET_SRS_DEGREE"), 1 << 17);
+    /// The maximum number of values and/or entries in data.
+    const MAX_DATA_ENTRIES: usize = value_or(option_env!("SNARKVM_DEVNET_MAX_DATA_ENTRIES"), 64);
+    /// The maximum number of inputs per transition.
+    const MAX_INPUTS: usize = value_or(option_env!("SNARKVM_DEVNET_MAX_INPUTS"), 16);
+    /// The maximum number of outputs per transition.
+    const MAX_OUTPUTS: usize = value_or(option_env!("SNARKVM_DEVNET_MAX_OUTPUTS"), 16);
+    /// The network name.
+    const NAME: &'static str = "Aleo Devnet";
+    /// The short network name.
+    const SHORT_NAME: &'static str = "devnet";
+
+    /// Returns the powers of `G`.
+    fn g_powers() -> &'static Vec<Group<Self>> {
+        &GENERATOR_G
+    }
+
+    /// Returns the scalar multiplication on the generator `G`.
+    fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+        GENERATOR_G
+            .iter()
+            .zip_eq(&scalar.to_bits_le())
+            .filter_map(|(base, bit)| match bit {
+                true => Some(base),
+                false => None,
+            })
+            .sum()
+    }
+
+    /// Returns the sponge parameters used for the sponge in the Marlin SNARK.
+    fn marlin_fs_parameters() -> &'static FiatShamirParameters<Self> {
+        &MARLIN_FS_PARAMETERS
+    }
+
+    /// Returns `None`, as the genesis block of a devnet is created locally.
+    fn genesis_bytes() -> Result<Option<&'static [u8]>> {
+        Ok(None)
+    }
+
+    /// Returns `None`, as the universal SRS of a devnet is sampled locally.
+    fn universal_srs_bytes() -> Result<Option<&'static [u8]>> {
+        Ok(None)
+    }
+
+    /// Returns `None`, as the `credits.aleo` circuit keys of a devnet are synthesized on demand.
+    fn credits_key_bytes(_function_name: &str) -> Result<Option<&'static (Vec<u8>, Vec<u8>)>> {
+        Ok(None)
+    }
+
+    /// Returns the balance commitment domain as a constant field element.
+    fn bcm_domain() -> Field<Self> {
+        *BCM_DOMAIN
+    }
+
+    /// Returns the encryption domain as a constant field element.
+    fn encryption_domain() -> Field<Self> {
+        *ENCRYPTION_DOMAIN
+    }
+
+    /// Returns the graph key domain as a constant field element.
+    fn graph_key_domain() -> Field<Self> {
+        *GRAPH_KEY_DOMAIN
+    }
+
+    /// Returns the randomizer domain as a constant field element.
+    fn randomizer_domain() -> Field<Self> {
+        *RANDOMIZER_DOMAIN
+    }
+
+    /// Returns the balance commitment randomizer domain as a constant field element.
+    fn r_bcm_domain() -> Field<Self> {
+        *R_BCM_DOMAIN
+    }
+
+    /// Returns the serial number domain as a constant field element.
+    fn serial_number_domain() -> Field<Self> {
+        *SERIAL_NUMBER_DOMAIN
+    }
+
+    /// Returns a BHP commitment with an input hasher of 256-bits.
+    fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        BHP_256.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 512-bits.
+    fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        BHP_512.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 768-bits.
+    fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        BHP_768.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits.
+    fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        BHP_1024.commit(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+    fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        PEDERSEN_64.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+    fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        PEDERSEN_128.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns the BHP hash with an input hasher of 256-bits.
+    fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
+        BHP_256.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
+        BHP_512.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits.
+    fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
+        BHP_768.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
+        BHP_1024.hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input.
+    fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
+        PEDERSEN_64.hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input.
+    fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
+        PEDERSEN_128.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2.
+    fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON_2.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4.
+    fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON_4.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8.
+    fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
+        POSEIDON_8.hash(input)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 2.
+    fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        POSEIDON_2.hash_many(input, num_outputs)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 4.
+    fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        POSEIDON_4.hash_many(input, num_outputs)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 8.
+    fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        POSEIDON_8.hash_many(input, num_outputs)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
+    fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
+        POSEIDON_2.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the affine curve.
+    fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
+        POSEIDON_4.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
+    fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
+        POSEIDON_8.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
+    fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        POSEIDON_2.hash_to_scalar(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
+    fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        POSEIDON_4.hash_to_scalar(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
+    fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        POSEIDON_8.hash_to_scalar(input)
+    }
+
+    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
+    fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
+        MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
+    }
+
+    /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
+    fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
+        MerkleTree::new(&*POSEIDON_4, &*POSEIDON_2, leaves)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_bhp<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<bool>,
+    ) -> bool {
+        path.verify(&*BHP_1024, &*BHP_512, root, leaf)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_psd<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<Field<Self>>,
+    ) -> bool {
+        path.verify(&*POSEIDON_4, &*POSEIDON_2, root, leaf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = Devnet;
+
+    #[test]
+    fn test_g_scalar_multiply() {
+        // Compute G^r.
+        let scalar = Scalar::rand(&mut TestRng::default());
+        let group = CurrentNetwork::g_scalar_multiply(&scalar);
+        assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
+    }
+
+    #[test]
+    fn test_value_or() {
+        assert_eq!(16, value_or(None, 16));
+        assert_eq!(0, value_or(Some("0"), 16));
+        assert_eq!(32, value_or(Some("32"), 16));
+    }
+
+    #[test]
+    fn test_g_powers_differ_from_testnet3() {
+        let devnet_g = CurrentNetwork::g_powers()[0].to_x_coordinate().to_bits_le();
+        let testnet3_g = Testnet3::g_powers()[0].to_x_coordinate().to_bits_le();
+        assert_ne!(devnet_g, testnet3_g);
+    }
+}
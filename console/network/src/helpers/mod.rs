@@ -17,5 +17,8 @@
 mod id;
 pub use id::*;
 
+mod network_bytes;
+pub use network_bytes::*;
+
 mod object;
 pub use object::*;
@@ -0,0 +1,31 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::Result;
+
+/// The loaders for the bytes of a network's genesis block, universal SRS, and `credits.aleo` circuit keys.
+///
+/// The console does not depend on the downloaded parameters, so the VM supplies these loaders
+/// to any network whose `Network` hooks return predefined bytes.
+#[derive(Copy, Clone)]
+pub struct NetworkBytes {
+    /// Returns the bytes of the genesis block.
+    pub genesis: fn() -> &'static [u8],
+    /// Returns the bytes of the universal SRS.
+    pub universal_srs: fn() -> Result<&'static [u8]>,
+    /// Returns the proving and verifying key bytes for the given function in `credits.aleo`.
+    pub credits_keys: fn(&str) -> Result<&'static (Vec<u8>, Vec<u8>)>,
+}
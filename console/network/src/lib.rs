@@ -27,6 +27,9 @@ pub use snarkvm_console_network_environment::*;
 mod helpers;
 pub use helpers::*;
 
+pub mod devnet;
+pub use devnet::Devnet;
+
 mod testnet3;
 pub use testnet3::*;
 
@@ -70,6 +73,8 @@ pub trait Network:
     const ID: u16;
     /// The network name.
     const NAME: &'static str;
+    /// The short network name, which is used as the path prefix of REST API routes.
+    const SHORT_NAME: &'static str;
     /// The network edition.
     const EDITION: u16;
 
@@ -94,6 +99,12 @@ pub trait Network:
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 8;
 
+    /// The maximum degree of the universal SRS, if it is sampled locally.
+    /// Note: This is only used if the network does not provide the universal SRS bytes.
+    /// A locally-sampled universal SRS is seeded with the network ID, so its trapdoor is public
+    /// and it is **insecure**; it is only suitable for local development.
+    const LOCAL_SRS_DEGREE: usize = 1 << 17;
+
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
     /// The block hash type.
@@ -112,6 +123,16 @@ pub trait Network:
     /// Returns the sponge parameters for Marlin.
     fn marlin_fs_parameters() -> &'static FiatShamirParameters<Self>;
 
+    /// Returns the bytes of the genesis block, or `None` if the network does not have a predefined genesis block.
+    fn genesis_bytes() -> Result<Option<&'static [u8]>>;
+
+    /// Returns the bytes of the universal SRS, or `None` if the universal SRS is sampled locally.
+    fn universal_srs_bytes() -> Result<Option<&'static [u8]>>;
+
+    /// Returns the proving and verifying key bytes for the given function in `credits.aleo`,
+    /// or `None` if the circuit keys are synthesized on demand.
+    fn credits_key_bytes(function_name: &str) -> Result<Option<&'static (Vec<u8>, Vec<u8>)>>;
+
     /// Returns the balance commitment domain as a constant field element.
     fn bcm_domain() -> Field<Self>;
 
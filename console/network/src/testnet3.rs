@@ -28,6 +28,11 @@ use snarkvm_console_algorithms::{
     BHP768,
 };
 
+use once_cell::sync::OnceCell;
+
+/// The loaders for the parameters of Testnet3, which are supplied by the VM.
+static NETWORK_BYTES: OnceCell<NetworkBytes> = OnceCell::new();
+
 lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
     pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases("AleoAccountEncryptionAndSignatureScheme0");
@@ -88,6 +93,17 @@ impl Testnet3 {
         }
         g_bases
     }
+
+    /// Supplies the loaders for the genesis block, universal SRS, and `credits.aleo` circuit keys.
+    /// Returns `false` if the loaders were already supplied.
+    pub fn supply_network_bytes(network_bytes: NetworkBytes) -> bool {
+        NETWORK_BYTES.set(network_bytes).is_ok()
+    }
+
+    /// Returns the loaders for the genesis block, universal SRS, and `credits.aleo` circuit keys.
+    fn network_bytes() -> Result<&'static NetworkBytes> {
+        NETWORK_BYTES.get().ok_or_else(|| anyhow!("The parameters for '{}' were not supplied by the VM", Self::NAME))
+    }
 }
 
 impl Environment for Testnet3 {
@@ -124,6 +140,8 @@ impl Network for Testnet3 {
     const ID: u16 = 3;
     /// The network name.
     const NAME: &'static str = "Aleo Testnet3";
+    /// The short network name.
+    const SHORT_NAME: &'static str = "testnet3";
 
     /// Returns the powers of `G`.
     fn g_powers() -> &'static Vec<Group<Self>> {
@@ -147,6 +165,21 @@ impl Network for Testnet3 {
         &MARLIN_FS_PARAMETERS
     }
 
+    /// Returns the bytes of the genesis block, as supplied by the VM.
+    fn genesis_bytes() -> Result<Option<&'static [u8]>> {
+        Ok(Some((Self::network_bytes()?.genesis)()))
+    }
+
+    /// Returns the bytes of the universal SRS, as supplied by the VM.
+    fn universal_srs_bytes() -> Result<Option<&'static [u8]>> {
+        Ok(Some((Self::network_bytes()?.universal_srs)()?))
+    }
+
+    /// Returns the proving and verifying key bytes for the given function in `credits.aleo`, as supplied by the VM.
+    fn credits_key_bytes(function_name: &str) -> Result<Option<&'static (Vec<u8>, Vec<u8>)>> {
+        Ok(Some((Self::network_bytes()?.credits_keys)(function_name)?))
+    }
+
     /// Returns the balance commitment domain as a constant field element.
     fn bcm_domain() -> Field<Self> {
         *BCM_DOMAIN
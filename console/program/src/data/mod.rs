@@ -27,7 +27,7 @@ mod plaintext;
 pub use plaintext::Plaintext;
 
 mod record;
-pub use record::{Balance, Entry, Owner, Record};
+pub use record::{Balance, Entry, Owner, Record, RecordDisclosure};
 
 mod register;
 pub use register::Register;
@@ -25,6 +25,16 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         self.decrypt_symmetric(&record_view_key)
     }
 
+    /// Decrypts `self` into plaintext using the given watch key, if the watch key owns the record.
+    pub fn decrypt_with_watch_key(&self, watch_key: &WatchKey<N>) -> Result<Option<Record<N, Plaintext<N>>>> {
+        match self.is_owner(&watch_key.address(), watch_key.view_key()) {
+            // Decrypt the record, if it is owned by the watch key.
+            true => self.decrypt(watch_key.view_key()).map(Some),
+            // Otherwise, skip the record.
+            false => Ok(None),
+        }
+    }
+
     /// Decrypts `self` into plaintext using the given record view key.
     pub fn decrypt_symmetric(&self, record_view_key: &Field<N>) -> Result<Record<N, Plaintext<N>>> {
         // Determine the number of randomizers needed to encrypt the record.
@@ -133,6 +143,8 @@ mod tests {
         let ciphertext = record.encrypt(randomizer)?;
         // Decrypt the record.
         assert_eq!(record, ciphertext.decrypt(&view_key)?);
+        // Decrypt the record with the watch key.
+        assert_eq!(Some(record), ciphertext.decrypt_with_watch_key(&WatchKey::try_from(view_key)?)?);
         Ok(())
     }
 
@@ -168,4 +180,34 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_decrypt_with_watch_key() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a view key and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Prepare and encrypt the record.
+            let randomizer = Scalar::rand(&mut rng);
+            let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+                Owner::Private(Plaintext::from(Literal::Address(address))),
+                Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(&mut rng) >> 12)))),
+                IndexMap::new(),
+                CurrentNetwork::g_scalar_multiply(&randomizer),
+            )?;
+            let ciphertext = record.encrypt(randomizer)?;
+
+            // Check that the owner's watch key decrypts the record.
+            assert_eq!(Some(record), ciphertext.decrypt_with_watch_key(&WatchKey::try_from(view_key)?)?);
+
+            // Check that another watch key skips the record.
+            let other = WatchKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            assert_eq!(None, ciphertext.decrypt_with_watch_key(&other)?);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Decrypts the records owned by the given view key, and returns them with their index in `records`.
+    /// Records that are not owned by the view key are skipped, and owned records that fail to decrypt
+    /// are returned with their error.
+    #[allow(clippy::type_complexity)]
+    pub fn decrypt_batch(view_key: &ViewKey<N>, records: &[Self]) -> Vec<(usize, Result<Record<N, Plaintext<N>>>)> {
+        // Derive the address from the view key.
+        let address = view_key.to_address();
+        // Precompute the non-adjacent form of the view key, from the most-significant digit,
+        // as the view key is the shared scalar of every record view key.
+        let view_key_naf: Vec<i64> = view_key.to_repr().find_wnaf().into_iter().rev().collect();
+
+        cfg_iter!(records)
+            .enumerate()
+            .filter_map(|(index, record)| {
+                // Skip the record, if its owner is public and is not the address.
+                if let Owner::Public(owner) = &record.owner {
+                    if owner != &address {
+                        return None;
+                    }
+                }
+                // Compute the record view key.
+                let record_view_key = Field::new(Self::mul_naf(&record.nonce, &view_key_naf).to_x_coordinate());
+                // Skip the record, if it is not owned by the view key.
+                if !record.is_owner_with_record_view_key(&address, &record_view_key) {
+                    return None;
+                }
+                // Decrypt the record.
+                Some((index, record.decrypt_symmetric(&record_view_key)))
+            })
+            .collect()
+    }
+
+    /// Returns the product of the given nonce and the scalar with the given non-adjacent form,
+    /// ordered from the most-significant digit.
+    fn mul_naf(nonce: &Group<N>, naf: &[i64]) -> N::Affine {
+        let nonce = nonce.to_affine();
+        let mut output = N::Projective::zero();
+        for digit in naf {
+            output.double_in_place();
+            match digit {
+                1 => output.add_assign_mixed(&nonce),
+                -1 => output.sub_assign_mixed(&nonce),
+                _ => (),
+            }
+        }
+        output.to_affine()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    /// Samples a record ciphertext owned by the given address, with a private or public owner.
+    #[allow(clippy::type_complexity)]
+    fn sample_record(
+        address: Address<CurrentNetwork>,
+        is_private: bool,
+        rng: &mut TestRng,
+    ) -> Result<(Record<CurrentNetwork, Plaintext<CurrentNetwork>>, Record<CurrentNetwork, Ciphertext<CurrentNetwork>>)>
+    {
+        let owner = match is_private {
+            true => Owner::Private(Plaintext::from(Literal::Address(address))),
+            false => Owner::Public(address),
+        };
+        let gates = Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(rng) >> 12))));
+        let data = IndexMap::from_iter(vec![(
+            Identifier::from_str("a")?,
+            Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng)))),
+        )]);
+        // Prepare the record.
+        let randomizer = Scalar::rand(rng);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+            owner,
+            gates,
+            data,
+            CurrentNetwork::g_scalar_multiply(&randomizer),
+        )?;
+        // Encrypt the record.
+        let ciphertext = record.encrypt(randomizer)?;
+        Ok((record, ciphertext))
+    }
+
+    #[test]
+    fn test_decrypt_batch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the view key and address of the account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Sample the address of another account.
+        let other = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+
+        // Sample a mix of owned and unowned records.
+        let mut expected = Vec::new();
+        let mut ciphertexts = Vec::with_capacity(ITERATIONS);
+        for index in 0..ITERATIONS {
+            let is_owned = rng.gen::<bool>();
+            let owner = if is_owned { address } else { other };
+            let (record, ciphertext) = sample_record(owner, rng.gen(), rng)?;
+            if is_owned {
+                expected.push((index, record));
+            }
+            ciphertexts.push(ciphertext);
+        }
+
+        // Check that only the owned records are decrypted, in order.
+        let candidate = Record::decrypt_batch(&view_key, &ciphertexts)
+            .into_iter()
+            .map(|(index, record)| Ok((index, record?)))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(expected, candidate);
+
+        // Check that the batch matches the single-record API.
+        for (index, record) in candidate {
+            assert!(ciphertexts[index].is_owner(&address, &view_key));
+            assert_eq!(record, ciphertexts[index].decrypt(&view_key)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_batch_empty() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Check that an empty batch decrypts to no records.
+        let view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert!(Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::decrypt_batch(&view_key, &[]).is_empty());
+        Ok(())
+    }
+}
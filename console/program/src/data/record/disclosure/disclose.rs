@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns a disclosure of the full record, which the owner may share with a third party.
+    pub fn to_disclosure(&self, view_key: &ViewKey<N>) -> Result<RecordDisclosure<N>> {
+        // Ensure the view key owns the record.
+        ensure!(self.is_owner(&view_key.to_address(), view_key), "Cannot disclose a record that is not owned");
+        // Compute the record view key.
+        let record_view_key = (*self.nonce() * **view_key).to_x_coordinate();
+        // Output the record disclosure.
+        Ok(RecordDisclosure { record_view_key })
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_to_disclosure() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let (record, ciphertext, _) = test_helpers::sample_record(&private_key, rng)?;
+
+            // Check that the disclosure decrypts the record.
+            let disclosure = ciphertext.to_disclosure(&view_key)?;
+            assert_eq!(record, ciphertext.decrypt_symmetric(&disclosure.record_view_key())?);
+
+            // Check that a non-owner cannot disclose the record.
+            let other = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(ciphertext.to_disclosure(&other).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod disclose;
+mod verify;
+
+use crate::{Ciphertext, Identifier, Plaintext, ProgramID, Record};
+use snarkvm_console_account::{Address, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+/// A disclosure of a full record to a third party (e.g. an auditor), without revealing the view key of the owner.
+/// The disclosure key is the record view key, which decrypts exactly one record.
+/// The third party verifies the decrypted record against the on-chain record commitment.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecordDisclosure<N: Network> {
+    /// The record view key.
+    record_view_key: Field<N>,
+}
+
+impl<N: Network> RecordDisclosure<N> {
+    /// Initializes a record disclosure from the given record view key.
+    pub const fn from_record_view_key(record_view_key: Field<N>) -> Self {
+        Self { record_view_key }
+    }
+
+    /// Returns the record view key.
+    pub const fn record_view_key(&self) -> Field<N> {
+        self.record_view_key
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+    use crate::{Balance, Entry, Literal, Owner};
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_console_types::{Scalar, U64};
+
+    use indexmap::IndexMap;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a record ciphertext with private, public, and constant entries, and its commitment.
+    #[allow(clippy::type_complexity)]
+    pub(super) fn sample_record(
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Result<(
+        Record<CurrentNetwork, Plaintext<CurrentNetwork>>,
+        Record<CurrentNetwork, Ciphertext<CurrentNetwork>>,
+        Field<CurrentNetwork>,
+    )> {
+        let address = Address::try_from(private_key)?;
+        let data = IndexMap::from_iter(vec![
+            (Identifier::from_str("a")?, Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng))))),
+            (Identifier::from_str("b")?, Entry::Public(Plaintext::from(Literal::Field(Field::rand(rng))))),
+            (Identifier::from_str("c")?, Entry::Private(Plaintext::from(Literal::Scalar(Scalar::rand(rng))))),
+            (Identifier::from_str("d")?, Entry::Constant(Plaintext::from(Literal::Field(Field::rand(rng))))),
+        ]);
+        // Prepare the record.
+        let randomizer = Scalar::rand(rng);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+            Owner::Private(Plaintext::from(Literal::Address(address))),
+            Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(rng) >> 12)))),
+            data,
+            CurrentNetwork::g_scalar_multiply(&randomizer),
+        )?;
+        // Compute the commitment.
+        let commitment = record.to_commitment(&ProgramID::from_str("token.aleo")?, &Identifier::from_str("token")?)?;
+        // Encrypt the record.
+        let ciphertext = record.encrypt(randomizer)?;
+        Ok((record, ciphertext, commitment))
+    }
+}
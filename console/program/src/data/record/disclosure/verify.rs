@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> RecordDisclosure<N> {
+    /// Decrypts the given record with the disclosure, and returns the plaintext record
+    /// if it matches the on-chain commitment and is owned by the given address.
+    pub fn verify(
+        &self,
+        record: &Record<N, Ciphertext<N>>,
+        address: &Address<N>,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        commitment: &Field<N>,
+    ) -> Result<Record<N, Plaintext<N>>> {
+        // Decrypt the record.
+        let plaintext = record.decrypt_symmetric(&self.record_view_key)?;
+        // Ensure the record matches the on-chain commitment.
+        ensure!(
+            plaintext.to_commitment(program_id, record_name)? == *commitment,
+            "The disclosed record does not match the commitment"
+        );
+        // Ensure the record is owned by the given address.
+        ensure!(**plaintext.owner() == *address, "The disclosed record is not owned by '{address}'");
+        // Output the plaintext record.
+        Ok(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_verify_record_disclosure() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let program_id = ProgramID::from_str("token.aleo")?;
+        let record_name = Identifier::from_str("token")?;
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+            let (record, ciphertext, commitment) = test_helpers::sample_record(&private_key, rng)?;
+
+            // Check that the disclosure verifies against the commitment.
+            let disclosure = ciphertext.to_disclosure(&ViewKey::try_from(&private_key)?)?;
+            assert_eq!(record, disclosure.verify(&ciphertext, &address, &program_id, &record_name, &commitment)?);
+
+            // Check that the disclosure fails for an incorrect commitment.
+            let commitment = Field::rand(rng);
+            assert!(disclosure.verify(&ciphertext, &address, &program_id, &record_name, &commitment).is_err());
+
+            // Check that an incorrect disclosure key fails.
+            let disclosure = RecordDisclosure::from_record_view_key(Field::rand(rng));
+            assert!(disclosure.verify(&ciphertext, &address, &program_id, &record_name, &commitment).is_err());
+        }
+        Ok(())
+    }
+}
@@ -23,11 +23,24 @@ impl<N: Network> Record<N, Ciphertext<N>> {
             // If the owner is public, check if the address is the owner.
             Owner::Public(owner) => owner == address,
             // If the owner is private, decrypt the owner to check if it matches the address.
-            Owner::Private(ciphertext) => {
+            Owner::Private(..) => {
                 // Compute the record view key.
                 let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+                // Check the owner with the record view key.
+                self.is_owner_with_record_view_key(address, &record_view_key)
+            }
+        }
+    }
+
+    /// Returns `true` if the given address is the owner, using the given record view key.
+    pub(super) fn is_owner_with_record_view_key(&self, address: &Address<N>, record_view_key: &Field<N>) -> bool {
+        match &self.owner {
+            // If the owner is public, check if the address is the owner.
+            Owner::Public(owner) => owner == address,
+            // If the owner is private, decrypt the owner to check if it matches the address.
+            Owner::Private(ciphertext) => {
                 // Compute the 0th randomizer.
-                let randomizer = N::hash_many_psd8(&[N::encryption_domain(), record_view_key], 1);
+                let randomizer = N::hash_many_psd8(&[N::encryption_domain(), *record_view_key], 1);
                 // Decrypt the owner.
                 match Address::from_field(&(ciphertext[0] - randomizer[0])) {
                     Ok(owner) => &owner == address,
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod disclosure;
+pub use disclosure::RecordDisclosure;
+
 mod entry;
 pub use entry::Entry;
 
@@ -22,6 +25,7 @@ pub use helpers::{Balance, Owner};
 
 mod bytes;
 mod decrypt;
+mod decrypt_batch;
 mod encrypt;
 mod equal;
 mod find;
@@ -35,7 +39,7 @@ mod to_commitment;
 mod to_fields;
 
 use crate::{Ciphertext, Identifier, Literal, Plaintext, ProgramID};
-use snarkvm_console_account::{Address, ViewKey};
+use snarkvm_console_account::{Address, ViewKey, WatchKey};
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Group, Scalar, U64};
 
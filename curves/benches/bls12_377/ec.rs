@@ -47,6 +47,23 @@ pub(crate) mod g1 {
         });
     }
 
+    pub fn bench_g1_mul_glv(c: &mut Criterion) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = TestRng::default();
+
+        let v: Vec<(G1, Fr)> = (0..SAMPLES).map(|_| (G1::rand(&mut rng), Fr::rand(&mut rng))).collect();
+
+        let mut count = 0;
+        c.bench_function("bls12_377: g1_mul_glv", |c| {
+            c.iter(|| {
+                let tmp = v[count].0.mul_glv(v[count].1);
+                count = (count + 1) % SAMPLES;
+                tmp
+            })
+        });
+    }
+
     pub fn bench_g1_add_assign(c: &mut Criterion) {
         const SAMPLES: usize = 1000;
 
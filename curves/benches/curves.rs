@@ -22,6 +22,7 @@ criterion_group!(
     bls12_377_ec,
     bls12_377::ec::g1::bench_g1_rand,
     bls12_377::ec::g1::bench_g1_mul_assign,
+    bls12_377::ec::g1::bench_g1_mul_glv,
     bls12_377::ec::g1::bench_g1_add_assign,
     bls12_377::ec::g1::bench_g1_add_assign_mixed,
     bls12_377::ec::g1::bench_g1_double,
@@ -16,13 +16,16 @@
 
 use std::{ops::Mul, str::FromStr};
 
-use snarkvm_fields::{field, Field, One, Zero};
-use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
+use snarkvm_fields::{field, Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    biginteger::{BigInteger256, BigInteger384},
+    BitIteratorBE,
+};
 
 use crate::{
     bls12_377::{Fq, Fr},
     templates::bls12::Bls12Parameters,
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    traits::{GLVParameters, ModelParameters, ShortWeierstrassParameters},
     ProjectiveCurve,
 };
 
@@ -80,6 +83,43 @@ impl ShortWeierstrassParameters for Bls12_377G1Parameters {
     }
 }
 
+impl GLVParameters for Bls12_377G1Parameters {
+    /// LAMBDA = x^2 - 1 = 91893752504881257701523279626832445440
+    const LAMBDA: Fr =
+        field!(Fr, BigInteger256([0xae8012cd506fe7e2, 0x8adb5f3b1ec9d536, 0x15b65fe3a66fe319, 0x91f331343200452]));
+    /// PHI = 80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410945
+    const PHI: Fq = field!(
+        Fq,
+        BigInteger384([
+            0xdacd106da5847973,
+            0xd8fe2454bac2a79a,
+            0x1ada4fd6fd832edc,
+            0xfb9868449d150908,
+            0xd63eb8aeea32285e,
+            0x167d6a36f873fd0,
+        ])
+    );
+
+    /// As `LAMBDA = x^2 - 1`, writing `k = q * x^2 + rem` with `rem < x^2` gives
+    /// `k = (q + rem) + q * LAMBDA`, where `q < 2^127` and `q + rem < 2^128`.
+    fn glv_decomposition(k: &Fr) -> (u128, u128) {
+        const X_SQUARED: u128 = (super::Bls12_377Parameters::X[0] as u128) * (super::Bls12_377Parameters::X[0] as u128);
+
+        // Compute `q` and `rem` with a bitwise long division, from the most significant bit.
+        let (mut q, mut rem) = (0u128, 0u128);
+        for bit in BitIteratorBE::new_without_leading_zeros(k.to_repr()) {
+            // `rem < X_SQUARED < 2^127`, so the shift does not overflow.
+            rem = (rem << 1) | bit as u128;
+            q <<= 1;
+            if rem >= X_SQUARED {
+                rem -= X_SQUARED;
+                q |= 1;
+            }
+        }
+        (q + rem, q)
+    }
+}
+
 ///
 /// G1_GENERATOR_X =
 /// 89363714989903307245735717098563574705733591463163614225748337416674727625843187853442697973404985688481508350822
@@ -144,4 +184,69 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_mul_outside_subgroup() {
+        let rng = &mut TestRng::default();
+
+        // Sample a point on the curve that is not in the prime-order subgroup.
+        let p = loop {
+            if let Some(p) = G1Affine::from_x_coordinate(Fq::rand(rng), rng.gen()) {
+                if !Bls12_377G1Parameters::is_in_correct_subgroup_assuming_on_curve(&p) {
+                    break p;
+                }
+            }
+        };
+
+        for _ in 0..100 {
+            let k = Fr::rand(rng);
+            assert_eq!(p.to_projective() * k, p.mul_bits(BitIteratorBE::new(k.to_repr())));
+        }
+    }
+
+    #[test]
+    fn test_glv_decomposition() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..1000 {
+            let k = Fr::rand(rng);
+            let (k1, k2) = Bls12_377G1Parameters::glv_decomposition(&k);
+            assert!(k2 < 1u128 << 127);
+
+            let k1 = Fr::from(k1 as u64) + Fr::from((k1 >> 64) as u64) * Fr::from(2u64).pow([64]);
+            let k2 = Fr::from(k2 as u64) + Fr::from((k2 >> 64) as u64) * Fr::from(2u64).pow([64]);
+            assert_eq!(k, k1 + k2 * Bls12_377G1Parameters::LAMBDA);
+        }
+    }
+
+    #[test]
+    fn test_glv_endomorphism() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let p = G1Affine::rand(rng);
+            let expected = p.mul_bits(BitIteratorBE::new(Bls12_377G1Parameters::LAMBDA.to_repr()));
+            assert_eq!(Bls12_377G1Parameters::glv_endomorphism(&p).to_projective(), expected);
+            assert_eq!(Bls12_377G1Parameters::glv_endomorphism_projective(&p.to_projective()), expected);
+        }
+    }
+
+    #[test]
+    fn test_glv_mul() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let p = G1Affine::rand(rng);
+            let k = Fr::rand(rng);
+            let expected = p.mul_bits(BitIteratorBE::new(k.to_repr()));
+            assert_eq!(Bls12_377G1Parameters::glv_mul(p.to_projective(), k), expected);
+            assert_eq!(p.to_projective() * k, expected);
+            assert_eq!(p.to_projective().mul_glv(k), expected);
+        }
+
+        let p = G1Affine::rand(rng).to_projective();
+        assert!(Bls12_377G1Parameters::glv_mul(p, Fr::zero()).is_zero());
+        assert_eq!(Bls12_377G1Parameters::glv_mul(p, Fr::one()), p);
+        assert_eq!(Bls12_377G1Parameters::glv_mul(p, -Fr::one()), -p);
+    }
 }
@@ -0,0 +1,185 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::{
+        bls12::TwistType,
+        bw6::Bw6Parameters,
+        short_weierstrass_jacobian::{Affine, Projective},
+    },
+    traits::{AffineCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{Field, One, Zero};
+use snarkvm_utilities::{bititerator::BitIteratorBE, serialize::*, ToBytes};
+
+use std::io::{Result as IoResult, Write};
+
+pub type G2Affine<P> = Affine<<P as Bw6Parameters>::G2Parameters>;
+pub type G2Projective<P> = Projective<<P as Bw6Parameters>::G2Parameters>;
+type CoeffTriplet<T> = (T, T, T);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct G2Prepared<P: Bw6Parameters> {
+    // Stores the coefficients of the line evaluations of the two Miller loops, as calculated in
+    // https://eprint.iacr.org/2013/722.pdf
+    pub ell_coeffs_1: Vec<CoeffTriplet<P::Fp>>,
+    pub ell_coeffs_2: Vec<CoeffTriplet<P::Fp>>,
+    pub infinity: bool,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct G2HomProjective<P: Bw6Parameters> {
+    x: P::Fp,
+    y: P::Fp,
+    z: P::Fp,
+}
+
+impl<P: Bw6Parameters> Default for G2Prepared<P> {
+    fn default() -> Self {
+        Self::from_affine(G2Affine::<P>::prime_subgroup_generator())
+    }
+}
+
+impl<P: Bw6Parameters> ToBytes for G2Prepared<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        for ell_coeffs in [&self.ell_coeffs_1, &self.ell_coeffs_2] {
+            (ell_coeffs.len() as u32).write_le(&mut writer)?;
+            for coeff in ell_coeffs {
+                coeff.0.write_le(&mut writer)?;
+                coeff.1.write_le(&mut writer)?;
+                coeff.2.write_le(&mut writer)?;
+            }
+        }
+        self.infinity.write_le(writer)
+    }
+}
+
+impl<P: Bw6Parameters> FromBytes for G2Prepared<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut read_ell_coeffs = || -> IoResult<Vec<CoeffTriplet<P::Fp>>> {
+            let ell_coeffs_len: u32 = FromBytes::read_le(&mut reader)?;
+            let mut ell_coeffs = Vec::with_capacity(ell_coeffs_len as usize);
+            for _ in 0..ell_coeffs_len {
+                let coeff_1: P::Fp = FromBytes::read_le(&mut reader)?;
+                let coeff_2: P::Fp = FromBytes::read_le(&mut reader)?;
+                let coeff_3: P::Fp = FromBytes::read_le(&mut reader)?;
+                ell_coeffs.push((coeff_1, coeff_2, coeff_3));
+            }
+            Ok(ell_coeffs)
+        };
+        let ell_coeffs_1 = read_ell_coeffs()?;
+        let ell_coeffs_2 = read_ell_coeffs()?;
+
+        let infinity: bool = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { ell_coeffs_1, ell_coeffs_2, infinity })
+    }
+}
+
+impl<P: Bw6Parameters> G2Prepared<P> {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine<P>) -> Self {
+        if q.is_zero() {
+            return Self { ell_coeffs_1: vec![], ell_coeffs_2: vec![], infinity: true };
+        }
+
+        // `one_half` = 1/2 in the field.
+        let one_half = P::Fp::half();
+
+        // The coefficients of the first Miller loop, over `x + 1`.
+        let mut r = G2HomProjective { x: q.x, y: q.y, z: P::Fp::one() };
+
+        let bit_iterator = BitIteratorBE::new(P::ATE_LOOP_COUNT_1);
+        let mut ell_coeffs_1 = Vec::with_capacity(bit_iterator.len());
+
+        for i in bit_iterator.skip(1) {
+            ell_coeffs_1.push(doubling_step::<P>(&mut r, &one_half));
+
+            if i {
+                ell_coeffs_1.push(addition_step::<P>(&mut r, &q));
+            }
+        }
+
+        // The coefficients of the second Miller loop, over `x^3 - x^2 - x`.
+        let mut r = G2HomProjective { x: q.x, y: q.y, z: P::Fp::one() };
+        let negative_q = -q;
+
+        let mut ell_coeffs_2 = Vec::with_capacity(P::ATE_LOOP_COUNT_2.len());
+
+        for digit in P::ATE_LOOP_COUNT_2.iter().rev().skip(1) {
+            ell_coeffs_2.push(doubling_step::<P>(&mut r, &one_half));
+
+            match digit {
+                1 => ell_coeffs_2.push(addition_step::<P>(&mut r, &q)),
+                -1 => ell_coeffs_2.push(addition_step::<P>(&mut r, &negative_q)),
+                _ => continue,
+            }
+        }
+
+        Self { ell_coeffs_1, ell_coeffs_2, infinity: false }
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+fn doubling_step<B: Bw6Parameters>(r: &mut G2HomProjective<B>, two_inv: &B::Fp) -> CoeffTriplet<B::Fp> {
+    // Formula for line function when working with
+    // homogeneous projective coordinates.
+
+    let a = r.x * r.y * two_inv;
+    let b = r.y.square();
+    let c = r.z.square();
+    let e = B::G2Parameters::WEIERSTRASS_B * (c.double() + c);
+    let f = e.double() + e;
+    let g = (b + f) * two_inv;
+    let h = (r.y + r.z).square() - (b + c);
+    let i = e - b;
+    let j = r.x.square();
+    let e_square = e.square();
+
+    r.x = a * (b - f);
+    r.y = g.square() - (e_square.double() + e_square);
+    r.z = b * h;
+    match B::TWIST_TYPE {
+        TwistType::M => (i, j.double() + j, -h),
+        TwistType::D => (-h, j.double() + j, i),
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+fn addition_step<B: Bw6Parameters>(r: &mut G2HomProjective<B>, q: &G2Affine<B>) -> CoeffTriplet<B::Fp> {
+    // Formula for line function when working with
+    // homogeneous projective coordinates.
+    let theta = r.y - (q.y * r.z);
+    let lambda = r.x - (q.x * r.z);
+    let c = theta.square();
+    let d = lambda.square();
+    let e = lambda * d;
+    let f = r.z * c;
+    let g = r.x * d;
+    let h = e + f - g.double();
+    r.x = lambda * h;
+    r.y = theta * (g - h) - (e * r.y);
+    r.z *= &e;
+    let j = theta * q.x - (lambda * q.y);
+
+    match B::TWIST_TYPE {
+        TwistType::M => (j, -theta, lambda),
+        TwistType::D => (lambda, -theta, j),
+    }
+}
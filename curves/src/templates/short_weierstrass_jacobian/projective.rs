@@ -16,7 +16,7 @@
 
 use crate::{
     templates::short_weierstrass_jacobian::Affine,
-    traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+    traits::{AffineCurve, GLVParameters, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
 use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::Uniform, serialize::*, FromBytes, ToBytes};
@@ -45,6 +45,16 @@ impl<P: Parameters> Projective<P> {
     }
 }
 
+impl<P: GLVParameters> Projective<P> {
+    /// Returns `self * scalar`, computed with the GLV endomorphism.
+    ///
+    /// Unlike `Mul`, which uses double-and-add, this is only correct if `self` is in the prime-order subgroup.
+    #[inline]
+    pub fn mul_glv(self, scalar: P::ScalarField) -> Self {
+        P::glv_mul(self, scalar)
+    }
+}
+
 impl<P: Parameters> Zero for Projective<P> {
     // The point at infinity is always represented by Z = 0.
     #[inline]
@@ -286,6 +286,59 @@ pub trait ShortWeierstrassParameters: ModelParameters {
     fn is_in_correct_subgroup_assuming_on_curve(p: &short_weierstrass_jacobian::Affine<Self>) -> bool;
 }
 
+/// The parameters of a GLV endomorphism `phi(x, y) = (PHI * x, y)` on a short Weierstrass curve,
+/// which acts on the prime-order subgroup as multiplication by `LAMBDA`.
+///
+/// The endomorphism splits a scalar multiplication into two of half the bit length,
+/// see <https://www.iacr.org/archive/crypto2001/21390189.pdf>.
+pub trait GLVParameters: ShortWeierstrassParameters {
+    /// A primitive cube root of unity in the base field.
+    const PHI: Self::BaseField;
+    /// A primitive cube root of unity in the scalar field, such that `phi(P) = LAMBDA * P`.
+    const LAMBDA: Self::ScalarField;
+
+    /// Decomposes the scalar `k` into `(k1, k2)`, such that `k = k1 + k2 * LAMBDA mod r`.
+    fn glv_decomposition(k: &Self::ScalarField) -> (u128, u128);
+
+    /// Returns `phi(p)`.
+    #[inline]
+    fn glv_endomorphism(p: &short_weierstrass_jacobian::Affine<Self>) -> short_weierstrass_jacobian::Affine<Self> {
+        short_weierstrass_jacobian::Affine::new(p.x * Self::PHI, p.y, p.infinity)
+    }
+
+    /// Returns `phi(p)`. As `x = X / Z^2` in Jacobian coordinates, it suffices to scale `X`.
+    #[inline]
+    fn glv_endomorphism_projective(
+        p: &short_weierstrass_jacobian::Projective<Self>,
+    ) -> short_weierstrass_jacobian::Projective<Self> {
+        short_weierstrass_jacobian::Projective::new(p.x * Self::PHI, p.y, p.z)
+    }
+
+    /// Returns `base * scalar`, computed as `base * k1 + phi(base) * k2` with a joint double-and-add.
+    ///
+    /// The endomorphism only acts as multiplication by `LAMBDA` on the prime-order subgroup,
+    /// so unlike `Mul`, the result is only correct if `base` is in the prime-order subgroup.
+    fn glv_mul(
+        base: short_weierstrass_jacobian::Projective<Self>,
+        scalar: Self::ScalarField,
+    ) -> short_weierstrass_jacobian::Projective<Self> {
+        let (k1, k2) = Self::glv_decomposition(&scalar);
+
+        let endomorphism = Self::glv_endomorphism_projective(&base);
+        let table = [base, endomorphism, base + endomorphism];
+
+        let mut res = short_weierstrass_jacobian::Projective::zero();
+        for i in (0..128 - (k1 | k2).leading_zeros()).rev() {
+            res.double_in_place();
+            match (((k1 >> i) & 1) as usize) | ((((k2 >> i) & 1) as usize) << 1) {
+                0 => {}
+                index => res += table[index - 1],
+            }
+        }
+        res
+    }
+}
+
 pub trait TwistedEdwardsParameters: ModelParameters {
     /// The coefficient `A` of the twisted Edwards curve.
     const EDWARDS_A: Self::BaseField;
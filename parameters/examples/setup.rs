@@ -20,13 +20,15 @@ use snarkvm::{
 };
 use snarkvm_algorithms::{
     crypto_hash::sha256::sha256,
+    polycommit::kzg10::UniversalParams,
     snark::marlin::{ahp::AHPForR1CS, MarlinHidingMode},
     SNARK,
 };
+use snarkvm_curves::PairingEngine;
 
 use anyhow::Result;
 use serde_json::{json, Value};
-use snarkvm_utilities::ToBytes;
+use snarkvm_utilities::{CanonicalSerialize, Compress, ToBytes};
 use std::{
     fs::File,
     io::{BufWriter, Read, Write},
@@ -85,17 +87,34 @@ pub fn kzg_powers_metadata() {
     }
 }
 
-/// Runs the trial SRS setup. (cargo run --release --example setup trial_srs 524288)
-pub fn trial_srs<N: Network>(num_gates: usize) -> Result<()> {
+/// Writes the given universal SRS as the trial SRS, along with its metadata.
+fn write_trial_srs<E: PairingEngine>(universal_srs: &UniversalParams<E>) -> Result<()> {
     const TRIAL_SRS_METADATA: &str = "universal.srs.trial.metadata";
     const TRIAL_SRS: &str = "universal.srs.trial";
 
+    let mut srs_bytes = vec![];
+    universal_srs.serialize_with_mode(&mut srs_bytes, Compress::No)?;
+
+    let srs_checksum = checksum(&srs_bytes);
+
+    let srs_metadata = json!({
+        "checksum": srs_checksum,
+        "size": srs_bytes.len(),
+    });
+
+    println!("{}", serde_json::to_string_pretty(&srs_metadata)?);
+    write_metadata(TRIAL_SRS_METADATA, &srs_metadata)?;
+    write_remote(TRIAL_SRS, &srs_checksum, &srs_bytes)?;
+
+    Ok(())
+}
+
+/// Runs the trial SRS setup. (cargo run --release --example setup trial_srs 524288)
+pub fn trial_srs<N: Network>(num_gates: usize) -> Result<()> {
     let mut rng = snarkvm_utilities::TestRng::fixed(1245897092);
 
     use snarkvm_algorithms::{crypto_hash::PoseidonSponge, snark::marlin};
     use snarkvm_console::network::Environment;
-    use snarkvm_curves::PairingEngine;
-    use snarkvm_utilities::{CanonicalSerialize, Compress};
 
     type Fq<N> = <<N as Environment>::PairingCurve as PairingEngine>::Fq;
     type Fr<N> = <N as Environment>::Field;
@@ -107,21 +126,30 @@ pub fn trial_srs<N: Network>(num_gates: usize) -> Result<()> {
     let universal_srs = Marlin::<N>::universal_setup(&max_degree, &mut rng)?;
     println!("Called universal setup: {} ms", timer.elapsed().as_millis());
 
-    let mut srs_bytes = vec![];
-    universal_srs.serialize_with_mode(&mut srs_bytes, Compress::No)?;
+    write_trial_srs(&universal_srs)
+}
 
-    let srs_checksum = checksum(&srs_bytes);
+/// Imports the transcript of a phase 1 powers-of-tau ceremony with `2^power` powers as the trial SRS.
+/// (cargo run --release --example setup import_srs transcript 20)
+pub fn import_srs<N: Network>(path: &str, power: u32) -> Result<()> {
+    use snarkvm_algorithms::polycommit::kzg10::PowersOfTau;
+    use std::io::BufReader;
 
-    let srs_metadata = json!({
-        "checksum": srs_checksum,
-        "size": srs_bytes.len(),
-    });
+    let timer = std::time::Instant::now();
+    let reader = BufReader::new(File::open(path)?);
+    let transcript = PowersOfTau::<N::PairingCurve>::read(reader, 1 << power, Compress::No)?;
+    println!("Loaded transcript {}: {} ms", hex::encode(transcript.hash), timer.elapsed().as_millis());
 
-    println!("{}", serde_json::to_string_pretty(&srs_metadata)?);
-    write_metadata(TRIAL_SRS_METADATA, &srs_metadata)?;
-    write_remote(TRIAL_SRS, &srs_checksum, &srs_bytes)?;
+    // The verifier randomness must not be predictable by the producer of the transcript.
+    let timer = std::time::Instant::now();
+    transcript.verify(&mut rand::thread_rng())?;
+    println!("Verified transcript: {} ms", timer.elapsed().as_millis());
 
-    Ok(())
+    let max_degree = transcript.max_degree();
+    let universal_srs = transcript.into_universal_params(max_degree)?;
+    println!("Derived universal SRS of degree {max_degree}");
+
+    write_trial_srs(&universal_srs)
 }
 
 /// Synthesizes the circuit keys for the credits program. (cargo run --release --example setup credits)
@@ -196,6 +224,10 @@ pub fn main() -> Result<()> {
 
     match args[1].as_str() {
         "trial_srs" => trial_srs::<Testnet3>(args[2].as_str().parse::<usize>()?)?,
+        "import_srs" => {
+            let power = args.get(3).map(|power| power.parse::<u32>()).transpose()?.unwrap_or(28);
+            import_srs::<Testnet3>(args[2].as_str(), power)?
+        }
         "credits" => credits_program::<Testnet3, snarkvm::circuit::AleoV0>()?,
         _ => panic!("Invalid parameter"),
     };
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, Index, LinearCombination, Namespace, Variable};
+use crate::{errors::SynthesisError, Index, LinearCombination, LookupTable, Namespace, Variable};
 use snarkvm_fields::Field;
 
 use std::marker::PhantomData;
@@ -68,6 +68,34 @@ pub trait ConstraintSystem<F: Field>: Sized {
         LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
         LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>;
 
+    /// Registers the given lookup table with the constraint system, returning its identifier.
+    /// Constraint systems that do not support lookups return an error.
+    fn add_lookup_table(&mut self, _table: LookupTable<F>) -> Result<usize, SynthesisError> {
+        Err(SynthesisError::LookupsUnsupported)
+    }
+
+    /// Enforce that (`A`, `B`, `C`) is a row of the lookup table with the given identifier.
+    /// The `annotation` function is invoked in testing contexts in order to derive a unique
+    /// name for the lookup in the current namespace.
+    /// Constraint systems that do not support lookups return an error.
+    fn enforce_lookup<A, AR, LA, LB, LC>(
+        &mut self,
+        _annotation: A,
+        _table_index: usize,
+        _a: LA,
+        _b: LB,
+        _c: LC,
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        Err(SynthesisError::LookupsUnsupported)
+    }
+
     /// Create a new (sub)namespace and enter into it. Not intended
     /// for downstream use; use `namespace` instead.
     fn push_namespace<NR, N>(&mut self, name_fn: N)
@@ -149,6 +177,30 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for &mut CS {
         (**self).enforce(annotation, a, b, c)
     }
 
+    #[inline]
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> Result<usize, SynthesisError> {
+        (**self).add_lookup_table(table)
+    }
+
+    #[inline]
+    fn enforce_lookup<A, AR, LA, LB, LC>(
+        &mut self,
+        annotation: A,
+        table_index: usize,
+        a: LA,
+        b: LB,
+        c: LC,
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        (**self).enforce_lookup(annotation, table_index, a, b, c)
+    }
+
     #[inline]
     fn push_namespace<NR, N>(&mut self, name_fn: N)
     where
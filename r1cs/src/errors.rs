@@ -49,6 +49,12 @@ pub enum SynthesisError {
     /// During CRS generation, we observed an unconstrained auxiliary variable
     #[error("Auxiliary variable was unconstrained")]
     UnconstrainedVariable,
+    /// During synthesis, a lookup constraint was given to a constraint system that does not support lookups.
+    #[error("Lookup constraints are not supported by this constraint system")]
+    LookupsUnsupported,
+    /// During synthesis, a lookup constraint referenced a lookup table that was not registered.
+    #[error("Lookup table {} does not exist", _0)]
+    MissingLookupTable(usize),
 }
 
 impl From<std::io::Error> for SynthesisError {
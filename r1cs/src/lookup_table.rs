@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::Field;
+
+use indexmap::IndexMap;
+
+/// A lookup table, given as a set of rows `(a, b, c)` that a lookup constraint may be resolved against.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LookupTable<F: Field> {
+    /// The rows of the table, mapped to their position in the table.
+    rows: IndexMap<(F, F, F), usize>,
+}
+
+impl<F: Field> LookupTable<F> {
+    /// Initializes a new, empty lookup table.
+    pub fn new() -> Self {
+        Self { rows: Default::default() }
+    }
+
+    /// Appends the given row to the lookup table, returning its position in the table.
+    /// If the row already exists, the table is left unchanged and the existing position is returned.
+    pub fn insert(&mut self, a: F, b: F, c: F) -> usize {
+        let position = self.rows.len();
+        *self.rows.entry((a, b, c)).or_insert(position)
+    }
+
+    /// Returns the position of the given row in the table, if it exists.
+    pub fn lookup(&self, a: &F, b: &F, c: &F) -> Option<usize> {
+        self.rows.get(&(*a, *b, *c)).copied()
+    }
+
+    /// Returns `true` if the given row exists in the table.
+    pub fn contains(&self, a: &F, b: &F, c: &F) -> bool {
+        self.rows.contains_key(&(*a, *b, *c))
+    }
+
+    /// Returns the number of rows in the table.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns an iterator over the rows of the table, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &(F, F, F)> {
+        self.rows.keys()
+    }
+}
+
+impl<F: Field> FromIterator<(F, F, F)> for LookupTable<F> {
+    /// Initializes a lookup table from the given rows.
+    fn from_iter<I: IntoIterator<Item = (F, F, F)>>(iter: I) -> Self {
+        let mut table = Self::new();
+        for (a, b, c) in iter {
+            table.insert(a, b, c);
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_lookup_table() {
+        let (zero, one) = (Fr::zero(), Fr::one());
+
+        let table: LookupTable<Fr> = [(zero, zero, zero), (zero, one, one), (one, zero, one), (one, one, zero)]
+            .into_iter()
+            .collect();
+        assert_eq!(table.len(), 4);
+        assert_eq!(table.lookup(&one, &zero, &one), Some(2));
+        assert!(table.contains(&one, &one, &zero));
+        assert!(!table.contains(&one, &one, &one));
+
+        // Inserting an existing row does not change the table.
+        let mut table = table;
+        assert_eq!(table.insert(zero, one, one), 1);
+        assert_eq!(table.len(), 4);
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, ConstraintSystem, LinearCombination, Variable};
+use crate::{errors::SynthesisError, ConstraintSystem, LinearCombination, LookupTable, Variable};
 use snarkvm_fields::Field;
 
 use std::marker::PhantomData;
@@ -63,6 +63,30 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for Namespace<'_, F,
         self.0.enforce(annotation, a, b, c)
     }
 
+    #[inline]
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> Result<usize, SynthesisError> {
+        self.0.add_lookup_table(table)
+    }
+
+    #[inline]
+    fn enforce_lookup<A, AR, LA, LB, LC>(
+        &mut self,
+        annotation: A,
+        table_index: usize,
+        a: LA,
+        b: LB,
+        c: LC,
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.0.enforce_lookup(annotation, table_index, a, b, c)
+    }
+
     // Downstream users who use `namespace` will never interact with these
     // functions and they will never be invoked because the namespace is
     // never a root constraint system.
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, Variable};
+use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, LookupTable, Variable};
 use snarkvm_fields::Field;
 
 /// Constraint system for testing purposes.
@@ -27,6 +27,8 @@ pub struct TestConstraintChecker<F: Field> {
     found_unsatisfactory_constraint: bool,
     // number of constraints
     num_constraints: usize,
+    // the list of registered lookup tables
+    lookup_tables: Vec<LookupTable<F>>,
     // constraint path segments in the stack
     segments: Vec<String>,
     // the first unsatisfied constraint
@@ -40,6 +42,7 @@ impl<F: Field> Default for TestConstraintChecker<F> {
             private_variables: vec![],
             found_unsatisfactory_constraint: false,
             num_constraints: 0,
+            lookup_tables: vec![],
             segments: vec![],
             first_unsatisfied_constraint: None,
         }
@@ -69,6 +72,32 @@ impl<F: Field> TestConstraintChecker<F> {
     pub fn public_inputs(&self) -> Vec<F> {
         self.public_variables[1..].to_vec()
     }
+
+    /// Evaluates the given linear combination on the current assignment.
+    fn eval_lc(&self, lc: LinearCombination<F>) -> F {
+        lc.0.into_iter()
+            .map(|(var, coeff)| {
+                let value = match var.get_unchecked() {
+                    Index::Public(index) => self.public_variables[index],
+                    Index::Private(index) => self.private_variables[index],
+                };
+                value * coeff
+            })
+            .sum::<F>()
+    }
+
+    /// Records the first unsatisfied constraint, with the given annotation.
+    fn set_unsatisfied(&mut self, annotation: &str) {
+        if self.first_unsatisfied_constraint.is_none() {
+            self.found_unsatisfactory_constraint = true;
+
+            assert!(!annotation.contains('/'), "'/' is not allowed in names");
+
+            let mut path = self.segments.clone();
+            path.push(annotation.to_string());
+            self.first_unsatisfied_constraint = Some(path.join("/"));
+        }
+    }
 }
 
 impl<F: Field> ConstraintSystem<F> for TestConstraintChecker<F> {
@@ -110,32 +139,46 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintChecker<F> {
     {
         self.num_constraints += 1;
 
-        let eval_lc = |lc: Vec<(Variable, F)>| -> F {
-            lc.into_iter()
-                .map(|(var, coeff)| {
-                    let value = match var.get_unchecked() {
-                        Index::Public(index) => self.public_variables[index],
-                        Index::Private(index) => self.private_variables[index],
-                    };
-                    value * coeff
-                })
-                .sum::<F>()
-        };
-
-        let a = eval_lc(a(LinearCombination::zero()).0);
-        let b = eval_lc(b(LinearCombination::zero()).0);
-        let c = eval_lc(c(LinearCombination::zero()).0);
-
-        if a * b != c && self.first_unsatisfied_constraint.is_none() {
-            self.found_unsatisfactory_constraint = true;
+        let a = self.eval_lc(a(LinearCombination::zero()));
+        let b = self.eval_lc(b(LinearCombination::zero()));
+        let c = self.eval_lc(c(LinearCombination::zero()));
 
-            let new = annotation().as_ref().to_string();
-            assert!(!new.contains('/'), "'/' is not allowed in names");
+        if a * b != c {
+            self.set_unsatisfied(annotation().as_ref());
+        }
+    }
 
-            let mut path = self.segments.clone();
-            path.push(new);
-            self.first_unsatisfied_constraint = Some(path.join("/"));
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> Result<usize, SynthesisError> {
+        self.lookup_tables.push(table);
+        Ok(self.lookup_tables.len() - 1)
+    }
+
+    fn enforce_lookup<A, AR, LA, LB, LC>(
+        &mut self,
+        annotation: A,
+        table_index: usize,
+        a: LA,
+        b: LB,
+        c: LC,
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.num_constraints += 1;
+
+        let a = self.eval_lc(a(LinearCombination::zero()));
+        let b = self.eval_lc(b(LinearCombination::zero()));
+        let c = self.eval_lc(c(LinearCombination::zero()));
+
+        let table = self.lookup_tables.get(table_index).ok_or(SynthesisError::MissingLookupTable(table_index))?;
+        if !table.contains(&a, &b, &c) {
+            self.set_unsatisfied(annotation().as_ref());
         }
+        Ok(())
     }
 
     fn push_namespace<NR: AsRef<str>, N: FnOnce() -> NR>(&mut self, name_fn: N) {
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, OptionalVec, Variable};
+use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, LookupTable, OptionalVec, Variable};
 use snarkvm_fields::Field;
 
 use cfg_if::cfg_if;
@@ -61,6 +61,8 @@ pub struct TestConstraint {
     a: Vec<(Variable, InternedField)>,
     b: Vec<(Variable, InternedField)>,
     c: Vec<(Variable, InternedField)>,
+    // the identifier of the lookup table, if this is a lookup constraint
+    lookup_table: Option<usize>,
 }
 
 #[derive(Default, Debug)]
@@ -100,6 +102,8 @@ pub struct TestConstraintSystem<F: Field> {
     public_variables: OptionalVec<InternedField>,
     // the list of currently applicable auxiliary variables
     private_variables: OptionalVec<InternedField>,
+    // the list of registered lookup tables
+    lookup_tables: Vec<LookupTable<F>>,
 }
 
 impl<F: Field> Default for TestConstraintSystem<F> {
@@ -138,6 +142,7 @@ impl<F: Field> Default for TestConstraintSystem<F> {
             constraints,
             public_variables: inputs,
             private_variables: Default::default(),
+            lookup_tables: Default::default(),
         }
     }
 }
@@ -227,14 +232,20 @@ impl<F: Field> TestConstraintSystem<F> {
     }
 
     pub fn which_is_unsatisfied(&self) -> Option<String> {
-        for TestConstraint { interned_path, a, b, c } in self.constraints.iter() {
+        for TestConstraint { interned_path, a, b, c, lookup_table } in self.constraints.iter() {
             let mut a = self.eval_lc(a.as_ref());
             let b = self.eval_lc(b.as_ref());
             let c = self.eval_lc(c.as_ref());
 
-            a.mul_assign(&b);
+            let is_satisfied = match lookup_table {
+                Some(table_index) => self.lookup_tables[*table_index].contains(&a, &b, &c),
+                None => {
+                    a.mul_assign(&b);
+                    a == c
+                }
+            };
 
-            if a != c {
+            if !is_satisfied {
                 return Some(self.unintern_path(*interned_path));
             }
         }
@@ -300,6 +311,39 @@ impl<F: Field> TestConstraintSystem<F> {
     }
 
     #[inline]
+    /// Registers a new constraint in the current namespace.
+    /// If `lookup_table` is given, the constraint is a lookup into the corresponding table.
+    fn insert_constraint<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC, lookup_table: Option<usize>)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let interned_path = self.compute_path(annotation().as_ref());
+        let index = self.constraints.next_idx();
+        let named_obj = NamedObject::Constraint(index);
+        self.register_object_in_namespace(named_obj.clone());
+        self.set_named_obj(interned_path, named_obj);
+
+        let mut intern_fields = |uninterned: Vec<(Variable, F)>| -> Vec<(Variable, InternedField)> {
+            uninterned
+                .into_iter()
+                .map(|(var, field)| {
+                    let interned_field = self.interned_fields.insert_full(field).0;
+                    (var, interned_field)
+                })
+                .collect()
+        };
+
+        let a = intern_fields(a(LinearCombination::zero()).0);
+        let b = intern_fields(b(LinearCombination::zero()).0);
+        let c = intern_fields(c(LinearCombination::zero()).0);
+
+        self.constraints.insert(TestConstraint { interned_path, a, b, c, lookup_table });
+    }
+
     fn set_named_obj(&mut self, interned_path: InternedPath, to: NamedObject) -> NamespaceIndex {
         match self.named_objects.entry(interned_path) {
             Entry::Vacant(e) => {
@@ -417,27 +461,34 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
         LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
         LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
     {
-        let interned_path = self.compute_path(annotation().as_ref());
-        let index = self.constraints.next_idx();
-        let named_obj = NamedObject::Constraint(index);
-        self.register_object_in_namespace(named_obj.clone());
-        self.set_named_obj(interned_path, named_obj);
-
-        let mut intern_fields = |uninterned: Vec<(Variable, F)>| -> Vec<(Variable, InternedField)> {
-            uninterned
-                .into_iter()
-                .map(|(var, field)| {
-                    let interned_field = self.interned_fields.insert_full(field).0;
-                    (var, interned_field)
-                })
-                .collect()
-        };
+        self.insert_constraint(annotation, a, b, c, None);
+    }
 
-        let a = intern_fields(a(LinearCombination::zero()).0);
-        let b = intern_fields(b(LinearCombination::zero()).0);
-        let c = intern_fields(c(LinearCombination::zero()).0);
+    fn add_lookup_table(&mut self, table: LookupTable<F>) -> Result<usize, SynthesisError> {
+        self.lookup_tables.push(table);
+        Ok(self.lookup_tables.len() - 1)
+    }
 
-        self.constraints.insert(TestConstraint { interned_path, a, b, c });
+    fn enforce_lookup<A, AR, LA, LB, LC>(
+        &mut self,
+        annotation: A,
+        table_index: usize,
+        a: LA,
+        b: LB,
+        c: LC,
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        if table_index >= self.lookup_tables.len() {
+            return Err(SynthesisError::MissingLookupTable(table_index));
+        }
+        self.insert_constraint(annotation, a, b, c, Some(table_index));
+        Ok(())
     }
 
     fn push_namespace<NR: AsRef<str>, N: FnOnce() -> NR>(&mut self, name_fn: N) {
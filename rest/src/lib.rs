@@ -22,6 +22,9 @@ extern crate tracing;
 mod helpers;
 pub use helpers::*;
 
+mod prover;
+pub use prover::*;
+
 mod routes;
 pub use routes::*;
 
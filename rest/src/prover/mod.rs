@@ -0,0 +1,247 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod routes;
+
+use crate::{with, OrReject, RestError};
+use snarkvm_circuit::Aleo;
+use snarkvm_compiler::{Authorization, Deployment, Execution, KeyCache, Process, Program, ProvingKey, VerifyingKey};
+use snarkvm_console::{
+    prelude::*,
+    program::{Identifier, ProgramID, Request, Value},
+    types::Field,
+};
+
+use anyhow::{ensure, Result};
+use core::marker::PhantomData;
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{net::SocketAddr, sync::Arc};
+use tokio::task::JoinHandle;
+use warp::{reject, reply, Filter, Rejection, Reply};
+
+/// The processes of the requested programs, keyed by the checksum of the program and its imports.
+type Processes<N> = RwLock<IndexMap<Field<N>, Arc<RwLock<Process<N>>>>>;
+
+/// A REST API server that synthesizes circuit keys and generates proofs on behalf of its clients,
+/// so that they may offload heavy proving to a shared machine.
+///
+/// The server accepts the `BuildRequest`s and `DeployRequest`s that are sent by `Package::build`
+/// and `Package::deploy`, along with `ExecuteRequest`s for authorized function calls.
+#[derive(Clone)]
+pub struct Prover<N: Network, A: Aleo<Network = N>> {
+    /// The process, which contains the programs and circuit keys that are common to all requests.
+    process: Arc<Process<N>>,
+    /// The processes of the requested programs, which share their circuit keys across requests.
+    processes: Arc<Processes<N>>,
+    /// The address of the server.
+    address: SocketAddr,
+    /// The server handles.
+    handles: Vec<Arc<JoinHandle<()>>>,
+    /// PhantomData.
+    _phantom: PhantomData<A>,
+}
+
+impl<N: Network, A: Aleo<Network = N> + Send + Sync> Prover<N, A> {
+    /// Initializes a new instance of the prover, with an optional cache of circuit keys on disk.
+    /// If the given port is `0`, the server listens on a port assigned by the operating system.
+    pub fn start(key_cache: Option<KeyCache<N>>, custom_port: Option<u16>) -> Result<Self> {
+        // Initialize the process.
+        let process = match key_cache {
+            Some(key_cache) => Process::load_with_key_cache(key_cache)?,
+            None => Process::load()?,
+        };
+
+        // Initialize the prover.
+        let mut prover = Self {
+            process: Arc::new(process),
+            processes: Default::default(),
+            address: ([0, 0, 0, 0], custom_port.unwrap_or(80)).into(),
+            handles: vec![],
+            _phantom: PhantomData,
+        };
+        // Spawn the server.
+        prover.spawn_server()?;
+
+        // Return the prover.
+        Ok(prover)
+    }
+
+    /// Returns the process.
+    pub fn process(&self) -> Arc<Process<N>> {
+        self.process.clone()
+    }
+
+    /// Returns the address of the server.
+    pub const fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns the handles.
+    pub fn handles(&self) -> &Vec<Arc<JoinHandle<()>>> {
+        &self.handles
+    }
+}
+
+impl<N: Network, A: Aleo<Network = N> + Send + Sync> Prover<N, A> {
+    /// Initializes the server.
+    fn spawn_server(&mut self) -> Result<()> {
+        // Initialize the routes.
+        let routes = self.routes();
+        // Bind the server, so that the address is in use once the prover is started.
+        let (address, server) = warp::serve(routes).try_bind_ephemeral(self.address)?;
+        self.address = address;
+        println!("\n🌐 Prover is running at http://{address}\n");
+        // Spawn the server.
+        self.handles.push(Arc::new(tokio::spawn(server)));
+        Ok(())
+    }
+
+    /// Returns the process for the given program and its imports, which is shared by all requests for them.
+    /// The first request adds the program and its imports to a copy of the given process.
+    /// Note: The imports must be given in the order they are to be added (i.e. dependencies first).
+    fn load_program(
+        process: &Process<N>,
+        processes: &Processes<N>,
+        program: &Program<N>,
+        imports: &[Program<N>],
+    ) -> Result<Arc<RwLock<Process<N>>>> {
+        // Compute the checksum of the program and its imports.
+        let mut bytes = program.to_bytes_le()?;
+        for import in imports {
+            bytes.extend(import.to_bytes_le()?);
+        }
+        let checksum = N::hash_bhp1024(&bytes.to_bits_le())?;
+
+        // Return the process for the program, if it was requested before.
+        if let Some(process) = processes.read().get(&checksum) {
+            return Ok(process.clone());
+        }
+
+        // Copy the process, so that requests for other programs do not observe this program.
+        let mut process = process.clone();
+        // Add the imports and the program to the process.
+        for program in imports.iter().chain([program]) {
+            match process.contains_program(program.id()) {
+                // Ensure the program matches the one in the process.
+                true => ensure!(
+                    process.get_program(program.id())? == program,
+                    "Program '{}' does not match the program in the prover",
+                    program.id()
+                ),
+                false => process.add_program(program)?,
+            }
+        }
+        // Note: If a concurrent request added the program in the meantime, its process is used instead.
+        Ok(processes.write().entry(checksum).or_insert_with(|| Arc::new(RwLock::new(process))).clone())
+    }
+}
+
+/// Runs the given closure on the blocking thread pool, so that proving does not stall the server.
+async fn spawn_blocking<T: 'static + Send>(closure: impl 'static + FnOnce() -> Result<T> + Send) -> Result<T> {
+    tokio::task::spawn_blocking(closure).await?
+}
+
+/// The request to synthesize the circuit keys of a function, as sent by `BuildRequest::send`.
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct BuildRequest<N: Network> {
+    program: Program<N>,
+    imports: Vec<Program<N>>,
+    function_name: Identifier<N>,
+}
+
+/// The synthesized circuit keys of a function, as expected by `BuildRequest::send`.
+#[derive(Serialize)]
+#[serde(bound = "")]
+struct BuildResponse<N: Network> {
+    program_id: ProgramID<N>,
+    function_name: Identifier<N>,
+    proving_key: ProvingKey<N>,
+    verifying_key: VerifyingKey<N>,
+}
+
+/// The request to verify a deployment, as sent by `DeployRequest::send`.
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct DeployRequest<N: Network> {
+    deployment: Deployment<N>,
+    program_id: ProgramID<N>,
+}
+
+/// The verified deployment, as expected by `DeployRequest::send`.
+#[derive(Serialize)]
+#[serde(bound = "")]
+struct DeployResponse<N: Network> {
+    deployment: Deployment<N>,
+}
+
+/// The request to prove an authorized function call.
+#[derive(Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct ExecuteRequest<N: Network> {
+    /// The program of the function.
+    program: Program<N>,
+    /// The imports of the program, in the order they are to be added (i.e. dependencies first).
+    imports: Vec<Program<N>>,
+    /// The signed requests of the authorization (see `Process::authorize`).
+    requests: Vec<Request<N>>,
+}
+
+impl<N: Network> ExecuteRequest<N> {
+    /// Initializes a new execute request.
+    pub fn new(program: Program<N>, imports: Vec<Program<N>>, authorization: &Authorization<N>) -> Self {
+        Self { program, imports, requests: authorization.to_vec_deque().into() }
+    }
+
+    /// Returns the program.
+    pub const fn program(&self) -> &Program<N> {
+        &self.program
+    }
+
+    /// Returns the imports.
+    pub const fn imports(&self) -> &Vec<Program<N>> {
+        &self.imports
+    }
+
+    /// Returns the signed requests.
+    pub const fn requests(&self) -> &Vec<Request<N>> {
+        &self.requests
+    }
+}
+
+/// The outputs and execution of a proven function call.
+#[derive(Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct ExecuteResponse<N: Network> {
+    /// The outputs of the function.
+    outputs: Vec<Value<N>>,
+    /// The execution of the function.
+    execution: Execution<N>,
+}
+
+impl<N: Network> ExecuteResponse<N> {
+    /// Returns the outputs.
+    pub const fn outputs(&self) -> &Vec<Value<N>> {
+        &self.outputs
+    }
+
+    /// Returns the execution.
+    pub const fn execution(&self) -> &Execution<N> {
+        &self.execution
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, A: Aleo<Network = N> + Send + Sync> Prover<N, A> {
+    /// Initializes the routes, given the process.
+    pub fn routes(&self) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+        // GET /{network}/build
+        let build = warp::get()
+            .and(warp::path(N::SHORT_NAME))
+            .and(warp::path("build"))
+            .and(warp::path::end())
+            .and(warp::body::content_length_limit(10 * 1024 * 1024))
+            .and(warp::body::json())
+            .and(with(self.process.clone()))
+            .and(with(self.processes.clone()))
+            .and_then(Self::build);
+
+        // POST /{network}/deploy
+        let deploy = warp::post()
+            .and(warp::path(N::SHORT_NAME))
+            .and(warp::path("deploy"))
+            .and(warp::path::end())
+            .and(warp::body::content_length_limit(10 * 1024 * 1024))
+            .and(warp::body::json())
+            .and(with(self.process.clone()))
+            .and_then(Self::deploy);
+
+        // POST /{network}/execute
+        let execute = warp::post()
+            .and(warp::path(N::SHORT_NAME))
+            .and(warp::path("execute"))
+            .and(warp::path::end())
+            .and(warp::body::content_length_limit(10 * 1024 * 1024))
+            .and(warp::body::json())
+            .and(with(self.process.clone()))
+            .and(with(self.processes.clone()))
+            .and_then(Self::execute);
+
+        // Return the list of routes.
+        build.or(deploy).or(execute)
+    }
+}
+
+impl<N: Network, A: Aleo<Network = N> + Send + Sync> Prover<N, A> {
+    /// Returns the proving and verifying key for the requested function.
+    async fn build(
+        request: BuildRequest<N>,
+        process: Arc<Process<N>>,
+        processes: Arc<Processes<N>>,
+    ) -> Result<impl Reply, Rejection> {
+        let response = spawn_blocking(move || {
+            // Prepare the program ID and function name.
+            let program_id = *request.program.id();
+            let function_name = request.function_name;
+
+            // Retrieve the process for the program and its imports.
+            let process = Self::load_program(&process, &processes, &request.program, &request.imports)?;
+            let process = process.read();
+            // Synthesize the proving and verifying key, if they were not requested before.
+            if !process.get_stack(&program_id)?.contains_proving_key(&function_name) {
+                process.synthesize_key::<A, _>(&program_id, &function_name, &mut rand::thread_rng())?;
+            }
+
+            Ok(BuildResponse {
+                program_id,
+                function_name,
+                proving_key: process.get_proving_key(&program_id, &function_name)?,
+                verifying_key: process.get_verifying_key(&program_id, &function_name)?,
+            })
+        })
+        .await
+        .or_reject()?;
+
+        Ok(reply::json(&response))
+    }
+
+    /// Returns the requested deployment, if it is valid.
+    async fn deploy(request: DeployRequest<N>, process: Arc<Process<N>>) -> Result<impl Reply, Rejection> {
+        // Ensure the program ID matches.
+        if request.deployment.program_id() != &request.program_id {
+            return Err(reject::custom(RestError::Request(format!(
+                "Program ID mismatch: {} != {}",
+                request.deployment.program_id(),
+                request.program_id
+            ))));
+        }
+
+        let response = spawn_blocking(move || {
+            // Verify the deployment.
+            process.verify_deployment::<A, _>(&request.deployment, &mut rand::thread_rng())?;
+
+            Ok(DeployResponse { deployment: request.deployment })
+        })
+        .await
+        .or_reject()?;
+
+        Ok(reply::json(&response))
+    }
+
+    /// Returns the outputs and execution for the requested function call.
+    async fn execute(
+        request: ExecuteRequest<N>,
+        process: Arc<Process<N>>,
+        processes: Arc<Processes<N>>,
+    ) -> Result<impl Reply, Rejection> {
+        let response = spawn_blocking(move || {
+            // Retrieve the process for the program and its imports.
+            let process = Self::load_program(&process, &processes, &request.program, &request.imports)?;
+            let process = process.read();
+            // Prove the function call.
+            // Note: The process synthesizes (or loads from the key cache) any missing circuit keys.
+            let authorization = Authorization::new(&request.requests);
+            let (response, execution) = process.execute::<A, _>(authorization, &mut rand::thread_rng())?;
+
+            Ok(ExecuteResponse { outputs: response.outputs().to_vec(), execution })
+        })
+        .await
+        .or_reject()?;
+
+        Ok(reply::json(&response))
+    }
+}
@@ -26,6 +26,35 @@ use bincode::Options;
 
 use std::{borrow::Cow, collections::BTreeMap, marker::PhantomData, rc::Rc, sync::Arc};
 
+impl Valid for () {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl CanonicalSerialize for () {
+    #[inline]
+    fn serialize_with_mode<W: Write>(&self, _writer: W, _compress: Compress) -> Result<(), SerializationError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        0
+    }
+}
+
+impl CanonicalDeserialize for () {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        _reader: R,
+        _compress: Compress,
+        _validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(())
+    }
+}
+
 impl Valid for bool {
     fn check(&self) -> Result<(), SerializationError> {
         Ok(())
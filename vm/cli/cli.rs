@@ -14,10 +14,28 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::cli::Updater;
+use crate::{
+    circuit::AleoV0,
+    cli::Updater,
+    compiler::{KeyCache, Program},
+    console::{
+        account::{Address, Message, PrivateKey, Signature},
+        network::Testnet3,
+    },
+    package::Package,
+    rest::Prover,
+};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use clap::Parser;
+use colored::Colorize;
+use core::str::FromStr;
+use std::path::PathBuf;
+
+type CurrentNetwork = Testnet3;
+
+/// The environment variable that holds the private key of the signer.
+const PRIVATE_KEY_VARIABLE: &str = "ALEO_PRIVATE_KEY";
 
 #[derive(Debug, Parser)]
 #[clap(name = "snarkVM", author = "The Aleo Team <hello@aleo.org>", setting = clap::AppSettings::ColoredHelp)]
@@ -41,6 +59,45 @@ pub enum Command {
         #[clap(short = 'q', long)]
         quiet: bool,
     },
+    /// Sign a message or file with an account private key, to prove ownership of the address
+    Sign {
+        /// The path of the file containing the private key of the signer [default: the `ALEO_PRIVATE_KEY` variable]
+        #[clap(long, parse(from_os_str))]
+        private_key_file: Option<PathBuf>,
+        /// The message to sign
+        #[clap(short, long)]
+        message: Option<String>,
+        /// The path of the file to sign
+        #[clap(short, long, parse(from_os_str))]
+        file: Option<PathBuf>,
+    },
+    /// Verify the signature of a message or file for an address
+    Verify {
+        /// The address of the signer
+        #[clap(long)]
+        address: String,
+        /// The signature to verify
+        #[clap(long)]
+        signature: String,
+        /// The signed message
+        #[clap(short, long)]
+        message: Option<String>,
+        /// The path of the signed file
+        #[clap(short, long, parse(from_os_str))]
+        file: Option<PathBuf>,
+    },
+    /// Check a program for unused inputs, dead instructions, and other inefficiencies
+    Check {
+        /// The path of the program file, or of the package directory
+        #[clap(parse(from_os_str))]
+        path: PathBuf,
+    },
+    /// Start a prover, which builds circuit keys and proves executions for other machines
+    Prover {
+        /// The port to listen on [default: 80]
+        #[clap(short, long)]
+        port: Option<u16>,
+    },
 }
 
 impl Command {
@@ -72,6 +129,208 @@ impl Command {
                     }
                 }
             }, // _ => Err(anyhow!("\nUnknown command\n")),
+            Command::Sign { private_key_file, message, file } => {
+                // Load the private key.
+                let private_key = Self::read_private_key(private_key_file)?;
+                // Load the message.
+                let message = Message::<CurrentNetwork>::new(&Self::read_payload(message, file)?)?;
+                // Sign the message.
+                let signature = message.sign(&private_key, &mut rand::thread_rng())?;
+                Ok(signature.to_string())
+            }
+            Command::Verify { address, signature, message, file } => {
+                // Load the address and signature.
+                let address = Address::<CurrentNetwork>::from_str(address)?;
+                let signature = Signature::<CurrentNetwork>::from_str(signature)?;
+                // Load the message.
+                let message = Message::<CurrentNetwork>::new(&Self::read_payload(message, file)?)?;
+                // Verify the signature.
+                match message.verify(&signature, &address) {
+                    true => Ok(format!("✅ The signature is valid for '{}'", address.to_string().bold())),
+                    false => bail!("The signature is invalid for '{address}'"),
+                }
+            }
+            Command::Check { path } => {
+                // Load the program, from either the package directory or the program file.
+                let program = match path.is_dir() {
+                    true => Package::<CurrentNetwork>::open(path)?.program().clone(),
+                    false => Program::<CurrentNetwork>::from_str(&std::fs::read_to_string(path)?)?,
+                };
+                // Lint the program.
+                let lints = program.lint();
+                match lints.is_empty() {
+                    true => Ok(format!("✅ Found no warnings in '{}'", program.id().to_string().bold())),
+                    false => Ok(format!(
+                        "{}\n\n⚠️  Found {} warning(s) in '{}'",
+                        lints.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"),
+                        lints.len(),
+                        program.id().to_string().bold()
+                    )),
+                }
+            }
+            Command::Prover { port } => {
+                // Open the key cache, so that circuit keys persist across restarts.
+                let key_cache = match KeyCache::open_default() {
+                    Ok(key_cache) => Some(key_cache),
+                    Err(error) => {
+                        eprintln!("Failed to open the key cache, so circuit keys will not be cached: {error}");
+                        None
+                    }
+                };
+                // Run the prover until the process is stopped.
+                tokio::runtime::Runtime::new()?.block_on(async {
+                    let _prover = Prover::<CurrentNetwork, AleoV0>::start(key_cache, *port)?;
+                    std::future::pending::<Result<String>>().await
+                })
+            }
         }
     }
+
+    /// Returns the private key from the given file, or from the `ALEO_PRIVATE_KEY` environment variable.
+    /// The private key is never read from the command line, as it would leak into the shell history.
+    fn read_private_key(file: &Option<PathBuf>) -> Result<PrivateKey<CurrentNetwork>> {
+        let private_key = match file {
+            Some(file) => std::fs::read_to_string(file)?,
+            None => match std::env::var(PRIVATE_KEY_VARIABLE) {
+                Ok(private_key) => private_key,
+                Err(_) => bail!("Specify '--private-key-file', or set the '{PRIVATE_KEY_VARIABLE}' variable"),
+            },
+        };
+        PrivateKey::from_str(private_key.trim())
+    }
+
+    /// Returns the payload from either the given message or the contents of the given file.
+    fn read_payload(message: &Option<String>, file: &Option<PathBuf>) -> Result<Vec<u8>> {
+        let max_size = Message::<CurrentNetwork>::MAX_PAYLOAD_SIZE;
+        let payload = match (message, file) {
+            (Some(message), None) => message.as_bytes().to_vec(),
+            (None, Some(file)) => {
+                // Ensure the file is within bounds, before reading it.
+                if std::fs::metadata(file)?.len() > max_size as u64 {
+                    bail!("The file '{}' exceeds the maximum message size of {max_size} bytes", file.display())
+                }
+                std::fs::read(file)?
+            }
+            _ => bail!("Specify exactly one of '--message' or '--file'"),
+        };
+        // Ensure the payload is within bounds.
+        match payload.len() <= max_size {
+            true => Ok(payload),
+            false => bail!("The message exceeds the maximum message size of {max_size} bytes"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        // Sample a private key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng())?;
+        let address = Address::try_from(&private_key)?;
+        let message = Some("I am the owner of this address.".to_string());
+
+        // Write the private key to a temporary file.
+        let directory = tempfile::tempdir()?;
+        let private_key_file = directory.path().join("private_key");
+        std::fs::write(&private_key_file, format!("{private_key}\n"))?;
+
+        // Sign the message.
+        let command = Command::Sign { private_key_file: Some(private_key_file), message: message.clone(), file: None };
+        let signature = command.start()?;
+
+        // Verify the signature.
+        let command =
+            Command::Verify { address: address.to_string(), signature: signature.clone(), message, file: None };
+        assert!(command.start().is_ok());
+
+        // Check that the signature is invalid for an incorrect message.
+        let message = Some("I am not the owner of this address.".to_string());
+        let command = Command::Verify { address: address.to_string(), signature, message, file: None };
+        assert!(command.start().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check() -> Result<()> {
+        // Write a program with an unused input to a temporary file.
+        let directory = tempfile::tempdir()?;
+        let path = directory.path().join("main.aleo");
+        std::fs::write(
+            &path,
+            "program check.aleo;\n\nfunction compute:\n    input r0 as u32.private;\n    input r1 as u32.private;\n    \
+             add r0 r0 into r2;\n    output r2 as u32.private;\n",
+        )?;
+
+        // Check the program.
+        let output = Command::Check { path }.start()?;
+        assert!(output.contains("warning[unused-input]: function 'compute': Input 'r1' is never read"));
+        assert!(output.contains("Found 1 warning(s)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_prover_build_request() -> Result<()> {
+        use crate::{
+            circuit::network::AleoDevnet,
+            console::{network::Devnet, prelude::Network, program::Identifier},
+            package::BuildRequest,
+        };
+
+        // Start a prover on a port assigned by the operating system.
+        // Note: The devnet synthesizes circuit keys locally, so the prover does not download any keys.
+        let runtime = tokio::runtime::Runtime::new()?;
+        let _guard = runtime.enter();
+        let prover = Prover::<Devnet, AleoDevnet>::start(None, Some(0))?;
+        let endpoint = format!("http://127.0.0.1:{}/{}/build", prover.address().port(), Devnet::SHORT_NAME);
+
+        // Send a build request for a function.
+        let program = Program::<Devnet>::from_str(
+            "program prover.aleo;\n\nfunction compute:\n    input r0 as u32.private;\n    \
+             add r0 r0 into r1;\n    output r1 as u32.private;\n",
+        )?;
+        let function_name = Identifier::from_str("compute")?;
+        let request = BuildRequest::new(program.clone(), vec![], function_name);
+        let response = request.send(&endpoint)?;
+        assert_eq!(response.program_id(), program.id());
+        assert_eq!(response.function_name(), &function_name);
+
+        // Check that a repeated request reuses the circuit keys of the first request.
+        let repeated_response = request.send(&endpoint)?;
+        assert!(response.verifying_key() == repeated_response.verifying_key());
+
+        // Check that a request for another network is rejected.
+        let endpoint = format!("http://127.0.0.1:{}/{}/build", prover.address().port(), Testnet3::SHORT_NAME);
+        assert!(request.send(&endpoint).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_payload() {
+        // Check that exactly one source must be specified.
+        assert!(Command::read_payload(&None, &None).is_err());
+        assert!(Command::read_payload(&Some("a".to_string()), &Some(PathBuf::from("a"))).is_err());
+        assert_eq!(b"a".to_vec(), Command::read_payload(&Some("a".to_string()), &None).unwrap());
+    }
+
+    #[test]
+    fn test_read_payload_exceeds_max_payload_size() -> Result<()> {
+        let max_size = Message::<CurrentNetwork>::MAX_PAYLOAD_SIZE;
+
+        // Check that an oversized message is rejected.
+        assert!(Command::read_payload(&Some("a".repeat(max_size + 1)), &None).is_err());
+
+        // Check that an oversized file is rejected.
+        let directory = tempfile::tempdir()?;
+        let file = directory.path().join("payload");
+        std::fs::write(&file, vec![0u8; max_size + 1])?;
+        assert!(Command::read_payload(&None, &Some(file.clone())).is_err());
+
+        // Check that a file of the maximum size is accepted.
+        std::fs::write(&file, vec![0u8; max_size])?;
+        assert_eq!(max_size, Command::read_payload(&None, &Some(file))?.len());
+        Ok(())
+    }
 }
@@ -18,6 +18,9 @@ use super::*;
 use crate::ProgramStorage;
 
 impl<N: Network> Block<N> {
+    /// The number of transactions in a genesis block.
+    pub const NUM_GENESIS_TRANSACTIONS: usize = 2;
+
     /// Initializes a new genesis block.
     pub fn genesis<P: ProgramStorage<N>, R: Rng + CryptoRng>(
         vm: &VM<N, P>,
@@ -30,15 +33,20 @@ impl<N: Network> Block<N> {
         let program_id = FromStr::from_str("credits.aleo")?;
         // Prepare the function name.
         let function_name = FromStr::from_str("genesis")?;
-        // Prepare the function inputs.
-        let inputs = [Value::from_str(&caller.to_string())?, Value::from_str("1_100_000_000_000_000_u64")?];
-        // Authorize the call to start.
-        let authorization = vm.authorize(private_key, &program_id, function_name, &inputs, rng)?;
-        // Execute the genesis function.
-        let transaction = Transaction::execute_authorization(vm, authorization, rng)?;
+        // Prepare the function inputs, which mint half of the starting supply.
+        // Note: The starting supply is minted in two records, so that the caller can pay
+        // the additional fee of a transaction from a record other than the one it spends.
+        let inputs = [Value::from_str(&caller.to_string())?, Value::from_str("550_000_000_000_000_u64")?];
 
         // Prepare the transactions.
-        let transactions = Transactions::from(&[transaction]);
+        let mut transactions = Vec::with_capacity(Self::NUM_GENESIS_TRANSACTIONS);
+        for _ in 0..Self::NUM_GENESIS_TRANSACTIONS {
+            // Authorize the call to start.
+            let authorization = vm.authorize(private_key, &program_id, function_name, &inputs, rng)?;
+            // Execute the genesis function.
+            transactions.push(Transaction::execute_authorization(vm, authorization, rng)?);
+        }
+        let transactions = Transactions::from(&transactions);
         // Prepare the block header.
         let header = Header::genesis(&transactions)?;
         // Prepare the previous block hash.
@@ -59,8 +67,9 @@ impl<N: Network> Block<N> {
         self.previous_hash == N::BlockHash::default()
             // Ensure the header is a genesis block header.
             && self.header.is_genesis()
-            // Ensure there is 1 transaction in the genesis block.
-            && self.transactions.len() == 1
+            // Ensure there are 1 to `NUM_GENESIS_TRANSACTIONS` transactions in the genesis block.
+            // Note: The Testnet3 genesis block mints the starting supply in a single transaction.
+            && (1..=Self::NUM_GENESIS_TRANSACTIONS).contains(&self.transactions.len())
     }
 }
 
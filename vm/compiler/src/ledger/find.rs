@@ -25,18 +25,13 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
         view_key: &'a ViewKey<N>,
         filter: RecordsFilter<N>,
     ) -> Result<impl '_ + Iterator<Item = (Field<N>, Cow<'_, Record<N, Ciphertext<N>>>)>> {
-        // Derive the address from the view key.
-        let address = view_key.to_address();
-        // Derive the `sk_tag` from the graph key.
-        let sk_tag = match GraphKey::try_from(view_key) {
-            Ok(graph_key) => graph_key.sk_tag(),
-            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        // Derive the watch key from the view key.
+        let watch_key = match WatchKey::try_from(view_key) {
+            Ok(watch_key) => watch_key,
+            Err(e) => bail!("Failed to derive the watch key from the view key: {e}"),
         };
-
-        /// A helper method to derive the tag from the `sk_tag` and commitment.
-        fn compute_tag<N: Network>(sk_tag: Field<N>, commitment: Field<N>) -> Result<Field<N>> {
-            N::hash_psd2(&[sk_tag, commitment])
-        }
+        // Retrieve the address from the watch key.
+        let address = watch_key.address();
 
         /// A helper method to derive the serial number from the private key and commitment.
         fn compute_serial_number<N: Network>(private_key: PrivateKey<N>, commitment: Field<N>) -> Result<Field<N>> {
@@ -61,20 +56,20 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             // Determine whether to decrypt this record (or not), based on the filter.
             let commitment = match filter {
                 RecordsFilter::All => Ok(Some(commitment)),
-                RecordsFilter::Spent => compute_tag(sk_tag, commitment).and_then(|tag| {
-                    // Determine if the record is spent.
-                    self.contains_tag(&tag).map(|is_spent| match is_spent {
+                // Determine if the record is spent.
+                RecordsFilter::Spent => {
+                    watch_key.is_spent(commitment, |tag| self.contains_tag(tag)).map(|is_spent| match is_spent {
                         true => Some(commitment),
                         false => None,
                     })
-                }),
-                RecordsFilter::Unspent => compute_tag(sk_tag, commitment).and_then(|tag| {
-                    // Determine if the record is spent.
-                    self.contains_tag(&tag).map(|is_spent| match is_spent {
+                }
+                // Determine if the record is spent.
+                RecordsFilter::Unspent => {
+                    watch_key.is_spent(commitment, |tag| self.contains_tag(tag)).map(|is_spent| match is_spent {
                         true => None,
                         false => Some(commitment),
                     })
-                }),
+                }
                 RecordsFilter::SlowSpent(private_key) => {
                     compute_serial_number(private_key, commitment).and_then(|serial_number| {
                         // Determine if the record is spent.
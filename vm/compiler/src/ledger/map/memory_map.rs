@@ -174,24 +174,32 @@ impl<
 
     ///
     /// Returns `true` if the given key exists in the map.
+    /// If an atomic batch is in progress, the queued operations are taken into account.
     ///
     fn contains_key<Q>(&self, key: &Q) -> Result<bool>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
     {
-        Ok(self.map.read().contains_key(key))
+        match self.get_queued(key) {
+            Some(value) => Ok(value.is_some()),
+            None => Ok(self.map.read().contains_key(key)),
+        }
     }
 
     ///
     /// Returns the value for the given key from the map, if it exists.
+    /// If an atomic batch is in progress, the queued operations are taken into account.
     ///
     fn get<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
     {
-        Ok(self.map.read().get(key).cloned().map(Cow::Owned))
+        match self.get_queued(key) {
+            Some(value) => Ok(value.map(Cow::Owned)),
+            None => Ok(self.map.read().get(key).cloned().map(Cow::Owned)),
+        }
     }
 
     ///
@@ -216,6 +224,34 @@ impl<
     }
 }
 
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MemoryMap<K, V>
+{
+    ///
+    /// Returns the latest queued operation for the given key, if an atomic batch is in progress.
+    /// The outer option is `None` if the key has no queued operation, and the inner option is `None`
+    /// if the latest queued operation removes the key.
+    ///
+    fn get_queued<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // If no atomic batch is in progress, there are no queued operations.
+        if !self.batch_in_progress.load(Ordering::SeqCst) {
+            return None;
+        }
+        // Find the latest queued operation for the key.
+        self.atomic_batch.lock().iter().rev().find_map(|operation| match operation {
+            BatchOperation::Insert(k, v) if k.borrow() == key => Some(Some(v.clone())),
+            BatchOperation::Remove(k) if k.borrow() == key => Some(None),
+            _ => None,
+        })
+    }
+}
+
 impl<
     K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
     V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
@@ -295,6 +331,11 @@ mod tests {
 
         // The map should still contains all the items.
         assert_eq!(map.iter().count(), NUM_ITEMS);
+        // However, reads within the batch should reflect the queued removals.
+        for i in 0..NUM_ITEMS {
+            assert!(!map.contains_key(&i).unwrap());
+            assert_eq!(map.get(&i).unwrap(), None);
+        }
 
         // Finish the current atomic write batch.
         map.finish_atomic().unwrap();
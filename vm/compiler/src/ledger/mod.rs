@@ -43,12 +43,13 @@ mod latest;
 
 use crate::program::Program;
 use console::{
-    account::{Address, GraphKey, PrivateKey, Signature, ViewKey},
+    account::{Address, PrivateKey, Signature, ViewKey, WatchKey},
     collections::merkle_tree::MerklePath,
     network::{prelude::*, BHPMerkleTree},
     program::{Ciphertext, Identifier, Plaintext, ProgramID, Record},
     types::{Field, Group},
 };
+#[cfg(test)]
 use snarkvm_parameters::testnet3::GenesisBytes;
 
 use anyhow::Result;
@@ -112,7 +113,11 @@ impl<N: Network> Ledger<N, BlockMemory<N>, ProgramMemory<N>> {
     /// Initializes a new instance of `Ledger` with the genesis block.
     pub fn new(dev: Option<u16>) -> Result<Self> {
         // Load the genesis block.
-        let genesis = Block::<N>::from_bytes_le(GenesisBytes::load_bytes())?;
+        crate::supply_network_bytes();
+        let genesis = match N::genesis_bytes()? {
+            Some(genesis_bytes) => Block::<N>::from_bytes_le(genesis_bytes)?,
+            None => bail!("'{}' does not provide a genesis block, use 'Ledger::new_with_genesis' instead", N::NAME),
+        };
         // Initialize the ledger.
         Self::new_with_genesis(&genesis, genesis.signature().to_address(), dev)
     }
@@ -191,7 +196,13 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             // If there are no previous hashes, add the genesis block.
             None => {
                 // Load the genesis block.
-                let genesis = Block::<N>::from_bytes_le(GenesisBytes::load_bytes())?;
+                crate::supply_network_bytes();
+                let genesis = match N::genesis_bytes()? {
+                    Some(genesis_bytes) => Block::<N>::from_bytes_le(genesis_bytes)?,
+                    None => {
+                        bail!("'{}' does not provide a genesis block, use 'Ledger::new_with_genesis' instead", N::NAME)
+                    }
+                };
                 // Add the genesis block.
                 ledger.blocks.insert(&genesis)?;
                 // Return the genesis height.
@@ -466,8 +477,17 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             bail!("Cannot validate a block with more than {} transactions", Transactions::<N>::MAX_TRANSACTIONS);
         }
 
-        // Ensure each transaction is well-formed and unique.
-        if !block.transactions().par_iter().all(|(_, transaction)| self.check_transaction(transaction).is_ok()) {
+        // Ensure each transaction is well-formed, verifying all of the proofs in the block with a single pairing check.
+        if !self.vm.verify_many(&block.transactions().values().collect::<Vec<_>>()) {
+            bail!("Invalid transaction found in the transactions list");
+        }
+
+        // Ensure each transaction is unique.
+        if !block
+            .transactions()
+            .par_iter()
+            .all(|(_, transaction)| self.check_transaction_is_unique(transaction).is_ok())
+        {
             bail!("Invalid transaction found in the transactions list");
         }
 
@@ -492,6 +512,15 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
             }
         }
 
+        // Ensure each transaction pays for its cost, where each transaction is priced after
+        // the deployments that precede it in the block are applied.
+        if height > 0 {
+            let minimum_fees = self.vm.estimate_fees(block.transactions().values())?;
+            for (transaction, minimum_fee) in block.transactions().values().zip_eq(minimum_fees) {
+                Self::check_transaction_pays(transaction, minimum_fee)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -664,12 +693,45 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
 
     /// Checks the given transaction is well formed and unique.
     pub fn check_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
-        let transaction_id = transaction.id();
-
         // Ensure the transaction is valid.
         if !self.vm.verify(transaction) {
-            bail!("Transaction '{transaction_id}' is invalid")
+            bail!("Transaction '{}' is invalid", transaction.id())
+        }
+        // Ensure the transaction is unique.
+        self.check_transaction_is_unique(transaction)?;
+        // Ensure the transaction pays for its cost.
+        self.check_transaction_fee(transaction)
+    }
+
+    /// Checks the given transaction pays a fee that covers its cost.
+    fn check_transaction_fee(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Compute the minimum fee for the transaction.
+        let minimum_fee = self.vm.estimate_fee(transaction)?;
+        // Ensure the transaction fee covers the minimum fee.
+        Self::check_transaction_pays(transaction, minimum_fee)
+    }
+
+    /// Checks the given transaction pays at least the given minimum fee.
+    fn check_transaction_pays(transaction: &Transaction<N>, minimum_fee: u64) -> Result<()> {
+        let fee = transaction.fee()?;
+        if fee >= 0 && (fee as u64) >= minimum_fee {
+            return Ok(());
         }
+        let transaction_id = transaction.id();
+        match transaction {
+            // Ensure an execution whose transitions do not cover its cost pays an additional fee.
+            Transaction::Execute(_, _, None) => bail!(
+                "Transaction '{transaction_id}' pays {fee} gates, but its minimum fee is {minimum_fee} gates, \
+                so it requires an additional fee"
+            ),
+            _ => bail!("Transaction '{transaction_id}' pays {fee} gates, but its minimum fee is {minimum_fee} gates"),
+        }
+    }
+
+    /// Checks the given transaction does not conflict with the ledger.
+    /// This method assumes the given transaction **is valid**.
+    fn check_transaction_is_unique(&self, transaction: &Transaction<N>) -> Result<()> {
+        let transaction_id = transaction.id();
 
         // Ensure the ledger does not already contain the given transaction ID.
         if self.contains_transaction_id(&transaction_id)? {
@@ -741,11 +803,28 @@ impl<N: Network, B: BlockStorage<N>, P: ProgramStorage<N>> Ledger<N, B, P> {
 
         /* Program */
 
-        // Ensure that the ledger does not already contain the given program ID.
+        // Ensure that a new program starts at edition 0, and that an upgrade is the next edition of the program.
         if let Transaction::Deploy(_, deployment, _) = &transaction {
             let program_id = deployment.program_id();
-            if self.contains_program_id(program_id)? {
-                bail!("Program ID '{program_id}' already exists in the ledger")
+            let edition = deployment.edition();
+            match self.transactions.get_program_edition(program_id)? {
+                Some(latest) => {
+                    if latest.checked_add(1) != Some(edition) {
+                        bail!("Program ID '{program_id}' already exists in the ledger at edition {latest}")
+                    }
+                }
+                None => ensure!(edition == 0, "Program ID '{program_id}' must be deployed at edition 0"),
+            }
+        }
+
+        // Ensure that each transition is for the latest edition of its program, as transitions for
+        // a retired edition are only verified as part of the blocks that already contain them.
+        for transition in transaction.transitions() {
+            let program_id = transition.program_id();
+            let latest = self.transactions.get_program_edition(program_id)?.unwrap_or(0);
+            let edition = transition.edition();
+            if edition != latest {
+                bail!("Transition '{}' is for edition {edition} of '{program_id}', not {latest}", transition.id())
             }
         }
 
@@ -1090,6 +1169,11 @@ mod tests {
         let mut ledger =
             Ledger::<_, BlockMemory<_>, ProgramMemory<_>>::new_with_genesis(&genesis, address, None).unwrap();
 
+        // Initialize the number of unspent records, which are minted in the genesis block.
+        let mut num_records = Block::<CurrentNetwork>::NUM_GENESIS_TRANSACTIONS;
+        // Initialize the additional fee, which covers the cost of a split.
+        let additional_fee_in_gates = 1_000_000_000u64;
+
         for height in 1..6 {
             // Fetch the unspent records.
             let records: Vec<_> = ledger
@@ -1097,9 +1181,11 @@ mod tests {
                 .unwrap()
                 .filter(|(_, record)| !record.gates().is_zero())
                 .collect();
-            assert_eq!(records.len(), 1 << (height - 1));
+            assert_eq!(records.len(), num_records);
 
-            for (_, record) in records {
+            // Split the first half of the records, and pay the additional fees with the second half.
+            let (records, credits) = records.split_at(records.len() / 2);
+            for ((_, record), (_, credits)) in records.iter().zip(credits) {
                 // Create a new transaction.
                 let transaction = Transaction::execute(
                     ledger.vm(),
@@ -1110,14 +1196,16 @@ mod tests {
                         Value::Record(record.clone()),
                         Value::from_str(&format!("{}u64", ***record.gates() / 2)).unwrap(),
                     ],
-                    None,
+                    Some((credits.clone(), additional_fee_in_gates)),
                     rng,
                 )
                 .unwrap();
                 // Add the transaction to the memory pool.
                 ledger.add_to_memory_pool(transaction).unwrap();
             }
-            assert_eq!(ledger.memory_pool().len(), 1 << (height - 1));
+            assert_eq!(ledger.memory_pool().len(), num_records / 2);
+            // Each transaction spends two records, and creates three records.
+            num_records += num_records / 2;
 
             // Propose the next block.
             let next_block = ledger.propose_next_block(&private_key, rng).unwrap();
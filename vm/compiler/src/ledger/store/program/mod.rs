@@ -645,6 +645,11 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         self.storage.start_atomic();
     }
 
+    /// Checks if an atomic batch is in progress.
+    pub fn is_atomic_in_progress(&self) -> bool {
+        self.storage.is_atomic_in_progress()
+    }
+
     /// Aborts an atomic batch write operation.
     pub fn abort_atomic(&self) {
         self.storage.abort_atomic();
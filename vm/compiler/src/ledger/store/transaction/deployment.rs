@@ -28,6 +28,7 @@ use crate::{
     snark::{Certificate, VerifyingKey},
 };
 use console::{
+    account::{Address, Signature},
     network::prelude::*,
     program::{Identifier, ProgramID},
 };
@@ -39,8 +40,8 @@ use std::borrow::Cow;
 
 /// A trait for deployment storage.
 pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
-    /// The mapping of `transaction ID` to `program ID`.
-    type IDMap: for<'a> Map<'a, N::TransactionID, ProgramID<N>>;
+    /// The mapping of `transaction ID` to `(program ID, edition)`.
+    type IDMap: for<'a> Map<'a, N::TransactionID, (ProgramID<N>, u16)>;
     /// The mapping of `program ID` to `edition`.
     type EditionMap: for<'a> Map<'a, ProgramID<N>, u16>;
     /// The mapping of `(program ID, edition)` to `transaction ID`.
@@ -51,6 +52,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     type VerifyingKeyMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     /// The mapping of `(program ID, function name, edition)` to `certificate`.
     type CertificateMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    /// The mapping of `(program ID, edition)` to `(owner, signature)`.
+    type OwnerMap: for<'a> Map<'a, (ProgramID<N>, u16), (Address<N>, Signature<N>)>;
     /// The mapping of `transaction ID` to `additional fee ID`.
     type AdditionalFeeMap: for<'a> Map<'a, N::TransactionID, N::TransitionID>;
     /// The transition storage.
@@ -71,6 +74,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn verifying_key_map(&self) -> &Self::VerifyingKeyMap;
     /// Returns the certificate map.
     fn certificate_map(&self) -> &Self::CertificateMap;
+    /// Returns the owner map.
+    fn owner_map(&self) -> &Self::OwnerMap;
     /// Returns the additional fee map.
     fn additional_fee_map(&self) -> &Self::AdditionalFeeMap;
     /// Returns the transition storage.
@@ -89,6 +94,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().start_atomic();
         self.verifying_key_map().start_atomic();
         self.certificate_map().start_atomic();
+        self.owner_map().start_atomic();
         self.additional_fee_map().start_atomic();
         self.transition_store().start_atomic();
     }
@@ -101,6 +107,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             || self.program_map().is_atomic_in_progress()
             || self.verifying_key_map().is_atomic_in_progress()
             || self.certificate_map().is_atomic_in_progress()
+            || self.owner_map().is_atomic_in_progress()
             || self.additional_fee_map().is_atomic_in_progress()
             || self.transition_store().is_atomic_in_progress()
     }
@@ -113,6 +120,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().abort_atomic();
         self.verifying_key_map().abort_atomic();
         self.certificate_map().abort_atomic();
+        self.owner_map().abort_atomic();
         self.additional_fee_map().abort_atomic();
         self.transition_store().abort_atomic();
     }
@@ -125,6 +133,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().finish_atomic()?;
         self.verifying_key_map().finish_atomic()?;
         self.certificate_map().finish_atomic()?;
+        self.owner_map().finish_atomic()?;
         self.additional_fee_map().finish_atomic()?;
         self.transition_store().finish_atomic()
     }
@@ -160,8 +169,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
 
         atomic_write_batch!(self, {
-            // Store the program ID.
-            self.id_map().insert(*transaction_id, program_id)?;
+            // Store the program ID and edition.
+            self.id_map().insert(*transaction_id, (program_id, edition))?;
             // Store the edition.
             self.edition_map().insert(program_id, edition)?;
 
@@ -177,6 +186,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
                 // Store the certificate.
                 self.certificate_map().insert((program_id, *function_name, edition), certificate.clone())?;
             }
+            // Store the owner and signature, if the program is upgradable.
+            if let (Some(owner), Some(signature)) = (deployment.owner(), deployment.signature()) {
+                self.owner_map().insert((program_id, edition), (*owner, *signature))?;
+            }
 
             // Store the additional fee ID.
             self.additional_fee_map().insert(*transaction_id, *additional_fee.id())?;
@@ -191,16 +204,19 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
 
     /// Removes the deployment transaction for the given `transaction ID`.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
-        // Retrieve the program ID.
-        let program_id = match self.get_program_id(transaction_id)? {
-            Some(edition) => edition,
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.id_map().get(transaction_id)? {
+            Some(id) => cow_to_copied!(id),
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
-        // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
-            Some(edition) => edition,
+        // Ensure the edition is the latest edition, as upgrades are removed in reverse order.
+        match self.get_edition(&program_id)? {
+            Some(latest) => ensure!(
+                edition == latest,
+                "Cannot remove edition {edition} of program '{program_id}' before its latest edition {latest}"
+            ),
             None => bail!("Failed to locate the edition for program '{program_id}'"),
-        };
+        }
         // Retrieve the program.
         let program = match self.program_map().get(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -215,8 +231,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_write_batch!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Revert the edition to the previous edition, if one exists.
+            match edition.checked_sub(1) {
+                Some(previous) => self.edition_map().insert(program_id, previous)?,
+                None => self.edition_map().remove(&program_id)?,
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -230,6 +249,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
                 // Remove the certificate.
                 self.certificate_map().remove(&(program_id, *function_name, edition))?;
             }
+            // Remove the owner and signature.
+            self.owner_map().remove(&(program_id, edition))?;
 
             // Remove the additional fee ID.
             self.additional_fee_map().remove(transaction_id)?;
@@ -260,7 +281,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         // Retrieve the program ID.
         match self.id_map().get(transaction_id)? {
-            Some(program_id) => Ok(Some(cow_to_copied!(program_id))),
+            Some(id) => Ok(Some(cow_to_copied!(id).0)),
             None => Ok(None),
         }
     }
@@ -325,16 +346,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the deployment for the given `transaction ID`.
     fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
-        // Retrieve the program ID.
-        let program_id = match self.get_program_id(transaction_id)? {
-            Some(edition) => edition,
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.id_map().get(transaction_id)? {
+            Some(id) => cow_to_copied!(id),
             None => return Ok(None),
         };
-        // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
-            Some(edition) => edition,
-            None => bail!("Failed to get the edition for program '{program_id}'"),
-        };
         // Retrieve the program.
         let program = match self.program_map().get(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -360,8 +376,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             verifying_keys.insert(*function_name, (verifying_key, certificate));
         }
 
+        // Retrieve the owner and signature, if the program is upgradable.
+        let owner = self.owner_map().get(&(program_id, edition))?.map(|owner| cow_to_copied!(owner));
+
         // Return the deployment.
-        Ok(Some(Deployment::new(edition, program, verifying_keys)?))
+        Ok(Some(Deployment::new(edition, program, verifying_keys)?.with_signed_owner(owner)?))
     }
 
     /// Returns the additional fee for the given `transaction ID`.
@@ -405,7 +424,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
 #[derive(Clone)]
 pub struct DeploymentMemory<N: Network> {
     /// The ID map.
-    id_map: MemoryMap<N::TransactionID, ProgramID<N>>,
+    id_map: MemoryMap<N::TransactionID, (ProgramID<N>, u16)>,
     /// The edition map.
     edition_map: MemoryMap<ProgramID<N>, u16>,
     /// The reverse ID map.
@@ -416,6 +435,8 @@ pub struct DeploymentMemory<N: Network> {
     verifying_key_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
     /// The certificate map.
     certificate_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+    /// The owner map.
+    owner_map: MemoryMap<(ProgramID<N>, u16), (Address<N>, Signature<N>)>,
     /// The additional fee map.
     additional_fee_map: MemoryMap<N::TransactionID, N::TransitionID>,
     /// The transition store.
@@ -424,12 +445,13 @@ pub struct DeploymentMemory<N: Network> {
 
 #[rustfmt::skip]
 impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
-    type IDMap = MemoryMap<N::TransactionID, ProgramID<N>>;
+    type IDMap = MemoryMap<N::TransactionID, (ProgramID<N>, u16)>;
     type EditionMap = MemoryMap<ProgramID<N>, u16>;
     type ReverseIDMap = MemoryMap<(ProgramID<N>, u16), N::TransactionID>;
     type ProgramMap = MemoryMap<(ProgramID<N>, u16), Program<N>>;
     type VerifyingKeyMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     type CertificateMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    type OwnerMap = MemoryMap<(ProgramID<N>, u16), (Address<N>, Signature<N>)>;
     type AdditionalFeeMap = MemoryMap<N::TransactionID, N::TransitionID>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -442,6 +464,7 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
             program_map: MemoryMap::default(),
             verifying_key_map: MemoryMap::default(),
             certificate_map: MemoryMap::default(),
+            owner_map: MemoryMap::default(),
             additional_fee_map: MemoryMap::default(),
             transition_store,
         })
@@ -477,6 +500,11 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
         &self.certificate_map
     }
 
+    /// Returns the owner map.
+    fn owner_map(&self) -> &Self::OwnerMap {
+        &self.owner_map
+    }
+
     /// Returns the additional fee map.
     fn additional_fee_map(&self) -> &Self::AdditionalFeeMap {
         &self.additional_fee_map
@@ -619,10 +647,7 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 
     /// Returns an iterator over the program IDs, for all deployments.
     pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
-        self.storage.id_map().values().map(|id| match id {
-            Cow::Borrowed(id) => Cow::Borrowed(id),
-            Cow::Owned(id) => Cow::Owned(id),
-        })
+        self.storage.edition_map().keys()
     }
 
     /// Returns an iterator over the programs, for all deployments.
@@ -26,6 +26,7 @@ use crate::{
         Transition,
     },
     process::Execution,
+    AggregatedProof,
 };
 use console::network::prelude::*;
 
@@ -41,6 +42,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     type ReverseIDMap: for<'a> Map<'a, N::TransitionID, N::TransactionID>;
     /// The mapping of `program ID` to `edition`.
     type EditionMap: for<'a> Map<'a, N::TransactionID, u16>;
+    /// The mapping of `transaction ID` to `(optional) aggregated proof`.
+    type ProofMap: for<'a> Map<'a, N::TransactionID, Option<AggregatedProof<N>>>;
     /// The transition storage.
     type TransitionStorage: TransitionStorage<N>;
 
@@ -53,6 +56,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_id_map(&self) -> &Self::ReverseIDMap;
     /// Returns the edition map.
     fn edition_map(&self) -> &Self::EditionMap;
+    /// Returns the proof map.
+    fn proof_map(&self) -> &Self::ProofMap;
     /// Returns the transition store.
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage>;
 
@@ -66,6 +71,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().start_atomic();
         self.reverse_id_map().start_atomic();
         self.edition_map().start_atomic();
+        self.proof_map().start_atomic();
         self.transition_store().start_atomic();
     }
 
@@ -74,6 +80,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().is_atomic_in_progress()
             || self.reverse_id_map().is_atomic_in_progress()
             || self.edition_map().is_atomic_in_progress()
+            || self.proof_map().is_atomic_in_progress()
             || self.transition_store().is_atomic_in_progress()
     }
 
@@ -82,6 +89,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().abort_atomic();
         self.reverse_id_map().abort_atomic();
         self.edition_map().abort_atomic();
+        self.proof_map().abort_atomic();
         self.transition_store().abort_atomic();
     }
 
@@ -90,6 +98,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().finish_atomic()?;
         self.reverse_id_map().finish_atomic()?;
         self.edition_map().finish_atomic()?;
+        self.proof_map().finish_atomic()?;
         self.transition_store().finish_atomic()
     }
 
@@ -119,6 +128,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             self.id_map().insert(*transaction_id, (transition_ids, optional_additional_fee_id))?;
             // Store the edition.
             self.edition_map().insert(*transaction_id, edition)?;
+            // Store the aggregated proof.
+            self.proof_map().insert(*transaction_id, execution.proof().clone())?;
 
             // Store the execution.
             for transition in transitions {
@@ -155,6 +166,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             self.id_map().remove(transaction_id)?;
             // Remove the edition.
             self.edition_map().remove(transaction_id)?;
+            // Remove the aggregated proof.
+            self.proof_map().remove(transaction_id)?;
 
             // Remove the execution.
             for transition_id in transition_ids {
@@ -211,8 +224,14 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             };
         }
 
+        // Retrieve the aggregated proof.
+        let proof = match self.proof_map().get(transaction_id)? {
+            Some(proof) => cow_to_cloned!(proof),
+            None => bail!("Failed to get the aggregated proof for transaction '{transaction_id}'"),
+        };
+
         // Return the execution.
-        Ok(Some(Execution::from(edition, &transitions)?))
+        Ok(Some(Execution::from(edition, &transitions, proof)?))
     }
 
     /// Returns the transaction for the given `transaction ID`.
@@ -240,8 +259,14 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             };
         }
 
+        // Retrieve the aggregated proof.
+        let proof = match self.proof_map().get(transaction_id)? {
+            Some(proof) => cow_to_cloned!(proof),
+            None => bail!("Failed to get the aggregated proof for transaction '{transaction_id}'"),
+        };
+
         // Construct the execution.
-        let execution = Execution::from(edition, &transitions)?;
+        let execution = Execution::from(edition, &transitions, proof)?;
 
         // Construct the transaction.
         let transaction = match optional_additional_fee_id {
@@ -275,6 +300,8 @@ pub struct ExecutionMemory<N: Network> {
     reverse_id_map: MemoryMap<N::TransitionID, N::TransactionID>,
     /// The edition map.
     edition_map: MemoryMap<N::TransactionID, u16>,
+    /// The proof map.
+    proof_map: MemoryMap<N::TransactionID, Option<AggregatedProof<N>>>,
     /// The transition store.
     transition_store: TransitionStore<N, TransitionMemory<N>>,
 }
@@ -284,6 +311,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, (Vec<N::TransitionID>, Option<N::TransitionID>)>;
     type ReverseIDMap = MemoryMap<N::TransitionID, N::TransactionID>;
     type EditionMap = MemoryMap<N::TransactionID, u16>;
+    type ProofMap = MemoryMap<N::TransactionID, Option<AggregatedProof<N>>>;
     type TransitionStorage = TransitionMemory<N>;
 
     /// Initializes the execution storage.
@@ -292,6 +320,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
             id_map: MemoryMap::default(),
             reverse_id_map: MemoryMap::default(),
             edition_map: MemoryMap::default(),
+            proof_map: MemoryMap::default(),
             transition_store,
         })
     }
@@ -311,6 +340,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
         &self.edition_map
     }
 
+    /// Returns the proof map.
+    fn proof_map(&self) -> &Self::ProofMap {
+        &self.proof_map
+    }
+
     /// Returns the transition store.
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
         &self.transition_store
@@ -359,6 +359,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().get_program_id(transaction_id)
     }
 
+    /// Returns the latest edition for the given `program ID`.
+    pub fn get_program_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
+        self.storage.deployment_store().get_edition(program_id)
+    }
+
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.deployment_store().get_program(program_id)
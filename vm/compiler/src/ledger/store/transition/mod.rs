@@ -45,6 +45,8 @@ use std::borrow::Cow;
 pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     /// The transition program IDs and function names.
     type LocatorMap: for<'a> Map<'a, N::TransitionID, (ProgramID<N>, Identifier<N>)>;
+    /// The transition program editions.
+    type EditionMap: for<'a> Map<'a, N::TransitionID, u16>;
     /// The transition inputs.
     type InputStorage: InputStorage<N>;
     /// The transition outputs.
@@ -52,7 +54,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     /// The transition finalize inputs.
     type FinalizeMap: for<'a> Map<'a, N::TransitionID, Option<Vec<Value<N>>>>;
     /// The transition proofs.
-    type ProofMap: for<'a> Map<'a, N::TransitionID, Proof<N>>;
+    type ProofMap: for<'a> Map<'a, N::TransitionID, Option<Proof<N>>>;
     /// The transition public keys.
     type TPKMap: for<'a> Map<'a, N::TransitionID, Group<N>>;
     /// The mapping of `transition public key` to `transition ID`.
@@ -69,6 +71,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the transition program IDs and function names.
     fn locator_map(&self) -> &Self::LocatorMap;
+    /// Returns the transition program editions.
+    fn edition_map(&self) -> &Self::EditionMap;
     /// Returns the transition input store.
     fn input_store(&self) -> &InputStore<N, Self::InputStorage>;
     /// Returns the transition output store.
@@ -97,6 +101,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.locator_map().start_atomic();
+        self.edition_map().start_atomic();
         self.input_store().start_atomic();
         self.output_store().start_atomic();
         self.finalize_map().start_atomic();
@@ -111,6 +116,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
         self.locator_map().is_atomic_in_progress()
+            || self.edition_map().is_atomic_in_progress()
             || self.input_store().is_atomic_in_progress()
             || self.output_store().is_atomic_in_progress()
             || self.finalize_map().is_atomic_in_progress()
@@ -125,6 +131,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.locator_map().abort_atomic();
+        self.edition_map().abort_atomic();
         self.input_store().abort_atomic();
         self.output_store().abort_atomic();
         self.finalize_map().abort_atomic();
@@ -139,6 +146,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.locator_map().finish_atomic()?;
+        self.edition_map().finish_atomic()?;
         self.input_store().finish_atomic()?;
         self.output_store().finish_atomic()?;
         self.finalize_map().finish_atomic()?;
@@ -165,6 +173,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             let transition_id = *transition.id();
             // Store the program ID and function name.
             self.locator_map().insert(transition_id, (*transition.program_id(), *transition.function_name()))?;
+            // Store the edition of the program.
+            self.edition_map().insert(transition_id, transition.edition())?;
             // Store the inputs.
             self.input_store().insert(transition_id, transition.inputs())?;
             // Store the outputs.
@@ -225,6 +235,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         let run_atomic_ops = || -> Result<()> {
             // Remove the program ID and function name.
             self.locator_map().remove(transition_id)?;
+            // Remove the edition of the program.
+            self.edition_map().remove(transition_id)?;
             // Remove the inputs.
             self.input_store().remove(transition_id)?;
             // Remove the outputs.
@@ -268,6 +280,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             Some(locator) => cow_to_cloned!(locator),
             None => return Ok(None),
         };
+        // Retrieve the edition of the program.
+        let edition = self.edition_map().get(transition_id)?;
         // Retrieve the inputs.
         let inputs = self.input_store().get_inputs(transition_id)?;
         // Retrieve the outputs.
@@ -283,12 +297,13 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the fee.
         let fee = self.fee_map().get(transition_id)?;
 
-        match (finalize, proof, tpk, tcm, fee) {
-            (Some(finalize), Some(proof), Some(tpk), Some(tcm), Some(fee)) => {
+        match (edition, finalize, proof, tpk, tcm, fee) {
+            (Some(edition), Some(finalize), Some(proof), Some(tpk), Some(tcm), Some(fee)) => {
                 // Construct the transition.
                 let transition = Transition::new(
                     program_id,
                     function_name,
+                    cow_to_copied!(edition),
                     inputs,
                     outputs,
                     cow_to_cloned!(finalize),
@@ -313,6 +328,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
 pub struct TransitionMemory<N: Network> {
     /// The transition program IDs and function names.
     locator_map: MemoryMap<N::TransitionID, (ProgramID<N>, Identifier<N>)>,
+    /// The transition program editions.
+    edition_map: MemoryMap<N::TransitionID, u16>,
     /// The transition input store.
     input_store: InputStore<N, InputMemory<N>>,
     /// The transition output store.
@@ -320,7 +337,7 @@ pub struct TransitionMemory<N: Network> {
     /// The transition finalize inputs.
     finalize_map: MemoryMap<N::TransitionID, Option<Vec<Value<N>>>>,
     /// The transition proofs.
-    proof_map: MemoryMap<N::TransitionID, Proof<N>>,
+    proof_map: MemoryMap<N::TransitionID, Option<Proof<N>>>,
     /// The transition public keys.
     tpk_map: MemoryMap<N::TransitionID, Group<N>>,
     /// The reverse `tpk` map.
@@ -336,10 +353,11 @@ pub struct TransitionMemory<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type LocatorMap = MemoryMap<N::TransitionID, (ProgramID<N>, Identifier<N>)>;
+    type EditionMap = MemoryMap<N::TransitionID, u16>;
     type InputStorage = InputMemory<N>;
     type OutputStorage = OutputMemory<N>;
     type FinalizeMap = MemoryMap<N::TransitionID, Option<Vec<Value<N>>>>;
-    type ProofMap = MemoryMap<N::TransitionID, Proof<N>>;
+    type ProofMap = MemoryMap<N::TransitionID, Option<Proof<N>>>;
     type TPKMap = MemoryMap<N::TransitionID, Group<N>>;
     type ReverseTPKMap = MemoryMap<Group<N>, N::TransitionID>;
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
@@ -350,6 +368,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn open(dev: Option<u16>) -> Result<Self> {
         Ok(Self {
             locator_map: MemoryMap::default(),
+            edition_map: MemoryMap::default(),
             input_store: InputStore::open(dev)?,
             output_store: OutputStore::open(dev)?,
             finalize_map: MemoryMap::default(),
@@ -367,6 +386,11 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
         &self.locator_map
     }
 
+    /// Returns the transition program editions.
+    fn edition_map(&self) -> &Self::EditionMap {
+        &self.edition_map
+    }
+
     /// Returns the transition input store.
     fn input_store(&self) -> &InputStore<N, Self::InputStorage> {
         &self.input_store
@@ -418,6 +442,8 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
 pub struct TransitionStore<N: Network, T: TransitionStorage<N>> {
     /// The map of transition program IDs and function names.
     locator: T::LocatorMap,
+    /// The map of transition program editions.
+    edition: T::EditionMap,
     /// The map of transition inputs.
     inputs: InputStore<N, T::InputStorage>,
     /// The map of transition outputs.
@@ -448,6 +474,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         // Return the transition store.
         Ok(Self {
             locator: storage.locator_map().clone(),
+            edition: storage.edition_map().clone(),
             inputs: (*storage.input_store()).clone(),
             outputs: (*storage.output_store()).clone(),
             finalize: storage.finalize_map().clone(),
@@ -465,6 +492,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     pub fn from(storage: T) -> Self {
         Self {
             locator: storage.locator_map().clone(),
+            edition: storage.edition_map().clone(),
             inputs: (*storage.input_store()).clone(),
             outputs: (*storage.output_store()).clone(),
             finalize: storage.finalize_map().clone(),
@@ -552,6 +580,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         }))
     }
 
+    /// Returns the edition of the program for the given `transition ID`.
+    pub fn get_edition(&self, transition_id: &N::TransitionID) -> Result<Option<u16>> {
+        Ok(self.edition.get(transition_id)?.map(|edition| cow_to_copied!(edition)))
+    }
+
     /// Returns the input IDs for the given `transition ID`.
     pub fn get_input_ids(&self, transition_id: &N::TransitionID) -> Result<Vec<Field<N>>> {
         self.inputs.get_input_ids(transition_id)
@@ -782,7 +815,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     /* Metadata */
 
     /// Returns an iterator over the proofs, for all transitions.
-    pub fn proofs(&self) -> impl '_ + Iterator<Item = Cow<'_, Proof<N>>> {
+    pub fn proofs(&self) -> impl '_ + Iterator<Item = Cow<'_, Option<Proof<N>>>> {
         self.proof.values()
     }
 
@@ -815,6 +848,7 @@ mod tests {
         let transitions = transaction
             .transitions()
             .chain([crate::process::test_helpers::sample_transition()].iter())
+            .chain([crate::process::test_helpers::sample_proven_transition()].iter())
             .cloned()
             .collect::<Vec<_>>();
 
@@ -49,7 +49,7 @@ impl<N: Network> Transaction<N> {
                 // Iterate through the functions in the deployment.
                 for (index, function) in deployment.program().functions().values().enumerate() {
                     // Check if the function ID matches the given ID.
-                    if *id == N::hash_bhp1024(&function.to_bytes_le()?.to_bits_le())? {
+                    if *id == deployment.to_function_id(function)? {
                         // Return the transaction leaf.
                         return Ok(TransactionLeaf::new(
                             0u8,
@@ -133,13 +133,13 @@ impl<N: Network> Transaction<N> {
             .values()
             .enumerate()
             .map(|(index, function)| {
-                // Construct the leaf as (variant || index || program ID || function name || Hash(function)).
+                // Construct the leaf as (variant || index || program ID || function name || function ID).
                 Ok(TransactionLeaf::new(
                     variant,
                     index as u16,
                     *program.id(),
                     *function.name(),
-                    N::hash_bhp1024(&function.to_bytes_le()?.to_bits_le())?,
+                    deployment.to_function_id(function)?,
                 )
                 .to_bits_le())
             })
@@ -85,6 +85,12 @@ impl<N: Network> Transaction<N> {
     ) -> Result<Self> {
         // Compute the deployment.
         let deployment = vm.deploy(program, rng)?;
+        // Ensure the additional fee covers the cost of the deployment.
+        let deployment_cost = vm.deployment_cost(&deployment)?;
+        ensure!(
+            additional_fee_in_gates >= deployment_cost,
+            "The additional fee of {additional_fee_in_gates} gates is below the deployment cost ({deployment_cost})"
+        );
         // Compute the additional fee.
         let (_, additional_fee) = vm.execute_additional_fee(private_key, credits, additional_fee_in_gates, rng)?;
         // Initialize the transaction.
@@ -218,6 +224,13 @@ impl<N: Network> Transaction<N> {
     pub fn fees(&self) -> impl '_ + Iterator<Item = &i64> {
         self.transitions().map(Transition::fee)
     }
+
+    /// Returns the total fee (in gates), for all transitions.
+    pub fn fee(&self) -> Result<i64> {
+        self.fees().try_fold(0i64, |total, fee| {
+            total.checked_add(*fee).ok_or_else(|| anyhow!("The total fee of transaction '{}' overflows", self.id()))
+        })
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -22,7 +22,7 @@ impl<N: Network> FromBytes for Transition<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid transition version"));
         }
 
@@ -32,6 +32,12 @@ impl<N: Network> FromBytes for Transition<N> {
         let program_id = FromBytes::read_le(&mut reader)?;
         // Read the function name.
         let function_name = FromBytes::read_le(&mut reader)?;
+        // Read the edition of the program.
+        let edition = match version {
+            // Version 0 transitions predate program upgrades, so they are for the first edition of the program.
+            0 => 0,
+            _ => u16::read_le(&mut reader)?,
+        };
 
         // Read the number of inputs.
         let num_inputs: u16 = FromBytes::read_le(&mut reader)?;
@@ -71,7 +77,21 @@ impl<N: Network> FromBytes for Transition<N> {
         };
 
         // Read the proof.
-        let proof = FromBytes::read_le(&mut reader)?;
+        let proof = match version {
+            // Version 0 transitions always carry their own proof.
+            0 => Some(FromBytes::read_le(&mut reader)?),
+            // Version 1 transitions carry a proof, unless they are proven by the aggregated proof of their execution.
+            _ => {
+                // Read the proof variant.
+                let proof_variant = u8::read_le(&mut reader)?;
+                match proof_variant {
+                    0 => None,
+                    1 => Some(FromBytes::read_le(&mut reader)?),
+                    2.. => return Err(error(format!("Invalid transition proof variant ({proof_variant})"))),
+                }
+            }
+        };
+
         // Read the transition public key.
         let tpk = FromBytes::read_le(&mut reader)?;
         // Read the transition commitment.
@@ -80,8 +100,9 @@ impl<N: Network> FromBytes for Transition<N> {
         let fee = FromBytes::read_le(&mut reader)?;
 
         // Construct the candidate transition.
-        let transition = Self::new(program_id, function_name, inputs, outputs, finalize, proof, tpk, tcm, fee)
-            .map_err(|e| error(e.to_string()))?;
+        let transition =
+            Self::new(program_id, function_name, edition, inputs, outputs, finalize, proof, tpk, tcm, fee)
+                .map_err(|e| error(e.to_string()))?;
         // Ensure the transition ID matches the expected ID.
         match transition_id == *transition.id() {
             true => Ok(transition),
@@ -94,7 +115,7 @@ impl<N: Network> ToBytes for Transition<N> {
     /// Writes the literal to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u16.write_le(&mut writer)?;
+        1u16.write_le(&mut writer)?;
 
         // Write the transition ID.
         self.id.write_le(&mut writer)?;
@@ -102,6 +123,8 @@ impl<N: Network> ToBytes for Transition<N> {
         self.program_id.write_le(&mut writer)?;
         // Write the function name.
         self.function_name.write_le(&mut writer)?;
+        // Write the edition of the program.
+        self.edition.write_le(&mut writer)?;
 
         // Write the number of inputs.
         (self.inputs.len() as u16).write_le(&mut writer)?;
@@ -130,7 +153,19 @@ impl<N: Network> ToBytes for Transition<N> {
         }
 
         // Write the proof.
-        self.proof.write_le(&mut writer)?;
+        match &self.proof {
+            None => {
+                // Write the proof variant.
+                0u8.write_le(&mut writer)?;
+            }
+            Some(proof) => {
+                // Write the proof variant.
+                1u8.write_le(&mut writer)?;
+                // Write the proof.
+                proof.write_le(&mut writer)?;
+            }
+        }
+
         // Write the transition public key.
         self.tpk.write_le(&mut writer)?;
         // Write the transition commitment.
@@ -157,6 +192,13 @@ mod tests {
         assert_eq!(expected, Transition::read_le(&expected_bytes[..])?);
         assert!(Transition::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
 
+        // Sample a transition that carries its own proof.
+        let expected = crate::process::test_helpers::sample_proven_transition();
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Transition::read_le(&expected_bytes[..])?);
+
         Ok(())
     }
 }
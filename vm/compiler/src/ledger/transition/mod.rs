@@ -58,14 +58,16 @@ pub struct Transition<N: Network> {
     program_id: ProgramID<N>,
     /// The function name.
     function_name: Identifier<N>,
+    /// The edition of the program.
+    edition: u16,
     /// The transition inputs.
     inputs: Vec<Input<N>>,
     /// The transition outputs.
     outputs: Vec<Output<N>>,
     /// The inputs for finalize.
     finalize: Option<Vec<Value<N>>>,
-    /// The transition proof.
-    proof: Proof<N>,
+    /// The transition proof, if the transition is not proven by the aggregated proof of its execution.
+    proof: Option<Proof<N>>,
     /// The transition public key.
     tpk: Group<N>,
     /// The transition commitment.
@@ -80,10 +82,11 @@ impl<N: Network> Transition<N> {
     pub fn new(
         program_id: ProgramID<N>,
         function_name: Identifier<N>,
+        edition: u16,
         inputs: Vec<Input<N>>,
         outputs: Vec<Output<N>>,
         finalize: Option<Vec<Value<N>>>,
-        proof: Proof<N>,
+        proof: Option<Proof<N>>,
         tpk: Group<N>,
         tcm: Field<N>,
         fee: i64,
@@ -91,17 +94,19 @@ impl<N: Network> Transition<N> {
         // Compute the transition ID.
         let id = *Self::function_tree(&program_id, &function_name, &inputs, &outputs)?.root();
         // Return the transition.
-        Ok(Self { id: id.into(), program_id, function_name, inputs, outputs, finalize, proof, tpk, tcm, fee })
+        Ok(Self { id: id.into(), program_id, function_name, edition, inputs, outputs, finalize, proof, tpk, tcm, fee })
     }
 
-    /// Initializes a new transition from a request and response.
+    /// Initializes a new transition from a request and response, for the given edition of the program.
+    #[allow(clippy::too_many_arguments)]
     pub fn from(
         request: &Request<N>,
         response: &Response<N>,
+        edition: u16,
         finalize: Option<Vec<Value<N>>>,
         output_types: &[ValueType<N>],
         output_registers: &[Register<N>],
-        proof: Proof<N>,
+        proof: Option<Proof<N>>,
         fee: i64,
     ) -> Result<Self> {
         let program_id = *request.program_id();
@@ -247,7 +252,7 @@ impl<N: Network> Transition<N> {
         // Retrieve the `tcm`.
         let tcm = *request.tcm();
         // Return the transition.
-        Self::new(program_id, function_name, inputs, outputs, finalize, proof, tpk, tcm, fee)
+        Self::new(program_id, function_name, edition, inputs, outputs, finalize, proof, tpk, tcm, fee)
     }
 }
 
@@ -267,6 +272,11 @@ impl<N: Network> Transition<N> {
         &self.function_name
     }
 
+    /// Returns the edition of the program.
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
     /// Returns the inputs.
     pub fn inputs(&self) -> &[Input<N>] {
         &self.inputs
@@ -282,8 +292,8 @@ impl<N: Network> Transition<N> {
         &self.finalize
     }
 
-    /// Returns the proof.
-    pub const fn proof(&self) -> &Proof<N> {
+    /// Returns the proof, if the transition is not proven by the aggregated proof of its execution.
+    pub const fn proof(&self) -> &Option<Proof<N>> {
         &self.proof
     }
 
@@ -21,16 +21,19 @@ impl<N: Network> Serialize for Transition<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut transition = serializer.serialize_struct("Transition", 10)?;
+                let mut transition = serializer.serialize_struct("Transition", 11)?;
                 transition.serialize_field("id", &self.id)?;
                 transition.serialize_field("program", &self.program_id)?;
                 transition.serialize_field("function", &self.function_name)?;
+                transition.serialize_field("edition", &self.edition)?;
                 transition.serialize_field("inputs", &self.inputs)?;
                 transition.serialize_field("outputs", &self.outputs)?;
                 if let Some(finalize) = &self.finalize {
                     transition.serialize_field("finalize", &finalize)?;
                 }
-                transition.serialize_field("proof", &self.proof)?;
+                if let Some(proof) = &self.proof {
+                    transition.serialize_field("proof", &proof)?;
+                }
                 transition.serialize_field("tpk", &self.tpk)?;
                 transition.serialize_field("tcm", &self.tcm)?;
                 transition.serialize_field("fee", &self.fee)?;
@@ -58,6 +61,8 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                     serde_json::from_value(transition["program"].clone()).map_err(de::Error::custom)?,
                     // Retrieve the function name.
                     serde_json::from_value(transition["function"].clone()).map_err(de::Error::custom)?,
+                    // Retrieve the edition.
+                    serde_json::from_value(transition["edition"].clone()).map_err(de::Error::custom)?,
                     // Retrieve the inputs.
                     serde_json::from_value(transition["inputs"].clone()).map_err(de::Error::custom)?,
                     // Retrieve the outputs.
@@ -68,7 +73,10 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                         None => None,
                     },
                     // Retrieve the proof.
-                    serde_json::from_value(transition["proof"].clone()).map_err(de::Error::custom)?,
+                    match transition.get("proof") {
+                        Some(proof) => Some(serde_json::from_value(proof.clone()).map_err(de::Error::custom)?),
+                        None => None,
+                    },
                     // Retrieve the `tpk`.
                     serde_json::from_value(transition["tpk"].clone()).map_err(de::Error::custom)?,
                     // Retrieve the `tcm`.
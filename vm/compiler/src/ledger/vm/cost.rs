@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
+    /// Returns the minimum fee (in gates) for the given transaction to be accepted by the ledger.
+    ///
+    /// Every transaction is charged, including executions that only call `credits.aleo`, which must
+    /// pay an additional fee if the fees of their own transitions do not cover their cost.
+    #[inline]
+    pub fn estimate_fee(&self, transaction: &Transaction<N>) -> Result<u64> {
+        match transaction {
+            Transaction::Deploy(_, deployment, _) => self.deployment_cost(deployment),
+            Transaction::Execute(_, execution, _) => self.execution_cost(execution),
+        }
+    }
+
+    /// Returns the minimum fee (in gates) for each of the given transactions, in order,
+    /// where each transaction is priced after the deployments that precede it are applied,
+    /// as they are when the transactions are finalized in a block.
+    #[inline]
+    pub fn estimate_fees<'a>(&self, transactions: impl IntoIterator<Item = &'a Transaction<N>>) -> Result<Vec<u64>> {
+        // Initialize a copy of the process, to apply the deployments to.
+        let mut process = self.process.read().clone();
+        // Price each transaction, and then apply it, if it is a deployment.
+        let mut fees = Vec::new();
+        for transaction in transactions {
+            match transaction {
+                Transaction::Deploy(_, deployment, _) => {
+                    fees.push(process.deployment_cost(deployment)?);
+                    process.load_deployment(deployment)?;
+                }
+                Transaction::Execute(_, execution, _) => fees.push(process.execution_cost(execution)?),
+            }
+        }
+        Ok(fees)
+    }
+
+    /// Returns the cost (in gates) of the given deployment.
+    #[inline]
+    pub fn deployment_cost(&self, deployment: &Deployment<N>) -> Result<u64> {
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the deployment.
+                let deployment = cast_ref!(&deployment as Deployment<$network>);
+                // Return the deployment cost.
+                $process.deployment_cost(deployment)
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
+
+    /// Returns the cost (in gates) of the given execution.
+    #[inline]
+    pub fn execution_cost(&self, execution: &Execution<N>) -> Result<u64> {
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the execution.
+                let execution = cast_ref!(&execution as Execution<$network>);
+                // Return the execution cost.
+                $process.execution_cost(execution)
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::vm::test_helpers::{sample_deployment_transaction, sample_program, sample_vm};
+    use console::{account::Address, network::Testnet3};
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_estimate_fees_applies_deployments_in_order() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the deployment.
+        let program = sample_program();
+        let deployment = sample_deployment_transaction(rng);
+
+        // Initialize a new caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Execute the program, on a VM that the deployment is finalized in.
+        let mut other = sample_vm();
+        other.finalize(&deployment).unwrap();
+        let inputs = [
+            Value::<CurrentNetwork>::from_str("{ amount: 9876543210u128 }").unwrap(),
+            Value::<CurrentNetwork>::from_str("{ amount: 9876543210u128 }").unwrap(),
+            Value::<CurrentNetwork>::from_str("{ amount: 9876543210u128 }").unwrap(),
+            Value::<CurrentNetwork>::from_str(&format!(
+                "{{ owner: {caller}.private, gates: 0u64.private, amount: 100u64.private, _nonce: 0group.public }}"
+            ))
+            .unwrap(),
+        ];
+        let function_name = Identifier::from_str("compute").unwrap();
+        let authorization = other.authorize(&caller_private_key, program.id(), function_name, &inputs, rng).unwrap();
+        let (_, execution) = other.execute(authorization, rng).unwrap();
+        let execution = Transaction::from_execution(execution, None).unwrap();
+
+        // Ensure the execution cannot be priced before the deployment is applied.
+        let vm = sample_vm();
+        assert!(vm.estimate_fee(&execution).is_err());
+        // Ensure the execution is priced after the deployment that precedes it.
+        let expected = [vm.estimate_fee(&deployment).unwrap(), other.estimate_fee(&execution).unwrap()];
+        assert_eq!(vm.estimate_fees([&deployment, &execution]).unwrap(), expected);
+        // Ensure the deployment is not applied to the VM itself.
+        assert!(!vm.contains_program(program.id()));
+        assert!(vm.estimate_fees([&execution, &deployment]).is_err());
+    }
+}
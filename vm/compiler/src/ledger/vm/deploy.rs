@@ -38,4 +38,32 @@ impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
         // Process the logic.
         process!(self, logic)
     }
+
+    /// Deploys the next edition of the program with the given program ID, signed by the program owner.
+    #[inline]
+    pub fn upgrade<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the private key and program.
+                let private_key = cast_ref!(&private_key as PrivateKey<$network>);
+                let program = cast_ref!(&program as Program<$network>);
+
+                // Compute the deployment.
+                let deployment = $process.upgrade::<$aleo, _>(private_key, program, rng)?;
+
+                // Prepare the return.
+                let deployment = cast_ref!(deployment as Deployment<N>).clone();
+                // Return the deployment.
+                Ok(deployment)
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
 }
@@ -45,7 +45,18 @@ macro_rules! process {
         // Process the logic.
         match N::ID {
             console::network::Testnet3::ID => {
-                $logic!($self.process.read(), console::network::Testnet3, circuit::AleoV0)
+                let process = $self.process.read();
+                match (&*process as &dyn std::any::Any).downcast_ref::<Process<console::network::Testnet3>>() {
+                    Some(process) => $logic!(process, console::network::Testnet3, circuit::AleoV0),
+                    None => Err(anyhow!("Failed to downcast the process")),
+                }
+            }
+            console::network::Devnet::ID => {
+                let process = $self.process.read();
+                match (&*process as &dyn std::any::Any).downcast_ref::<Process<console::network::Devnet>>() {
+                    Some(process) => $logic!(process, console::network::Devnet, circuit::AleoDevnet),
+                    None => Err(anyhow!("Failed to downcast the process")),
+                }
             }
             _ => Err(anyhow!("Unsupported VM configuration for network: {}", N::ID)),
         }
@@ -60,7 +71,18 @@ macro_rules! process_mut {
         // Process the logic.
         match N::ID {
             console::network::Testnet3::ID => {
-                $logic!($self.process.write(), console::network::Testnet3, circuit::AleoV0)
+                let mut process = $self.process.write();
+                match (&mut *process as &mut dyn std::any::Any).downcast_mut::<Process<console::network::Testnet3>>() {
+                    Some(process) => $logic!(process, console::network::Testnet3, circuit::AleoV0),
+                    None => Err(anyhow!("Failed to downcast the process")),
+                }
+            }
+            console::network::Devnet::ID => {
+                let mut process = $self.process.write();
+                match (&mut *process as &mut dyn std::any::Any).downcast_mut::<Process<console::network::Devnet>>() {
+                    Some(process) => $logic!(process, console::network::Devnet, circuit::AleoDevnet),
+                    None => Err(anyhow!("Failed to downcast the process")),
+                }
             }
             _ => Err(anyhow!("Unsupported VM configuration for network: {}", N::ID)),
         }
@@ -17,6 +17,7 @@
 mod helpers;
 
 mod authorize;
+mod cost;
 mod deploy;
 mod execute;
 mod finalize;
@@ -32,6 +33,8 @@ use crate::{
     process,
     process::{Authorization, Deployment, Execution, Process},
     program::Program,
+    DeferredProof,
+    VerifyingKey,
 };
 use console::{
     account::PrivateKey,
@@ -41,12 +44,13 @@ use console::{
 
 use core::marker::PhantomData;
 use parking_lot::RwLock;
+use rayon::prelude::*;
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct VM<N: Network, P: ProgramStorage<N>> {
-    /// The process for Aleo Testnet3 (V0).
-    process: Arc<RwLock<Process<console::network::Testnet3>>>,
+    /// The process.
+    process: Arc<RwLock<Process<N>>>,
     /// The program store.
     store: ProgramStore<N, P>,
     /// PhantomData.
@@ -79,18 +83,8 @@ impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
             };
         }
 
-        // Cast the process into the appropriate network.
-        macro_rules! logic {
-            ($process:expr, $network:path, $aleo:path) => {{
-                // Prepare the process.
-                let process = cast_ref!(process as Process<$network>);
-
-                // Return the new VM.
-                Ok(Self { process: Arc::new(RwLock::new((*process).clone())), store, _phantom: PhantomData })
-            }};
-        }
-        // Process the logic.
-        process!(self, logic)
+        // Return the new VM.
+        Ok(Self { process: Arc::new(RwLock::new(process)), store, _phantom: PhantomData })
     }
 
     /// Deploys a program with the given program ID.
@@ -191,14 +185,19 @@ function compute:
                     .collect::<indexmap::IndexMap<_, _>>();
                 trace!("Unspent Records:\n{:#?}", records);
 
-                // Prepare the additional fee.
+                // Prepare the credits to pay the additional fee.
                 let credits = records.values().next().unwrap().clone();
-                let additional_fee = (credits, 10);
 
                 // Initialize the VM.
                 let vm = sample_vm();
                 // Deploy.
-                let transaction = Transaction::deploy(&vm, &caller_private_key, &program, additional_fee, rng).unwrap();
+                let deployment = vm.deploy(&program, rng).unwrap();
+                // Pay the deployment cost as the additional fee.
+                let additional_fee_in_gates = vm.deployment_cost(&deployment).unwrap();
+                let (_, additional_fee) =
+                    vm.execute_additional_fee(&caller_private_key, credits, additional_fee_in_gates, rng).unwrap();
+                let transaction = Transaction::from_deployment(deployment, additional_fee).unwrap();
+                assert_eq!(vm.estimate_fee(&transaction).unwrap(), additional_fee_in_gates);
                 // Verify.
                 assert!(vm.verify(&transaction));
                 // Return the transaction.
@@ -226,8 +225,9 @@ function compute:
                     .filter(|(_, record)| !record.gates().is_zero())
                     .collect::<indexmap::IndexMap<_, _>>();
                 trace!("Unspent Records:\n{:#?}", records);
-                // Select a record to spend.
-                let record = records.values().next().unwrap().clone();
+                // Select a record to spend, and a record to pay the additional fee.
+                let mut records = records.values().cloned();
+                let (record, credits) = (records.next().unwrap(), records.next().unwrap());
 
                 // Initialize the VM.
                 let vm = sample_vm();
@@ -249,7 +249,13 @@ function compute:
                 assert_eq!(authorization.len(), 1);
 
                 // Execute.
-                let transaction = Transaction::execute_authorization(&vm, authorization, rng).unwrap();
+                let (_, execution) = vm.execute(authorization, rng).unwrap();
+                // Pay the execution cost as the additional fee.
+                let additional_fee_in_gates = vm.execution_cost(&execution).unwrap();
+                let (_, additional_fee) =
+                    vm.execute_additional_fee(&caller_private_key, credits, additional_fee_in_gates, rng).unwrap();
+                let transaction = Transaction::from_execution(execution, Some(additional_fee)).unwrap();
+                assert_eq!(vm.estimate_fee(&transaction).unwrap(), additional_fee_in_gates);
                 // Verify.
                 assert!(vm.verify(&transaction));
                 // Return the transaction.
@@ -20,6 +20,76 @@ impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
     /// Verifies the transaction in the VM.
     #[inline]
     pub fn verify(&self, transaction: &Transaction<N>) -> bool {
+        self.verify_many(&[transaction])
+    }
+
+    /// Verifies the given transactions in the VM.
+    /// The proofs of every execution and additional fee are checked together with a single pairing check.
+    #[inline]
+    pub fn verify_many(&self, transactions: &[&Transaction<N>]) -> bool {
+        // Ensure each transaction is well-formed.
+        if !transactions.par_iter().all(|transaction| Self::check_well_formed(transaction)) {
+            return false;
+        }
+
+        // Ensure each deployment is valid.
+        if !transactions.par_iter().all(|transaction| match transaction {
+            Transaction::Deploy(_, deployment, _) => self.verify_deployment(deployment),
+            Transaction::Execute(..) => true,
+        }) {
+            return false;
+        }
+
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                let task = || {
+                    // Acquire the process once, for all of the transactions.
+                    let process = $process;
+                    // Verify each execution and additional fee, deferring the proof checks.
+                    let proofs = transactions
+                        .par_iter()
+                        .map(|transaction| -> Result<Vec<DeferredProof<$network>>> {
+                            // Prepare the transaction.
+                            let transaction = cast_ref!((**transaction) as Transaction<$network>);
+                            match transaction {
+                                Transaction::Deploy(_, _, additional_fee) => {
+                                    // Verify the additional fee.
+                                    Ok(vec![process.verify_additional_fee_deferred(additional_fee)?])
+                                }
+                                Transaction::Execute(_, execution, additional_fee) => {
+                                    // Verify the execution.
+                                    let mut proofs = process.verify_execution_deferred(execution)?;
+                                    // Verify the additional fee, if it exists.
+                                    if let Some(additional_fee) = additional_fee {
+                                        proofs.push(process.verify_additional_fee_deferred(additional_fee)?);
+                                    }
+                                    Ok(proofs)
+                                }
+                            }
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+
+                    // Ensure the proofs are valid.
+                    ensure!(VerifyingKey::verify_many(&proofs.concat()), "The transaction proofs are invalid");
+                    Ok(())
+                };
+                task()
+            }};
+        }
+
+        // Process the logic.
+        match process!(self, logic) {
+            Ok(()) => true,
+            Err(error) => {
+                warn!("Transaction verification failed: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the transaction is well-formed, without checking the deployment, execution, or proofs.
+    fn check_well_formed(transaction: &Transaction<N>) -> bool {
         // Compute the Merkle root of the transaction.
         match transaction.to_root() {
             // Ensure the transaction ID is correct.
@@ -65,37 +135,17 @@ impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
             return false;
         }
 
-        match transaction {
-            Transaction::Deploy(_, deployment, additional_fee) => {
-                // Check the deployment size.
-                if let Err(error) = Transaction::check_deployment_size(deployment) {
-                    warn!("Invalid transaction size (deployment): {error}");
-                    return false;
-                }
-                // Verify the deployment.
-                self.verify_deployment(deployment)
-                    // Verify the additional fee.
-                    && self.verify_additional_fee(additional_fee)
-            }
-            Transaction::Execute(_, execution, additional_fee) => {
-                // Check the deployment size.
-                if let Err(error) = Transaction::check_execution_size(execution) {
-                    warn!("Invalid transaction size (execution): {error}");
-                    return false;
-                }
-
-                // Verify the additional fee, if it exists.
-                let check_additional_fee = match additional_fee {
-                    Some(additional_fee) => self.verify_additional_fee(additional_fee),
-                    None => true,
-                };
-
-                // Verify the execution.
-                self.verify_execution(execution)
-                    // Verify the additional fee.
-                    && check_additional_fee
-            }
+        // Check the transaction size.
+        let check_size = match transaction {
+            Transaction::Deploy(_, deployment, _) => Transaction::check_deployment_size(deployment),
+            Transaction::Execute(_, execution, _) => Transaction::check_execution_size(execution),
+        };
+        if let Err(error) = check_size {
+            warn!("Invalid transaction size: {error}");
+            return false;
         }
+
+        true
     }
 
     /// Verifies the given deployment.
@@ -125,58 +175,6 @@ impl<N: Network, P: ProgramStorage<N>> VM<N, P> {
             }
         }
     }
-
-    /// Verifies the given execution.
-    #[inline]
-    fn verify_execution(&self, execution: &Execution<N>) -> bool {
-        // Compute the core logic.
-        macro_rules! logic {
-            ($process:expr, $network:path, $aleo:path) => {{
-                let task = || {
-                    // Prepare the execution.
-                    let execution = cast_ref!(&execution as Execution<$network>);
-                    // Verify the execution.
-                    $process.verify_execution(execution)
-                };
-                task()
-            }};
-        }
-
-        // Process the logic.
-        match process!(self, logic) {
-            Ok(()) => true,
-            Err(error) => {
-                warn!("Execution verification failed: {error}");
-                false
-            }
-        }
-    }
-
-    /// Verifies the given additional fee.
-    #[inline]
-    fn verify_additional_fee(&self, additional_fee: &AdditionalFee<N>) -> bool {
-        // Compute the core logic.
-        macro_rules! logic {
-            ($process:expr, $network:path, $aleo:path) => {{
-                let task = || {
-                    // Prepare the additional fee.
-                    let additional_fee = cast_ref!(&additional_fee as AdditionalFee<$network>);
-                    // Verify the additional fee.
-                    $process.verify_additional_fee(additional_fee)
-                };
-                task()
-            }};
-        }
-
-        // Process the logic.
-        match process!(self, logic) {
-            Ok(()) => true,
-            Err(error) => {
-                warn!("Additional fee verification failed: {error}");
-                false
-            }
-        }
-    }
 }
 
 #[cfg(test)]
@@ -198,6 +196,9 @@ mod tests {
         let execution_transaction = crate::ledger::vm::test_helpers::sample_execution_transaction(rng);
         // Ensure the transaction verifies.
         assert!(vm.verify(&execution_transaction));
+
+        // Ensure the transactions verify together.
+        assert!(vm.verify_many(&[&deployment_transaction, &execution_transaction]));
     }
 
     #[test]
@@ -35,3 +35,24 @@ pub use program::*;
 
 mod snark;
 pub use snark::*;
+
+use console::network::{NetworkBytes, Testnet3};
+
+/// Supplies the loaders for the parameters of Testnet3, as the console does not depend on `snarkvm-parameters`.
+/// Note: This must be called before any of the `Network` hooks for predefined bytes are used.
+pub(crate) fn supply_network_bytes() {
+    static SUPPLY: std::sync::Once = std::sync::Once::new();
+    SUPPLY.call_once(|| {
+        Testnet3::supply_network_bytes(NetworkBytes {
+            genesis: snarkvm_parameters::testnet3::GenesisBytes::load_bytes,
+            universal_srs: || {
+                static UNIVERSAL_SRS: once_cell::sync::OnceCell<Vec<u8>> = once_cell::sync::OnceCell::new();
+                Ok(UNIVERSAL_SRS.get_or_try_init(snarkvm_parameters::testnet3::TrialSRS::load_bytes)?)
+            },
+            credits_keys: |function_name| match snarkvm_parameters::testnet3::TESTNET3_CREDITS_PROGRAM.get(function_name) {
+                Some(keys) => Ok(keys),
+                None => anyhow::bail!("Circuit keys for 'credits.aleo/{function_name}' not found"),
+            },
+        });
+    });
+}
@@ -43,7 +43,7 @@ impl<N: Network> Process<N> {
         // Construct the call stack.
         let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
         // Construct the authorization from the function.
-        let _response = self.get_stack(&program_id)?.execute_function::<A, R>(call_stack, rng)?;
+        let _response = self.get_stack(&program_id)?.execute_function::<A>(call_stack)?;
 
         // Retrieve the main request (without popping it).
         let request = authorization.peek_next()?;
@@ -55,19 +55,55 @@ impl<N: Network> Process<N> {
 
         // Initialize the execution.
         let execution = Arc::new(RwLock::new(Execution::new()));
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
         // Execute the circuit.
-        let response = stack.execute_function::<A, R>(CallStack::execute(authorization, execution.clone())?, rng)?;
+        let call_stack = CallStack::execute(authorization, execution.clone(), assignments.clone())?;
+        let response = stack.execute_function::<A>(call_stack)?;
         // Extract the execution.
         let execution = execution.read().clone();
         // Ensure the execution contains 1 transition.
         ensure!(execution.len() == 1, "Execution of '{}/{}' does not contain 1 transition", program_id, function_name);
 
-        Ok((response, execution.peek()?))
+        // Retrieve the transition.
+        let transition = execution.peek()?;
+        // Prove the transition, as the additional fee carries its own proof.
+        let proof = self.get_proving_key(&program_id, &function_name)?.prove(
+            &function_name,
+            &assignments.read()[0],
+            rng,
+        )?;
+        // Construct the additional fee.
+        let additional_fee = Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.edition(),
+            transition.inputs().to_vec(),
+            transition.outputs().to_vec(),
+            transition.finalize().clone(),
+            Some(proof),
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.fee(),
+        )?;
+
+        Ok((response, additional_fee))
     }
 
     /// Verifies the given additional fee is valid.
     #[inline]
     pub fn verify_additional_fee(&self, additional_fee: &AdditionalFee<N>) -> Result<()> {
+        // Verify the additional fee, deferring the proof check.
+        let proof = self.verify_additional_fee_deferred(additional_fee)?;
+        // Ensure the proof is valid.
+        ensure!(VerifyingKey::verify_many(&[proof]), "The proof for the additional fee is invalid");
+        Ok(())
+    }
+
+    /// Verifies the given additional fee is valid, except for its proof.
+    /// Returns the proof, which the caller must verify (e.g. with `VerifyingKey::verify_many`).
+    #[inline]
+    pub fn verify_additional_fee_deferred(&self, additional_fee: &AdditionalFee<N>) -> Result<DeferredProof<N>> {
         #[cfg(debug_assertions)]
         println!("Verifying additional fee for {}/{}...", additional_fee.program_id(), additional_fee.function_name());
 
@@ -117,8 +153,8 @@ impl<N: Network> Process<N> {
         // Extend the inputs with the fee.
         inputs.push(*I64::<N>::new(*additional_fee.fee()).to_field()?);
 
-        // Retrieve the stack.
-        let stack = self.get_stack(additional_fee.program_id())?;
+        // Retrieve the stack, at the edition of the program that the additional fee was created for.
+        let stack = self.get_stack_at_edition(additional_fee.program_id(), additional_fee.edition())?;
         // Retrieve the function from the stack.
         let function = stack.get_function(additional_fee.function_name())?;
         // Ensure the number of function calls in this function is 1.
@@ -130,12 +166,13 @@ impl<N: Network> Process<N> {
         println!("Additional fee public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
         // Retrieve the verifying key.
-        let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
-        // Ensure the proof is valid.
-        ensure!(
-            verifying_key.verify(function.name(), &inputs, additional_fee.proof()),
-            "The proof for the additional fee is invalid"
-        );
-        Ok(())
+        let verifying_key = stack.get_verifying_key(function.name())?;
+        // Retrieve the proof.
+        let proof = match additional_fee.proof() {
+            Some(proof) => proof.clone(),
+            None => bail!("The additional fee is missing its proof"),
+        };
+        // Defer the proof check.
+        Ok((verifying_key, inputs, proof))
     }
 }
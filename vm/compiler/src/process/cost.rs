@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{program::finalize::Command, Opcode};
+
+impl<N: Network> Process<N> {
+    /// The cost in gates to store one byte of a deployment or execution in the ledger.
+    pub const STORAGE_COST_PER_BYTE: u64 = 1_000;
+    /// The cost in gates for one constraint of a function circuit.
+    pub const CONSTRAINT_COST: u64 = 25;
+    /// The cost in gates to initialize one mapping in storage.
+    pub const MAPPING_INITIALIZATION_COST: u64 = 100_000;
+
+    /// Returns the cost in gates to evaluate the given finalize command.
+    pub fn finalize_command_cost(command: &Command<N>) -> u64 {
+        match command {
+            // A decrement or increment reads and writes a mapping entry in storage.
+            Command::Decrement(..) | Command::Increment(..) => 10_000,
+            Command::Instruction(instruction) => match instruction.opcode() {
+                Opcode::Commit(..) | Opcode::Hash(..) => 5_000,
+                Opcode::Assert(..)
+                | Opcode::Call
+                | Opcode::Cast
+                | Opcode::Command(..)
+                | Opcode::Finalize(..)
+                | Opcode::Is(..)
+                | Opcode::Literal(..) => 1_000,
+            },
+        }
+    }
+
+    /// Returns the cost in gates of the given deployment, which is the sum of
+    /// its storage cost, the cost of the constraints of each function circuit,
+    /// and the cost to initialize each new mapping.
+    pub fn deployment_cost(&self, deployment: &Deployment<N>) -> Result<u64> {
+        // Compute the storage cost.
+        let mut cost = Self::storage_cost(deployment.to_bytes_le()?.len())?;
+
+        // Add the cost of the constraints of each function circuit.
+        for (verifying_key, _) in deployment.verifying_keys().values() {
+            cost = add_cost(cost, Self::constraint_cost(verifying_key)?)?;
+        }
+
+        // Retrieve the previous edition of the program, if the deployment is an upgrade.
+        let previous = match self.contains_program(deployment.program_id()) {
+            true => Some(self.get_program(deployment.program_id())?),
+            false => None,
+        };
+        // Add the cost to initialize each new mapping.
+        for name in deployment.program().mappings().keys() {
+            match previous {
+                Some(previous) if previous.contains_mapping(name) => continue,
+                _ => cost = add_cost(cost, Self::MAPPING_INITIALIZATION_COST)?,
+            }
+        }
+        Ok(cost)
+    }
+
+    /// Returns the cost in gates of the given execution, which is the sum of
+    /// its storage cost, the cost of the constraints of each transition circuit,
+    /// and the cost of the finalize commands of each transition.
+    pub fn execution_cost(&self, execution: &Execution<N>) -> Result<u64> {
+        // Compute the storage cost.
+        let mut cost = Self::storage_cost(execution.to_bytes_le()?.len())?;
+
+        for transition in execution.iter() {
+            // Retrieve the stack of the edition of the program that the transition was created for.
+            let stack = self.get_stack_at_edition(transition.program_id(), transition.edition())?;
+            // Add the cost of the constraints of the transition circuit.
+            let verifying_key = stack.get_verifying_key(transition.function_name())?;
+            cost = add_cost(cost, Self::constraint_cost(&verifying_key)?)?;
+
+            // Add the cost of the finalize commands, if the transition is finalized.
+            if transition.finalize().is_some() {
+                if let Some(finalize) = stack.get_function(transition.function_name())?.finalize_logic() {
+                    for command in finalize.commands() {
+                        cost = add_cost(cost, Self::finalize_command_cost(command))?;
+                    }
+                }
+            }
+        }
+        Ok(cost)
+    }
+
+    /// Returns the cost in gates to store the given number of bytes.
+    fn storage_cost(num_bytes: usize) -> Result<u64> {
+        (num_bytes as u64)
+            .checked_mul(Self::STORAGE_COST_PER_BYTE)
+            .ok_or_else(|| anyhow!("The storage cost of {num_bytes} bytes overflows"))
+    }
+
+    /// Returns the cost in gates of the constraints of the circuit for the given verifying key.
+    fn constraint_cost(verifying_key: &VerifyingKey<N>) -> Result<u64> {
+        let num_constraints = verifying_key.circuit_info.num_constraints;
+        (num_constraints as u64)
+            .checked_mul(Self::CONSTRAINT_COST)
+            .ok_or_else(|| anyhow!("The cost of {num_constraints} constraints overflows"))
+    }
+}
+
+/// Returns the sum of the given costs, or an error if the sum overflows.
+fn add_cost(cost: u64, additional_cost: u64) -> Result<u64> {
+    cost.checked_add(additional_cost).ok_or_else(|| anyhow!("The cost overflows"))
+}
@@ -30,6 +30,29 @@ impl<N: Network> Process<N> {
         stack.deploy::<A, R>(rng)
     }
 
+    /// Deploys the next edition of the given program ID, signed by the owner of the program.
+    #[inline]
+    pub fn upgrade<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the given private key belongs to the program owner.
+        match self.owners.get(program_id) {
+            Some(owner) => {
+                ensure!(owner == &Address::try_from(private_key)?, "Only the owner may upgrade '{program_id}'")
+            }
+            None => bail!("Program '{program_id}' is not upgradable, as it was deployed without an owner"),
+        }
+        // Compute the stack.
+        let stack = Stack::upgrade(self, program)?;
+        // Return the signed deployment.
+        stack.deploy::<A, R>(rng)?.with_owner(private_key, rng)
+    }
+
     /// Verifies the given deployment is well-formed.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
@@ -39,10 +62,17 @@ impl<N: Network> Process<N> {
     ) -> Result<()> {
         // Retrieve the program ID.
         let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure the owner signature is valid.
+        ensure!(deployment.verify_owner()?, "Invalid owner signature in the deployment of '{program_id}'");
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = match self.contains_program(program_id) {
+            // If the program exists, ensure the upgrade is authorized and compatible.
+            true => {
+                self.check_upgrade_owner(deployment)?;
+                Stack::upgrade(self, deployment.program())?
+            }
+            false => Stack::new(self, deployment.program())?,
+        };
         // Ensure the verifying keys are well-formed and the certificates are valid.
         stack.verify_deployment::<A, R>(deployment, rng)
     }
@@ -55,25 +85,40 @@ impl<N: Network> Process<N> {
         store: &ProgramStore<N, P>,
         deployment: &Deployment<N>,
     ) -> Result<()> {
-        // TODO (howardwu): Make this function atomic.
-        // TODO (howardwu): Check the program ID and all mappings don't exist in the 'store'. (add this to verify_deployment too)
-
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = match self.contains_program(program_id) {
+            true => Stack::upgrade(self, deployment.program())?,
+            false => {
+                // Ensure the program does not already exist in storage.
+                ensure!(!store.contains_program(program_id)?, "Program '{program_id}' already exists in storage");
+                Stack::new(self, deployment.program())?
+            }
+        };
         // Insert the verifying keys.
         for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
             stack.insert_verifying_key(function_name, verifying_key.clone())?;
         }
 
-        // Retrieve the program ID.
-        let program_id = deployment.program_id();
-        // Iterate through the program mappings.
-        for mapping in deployment.program().mappings().values() {
-            store.initialize_mapping(program_id, mapping.name())?;
-        }
+        // Stage the stack in a copy of the process, so that the process is only updated if the store is.
+        let mut process = self.clone();
+        process.insert_deployed_stack(stack, deployment.owner())?;
 
-        // Add the stack to the process.
-        self.stacks.insert(*deployment.program_id(), stack);
+        // Initialize the program mappings as a single atomic batch.
+        atomic_write_batch!(store, {
+            for mapping in deployment.program().mappings().values() {
+                // Initialize the mapping, if it is new. Mappings from a previous edition retain their
+                // entries, as an upgrade preserves their key and value types.
+                if !store.contains_mapping(program_id, mapping.name())? {
+                    store.initialize_mapping(program_id, mapping.name())?;
+                }
+            }
+            Ok(())
+        });
+
+        // Update the process.
+        *self = process;
         Ok(())
     }
 
@@ -82,13 +127,78 @@ impl<N: Network> Process<N> {
     #[inline]
     pub(crate) fn load_deployment(&mut self, deployment: &Deployment<N>) -> Result<()> {
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = match self.contains_program(deployment.program_id()) {
+            true => Stack::upgrade(self, deployment.program())?,
+            false => Stack::new(self, deployment.program())?,
+        };
         // Insert the verifying keys.
         for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
             stack.insert_verifying_key(function_name, verifying_key.clone())?;
         }
         // Add the stack to the process.
-        self.stacks.insert(*deployment.program_id(), stack);
+        self.insert_deployed_stack(stack, deployment.owner())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Ensures the given deployment of an existing program is signed by the owner of the program.
+    #[inline]
+    fn check_upgrade_owner(&self, deployment: &Deployment<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Retrieve the program owner.
+        let owner = match self.owners.get(program_id) {
+            Some(owner) => owner,
+            None => bail!("Program '{program_id}' is not upgradable, as it was deployed without an owner"),
+        };
+        // Ensure the deployment is signed by the program owner.
+        match deployment.owner() {
+            Some(candidate) => ensure!(candidate == owner, "Only the owner may upgrade '{program_id}'"),
+            None => bail!("The upgrade of '{program_id}' must be signed by its owner"),
+        }
+        Ok(())
+    }
+
+    /// Adds the given stack of a deployed program to the process.
+    /// If the stack is an upgrade, the previous stack is retired, and the dependent programs are refreshed.
+    #[inline]
+    fn insert_deployed_stack(&mut self, stack: Stack<N>, owner: Option<&Address<N>>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
+        // Add the stack to the process.
+        match self.stacks.insert(program_id, stack) {
+            // Retire the stack of the previous edition, retaining its verifying keys.
+            Some(previous) => {
+                self.retired_stacks.entry(program_id).or_default().push(previous);
+                self.refresh_dependents(program_id)
+            }
+            // Record the owner of the program, if it is upgradable.
+            None => {
+                if let Some(owner) = owner {
+                    self.owners.insert(program_id, *owner);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Reinitializes the stacks of all programs that (transitively) import the given program ID,
+    /// so that their external stacks reflect the current edition of the program.
+    #[inline]
+    fn refresh_dependents(&mut self, program_id: ProgramID<N>) -> Result<()> {
+        // Initialize the set of refreshed program IDs.
+        let mut refreshed = vec![program_id];
+        // Iterate through the stacks, which are ordered such that imports precede their dependents.
+        for index in 0..self.stacks.len() {
+            // Retrieve the stack.
+            let stack = &self.stacks[index];
+            // Reinitialize the stack, if it imports a refreshed program.
+            if stack.program().imports().keys().any(|import| refreshed.contains(import)) {
+                let stack = stack.reinitialize(self)?;
+                refreshed.push(*stack.program_id());
+                self.stacks[index] = stack;
+            }
+        }
         Ok(())
     }
 }
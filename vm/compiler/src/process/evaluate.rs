@@ -29,4 +29,29 @@ impl<N: Network> Process<N> {
         // Evaluate the function.
         self.get_stack(request.program_id())?.evaluate_function::<A>(CallStack::evaluate(authorization)?)
     }
+
+    /// Evaluates a program function on the given request, and traces each instruction that is evaluated
+    /// in the function, along with the closures and functions that it calls.
+    ///
+    /// # Errors
+    /// If the evaluation fails, the error includes the trace up to the instruction that failed.
+    #[inline]
+    pub fn trace<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        // Enable the tracer.
+        Tracer::<N>::enable();
+        // Evaluate the authorization.
+        let result = self.evaluate::<A>(authorization);
+        // Disable the tracer, even if the evaluation failed.
+        let trace = Tracer::<N>::disable();
+
+        match (result, trace) {
+            (Ok(response), Some(trace)) => Ok((response, trace)),
+            (Ok(_), None) => bail!("Failed to trace the evaluation"),
+            (Err(error), Some(trace)) => bail!("{error}\n\n{trace}"),
+            (Err(error), None) => Err(error),
+        }
+    }
 }
@@ -32,21 +32,72 @@ impl<N: Network> Process<N> {
 
         // Initialize the execution.
         let execution = Arc::new(RwLock::new(Execution::new()));
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
         // Retrieve the stack.
         let stack = self.get_stack(request.program_id())?;
         // Execute the circuit.
-        let response = stack.execute_function::<A, R>(CallStack::execute(authorization, execution.clone())?, rng)?;
+        let call_stack = CallStack::execute(authorization, execution.clone(), assignments.clone())?;
+        let response = stack.execute_function::<A>(call_stack)?;
         // Extract the execution.
         let execution = execution.read().clone();
         // Ensure the execution is not empty.
         ensure!(!execution.is_empty(), "Execution of '{}/{}' is empty", request.program_id(), request.function_name());
 
+        // Retrieve the proving key of each transition.
+        let proving_keys = execution
+            .iter()
+            .map(|transition| self.get_proving_key(transition.program_id(), transition.function_name()))
+            .collect::<Result<Vec<_>>>()?;
+        // Prove the transitions with one aggregated proof, in the order of the transitions.
+        let assignments = assignments.read();
+        let keys_to_assignments = proving_keys.iter().zip_eq(assignments.iter()).collect::<Vec<_>>();
+        let proof = ProvingKey::prove_aggregate(&keys_to_assignments, rng)?;
+        // Construct the proven execution.
+        let execution = Execution::from(execution.edition(), &execution, Some(proof))?;
+
         Ok((response, execution))
     }
 
+    /// Executes the given authorization, and profiles the circuit count of each instruction
+    /// in the executed function, along with the closures and functions that it calls.
+    #[inline]
+    pub fn profile<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Execution<N>, Profile<N>)> {
+        // Enable the profiler.
+        Profiler::<N>::enable();
+        // Execute the authorization.
+        let result = self.execute::<A, R>(authorization, rng);
+        // Disable the profiler, even if the execution failed.
+        let profile = Profiler::<N>::disable();
+
+        // Retrieve the response and execution.
+        let (response, execution) = result?;
+        // Retrieve the profile.
+        let profile = profile.ok_or_else(|| anyhow!("Failed to profile the execution"))?;
+
+        Ok((response, execution, profile))
+    }
+
     /// Verifies the given execution is valid.
     #[inline]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
+        // Verify the execution, deferring the proof checks.
+        let proofs = self.verify_execution_deferred(execution)?;
+        // Ensure the proofs are valid.
+        ensure!(VerifyingKey::verify_many(&proofs), "Execution is invalid");
+        Ok(())
+    }
+
+    /// Verifies the given execution is valid, except for the transition proofs.
+    /// Each transition is verified against the edition of its program that it was created for.
+    /// If the execution carries an aggregated proof, it is checked here and no proofs are returned.
+    /// Otherwise, returns the transition proofs, which the caller must verify (e.g. with `VerifyingKey::verify_many`).
+    #[inline]
+    pub fn verify_execution_deferred(&self, execution: &Execution<N>) -> Result<Vec<DeferredProof<N>>> {
         // Retrieve the edition.
         let edition = execution.edition();
         // Ensure the edition matches.
@@ -60,7 +111,7 @@ impl<N: Network> Process<N> {
             // Retrieve the transition (without popping it).
             let transition = execution.peek()?;
             // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
+            let stack = self.get_stack_at_edition(transition.program_id(), transition.edition())?;
             // Ensure the number of calls matches the number of transitions.
             let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
             ensure!(
@@ -72,6 +123,8 @@ impl<N: Network> Process<N> {
 
         // Replicate the execution stack for verification.
         let mut queue = execution.clone();
+        // Initialize a vector for the verifying key, public inputs, and (optional) proof of each transition.
+        let mut instances = Vec::with_capacity(execution.len());
 
         // Verify each transition.
         while let Ok(transition) = queue.pop() {
@@ -115,7 +168,7 @@ impl<N: Network> Process<N> {
             inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
 
             // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
+            let stack = self.get_stack_at_edition(transition.program_id(), transition.edition())?;
             // Retrieve the function from the stack.
             let function = stack.get_function(transition.function_name())?;
             // Determine the number of function calls in this function.
@@ -179,14 +232,35 @@ impl<N: Network> Process<N> {
             println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
             // Retrieve the verifying key.
-            let verifying_key = self.get_verifying_key(transition.program_id(), transition.function_name())?;
-            // Ensure the proof is valid.
-            ensure!(
-                verifying_key.verify(transition.function_name(), &inputs, transition.proof()),
-                "Transition is invalid"
-            );
+            let verifying_key = stack.get_verifying_key(transition.function_name())?;
+            // Add the instance of the transition.
+            instances.push((verifying_key, inputs, transition.proof().clone()));
+        }
+        // Restore the order of the transitions, which is the order they were proven in.
+        instances.reverse();
+
+        match execution.proof() {
+            // If the execution carries an aggregated proof, check it against every transition.
+            Some(proof) => {
+                // Ensure the transitions do not carry their own proofs.
+                ensure!(
+                    instances.iter().all(|(_, _, proof)| proof.is_none()),
+                    "A transition carries its own proof in an execution with an aggregated proof"
+                );
+                // Ensure the aggregated proof is valid.
+                let keys_to_inputs = instances.into_iter().map(|(key, inputs, _)| (key, inputs)).collect::<Vec<_>>();
+                ensure!(VerifyingKey::verify_aggregate(&keys_to_inputs, proof), "The aggregated proof is invalid");
+                Ok(vec![])
+            }
+            // Otherwise, defer the check of the proof of each transition.
+            None => instances
+                .into_iter()
+                .map(|(verifying_key, inputs, proof)| match proof {
+                    Some(proof) => Ok((verifying_key, inputs, proof)),
+                    None => bail!("A transition is missing its proof in an execution without an aggregated proof"),
+                })
+                .collect(),
         }
-        Ok(())
     }
 
     /// Finalizes the execution.
@@ -205,7 +279,7 @@ impl<N: Network> Process<N> {
             // Retrieve the transition (without popping it).
             let transition = execution.peek()?;
             // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
+            let stack = self.get_stack_at_edition(transition.program_id(), transition.edition())?;
             // Ensure the number of calls matches the number of transitions.
             let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
             ensure!(
@@ -215,61 +289,100 @@ impl<N: Network> Process<N> {
             );
         }
 
-        // TODO (howardwu): This is a temporary approach. We should create a "CallStack" and recurse through the stack.
-        //  Currently this loop assumes a linearly execution stack.
-        // Finalize each transition, starting from the last one.
-        #[allow(clippy::into_iter_on_ref)]
-        for transition in execution.into_iter().rev() {
-            #[cfg(debug_assertions)]
-            println!("Finalizing transition for {}/{}...", transition.program_id(), transition.function_name());
+        // Finalize the transitions as a single atomic batch, starting from the root transition (the last one),
+        // so that a failure in any (nested) finalize scope reverts every mapping update from the execution.
+        atomic_write_batch!(store, { self.finalize_transition(store, execution, execution.len() - 1) });
 
-            // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
-            // Retrieve the function name.
-            let function_name = transition.function_name();
-
-            // If there is a finalize scope, finalize the function.
-            if let Some((_, finalize)) = stack.get_function(function_name)?.finalize() {
-                // Retrieve the finalize inputs.
-                let inputs = match transition.finalize() {
-                    Some(inputs) => inputs,
-                    // Ensure the transition contains finalize inputs.
-                    None => bail!("The transition is missing inputs for 'finalize'"),
-                };
-
-                // Initialize the registers.
-                let mut registers = FinalizeRegisters::<N>::new(stack.get_finalize_types(finalize.name())?.clone());
-
-                // Store the inputs.
-                finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
-                    // Assign the input value to the register.
-                    registers.store(stack, register, input.clone())
-                })?;
-
-                // Evaluate the commands.
-                for command in finalize.commands() {
-                    // If the evaluation fails, bail and return the error.
-                    if let Err(error) = command.evaluate_finalize(stack, store, &mut registers) {
-                        bail!("'finalize' failed to evaluate command ({command}): {error}");
-                    }
-                }
+        Ok(())
+    }
 
-                // Retrieve the output registers.
-                let output_registers =
-                    &finalize.outputs().iter().map(|output| output.register().clone()).collect::<Vec<_>>();
-
-                // TODO (howardwu): Save the outputs in ProgramStore.
-                // Load the outputs.
-                let _outputs = output_registers
-                    .iter()
-                    .map(|register| {
-                        // Retrieve the stack value from the register.
-                        registers.load(stack, &Operand::Register(register.clone()))
-                    })
-                    .collect::<Result<Vec<_>>>()?;
+    /// Finalizes the transition at the given index in the execution, followed by the transitions of
+    /// its function calls, in the order they are called. The transitions of a call are stored before
+    /// the transition of its caller, so the calls of the transition are found by walking backwards.
+    #[inline]
+    fn finalize_transition<P: ProgramStorage<N>>(
+        &self,
+        store: &ProgramStore<N, P>,
+        execution: &Execution<N>,
+        index: usize,
+    ) -> Result<()> {
+        // Retrieve the transition.
+        let transition = execution.get(index)?;
+
+        #[cfg(debug_assertions)]
+        println!("Finalizing transition for {}/{}...", transition.program_id(), transition.function_name());
+
+        // Retrieve the stack, at the edition of the program that the transition was created for.
+        let stack = self.get_stack_at_edition(transition.program_id(), transition.edition())?;
+        // Retrieve the function name.
+        let function_name = transition.function_name();
+
+        // If there is a finalize scope, finalize the function.
+        if let Some((_, finalize)) = stack.get_function(function_name)?.finalize() {
+            // Retrieve the finalize inputs.
+            let inputs = match transition.finalize() {
+                Some(inputs) => inputs,
+                // Ensure the transition contains finalize inputs.
+                None => bail!("The transition is missing inputs for 'finalize'"),
+            };
+
+            // Initialize the registers.
+            let mut registers = FinalizeRegisters::<N>::new(stack.get_finalize_types(finalize.name())?.clone());
+
+            // Store the inputs.
+            finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
+                // Assign the input value to the register.
+                registers.store(stack, register, input.clone())
+            })?;
+
+            // Evaluate the commands.
+            for command in finalize.commands() {
+                // If the evaluation fails, bail and return the error.
+                if let Err(error) = command.evaluate_finalize(stack, store, &mut registers) {
+                    bail!("'finalize' failed to evaluate command ({command}): {error}");
+                }
             }
+
+            // Retrieve the output registers.
+            let output_registers =
+                &finalize.outputs().iter().map(|output| output.register().clone()).collect::<Vec<_>>();
+
+            // TODO (howardwu): Save the outputs in ProgramStore.
+            // Load the outputs.
+            let _outputs = output_registers
+                .iter()
+                .map(|register| {
+                    // Retrieve the stack value from the register.
+                    registers.load(stack, &Operand::Register(register.clone()))
+                })
+                .collect::<Result<Vec<_>>>()?;
         }
 
-        Ok(())
+        // Determine the index of the first transition in the call tree of this transition.
+        let num_transitions = stack.get_number_of_calls(function_name)?;
+        let first = (index + 1)
+            .checked_sub(num_transitions)
+            .ok_or_else(|| anyhow!("The execution is missing transitions for '{function_name}'"))?;
+
+        // Collect the indices of the transitions for the function calls, from the last call to the first.
+        let mut calls = Vec::new();
+        let mut next = index;
+        while next > first {
+            // Retrieve the transition of the call.
+            let call_index = next - 1;
+            let call = execution.get(call_index)?;
+            // Skip over the transitions in the call tree of the call.
+            let num_call_transitions = self
+                .get_stack_at_edition(call.program_id(), call.edition())?
+                .get_number_of_calls(call.function_name())?;
+            next = (call_index + 1)
+                .checked_sub(num_call_transitions)
+                .filter(|next| *next >= first)
+                .ok_or_else(|| anyhow!("The execution is missing transitions for '{}'", call.function_name()))?;
+            calls.push(call_index);
+        }
+
+        // Finalize the function calls, in the order they are called.
+        calls.into_iter().rev().try_for_each(|call_index| self.finalize_transition(store, execution, call_index))
     }
 }
@@ -0,0 +1,318 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::types::Field;
+
+use core::marker::PhantomData;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// A content-addressed cache of circuit keys on disk, which is shared across packages and processes.
+///
+/// Each key is stored under the checksum of its function circuit (see `Stack::to_key_checksum`),
+/// and the least recently used keys are evicted once the cache exceeds its maximum size.
+#[derive(Clone)]
+pub struct KeyCache<N: Network> {
+    /// The directory of the cache.
+    directory: PathBuf,
+    /// The maximum size of the cache, in bytes.
+    max_size_in_bytes: u64,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> KeyCache<N> {
+    /// The default maximum size of the cache, in bytes.
+    pub const DEFAULT_MAX_SIZE_IN_BYTES: u64 = 4 * 1024 * 1024 * 1024;
+
+    /// Opens the cache at the given directory, creating the directory if it does not exist.
+    pub fn open<P: AsRef<Path>>(directory: P, max_size_in_bytes: u64) -> Result<Self> {
+        // Create the directory, if it does not exist.
+        let directory = directory.as_ref().to_path_buf();
+        fs::create_dir_all(&directory)?;
+        // Return the cache.
+        Ok(Self { directory, max_size_in_bytes, _phantom: PhantomData })
+    }
+
+    /// Opens the cache at the default directory (i.e. `~/.aleo/resources/keys/{network}`),
+    /// with the default maximum size.
+    pub fn open_default() -> Result<Self> {
+        let directory = aleo_std::aleo_dir().join("resources").join("keys").join(N::ID.to_string());
+        Self::open(directory, Self::DEFAULT_MAX_SIZE_IN_BYTES)
+    }
+
+    /// Returns the directory of the cache.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the maximum size of the cache, in bytes.
+    pub const fn max_size_in_bytes(&self) -> u64 {
+        self.max_size_in_bytes
+    }
+
+    /// Returns the proving key for the given checksum, if it exists in the cache.
+    pub fn get_proving_key(&self, checksum: &Field<N>) -> Result<Option<ProvingKey<N>>> {
+        self.read(&self.path(checksum, "prover"))?.map(|bytes| ProvingKey::from_bytes_le(&bytes)).transpose()
+    }
+
+    /// Returns the verifying key for the given checksum, if it exists in the cache.
+    pub fn get_verifying_key(&self, checksum: &Field<N>) -> Result<Option<VerifyingKey<N>>> {
+        self.read(&self.path(checksum, "verifier"))?.map(|bytes| VerifyingKey::from_bytes_le(&bytes)).transpose()
+    }
+
+    /// Inserts the given proving key for the given checksum, if it does not already exist in the cache.
+    pub fn insert_proving_key(&self, checksum: &Field<N>, proving_key: &ProvingKey<N>) -> Result<()> {
+        let path = self.path(checksum, "prover");
+        match path.exists() {
+            true => Ok(()),
+            false => self.write(&path, &proving_key.to_bytes_le()?),
+        }
+    }
+
+    /// Inserts the given verifying key for the given checksum, if it does not already exist in the cache.
+    pub fn insert_verifying_key(&self, checksum: &Field<N>, verifying_key: &VerifyingKey<N>) -> Result<()> {
+        let path = self.path(checksum, "verifier");
+        match path.exists() {
+            true => Ok(()),
+            false => self.write(&path, &verifying_key.to_bytes_le()?),
+        }
+    }
+
+    /// Returns the size of the cache, in bytes.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        self.entries()?.iter().map(|(_, size, _)| *size).try_fold(0u64, |total, size| {
+            total.checked_add(size).ok_or_else(|| anyhow!("The size of the key cache overflows"))
+        })
+    }
+}
+
+impl<N: Network> KeyCache<N> {
+    /// Returns the path of the key file for the given checksum and extension.
+    fn path(&self, checksum: &Field<N>, extension: &str) -> PathBuf {
+        self.directory.join(format!("{checksum}.{extension}"))
+    }
+
+    /// Returns the bytes of the given key file, if it exists, and marks the key file as recently used.
+    fn read(&self, path: &Path) -> Result<Option<Vec<u8>>> {
+        // Read the key file, returning `None` if it does not exist.
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+        // Mark the key file as recently used, so that it is evicted last.
+        // Note: A concurrent process may have already evicted the key file.
+        let _ = filetime::set_file_mtime(path, filetime::FileTime::now());
+        Ok(Some(bytes))
+    }
+
+    /// Writes the given bytes to the given key file, and evicts the least recently used key files
+    /// until the cache is within its maximum size.
+    fn write(&self, path: &Path, bytes: &[u8]) -> Result<()> {
+        // Write to a temporary file first, so that a concurrent process never reads a partial key file.
+        let temporary_path = PathBuf::from(format!("{}.{}.tmp", path.display(), std::process::id()));
+        fs::write(&temporary_path, bytes)?;
+        fs::rename(&temporary_path, path)?;
+        // Evict the least recently used key files.
+        self.evict()
+    }
+
+    /// Evicts the least recently used key files until the cache is within its maximum size.
+    fn evict(&self) -> Result<()> {
+        // Retrieve the key files, in order from least to most recently used.
+        let mut entries = self.entries()?;
+        entries.sort_by_key(|(_, _, modified)| *modified);
+
+        // Compute the size of the cache.
+        let mut size_in_bytes = entries.iter().map(|(_, size, _)| *size).sum::<u64>();
+        // Remove the least recently used key files, until the cache is within its maximum size.
+        for (path, size, _) in entries {
+            if size_in_bytes <= self.max_size_in_bytes {
+                break;
+            }
+            // Note: A concurrent process may have already removed the key file.
+            if fs::remove_file(&path).is_ok() {
+                size_in_bytes = size_in_bytes.saturating_sub(size);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the path, size, and last modified time of each key file in the cache.
+    fn entries(&self) -> Result<Vec<(PathBuf, u64, SystemTime)>> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&self.directory)? {
+            let path = entry?.path();
+            // Skip anything that is not a key file.
+            match path.extension().and_then(|extension| extension.to_str()) {
+                Some("prover") | Some("verifier") => (),
+                _ => continue,
+            }
+            // Retrieve the metadata of the key file.
+            // Note: A concurrent process may have already evicted the key file.
+            if let Ok(metadata) = fs::metadata(&path) {
+                entries.push((path, metadata.len(), metadata.modified()?));
+            }
+        }
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoDevnet;
+    use console::network::Devnet;
+
+    type CurrentNetwork = Devnet;
+
+    /// Returns a new directory for a key cache.
+    fn sample_directory(rng: &mut TestRng) -> PathBuf {
+        std::env::temp_dir().join(format!("snarkvm_key_cache_{}", rng.gen::<u64>()))
+    }
+
+    #[test]
+    fn test_key_cache_across_processes() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the key cache.
+        let directory = sample_directory(rng);
+        let max_size_in_bytes = KeyCache::<CurrentNetwork>::DEFAULT_MAX_SIZE_IN_BYTES;
+        let key_cache = KeyCache::<CurrentNetwork>::open(&directory, max_size_in_bytes).unwrap();
+        assert_eq!(key_cache.size_in_bytes().unwrap(), 0);
+
+        // Synthesize the circuit keys, and ensure they are stored in the key cache.
+        let mut process = Process::load_with_key_cache(key_cache.clone()).unwrap();
+        process.add_program(&program).unwrap();
+        process.synthesize_key::<AleoDevnet, _>(program.id(), &function_name, rng).unwrap();
+        let checksum = process.get_stack(program.id()).unwrap().to_key_checksum(&function_name).unwrap();
+        let proving_key = key_cache.get_proving_key(&checksum).unwrap().unwrap();
+        let verifying_key = key_cache.get_verifying_key(&checksum).unwrap().unwrap();
+        assert_eq!(verifying_key, process.get_verifying_key(program.id(), &function_name).unwrap());
+        assert!(key_cache.size_in_bytes().unwrap() > 0);
+
+        // Ensure a new process loads the circuit keys from the key cache.
+        let mut process = Process::load_with_key_cache(key_cache.clone()).unwrap();
+        process.add_program(&program).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+        assert_eq!(stack.to_key_checksum(&function_name).unwrap(), checksum);
+        assert!(stack.load_cached_keys(&function_name).unwrap());
+        let candidate = stack.get_proving_key(&function_name).unwrap();
+        assert_eq!(candidate.to_bytes_le().unwrap(), proving_key.to_bytes_le().unwrap());
+        assert_eq!(stack.get_verifying_key(&function_name).unwrap(), verifying_key);
+
+        // Ensure a different program does not share the circuit keys.
+        let program = Program::<CurrentNetwork>::from_str(&program.to_string().replace("add", "sub")).unwrap();
+        let mut process = Process::load_with_key_cache(key_cache).unwrap();
+        process.add_program(&program).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+        assert_ne!(stack.to_key_checksum(&function_name).unwrap(), checksum);
+        assert!(!stack.load_cached_keys(&function_name).unwrap());
+
+        // Proactively remove the key cache.
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_key_cache_loads_credits_keys() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the key cache.
+        let directory = sample_directory(rng);
+        let max_size_in_bytes = KeyCache::<CurrentNetwork>::DEFAULT_MAX_SIZE_IN_BYTES;
+        let key_cache = KeyCache::<CurrentNetwork>::open(&directory, max_size_in_bytes).unwrap();
+
+        // Synthesize circuit keys, and store them in the key cache under the checksum of 'credits.aleo/transfer'.
+        // Note: Any circuit keys suffice, as the key cache does not inspect them.
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+        process.synthesize_key::<AleoDevnet, _>(program.id(), &function_name, rng).unwrap();
+        let verifying_key = process.get_verifying_key(program.id(), &function_name).unwrap();
+        let proving_key = process.get_proving_key(program.id(), &function_name).unwrap();
+
+        let credits_id = ProgramID::from_str("credits.aleo").unwrap();
+        let transfer = Identifier::from_str("transfer").unwrap();
+        let checksum = process.get_stack(&credits_id).unwrap().to_key_checksum(&transfer).unwrap();
+        key_cache.insert_proving_key(&checksum, &proving_key).unwrap();
+        key_cache.insert_verifying_key(&checksum, &verifying_key).unwrap();
+
+        // Ensure a new process loads the 'credits.aleo' circuit keys from the key cache.
+        let process = Process::load_with_key_cache(key_cache).unwrap();
+        assert_eq!(process.get_verifying_key(&credits_id, &transfer).unwrap(), verifying_key);
+        let candidate = process.get_proving_key(&credits_id, &transfer).unwrap();
+        assert_eq!(candidate.to_bytes_le().unwrap(), proving_key.to_bytes_le().unwrap());
+
+        // Proactively remove the key cache.
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_key_cache_evicts_least_recently_used() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the key cache, with room for a single key file.
+        let directory = sample_directory(rng);
+        let key_cache = KeyCache::<CurrentNetwork>::open(&directory, 1).unwrap();
+
+        // Insert a key file, and mark it as used long ago.
+        let (first, second) = (Field::from_u64(1), Field::from_u64(2));
+        key_cache.write(&key_cache.path(&first, "verifier"), &[1u8]).unwrap();
+        filetime::set_file_mtime(key_cache.path(&first, "verifier"), filetime::FileTime::zero()).unwrap();
+        assert_eq!(key_cache.size_in_bytes().unwrap(), 1);
+
+        // Insert a second key file, and ensure the first key file is evicted.
+        key_cache.write(&key_cache.path(&second, "verifier"), &[2u8]).unwrap();
+        assert_eq!(key_cache.size_in_bytes().unwrap(), 1);
+        assert!(!key_cache.path(&first, "verifier").exists());
+        assert!(key_cache.path(&second, "verifier").exists());
+
+        // Proactively remove the key cache.
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -19,22 +19,29 @@ pub use stack::*;
 
 mod additional_fee;
 mod authorize;
+mod cost;
 mod deploy;
 mod evaluate;
 mod execute;
 
+mod key_cache;
+pub use key_cache::*;
+
 use crate::{
+    atomic_write_batch,
     ledger::{ProgramStorage, ProgramStore},
     AdditionalFee,
+    DeferredProof,
     Instruction,
     Operand,
     Program,
     ProvingKey,
+    Transition,
     UniversalSRS,
     VerifyingKey,
 };
 use console::{
-    account::PrivateKey,
+    account::{Address, PrivateKey},
     network::prelude::*,
     program::{Identifier, Plaintext, ProgramID, Record, Request, Response, Value},
     types::{I64, U64},
@@ -55,6 +62,12 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// The mapping of program IDs to the stacks of their previous editions, in order of edition.
+    retired_stacks: IndexMap<ProgramID<N>, Vec<Stack<N>>>,
+    /// The mapping of upgradable program IDs to their owners.
+    owners: IndexMap<ProgramID<N>, Address<N>>,
+    /// The cache of circuit keys on disk, if one is used.
+    key_cache: Option<KeyCache<N>>,
 }
 
 impl<N: Network> Process<N> {
@@ -62,7 +75,13 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn setup<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(rng: &mut R) -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            retired_stacks: IndexMap::new(),
+            owners: IndexMap::new(),
+            key_cache: None,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -70,6 +89,7 @@ impl<N: Network> Process<N> {
         let stack = Stack::new(&process, &program)?;
 
         // Synthesize the 'credits.aleo' circuit keys.
+        crate::supply_network_bytes();
         for function_name in program.functions().keys() {
             stack.synthesize_key::<A, _>(function_name, rng)?;
         }
@@ -98,7 +118,13 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn load() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            retired_stacks: IndexMap::new(),
+            owners: IndexMap::new(),
+            key_cache: None,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -106,16 +132,54 @@ impl<N: Network> Process<N> {
         let stack = Stack::new(&process, &program)?;
 
         // Synthesize the 'credits.aleo' circuit keys.
+        crate::supply_network_bytes();
+        for function_name in program.functions().keys() {
+            // Load the proving and verifying key bytes, if the network provides them.
+            // Otherwise, the circuit keys are synthesized on demand.
+            if let Some((proving_key, verifying_key)) = N::credits_key_bytes(&function_name.to_string())? {
+                // Insert the proving and verifying key.
+                stack.insert_proving_key(function_name, ProvingKey::from_bytes_le(proving_key)?)?;
+                stack.insert_verifying_key(function_name, VerifyingKey::from_bytes_le(verifying_key)?)?;
+            }
+        }
+
+        // Add the stack to the process.
+        process.stacks.insert(*program.id(), stack);
+        // Return the process.
+        Ok(process)
+    }
+
+    /// Initializes a new process, which consults the given key cache before synthesizing circuit keys,
+    /// and stores the circuit keys it synthesizes or inserts in the key cache.
+    #[inline]
+    pub fn load_with_key_cache(key_cache: KeyCache<N>) -> Result<Self> {
+        // Initialize the process.
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            retired_stacks: IndexMap::new(),
+            owners: IndexMap::new(),
+            key_cache: Some(key_cache),
+        };
+
+        // Initialize the 'credits.aleo' program.
+        let program = Program::credits()?;
+        // Compute the 'credits.aleo' program stack, which uses the key cache of the process.
+        let stack = Stack::new(&process, &program)?;
+
+        // Load the 'credits.aleo' circuit keys.
+        crate::supply_network_bytes();
         for function_name in program.functions().keys() {
-            // TODO (howardwu): Abstract this into the `Network` trait.
-            // Load the proving and verifying key bytes.
-            let (proving_key, verifying_key) = snarkvm_parameters::testnet3::TESTNET3_CREDITS_PROGRAM
-                .get(&function_name.to_string())
-                .ok_or_else(|| anyhow!("Circuit keys for credits.aleo/{function_name}' not found"))?;
-
-            // Insert the proving and verifying key.
-            stack.insert_proving_key(function_name, ProvingKey::from_bytes_le(proving_key)?)?;
-            stack.insert_verifying_key(function_name, VerifyingKey::from_bytes_le(verifying_key)?)?;
+            // Load the circuit keys from the key cache, if they exist.
+            if stack.load_cached_keys(function_name)? {
+                continue;
+            }
+            // Otherwise, load the proving and verifying key bytes, if the network provides them.
+            // Note: Inserting the circuit keys also stores them in the key cache.
+            if let Some((proving_key, verifying_key)) = N::credits_key_bytes(&function_name.to_string())? {
+                stack.insert_proving_key(function_name, ProvingKey::from_bytes_le(proving_key)?)?;
+                stack.insert_verifying_key(function_name, VerifyingKey::from_bytes_le(verifying_key)?)?;
+            }
         }
 
         // Add the stack to the process.
@@ -124,13 +188,32 @@ impl<N: Network> Process<N> {
         Ok(process)
     }
 
+    /// Initializes a new process with the key cache at the default directory (see `KeyCache::open_default`).
+    /// If the default directory cannot be created, the process is initialized without a key cache.
+    #[inline]
+    pub fn load_with_default_key_cache() -> Result<Self> {
+        match KeyCache::open_default() {
+            Ok(key_cache) => Self::load_with_key_cache(key_cache),
+            Err(error) => {
+                warn!("Failed to open the key cache, so circuit keys will not be cached: {error}");
+                Self::load()
+            }
+        }
+    }
+
     /// Initializes a new process with a cache of previously used keys. This version is suitable for tests
     /// (which often use nested loops that keep reusing those), as their deserialization is slow.
     #[cfg(test)]
     #[inline]
     pub fn load_with_cache(cache: &mut HashMap<String, (ProvingKey<N>, VerifyingKey<N>)>) -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            retired_stacks: IndexMap::new(),
+            owners: IndexMap::new(),
+            key_cache: None,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -138,20 +221,22 @@ impl<N: Network> Process<N> {
         let stack = Stack::new(&process, &program)?;
 
         // Synthesize the 'credits.aleo' circuit keys.
+        crate::supply_network_bytes();
         for function_name in program.functions().keys() {
-            // TODO (howardwu): Abstract this into the `Network` trait.
-            // Load the proving and verifying key bytes.
-            let (proving_key, verifying_key) = snarkvm_parameters::testnet3::TESTNET3_CREDITS_PROGRAM
-                .get(&function_name.to_string())
-                .ok_or_else(|| anyhow!("Circuit keys for credits.aleo/{function_name}' not found"))?;
-
-            let (proving_key, verifying_key) = cache.entry(function_name.to_string()).or_insert_with(|| {
-                (ProvingKey::from_bytes_le(proving_key).unwrap(), VerifyingKey::from_bytes_le(verifying_key).unwrap())
-            });
-
-            // Insert the proving and verifying key.
-            stack.insert_proving_key(function_name, proving_key.clone())?;
-            stack.insert_verifying_key(function_name, verifying_key.clone())?;
+            // Load the proving and verifying key bytes, if the network provides them.
+            // Otherwise, the circuit keys are synthesized on demand.
+            if let Some((proving_key, verifying_key)) = N::credits_key_bytes(&function_name.to_string())? {
+                let (proving_key, verifying_key) = cache.entry(function_name.to_string()).or_insert_with(|| {
+                    (
+                        ProvingKey::from_bytes_le(proving_key).unwrap(),
+                        VerifyingKey::from_bytes_le(verifying_key).unwrap(),
+                    )
+                });
+
+                // Insert the proving and verifying key.
+                stack.insert_proving_key(function_name, proving_key.clone())?;
+                stack.insert_verifying_key(function_name, verifying_key.clone())?;
+            }
         }
 
         // Add the stack to the process.
@@ -166,6 +251,12 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the key cache, if the process uses one.
+    #[inline]
+    pub const fn key_cache(&self) -> Option<&KeyCache<N>> {
+        self.key_cache.as_ref()
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -183,6 +274,33 @@ impl<N: Network> Process<N> {
         Ok(stack)
     }
 
+    /// Returns the stack for the given program ID at the given edition.
+    /// This is used to verify transactions that were created for a previous edition of the program.
+    #[inline]
+    pub fn get_stack_at_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<&Stack<N>> {
+        // Retrieve the stack of the current edition.
+        let stack = self.get_stack(program_id)?;
+        // Return the stack of the current edition, or of a retired edition.
+        let stack = match stack.edition() == edition {
+            true => stack,
+            false => self
+                .retired_stacks
+                .get(program_id)
+                .and_then(|stacks| stacks.get(edition as usize))
+                .ok_or_else(|| anyhow!("Edition {edition} of program '{program_id}' does not exist"))?,
+        };
+        // Ensure the edition matches.
+        ensure!(stack.edition() == edition, "Expected edition {edition} of '{program_id}', found {}", stack.edition());
+        // Return the stack.
+        Ok(stack)
+    }
+
+    /// Returns the owner of the given program ID, if the program is upgradable.
+    #[inline]
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Option<&Address<N>> {
+        self.owners.get(program_id)
+    }
+
     /// Returns the program for the given program ID.
     #[inline]
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<&Program<N>> {
@@ -361,6 +479,76 @@ function compute:
         execution.pop().unwrap()
     }
 
+    /// Samples a transition that carries its own proof, as an additional fee does.
+    pub(crate) fn sample_proven_transition() -> Transition<CurrentNetwork> {
+        static INSTANCE: OnceCell<Transition<CurrentNetwork>> = OnceCell::new();
+        INSTANCE
+            .get_or_init(|| {
+                // Initialize a new program.
+                let (_, program) = Program::<CurrentNetwork>::parse(
+                    r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+                )
+                .unwrap();
+
+                // Declare the function name.
+                let function_name = Identifier::from_str("compute").unwrap();
+
+                // Initialize the RNG.
+                let rng = &mut TestRng::default();
+                // Initialize a new caller account.
+                let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+                // Construct the process.
+                let process = sample_process(&program);
+                // Authorize the function call.
+                let authorization = process
+                    .authorize::<CurrentAleo, _>(
+                        &caller_private_key,
+                        program.id(),
+                        function_name,
+                        &[
+                            Value::<CurrentNetwork>::from_str("5u32").unwrap(),
+                            Value::<CurrentNetwork>::from_str("10u32").unwrap(),
+                        ],
+                        rng,
+                    )
+                    .unwrap();
+
+                // Execute the request, saving the assignment of the transition.
+                let execution = Arc::new(RwLock::new(Execution::new()));
+                let assignments = Assignments::<CurrentNetwork>::default();
+                let call_stack = CallStack::execute(authorization, execution.clone(), assignments.clone()).unwrap();
+                process.get_stack(program.id()).unwrap().execute_function::<CurrentAleo>(call_stack).unwrap();
+                let transition = execution.read().peek().unwrap();
+
+                // Prove the transition.
+                let proving_key = process.get_proving_key(program.id(), &function_name).unwrap();
+                let proof = proving_key.prove(&function_name, &assignments.read()[0], rng).unwrap();
+                // Return the proven transition.
+                Transition::new(
+                    *transition.program_id(),
+                    *transition.function_name(),
+                    transition.edition(),
+                    transition.inputs().to_vec(),
+                    transition.outputs().to_vec(),
+                    transition.finalize().clone(),
+                    Some(proof),
+                    *transition.tpk(),
+                    *transition.tcm(),
+                    *transition.fee(),
+                )
+                .unwrap()
+            })
+            .clone()
+    }
+
     /// Initializes a new process with the given program.
     pub(crate) fn sample_process(program: &Program<CurrentNetwork>) -> Process<CurrentNetwork> {
         // Construct a new process.
@@ -487,10 +675,10 @@ mod tests {
             .unwrap();
         let result = process.execute::<CurrentAleo, _>(authorization, rng);
         assert!(result.is_err());
-        assert_eq!(
-            result.err().unwrap().to_string(),
-            format!("'token.aleo/genesis' is not satisfied on the given inputs (26479 constraints).")
-        );
+        // Ensure the error reports the first unsatisfied constraint.
+        let error = result.err().unwrap().to_string();
+        assert!(error.starts_with("'token.aleo/genesis' is not satisfied on the given inputs (26479 constraints). "));
+        assert!(error.contains(" is not satisfied: ("));
     }
 
     #[test]
@@ -1003,6 +1191,9 @@ function transfer:
 
         assert!(process.verify_execution(&execution).is_ok());
 
+        // Ensure both transitions are proven by one aggregated proof.
+        assert_eq!(execution.proof().as_ref().unwrap().batch_sizes(), &[1, 1]);
+
         // use circuit::Environment;
         //
         // assert_eq!(6427, CurrentAleo::num_constants());
@@ -1018,6 +1209,187 @@ function transfer:
         // assert_eq!(215810, CurrentAleo::num_gates());
     }
 
+    #[test]
+    fn test_process_execute_preserves_circuit() {
+        use circuit::{Environment, Inject};
+
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Start synthesizing a circuit in the current environment.
+        CurrentAleo::reset();
+        let a = circuit::Field::<CurrentAleo>::new(circuit::Mode::Private, Field::from_u64(5));
+        let _b = a.square();
+        let expected = CurrentAleo::count();
+
+        // Authorize and execute the function call, which is synthesized in a session of its own.
+        let inputs = [Value::from_str("5u32").unwrap(), Value::from_str("10u32").unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("compute").unwrap(),
+                &inputs,
+                rng,
+            )
+            .unwrap();
+        let (_response, execution) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert!(process.verify_execution(&execution).is_ok());
+
+        // Ensure the circuit in the current environment is unchanged.
+        assert_eq!(expected, CurrentAleo::count());
+        assert!(CurrentAleo::is_satisfied());
+
+        CurrentAleo::reset();
+    }
+
+    #[test]
+    fn test_process_deploy_and_execute_concurrently_devnet() {
+        use circuit::network::AleoDevnet;
+        use console::network::Devnet;
+
+        // Initialize a new program.
+        let (string, program) = Program::<Devnet>::parse(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;
+
+function scale:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    mul r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Deploy the program, which synthesizes the circuit of each function in parallel.
+        let mut process = Process::<Devnet>::load().unwrap();
+        let deployment = process.deploy::<AleoDevnet, _>(&program, rng).unwrap();
+        process.verify_deployment::<AleoDevnet, _>(&deployment, rng).unwrap();
+        process.load_deployment(&deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<Devnet>::new(rng).unwrap();
+        // Authorize a call to each function.
+        let inputs = [Value::from_str("5u32").unwrap(), Value::from_str("10u32").unwrap()];
+        let authorizations = ["compute", "scale"].map(|function_name| {
+            let function_name = Identifier::from_str(function_name).unwrap();
+            process.authorize::<AleoDevnet, _>(&caller_private_key, program.id(), function_name, &inputs, rng).unwrap()
+        });
+
+        // Execute the function calls concurrently, each on a thread (and in a circuit session) of its own.
+        let process = &process;
+        let executions = std::thread::scope(|scope| {
+            let handles = authorizations
+                .map(|authorization| {
+                    scope.spawn(move || process.execute::<AleoDevnet, _>(authorization, &mut TestRng::default()))
+                })
+                .into_iter()
+                .collect::<Vec<_>>();
+            handles.into_iter().map(|handle| handle.join().unwrap().unwrap()).collect::<Vec<_>>()
+        });
+
+        // Ensure each execution is unaffected by the other.
+        for ((response, execution), expected) in executions.iter().zip_eq(["15u32", "50u32"]) {
+            assert_eq!(response.outputs(), &[Value::from_str(expected).unwrap()]);
+            assert!(process.verify_execution(execution).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_process_execute_devnet() {
+        use circuit::network::AleoDevnet;
+        use console::network::Devnet;
+
+        // Initialize a new program.
+        let (string, program) = Program::<Devnet>::parse(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Construct the process, which synthesizes its circuit keys on demand.
+        let mut process = Process::<Devnet>::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<Devnet>::new(rng).unwrap();
+
+        // Authorize and execute the function call.
+        let inputs = [Value::from_str("5u32").unwrap(), Value::from_str("10u32").unwrap()];
+        let authorization = process
+            .authorize::<AleoDevnet, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("compute").unwrap(),
+                &inputs,
+                rng,
+            )
+            .unwrap();
+        let (response, execution) = process.execute::<AleoDevnet, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), &[Value::from_str("15u32").unwrap()]);
+        assert!(process.verify_execution(&execution).is_ok());
+
+        // Ensure the transitions are proven by the aggregated proof of the execution.
+        assert!(execution.proof().is_some());
+        assert!(execution.iter().all(|transition| transition.proof().is_none()));
+
+        // Execute the function call on different inputs.
+        let inputs = [Value::from_str("6u32").unwrap(), Value::from_str("10u32").unwrap()];
+        let authorization = process
+            .authorize::<AleoDevnet, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("compute").unwrap(),
+                &inputs,
+                rng,
+            )
+            .unwrap();
+        let (_, other_execution) = process.execute::<AleoDevnet, _>(authorization, rng).unwrap();
+        assert!(process.verify_execution(&other_execution).is_ok());
+
+        // Ensure the execution fails to verify with the aggregated proof of another execution.
+        let candidate = Execution::from(execution.edition(), &execution, other_execution.proof().clone()).unwrap();
+        assert!(process.verify_execution(&candidate).is_err());
+    }
+
     #[test]
     fn test_process_execute_and_finalize_increment() {
         // Initialize a new program.
@@ -1325,4 +1697,580 @@ finalize mint_public:
             store.get_value(program_id, &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
         assert_eq!(candidate, Value::from_str("3u64").unwrap());
     }
+
+    #[test]
+    fn test_process_upgrade_devnet() {
+        use circuit::network::AleoDevnet;
+        use console::network::Devnet;
+
+        // Initialize the first edition of the program.
+        let program = Program::<Devnet>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;
+",
+        )
+        .unwrap();
+        // Initialize the second edition of the program, which multiplies the inputs and adds a mapping.
+        let upgrade = Program::<Devnet>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+mapping calls:
+    key owner as address.public;
+    value count as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    mul r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;
+    increment calls[r0] by 1u64;
+",
+        )
+        .unwrap();
+
+        // Declare the program ID, mapping, and function name.
+        let program_id = program.id();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize the owner and caller accounts.
+        let owner_private_key = PrivateKey::<Devnet>::new(rng).unwrap();
+        let caller_private_key = PrivateKey::<Devnet>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Construct the process and program store.
+        let mut process = Process::<Devnet>::load().unwrap();
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Deploy the first edition, signed by the owner.
+        let deployment = process.deploy::<AleoDevnet, _>(&program, rng).unwrap().with_owner(&owner_private_key, rng);
+        let deployment = deployment.unwrap();
+        assert_eq!(deployment.edition(), 0);
+        process.verify_deployment::<AleoDevnet, _>(&deployment, rng).unwrap();
+        process.finalize_deployment(&store, &deployment).unwrap();
+        assert_eq!(process.get_owner(program_id), Some(&Address::try_from(&owner_private_key).unwrap()));
+
+        // Executes the program function, and finalizes the execution.
+        let execute = |process: &mut Process<Devnet>, rng: &mut TestRng| {
+            let inputs = [
+                Value::from_str(&caller.to_string()).unwrap(),
+                Value::from_str("3u64").unwrap(),
+                Value::from_str("5u64").unwrap(),
+            ];
+            let authorization = process
+                .authorize::<AleoDevnet, _>(&caller_private_key, program_id, function_name, &inputs, rng)
+                .unwrap();
+            let (_, execution) = process.execute::<AleoDevnet, _>(authorization, rng).unwrap();
+            process.verify_execution(&execution).unwrap();
+            process.finalize_execution(&store, &execution).unwrap();
+            execution
+        };
+
+        // Execute the first edition.
+        let execution = execute(&mut process, rng);
+        let key = Plaintext::from(Literal::Address(caller));
+        assert_eq!(store.get_value(program_id, &mapping_name, &key).unwrap(), Some(Value::from_str("8u64").unwrap()));
+
+        // Ensure only the owner may upgrade the program.
+        assert!(process.upgrade::<AleoDevnet, _>(&caller_private_key, &upgrade, rng).is_err());
+        // Ensure an unsigned upgrade is rejected.
+        let unsigned = Stack::upgrade(&process, &upgrade).unwrap().deploy::<AleoDevnet, _>(rng).unwrap();
+        assert!(process.verify_deployment::<AleoDevnet, _>(&unsigned, rng).is_err());
+
+        // Deploy the second edition.
+        let deployment = process.upgrade::<AleoDevnet, _>(&owner_private_key, &upgrade, rng).unwrap();
+        assert_eq!(deployment.edition(), 1);
+        process.verify_deployment::<AleoDevnet, _>(&deployment, rng).unwrap();
+        process.finalize_deployment(&store, &deployment).unwrap();
+        assert_eq!(process.get_program(program_id).unwrap(), &upgrade);
+
+        // Ensure the existing mapping is preserved, and the new mapping is initialized.
+        assert_eq!(store.get_value(program_id, &mapping_name, &key).unwrap(), Some(Value::from_str("8u64").unwrap()));
+        assert!(store.contains_mapping(program_id, &Identifier::from_str("calls").unwrap()).unwrap());
+
+        // Ensure the execution of the first edition still verifies, against the first edition.
+        let transition = execution.peek().unwrap();
+        assert_eq!(transition.edition(), 0);
+        assert!(process.verify_execution(&execution).is_ok());
+
+        // Ensure the execution of the first edition fails to verify against the second edition.
+        let candidate = Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            1,
+            transition.inputs().to_vec(),
+            transition.outputs().to_vec(),
+            transition.finalize().clone(),
+            transition.proof().clone(),
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.fee(),
+        )
+        .unwrap();
+        let candidate = Execution::from(execution.edition(), &[candidate], execution.proof().clone()).unwrap();
+        assert!(process.verify_execution(&candidate).is_err());
+
+        // Execute the second edition.
+        let execution = execute(&mut process, rng);
+        assert_eq!(execution.peek().unwrap().edition(), 1);
+        assert_eq!(store.get_value(program_id, &mapping_name, &key).unwrap(), Some(Value::from_str("23u64").unwrap()));
+    }
+
+    #[test]
+    fn test_process_upgrade_rejects_incompatible_programs() {
+        use console::network::Devnet;
+
+        // Initialize the first edition of the program.
+        let program = Program::<Devnet>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::<Devnet>::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Ensure a new program ID cannot be upgraded.
+        let candidate = Program::from_str(&program.to_string().replace("testing.aleo", "other.aleo")).unwrap();
+        assert!(Stack::upgrade(&process, &candidate).is_err());
+        // Ensure a mapping cannot change its value type.
+        let candidate = Program::from_str(&program.to_string().replace("amount as u64", "amount as u32")).unwrap();
+        let error = Stack::upgrade(&process, &candidate).err().unwrap();
+        assert!(error.to_string().contains("mapping 'account' changes its value"), "{error}");
+        // Ensure a mapping cannot change its key type.
+        let candidate = Program::from_str(&program.to_string().replace("owner as address", "owner as field")).unwrap();
+        let error = Stack::upgrade(&process, &candidate).err().unwrap();
+        assert!(error.to_string().contains("mapping 'account' changes its key"), "{error}");
+        // Ensure a function cannot change its output types.
+        let candidate =
+            Program::from_str(&program.to_string().replace("output r1 as u64.public", "output r1 as u64.private"))
+                .unwrap();
+        let error = Stack::upgrade(&process, &candidate).err().unwrap();
+        assert!(error.to_string().contains("function 'compute' changes its output types"), "{error}");
+
+        // Ensure a compatible upgrade is the next edition.
+        let noop = "function noop:\n    input r0 as u8.public;\n    add r0 r0 into r1;\n    output r1 as u8.public;";
+        let candidate = Program::from_str(&format!("{program}\n{noop}")).unwrap();
+        assert_eq!(Stack::upgrade(&process, &candidate).unwrap().edition(), 1);
+    }
+
+    #[test]
+    fn test_process_finalize_execution_is_atomic() {
+        use circuit::network::AleoDevnet;
+        use console::network::Devnet;
+
+        // Initialize a program, whose finalize scope fails if its mapping is not initialized in storage.
+        let child = Program::<Devnet>::from_str(
+            r"
+program child.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function spend:
+    input r0 as address.public;
+    input r1 as u64.public;
+    add r1 r1 into r2;
+    output r2 as u64.public;
+    finalize r0 r1;
+
+finalize spend:
+    input r0 as address.public;
+    input r1 as u64.public;
+    decrement account[r0] by r1;
+",
+        )
+        .unwrap();
+        // Initialize a program, whose finalize scope succeeds before the finalize scope of its call.
+        let parent = Program::<Devnet>::from_str(
+            r"
+import child.aleo;
+
+program parent.aleo;
+
+mapping counter:
+    key owner as address.public;
+    value count as u64.public;
+
+function pay:
+    input r0 as address.public;
+    input r1 as u64.public;
+    call child.aleo/spend r0 r1 into r2;
+    output r2 as u64.public;
+    finalize r0 r1;
+
+finalize pay:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment counter[r0] by r1;
+",
+        )
+        .unwrap();
+
+        // Construct the process and program store.
+        let mut process = Process::<Devnet>::load().unwrap();
+        process.add_program(&child).unwrap();
+        process.add_program(&parent).unwrap();
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        let account = Identifier::from_str("account").unwrap();
+        let counter = Identifier::from_str("counter").unwrap();
+        store.initialize_mapping(parent.id(), &counter).unwrap();
+
+        // Initialize the RNG and caller.
+        let rng = &mut TestRng::default();
+        let caller_private_key = PrivateKey::<Devnet>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Execute the parent function.
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("5u64").unwrap()];
+        let function_name = Identifier::from_str("pay").unwrap();
+        let authorization =
+            process.authorize::<AleoDevnet, _>(&caller_private_key, parent.id(), function_name, &inputs, rng).unwrap();
+        let (_, execution) = process.execute::<AleoDevnet, _>(authorization, rng).unwrap();
+        assert_eq!(execution.len(), 2);
+
+        // Ensure the nested decrement fails, and the increment in the parent is reverted.
+        assert!(process.finalize_execution(&store, &execution).is_err());
+        assert!(!store.is_atomic_in_progress());
+        assert_eq!(store.get_value(parent.id(), &counter, &key).unwrap(), None);
+
+        // Initialize and fund the account, and ensure the execution now finalizes every mapping update.
+        store.initialize_mapping(child.id(), &account).unwrap();
+        store.insert_key_value(child.id(), &account, key.clone(), Value::from_str("8u64").unwrap()).unwrap();
+        process.finalize_execution(&store, &execution).unwrap();
+        assert_eq!(store.get_value(parent.id(), &counter, &key).unwrap(), Some(Value::from_str("5u64").unwrap()));
+        assert_eq!(store.get_value(child.id(), &account, &key).unwrap(), Some(Value::from_str("3u64").unwrap()));
+    }
+
+    #[test]
+    fn test_process_cost_devnet() {
+        use circuit::network::AleoDevnet;
+        use console::network::Devnet;
+
+        // Initialize a program, whose finalize scope increments a mapping twice.
+        let program = Program::<Devnet>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    add r1 r1 into r2;
+    output r2 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;
+    increment account[r0] by r1;
+",
+        )
+        .unwrap();
+
+        // Initialize the RNG and process.
+        let rng = &mut TestRng::default();
+        let mut process = Process::<Devnet>::load().unwrap();
+
+        // Ensure the deployment cost prices the bytes, the constraints, and the new mapping.
+        let deployment = process.deploy::<AleoDevnet, _>(&program, rng).unwrap();
+        let num_constraints = deployment
+            .verifying_keys()
+            .values()
+            .map(|(verifying_key, _)| verifying_key.circuit_info.num_constraints as u64)
+            .sum::<u64>();
+        let expected = deployment.to_bytes_le().unwrap().len() as u64 * Process::<Devnet>::STORAGE_COST_PER_BYTE
+            + num_constraints * Process::<Devnet>::CONSTRAINT_COST
+            + Process::<Devnet>::MAPPING_INITIALIZATION_COST;
+        assert_eq!(process.deployment_cost(&deployment).unwrap(), expected);
+
+        // Load the deployment, and ensure an existing mapping is not priced again.
+        process.load_deployment(&deployment).unwrap();
+        let expected = expected - Process::<Devnet>::MAPPING_INITIALIZATION_COST;
+        assert_eq!(process.deployment_cost(&deployment).unwrap(), expected);
+
+        // Execute the program.
+        let caller_private_key = PrivateKey::<Devnet>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("3u64").unwrap()];
+        let authorization =
+            process.authorize::<AleoDevnet, _>(&caller_private_key, program.id(), function_name, &inputs, rng).unwrap();
+        let (_, execution) = process.execute::<AleoDevnet, _>(authorization, rng).unwrap();
+
+        // Ensure the execution cost prices the bytes, the constraints, and each finalize command.
+        let verifying_key = process.get_stack(program.id()).unwrap().get_verifying_key(&function_name).unwrap();
+        let expected = execution.to_bytes_le().unwrap().len() as u64 * Process::<Devnet>::STORAGE_COST_PER_BYTE
+            + verifying_key.circuit_info.num_constraints as u64 * Process::<Devnet>::CONSTRAINT_COST
+            + 2 * 10_000;
+        assert_eq!(process.execution_cost(&execution).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_process_profile_devnet() {
+        use circuit::network::AleoDevnet;
+        use console::network::Devnet;
+
+        // Initialize a program, with a function to be called.
+        let child = Program::<Devnet>::from_str(
+            r"
+program child.aleo;
+
+function twice:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;
+",
+        )
+        .unwrap();
+        // Initialize a program, whose function calls a closure and a function.
+        let parent = Program::<Devnet>::from_str(
+            r"
+import child.aleo;
+
+program parent.aleo;
+
+closure squared:
+    input r0 as u64;
+    mul r0 r0 into r1;
+    output r1 as u64;
+
+function compute:
+    input r0 as u64.public;
+    call squared r0 into r1;
+    call child.aleo/twice r1 into r2;
+    add r1 r2 into r3;
+    output r3 as u64.public;
+",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::<Devnet>::load().unwrap();
+        process.add_program(&child).unwrap();
+        process.add_program(&parent).unwrap();
+
+        // Initialize the RNG and caller.
+        let rng = &mut TestRng::default();
+        let caller_private_key = PrivateKey::<Devnet>::new(rng).unwrap();
+
+        // Profile the execution of the parent function.
+        let function_name = Identifier::from_str("compute").unwrap();
+        let inputs = [Value::from_str("3u64").unwrap()];
+        let authorization =
+            process.authorize::<AleoDevnet, _>(&caller_private_key, parent.id(), function_name, &inputs, rng).unwrap();
+        let (response, execution, profile) = process.profile::<AleoDevnet, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), [Value::from_str("27u64").unwrap()]);
+        assert_eq!(execution.len(), 2);
+
+        // Ensure the profile of the function contains each instruction, and its whole circuit.
+        assert_eq!(profile.kind(), ProfileKind::Function);
+        assert_eq!((profile.program_id(), profile.name()), (parent.id(), &function_name));
+        assert_eq!(profile.instructions().len(), 3);
+        let verifying_key = process.get_verifying_key(parent.id(), &function_name).unwrap();
+        assert!(profile.total().num_constraints() <= verifying_key.circuit_info.num_constraints as u64);
+        let num_constraints = profile.instructions().iter().map(|instruction| instruction.count().num_constraints());
+        assert!(num_constraints.sum::<u64>() < profile.total().num_constraints());
+        assert!(profile.instructions()[2].count().num_constraints() > 0);
+        assert!(profile.instructions()[2].call().is_none());
+
+        // Ensure the call to the closure counts the closure.
+        let closure = profile.instructions()[0].call().unwrap();
+        assert_eq!(closure.kind(), ProfileKind::Closure);
+        assert_eq!(closure.name(), &Identifier::from_str("squared").unwrap());
+        assert_eq!(closure.instructions().len(), 1);
+        assert_eq!(closure.total(), closure.instructions()[0].count());
+        assert!(profile.instructions()[0].count().num_constraints() >= closure.total().num_constraints());
+
+        // Ensure the call to the function is profiled in a circuit of its own.
+        let function = profile.instructions()[1].call().unwrap();
+        assert_eq!(function.kind(), ProfileKind::Function);
+        assert_eq!(function.program_id(), child.id());
+        assert_eq!(function.instructions().len(), 1);
+        assert!(function.total().num_constraints() > 0);
+
+        // Ensure the profile is printed as a table, and exported as JSON.
+        let table = profile.to_string();
+        assert!(table.starts_with("function 'parent.aleo/compute'"));
+        assert!(table.contains("    closure 'parent.aleo/squared'"));
+        assert!(table.contains("    function 'child.aleo/twice'"));
+        let json = serde_json::to_string(&profile).unwrap();
+        assert_eq!(profile, serde_json::from_str(&json).unwrap());
+
+        // Ensure the profiler is disabled after profiling.
+        let authorization =
+            process.authorize::<AleoDevnet, _>(&caller_private_key, parent.id(), function_name, &inputs, rng).unwrap();
+        process.execute::<AleoDevnet, _>(authorization, rng).unwrap();
+        assert!(Profiler::<Devnet>::disable().is_none());
+    }
+
+    #[test]
+    fn test_process_trace_devnet() {
+        use circuit::network::AleoDevnet;
+        use console::network::Devnet;
+
+        // Initialize a program, with a function to be called.
+        let child = Program::<Devnet>::from_str(
+            r"
+program child.aleo;
+
+function twice:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;
+",
+        )
+        .unwrap();
+        // Initialize a program, whose function calls a closure and a function.
+        let parent = Program::<Devnet>::from_str(
+            r"
+import child.aleo;
+
+program parent.aleo;
+
+closure squared:
+    input r0 as u64;
+    assert.neq r0 0u64;
+    mul r0 r0 into r1;
+    output r1 as u64;
+
+function compute:
+    input r0 as u64.public;
+    call squared r0 into r1;
+    call child.aleo/twice r1 into r2;
+    add r1 r2 into r3;
+    output r3 as u64.public;
+",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::<Devnet>::load().unwrap();
+        process.add_program(&child).unwrap();
+        process.add_program(&parent).unwrap();
+
+        // Initialize the RNG and caller.
+        let rng = &mut TestRng::default();
+        let caller_private_key = PrivateKey::<Devnet>::new(rng).unwrap();
+
+        // Trace the evaluation of the parent function.
+        let function_name = Identifier::from_str("compute").unwrap();
+        let inputs = [Value::from_str("3u64").unwrap()];
+        let authorization =
+            process.authorize::<AleoDevnet, _>(&caller_private_key, parent.id(), function_name, &inputs, rng).unwrap();
+        let (response, trace) = process.trace::<AleoDevnet>(authorization).unwrap();
+        assert_eq!(response.outputs(), [Value::from_str("27u64").unwrap()]);
+
+        // Ensure the trace of the function contains each instruction, with its operands and destinations.
+        let value = |string: &str| Value::<Devnet>::from_str(string).unwrap();
+        let register = |string: &str| console::program::Register::<Devnet>::from_str(string).unwrap();
+        assert_eq!(trace.kind(), TraceKind::Function);
+        assert_eq!((trace.program_id(), trace.name()), (parent.id(), &function_name));
+        assert_eq!(trace.inputs(), [(register("r0"), value("3u64"))]);
+        assert_eq!(trace.outputs(), [(register("r3"), value("27u64"))]);
+        assert_eq!(trace.steps().len(), 3);
+        let step = &trace.steps()[2];
+        assert_eq!((step.index(), step.instruction()), (2, "add r1 r2 into r3;"));
+        assert_eq!(step.operands(), [
+            (Operand::from_str("r1").unwrap(), value("9u64")),
+            (Operand::from_str("r2").unwrap(), value("18u64"))
+        ]);
+        assert_eq!(step.destinations(), [(register("r3"), value("27u64"))]);
+        assert!(step.call().is_none());
+
+        // Ensure the calls to the closure and function are traced.
+        let closure = trace.steps()[0].call().unwrap();
+        assert_eq!(closure.kind(), TraceKind::Closure);
+        assert_eq!(closure.name(), &Identifier::from_str("squared").unwrap());
+        assert_eq!(closure.outputs(), [(register("r1"), value("9u64"))]);
+        let function = trace.steps()[1].call().unwrap();
+        assert_eq!(function.kind(), TraceKind::Function);
+        assert_eq!(function.program_id(), child.id());
+        assert_eq!(function.steps()[0].destinations(), [(register("r1"), value("18u64"))]);
+
+        // Ensure the stepper steps into, over, and out of calls.
+        let instructions = trace.stepper().map(|step| step.instruction()).collect::<Vec<_>>();
+        assert_eq!(instructions, [
+            "call squared r0 into r1;",
+            "assert.neq r0 0u64 ;",
+            "mul r0 r0 into r1;",
+            "call child.aleo/twice r1 into r2;",
+            "add r0 r0 into r1;",
+            "add r1 r2 into r3;"
+        ]);
+        let mut stepper = trace.stepper();
+        assert_eq!(stepper.step_into().unwrap().index(), 0);
+        assert_eq!(stepper.step_into().unwrap().instruction(), "assert.neq r0 0u64 ;");
+        assert_eq!((stepper.depth(), stepper.trace().unwrap().name()), (1, closure.name()));
+        assert_eq!(stepper.step_out().unwrap().index(), 1);
+        assert_eq!(stepper.step_over().unwrap().index(), 2);
+        assert_eq!(stepper.depth(), 0);
+        assert!(stepper.step_over().is_none());
+
+        // Ensure the trace is printed as a listing, and exported as JSON.
+        let listing = trace.to_string();
+        assert!(listing.starts_with("function 'parent.aleo/compute'"));
+        assert!(listing.contains("        closure 'parent.aleo/squared'"));
+        assert!(listing.contains("        function 'child.aleo/twice'"));
+        assert!(listing.contains("    output r3 = 27u64"));
+        let json = serde_json::to_string(&trace).unwrap();
+        assert_eq!(trace, serde_json::from_str(&json).unwrap());
+
+        // Ensure a failed evaluation reports the trace up to the instruction that failed.
+        let inputs = [Value::from_str("0u64").unwrap()];
+        let authorization =
+            process.authorize::<AleoDevnet, _>(&caller_private_key, parent.id(), function_name, &inputs, rng).unwrap();
+        let error = process.trace::<AleoDevnet>(authorization).unwrap_err().to_string();
+        assert!(error.contains("closure 'parent.aleo/squared'"));
+        assert!(error.contains("           r0 = 0u64"));
+        assert!(!error.contains("mul r0 r0 into r1;"));
+
+        // Ensure the tracer is disabled after tracing.
+        assert!(Tracer::<Devnet>::disable().is_none());
+    }
 }
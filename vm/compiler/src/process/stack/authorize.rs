@@ -50,7 +50,7 @@ impl<N: Network> Stack<N> {
         // Construct the call stack.
         let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
         // Construct the authorization from the function.
-        let _response = self.execute_function::<A, R>(call_stack, rng)?;
+        let _response = self.execute_function::<A>(call_stack)?;
         // Return the authorization.
         Ok(authorization)
     }
@@ -16,6 +16,10 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 impl<N: Network> Stack<N> {
     /// Deploys the given program ID, if it does not exist.
     #[inline]
@@ -23,27 +27,33 @@ impl<N: Network> Stack<N> {
         // Ensure the program contains functions.
         ensure!(!self.program.functions().is_empty(), "Program '{}' has no functions", self.program.id());
 
-        // Initialize a mapping for the bundle.
-        let mut bundle = IndexMap::with_capacity(self.program.functions().len());
+        // Sample a seed for each function, so that the functions can be synthesized independently.
+        let functions =
+            self.program.functions().keys().map(|function_name| (function_name, rng.gen())).collect::<Vec<_>>();
 
-        for function_name in self.program.functions().keys() {
-            // Synthesize the proving and verifying key.
-            self.synthesize_key::<A, R>(function_name, rng)?;
+        // Synthesize and certify the circuit of each function, in parallel.
+        // Note: Each function is synthesized in a circuit session of its own, which is local to its thread,
+        // so the syntheses do not interfere with each other.
+        let bundle = cfg_into_iter!(functions)
+            .map(|(function_name, seed)| {
+                // Synthesize the proving and verifying key.
+                self.synthesize_key::<A, _>(function_name, &mut StdRng::from_seed(seed))?;
 
-            // Retrieve the proving key.
-            let proving_key = self.get_proving_key(function_name)?;
-            // Retrieve the verifying key.
-            let verifying_key = self.get_verifying_key(function_name)?;
+                // Retrieve the proving key.
+                let proving_key = self.get_proving_key(function_name)?;
+                // Retrieve the verifying key.
+                let verifying_key = self.get_verifying_key(function_name)?;
 
-            // Certify the circuit.
-            let certificate = Certificate::certify(function_name, &proving_key, &verifying_key)?;
+                // Certify the circuit.
+                let certificate = Certificate::certify(function_name, &proving_key, &verifying_key)?;
 
-            // Add the verifying key and certificate to the bundle.
-            bundle.insert(*function_name, (verifying_key, certificate));
-        }
+                // Return the verifying key and certificate for the bundle.
+                Ok((*function_name, (verifying_key, certificate)))
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         // Return the deployment.
-        Deployment::new(N::EDITION, self.program.clone(), bundle)
+        Deployment::new(self.edition, self.program.clone(), bundle.into_iter().collect())
     }
 
     /// Checks each function in the program on the given verifying key and certificate.
@@ -65,7 +75,11 @@ impl<N: Network> Stack<N> {
         // Sanity Checks //
 
         // Ensure the edition matches.
-        ensure!(edition == N::EDITION, "Deployed the wrong edition (expected '{}', found '{edition}').", N::EDITION);
+        ensure!(
+            edition == self.edition,
+            "Deployed the wrong edition (expected '{}', found '{edition}').",
+            self.edition
+        );
         // Ensure the program matches.
         ensure!(program == deployment.program(), "The stack program does not match the deployment program");
         // Ensure the program network-level domain (NLD) is correct.
@@ -124,7 +138,7 @@ impl<N: Network> Stack<N> {
             // Initialize the call stack.
             let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
             // Synthesize the circuit.
-            let _response = self.execute_function::<A, R>(call_stack, rng)?;
+            let _response = self.execute_function::<A>(call_stack)?;
             // Check the certificate.
             match assignments.read().last() {
                 None => bail!("The assignment for function '{}' is missing in '{program_id}'", function.name()),
@@ -46,7 +46,14 @@ impl<N: Network> FromBytes for Deployment<N> {
             bundle.insert(identifier, (verifying_key, certificate));
         }
 
-        Ok(Self { edition, program, verifying_keys: bundle })
+        // Read the owner.
+        let owner = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some((Address::read_le(&mut reader)?, Signature::read_le(&mut reader)?)),
+            _ => return Err(error("Invalid deployment owner variant")),
+        };
+
+        Ok(Self { edition, program, verifying_keys: bundle, owner })
     }
 }
 
@@ -70,7 +77,15 @@ impl<N: Network> ToBytes for Deployment<N> {
             // Write the certificate.
             certificate.write_le(&mut writer)?;
         }
-        Ok(())
+        // Write the owner.
+        match &self.owner {
+            None => 0u8.write_le(&mut writer),
+            Some((address, signature)) => {
+                1u8.write_le(&mut writer)?;
+                address.write_le(&mut writer)?;
+                signature.write_le(&mut writer)
+            }
+        }
     }
 }
 
@@ -90,6 +105,15 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
         assert!(Deployment::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the byte representation of a deployment with an owner.
+        let rng = &mut TestRng::default();
+        let expected = expected.with_owner(&PrivateKey::new(rng)?, rng)?;
+        let expected_bytes = expected.to_bytes_le()?;
+        let candidate = Deployment::read_le(&expected_bytes[..])?;
+        assert_eq!(expected, candidate);
+        assert!(candidate.owner().is_some());
+        assert!(candidate.verify_owner()?);
         Ok(())
     }
 }
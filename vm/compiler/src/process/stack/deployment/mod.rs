@@ -18,22 +18,26 @@ mod bytes;
 mod serialize;
 mod string;
 
-use crate::{Certificate, Program, VerifyingKey};
+use crate::{Certificate, Function, Program, VerifyingKey};
 use console::{
+    account::{Address, PrivateKey, Signature},
     network::prelude::*,
     program::{Identifier, ProgramID},
+    types::Field,
 };
 
 use indexmap::IndexMap;
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Deployment<N: Network> {
-    /// The edition.
+    /// The edition of the program, starting at `0` and incremented on each upgrade.
     edition: u16,
     /// The program.
     program: Program<N>,
     /// The mapping of function names to their verifying key and certificate.
     verifying_keys: IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)>,
+    /// The program owner and their signature on the deployment, if the program is upgradable.
+    owner: Option<(Address<N>, Signature<N>)>,
 }
 
 impl<N: Network> Deployment<N> {
@@ -43,7 +47,28 @@ impl<N: Network> Deployment<N> {
         program: Program<N>,
         verifying_keys: IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)>,
     ) -> Result<Self> {
-        Ok(Self { edition, program, verifying_keys })
+        Ok(Self { edition, program, verifying_keys, owner: None })
+    }
+
+    /// Signs the deployment with the given private key, designating its address as the program owner.
+    /// Only owned programs may be upgraded, and only by their owner.
+    pub fn with_owner<R: Rng + CryptoRng>(mut self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Self> {
+        // Compute the owner address.
+        let address = Address::try_from(private_key)?;
+        // Sign the deployment checksum.
+        let signature = Signature::sign(private_key, &[self.to_checksum()?], rng)?;
+        // Set the owner.
+        self.owner = Some((address, signature));
+        Ok(self)
+    }
+
+    /// Sets the given owner and signature on the deployment, ensuring the signature is valid.
+    pub(crate) fn with_signed_owner(mut self, owner: Option<(Address<N>, Signature<N>)>) -> Result<Self> {
+        // Set the owner.
+        self.owner = owner;
+        // Ensure the owner signature is valid.
+        ensure!(self.verify_owner()?, "Invalid owner signature for deployment of '{}'", self.program.id());
+        Ok(self)
     }
 
     /// Returns the edition.
@@ -65,6 +90,52 @@ impl<N: Network> Deployment<N> {
     pub const fn verifying_keys(&self) -> &IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)> {
         &self.verifying_keys
     }
+
+    /// Returns the program owner, if the deployment is signed.
+    pub fn owner(&self) -> Option<&Address<N>> {
+        self.owner.as_ref().map(|(address, _)| address)
+    }
+
+    /// Returns the owner signature, if the deployment is signed.
+    pub fn signature(&self) -> Option<&Signature<N>> {
+        self.owner.as_ref().map(|(_, signature)| signature)
+    }
+
+    /// Returns `true` if the deployment is unsigned, or if the owner signature is valid.
+    pub fn verify_owner(&self) -> Result<bool> {
+        match &self.owner {
+            Some((address, signature)) => Ok(signature.verify(address, &[self.to_checksum()?])),
+            None => Ok(true),
+        }
+    }
+
+    /// Returns the ID of the given function in the deployment.
+    /// If the deployment is signed, the ID also commits to the program owner and their signature.
+    pub fn to_function_id(&self, function: &Function<N>) -> Result<Field<N>> {
+        // Retrieve the function bits.
+        let mut bits = function.to_bytes_le()?.to_bits_le();
+        // If the deployment is signed, append the owner and signature bits.
+        if let Some((address, signature)) = &self.owner {
+            bits.extend(address.to_bits_le());
+            bits.extend(signature.to_bytes_le()?.to_bits_le());
+        }
+        // Compute the function ID.
+        N::hash_bhp1024(&bits)
+    }
+
+    /// Returns the checksum of the edition, program, and verifying keys, which is signed by the owner.
+    fn to_checksum(&self) -> Result<Field<N>> {
+        // Write the edition and program.
+        let mut bytes = self.edition.to_bytes_le()?;
+        bytes.extend(self.program.to_bytes_le()?);
+        // Write each function name and verifying key.
+        for (function_name, (verifying_key, _)) in &self.verifying_keys {
+            bytes.extend(function_name.to_bytes_le()?);
+            bytes.extend(verifying_key.to_bytes_le()?);
+        }
+        // Compute the checksum.
+        N::hash_bhp1024(&bytes.to_bits_le())
+    }
 }
 
 #[cfg(test)]
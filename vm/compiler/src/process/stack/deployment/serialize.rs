@@ -21,10 +21,13 @@ impl<N: Network> Serialize for Deployment<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut deployment = serializer.serialize_struct("Deployment", 3)?;
+                let mut deployment = serializer.serialize_struct("Deployment", 3 + self.owner.is_some() as usize)?;
                 deployment.serialize_field("edition", &self.edition)?;
                 deployment.serialize_field("program", &self.program)?;
                 deployment.serialize_field("verifying_keys", &self.verifying_keys)?;
+                if let Some(owner) = &self.owner {
+                    deployment.serialize_field("owner", owner)?;
+                }
                 deployment.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -38,18 +41,22 @@ impl<'de, N: Network> Deserialize<'de> for Deployment<N> {
         match deserializer.is_human_readable() {
             true => {
                 // Parse the deployment from a string into a value.
-                let deployment = serde_json::Value::deserialize(deserializer)?;
+                let value = serde_json::Value::deserialize(deserializer)?;
 
                 // Recover the deployment.
-                let deployment = Self::new(
+                let mut deployment = Self::new(
                     // Retrieve the edition.
-                    serde_json::from_value(deployment["edition"].clone()).map_err(de::Error::custom)?,
+                    serde_json::from_value(value["edition"].clone()).map_err(de::Error::custom)?,
                     // Retrieve the program.
-                    serde_json::from_value(deployment["program"].clone()).map_err(de::Error::custom)?,
+                    serde_json::from_value(value["program"].clone()).map_err(de::Error::custom)?,
                     // Retrieve the verifying keys.
-                    serde_json::from_value(deployment["verifying_keys"].clone()).map_err(de::Error::custom)?,
+                    serde_json::from_value(value["verifying_keys"].clone()).map_err(de::Error::custom)?,
                 )
                 .map_err(de::Error::custom)?;
+                // Retrieve the owner, if it exists.
+                if let Some(owner) = value.get("owner") {
+                    deployment.owner = Some(serde_json::from_value(owner.clone()).map_err(de::Error::custom)?);
+                }
 
                 Ok(deployment)
             }
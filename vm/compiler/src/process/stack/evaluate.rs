@@ -48,21 +48,38 @@ impl<N: Network> Stack<N> {
             registers.store(self, register, input.clone())
         })?;
 
+        // Start the trace of the closure, if the tracer is enabled.
+        let input_registers = closure.inputs().iter().map(|input| input.register()).collect::<Vec<_>>();
+        Tracer::<N>::enter(self.program_id(), closure.name(), TraceKind::Closure, &input_registers, inputs);
+
         // Evaluate the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            // Start the step of the instruction, if the tracer is enabled.
+            Tracer::<N>::step(self, &registers, index, instruction);
             // If the evaluation fails, bail and return the error.
             if let Err(error) = instruction.evaluate(self, &mut registers) {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
+            // Complete the step of the instruction, if the tracer is enabled.
+            Tracer::<N>::complete(self, &registers, instruction);
         }
 
+        // Retrieve the output registers.
+        let output_registers = closure.outputs().iter().map(|output| output.register().clone()).collect::<Vec<_>>();
+
         // Load the outputs.
-        let outputs = closure.outputs().iter().map(|output| {
-            // Retrieve the stack value from the register.
-            registers.load(self, &Operand::Register(output.register().clone()))
-        });
+        let outputs = output_registers
+            .iter()
+            .map(|register| {
+                // Retrieve the stack value from the register.
+                registers.load(self, &Operand::Register(register.clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
 
-        outputs.collect()
+        // Complete the trace of the closure, if the tracer is enabled.
+        Tracer::<N>::exit(&output_registers, &outputs);
+
+        Ok(outputs)
     }
 
     /// Evaluates a program function on the given inputs.
@@ -119,12 +136,20 @@ impl<N: Network> Stack<N> {
             registers.store(self, register, input.clone())
         })?;
 
+        // Start the trace of the function, if the tracer is enabled.
+        let input_registers = function.inputs().iter().map(|input| input.register()).collect::<Vec<_>>();
+        Tracer::<N>::enter(self.program_id(), function.name(), TraceKind::Function, &input_registers, inputs);
+
         // Evaluate the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // Start the step of the instruction, if the tracer is enabled.
+            Tracer::<N>::step(self, &registers, index, instruction);
             // If the evaluation fails, bail and return the error.
             if let Err(error) = instruction.evaluate(self, &mut registers) {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
+            // Complete the step of the instruction, if the tracer is enabled.
+            Tracer::<N>::complete(self, &registers, instruction);
         }
 
         // Retrieve the output registers.
@@ -139,6 +164,9 @@ impl<N: Network> Stack<N> {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        // Complete the trace of the function, if the tracer is enabled.
+        Tracer::<N>::exit(output_registers, &outputs);
+
         // Compute the response.
         Response::new(
             self.program.id(),
@@ -60,8 +60,11 @@ impl<N: Network> Stack<N> {
             registers.store_circuit(self, register, input.clone())
         })?;
 
+        // Start the profile of the closure, if the profiler is enabled.
+        Profiler::<N>::enter(self.program_id(), closure.name(), ProfileKind::Closure);
+
         // Execute the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
@@ -69,10 +72,17 @@ impl<N: Network> Stack<N> {
                     bail!("Failed to evaluate instruction ({instruction}): {error}");
                 }
             }
-            // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            // Retrieve the circuit count, before the instruction is executed.
+            let count = CircuitCount::current::<A>();
+            // Execute the instruction, in a scope of its own.
+            A::scope(Self::instruction_scope(index), || instruction.execute(self, &mut registers))?;
+            // Record the circuit count of the instruction, if the profiler is enabled.
+            Profiler::<N>::record(instruction, CircuitCount::current::<A>().since(&count));
         }
 
+        // Complete the profile of the closure, if the profiler is enabled.
+        Profiler::<N>::exit(None);
+
         // Ensure the number of public variables remains the same.
         ensure!(A::num_public() == num_public, "Illegal closure operation: instructions injected public variables");
 
@@ -87,22 +97,26 @@ impl<N: Network> Stack<N> {
 
     /// Executes a program function on the given inputs.
     ///
+    /// The function is synthesized in a new circuit session, so that it does not interfere
+    /// with any synthesis already in progress on this thread (e.g. the caller of a `call`).
+    ///
     /// Note: To execute a transition, do **not** call this method. Instead, call `Process::execute`.
     ///
     /// # Errors
     /// This method will halt if the given inputs are not the same length as the input statements.
     #[inline]
-    pub fn execute_function<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+    pub fn execute_function<A: circuit::Aleo<Network = N>>(&self, call_stack: CallStack<N>) -> Result<Response<N>> {
+        A::enter(&mut circuit::Session::new(), || self.execute_function_in_session::<A>(call_stack))
+    }
+
+    /// Executes a program function on the given inputs, in the current circuit session.
+    fn execute_function_in_session<A: circuit::Aleo<Network = N>>(
         &self,
         mut call_stack: CallStack<N>,
-        rng: &mut R,
     ) -> Result<Response<N>> {
         // Ensure the call stack is not `Evaluate`.
         ensure!(!matches!(call_stack, CallStack::Evaluate(..)), "Illegal operation: cannot evaluate in execute mode");
 
-        // Ensure the circuit environment is clean.
-        A::reset();
-
         // Retrieve the next request.
         let console_request = call_stack.pop()?;
 
@@ -116,6 +130,8 @@ impl<N: Network> Stack<N> {
 
         // Retrieve the function from the program.
         let function = self.get_function(console_request.function_name())?;
+        // Start the profile of the function, if the profiler is enabled.
+        Profiler::<N>::enter(self.program_id(), function.name(), ProfileKind::Function);
         // Retrieve the number of inputs.
         let num_inputs = function.inputs().len();
         // Ensure the number of inputs matches the number of input statements.
@@ -179,7 +195,7 @@ impl<N: Network> Stack<N> {
         let mut contains_function_call = false;
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
@@ -188,8 +204,12 @@ impl<N: Network> Stack<N> {
                 }
             }
 
-            // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            // Retrieve the circuit count, before the instruction is executed.
+            let count = CircuitCount::current::<A>();
+            // Execute the instruction, in a scope of its own.
+            A::scope(Self::instruction_scope(index), || instruction.execute(self, &mut registers))?;
+            // Record the circuit count of the instruction, if the profiler is enabled.
+            Profiler::<N>::record(instruction, CircuitCount::current::<A>().since(&count));
 
             // If the instruction was a function call, then set the tracker to `true`.
             if let Instruction::Call(call) = instruction {
@@ -382,16 +402,32 @@ impl<N: Network> Stack<N> {
 
         // If the circuit is in `Execute` mode, then ensure the circuit is satisfied.
         if let CallStack::Execute(..) = registers.call_stack() {
-            // If the circuit is empty or not satisfied, then throw an error.
+            // If the circuit is empty, then throw an error.
             ensure!(
-                A::num_constraints() > 0 && A::is_satisfied(),
-                "'{}/{}' is not satisfied on the given inputs ({} constraints).",
+                A::num_constraints() > 0,
+                "'{}/{}' is not satisfied on the given inputs (0 constraints).",
                 self.program.id(),
                 function.name(),
-                A::num_constraints()
             );
+            // If the circuit is not satisfied, then throw an error with the first unsatisfied constraint.
+            if let Some(constraint) = A::first_unsatisfied_constraint() {
+                // Retrieve the instruction that enforced the constraint, if it was enforced by one.
+                let instruction = match Self::instruction_in_scope(function.instructions(), constraint.scope()) {
+                    Some(instruction) => format!(" (from '{instruction}')"),
+                    None => String::new(),
+                };
+                bail!(
+                    "'{}/{}' is not satisfied on the given inputs ({} constraints). {constraint}{instruction}",
+                    self.program.id(),
+                    function.name(),
+                    A::num_constraints()
+                )
+            }
         }
 
+        // Complete the profile of the function, if the profiler is enabled.
+        Profiler::<N>::exit(Some(CircuitCount::current::<A>()));
+
         // Eject the circuit assignment and reset the circuit.
         let assignment = A::eject_assignment_and_reset();
 
@@ -412,24 +448,41 @@ impl<N: Network> Stack<N> {
             assignments.write().push(assignment);
         }
         // If the circuit is in `Execute` mode, then execute the circuit into a transition.
-        else if let CallStack::Execute(_, ref execution) = registers.call_stack() {
+        else if let CallStack::Execute(_, ref execution, ref assignments) = registers.call_stack() {
             registers.ensure_console_and_circuit_registers_match()?;
 
-            // Retrieve the proving key.
-            let proving_key = self.get_proving_key(function.name())?;
-            // Execute the circuit.
-            let proof = proving_key.prove(function.name(), &assignment, rng)?;
-            // Construct the transition.
-            let transition =
-                Transition::from(&console_request, &response, finalize, &output_types, output_registers, proof, *fee)?;
+            // Construct the transition, which is proven with the rest of the execution once it is complete.
+            let transition = Transition::from(
+                &console_request,
+                &response,
+                self.edition,
+                finalize,
+                &output_types,
+                output_registers,
+                None,
+                *fee,
+            )?;
             // Add the transition to the execution.
             execution.write().push(transition);
+            // Add the assignment to the assignments.
+            assignments.write().push(assignment);
         }
 
         // Return the response.
         Ok(response)
     }
 
+    /// Returns the name of the circuit scope for the instruction at the given index.
+    fn instruction_scope(index: usize) -> String {
+        format!("instruction {index}")
+    }
+
+    /// Returns the instruction whose circuit scope the given scope path originates from, if any.
+    fn instruction_in_scope<'a>(instructions: &'a [Instruction<N>], scope: &str) -> Option<&'a Instruction<N>> {
+        let index = scope.split('.').next()?.strip_prefix("instruction ")?.parse::<usize>().ok()?;
+        instructions.get(index)
+    }
+
     /// Prints the current state of the circuit.
     #[cfg(debug_assertions)]
     fn log_circuit<A: circuit::Aleo<Network = N>, S: Into<String>>(scope: S) {
@@ -22,7 +22,7 @@ impl<N: Network> FromBytes for Execution<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid execution version"));
         }
         // Read the edition.
@@ -37,8 +37,23 @@ impl<N: Network> FromBytes for Execution<N> {
         // Read the transitions.
         let transitions =
             (0..num_transitions).map(|_| Transition::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the aggregated proof.
+        let proof = match version {
+            // Version 0 executions are proven by the proof of each transition.
+            0 => None,
+            // Version 1 executions carry an aggregated proof, unless each transition carries its own proof.
+            _ => {
+                // Read the proof variant.
+                let proof_variant = u8::read_le(&mut reader)?;
+                match proof_variant {
+                    0 => None,
+                    1 => Some(FromBytes::read_le(&mut reader)?),
+                    2.. => return Err(error(format!("Invalid execution proof variant ({proof_variant})"))),
+                }
+            }
+        };
         // Return the new `Execution` instance.
-        Self::from(edition, &transitions).map_err(|e| error(e.to_string()))
+        Self::from(edition, &transitions, proof).map_err(|e| error(e.to_string()))
     }
 }
 
@@ -46,13 +61,26 @@ impl<N: Network> ToBytes for Execution<N> {
     /// Writes the execution to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u16.write_le(&mut writer)?;
+        1u16.write_le(&mut writer)?;
         // Write the edition.
         self.edition.write_le(&mut writer)?;
         // Write the number of transitions.
         (self.transitions.len() as u16).write_le(&mut writer)?;
         // Write the transitions.
-        self.transitions.write_le(&mut writer)
+        self.transitions.write_le(&mut writer)?;
+        // Write the aggregated proof.
+        match &self.proof {
+            None => {
+                // Write the proof variant.
+                0u8.write_le(&mut writer)
+            }
+            Some(proof) => {
+                // Write the proof variant.
+                1u8.write_le(&mut writer)?;
+                // Write the aggregated proof.
+                proof.write_le(&mut writer)
+            }
+        }
     }
 }
 
@@ -74,4 +102,24 @@ mod tests {
         assert!(Execution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_without_aggregated_proof() -> Result<()> {
+        // Construct an execution whose transition carries its own proof.
+        let transition = crate::process::test_helpers::sample_proven_transition();
+        let expected = Execution::<CurrentNetwork>::from(CurrentNetwork::EDITION, &[transition.clone()], None)?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Execution::read_le(&expected_bytes[..])?);
+
+        // Ensure an execution in the version 0 format, which has no aggregated proof, is still readable.
+        let mut legacy_bytes = vec![];
+        0u16.write_le(&mut legacy_bytes)?;
+        CurrentNetwork::EDITION.write_le(&mut legacy_bytes)?;
+        1u16.write_le(&mut legacy_bytes)?;
+        transition.write_le(&mut legacy_bytes)?;
+        assert_eq!(expected, Execution::read_le(&legacy_bytes[..])?);
+        Ok(())
+    }
 }
@@ -18,7 +18,7 @@ mod bytes;
 mod serialize;
 mod string;
 
-use crate::Transition;
+use crate::{AggregatedProof, Transition};
 use console::network::prelude::*;
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -27,29 +27,46 @@ pub struct Execution<N: Network> {
     edition: u16,
     /// The transitions.
     transitions: Vec<Transition<N>>,
+    /// The aggregated proof of the transitions, if they do not carry their own proofs.
+    proof: Option<AggregatedProof<N>>,
 }
 
 impl<N: Network> Execution<N> {
     /// Initialize a new `Execution` instance.
     pub fn new() -> Self {
-        Self { edition: N::EDITION, transitions: Vec::new() }
+        Self { edition: N::EDITION, transitions: Vec::new(), proof: None }
     }
 
-    /// Initializes a new `Execution` instance with the given transitions.
-    pub fn from(edition: u16, transitions: &[Transition<N>]) -> Result<Self> {
+    /// Initializes a new `Execution` instance with the given transitions, and their aggregated proof if one exists.
+    pub fn from(edition: u16, transitions: &[Transition<N>], proof: Option<AggregatedProof<N>>) -> Result<Self> {
         // Ensure the transitions is not empty.
         ensure!(!transitions.is_empty(), "Execution cannot initialize from empty list of transitions");
-        // Return the new `Execution` instance.
-        match edition == N::EDITION {
-            true => Ok(Self { edition, transitions: transitions.to_vec() }),
-            false => bail!("Execution cannot initialize with a different edition"),
+        // Ensure the edition matches.
+        ensure!(edition == N::EDITION, "Execution cannot initialize with a different edition");
+        // Ensure the transitions are proven either by the aggregated proof, or each by their own proof.
+        match proof {
+            Some(_) => ensure!(
+                transitions.iter().all(|transition| transition.proof().is_none()),
+                "Execution cannot initialize with an aggregated proof for transitions that carry their own proofs"
+            ),
+            None => ensure!(
+                transitions.iter().all(|transition| transition.proof().is_some()),
+                "Execution cannot initialize without a proof for each transition"
+            ),
         }
+        // Return the new `Execution` instance.
+        Ok(Self { edition, transitions: transitions.to_vec(), proof })
     }
 
     /// Returns the edition.
     pub const fn edition(&self) -> u16 {
         self.edition
     }
+
+    /// Returns the aggregated proof of the transitions, if they do not carry their own proofs.
+    pub const fn proof(&self) -> &Option<AggregatedProof<N>> {
+        &self.proof
+    }
 }
 
 impl<N: Network> Execution<N> {
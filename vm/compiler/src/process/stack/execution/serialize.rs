@@ -21,9 +21,12 @@ impl<N: Network> Serialize for Execution<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut execution = serializer.serialize_struct("Execution", 2)?;
+                let mut execution = serializer.serialize_struct("Execution", 3)?;
                 execution.serialize_field("edition", &self.edition)?;
                 execution.serialize_field("transitions", &self.transitions)?;
+                if let Some(proof) = &self.proof {
+                    execution.serialize_field("proof", &proof)?;
+                }
                 execution.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -43,8 +46,13 @@ impl<'de, N: Network> Deserialize<'de> for Execution<N> {
                 // Retrieve the transitions.
                 let transitions: Vec<_> =
                     serde_json::from_value(execution["transitions"].clone()).map_err(de::Error::custom)?;
+                // Retrieve the aggregated proof.
+                let proof = match execution.get("proof") {
+                    Some(proof) => Some(serde_json::from_value(proof.clone()).map_err(de::Error::custom)?),
+                    None => None,
+                };
                 // Recover the execution.
-                Self::from(edition, &transitions).map_err(de::Error::custom)
+                Self::from(edition, &transitions, proof).map_err(de::Error::custom)
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "execution"),
         }
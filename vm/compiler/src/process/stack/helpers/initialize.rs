@@ -17,18 +17,20 @@
 use super::*;
 
 impl<N: Network> Stack<N> {
-    /// Initializes a new stack, given the process and program.
+    /// Initializes a new stack, given the process, program, and edition.
     #[inline]
-    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
         // Construct the stack for the program.
         let mut stack = Self {
             program: program.clone(),
+            edition,
             external_stacks: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            key_cache: process.key_cache().cloned(),
         };
 
         // Add all of the imports into the stack.
@@ -55,6 +57,19 @@ impl<N: Network> Stack<N> {
         // Return the stack.
         Ok(stack)
     }
+
+    /// Reinitializes the stack against the current imports in the given process,
+    /// retaining the edition and circuit keys of the stack.
+    #[inline]
+    pub(crate) fn reinitialize(&self, process: &Process<N>) -> Result<Self> {
+        // Initialize the stack with the current imports.
+        let mut stack = Self::initialize(process, &self.program, self.edition)?;
+        // Retain the circuit keys.
+        stack.proving_keys = self.proving_keys.clone();
+        stack.verifying_keys = self.verifying_keys.clone();
+        // Return the stack.
+        Ok(stack)
+    }
 }
 
 impl<N: Network> Stack<N> {
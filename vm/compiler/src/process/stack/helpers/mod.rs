@@ -20,3 +20,4 @@ mod initialize;
 mod matches;
 mod sample;
 mod synthesize;
+mod upgrade;
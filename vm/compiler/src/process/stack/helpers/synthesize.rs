@@ -28,6 +28,10 @@ impl<N: Network> Stack<N> {
         if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
             return Ok(());
         }
+        // If the proving and verifying key exist in the key cache, skip the synthesis for this function.
+        if self.load_cached_keys(function_name)? {
+            return Ok(());
+        }
 
         // Retrieve the program ID.
         let program_id = self.program_id();
@@ -59,7 +63,7 @@ impl<N: Network> Stack<N> {
         // Initialize the call stack.
         let call_stack = CallStack::Synthesize(vec![request], burner_private_key, authorization);
         // Synthesize the circuit.
-        let _response = self.execute_function::<A, R>(call_stack, rng)?;
+        let _response = self.execute_function::<A>(call_stack)?;
 
         // Ensure the proving key exists.
         ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
@@ -79,6 +83,10 @@ impl<N: Network> Stack<N> {
         if self.contains_proving_key(function_name) && self.contains_verifying_key(function_name) {
             return Ok(());
         }
+        // If the proving and verifying key exist in the key cache, skip the synthesis for this function.
+        if self.load_cached_keys(function_name)? {
+            return Ok(());
+        }
 
         // Synthesize the proving and verifying key.
         let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(function_name, assignment)?;
@@ -87,4 +95,46 @@ impl<N: Network> Stack<N> {
         // Insert the verifying key.
         self.insert_verifying_key(function_name, verifying_key)
     }
+
+    /// Returns the checksum of the circuit for the given function name, which identifies its circuit keys
+    /// in a key cache. The checksum commits to the network, the compiler version, the program, and its imports.
+    #[inline]
+    pub fn to_key_checksum(&self, function_name: &Identifier<N>) -> Result<Field<N>> {
+        // Ensure the function exists.
+        ensure!(self.program.contains_function(function_name), "Function '{function_name}' does not exist.");
+
+        // Construct the preimage.
+        let mut preimage = N::ID.to_bits_le();
+        preimage.extend(env!("CARGO_PKG_VERSION").as_bytes().to_bits_le());
+        preimage.extend(self.program.to_bytes_le()?.to_bits_le());
+        preimage.extend(function_name.to_bits_le());
+        for external_stack in self.external_stacks.values() {
+            preimage.extend(external_stack.program().to_bytes_le()?.to_bits_le());
+        }
+        // Compute the checksum.
+        N::hash_bhp1024(&preimage)
+    }
+
+    /// Loads the proving and verifying key for the given function name from the key cache, if both exist.
+    /// Returns `true` if the circuit keys were loaded.
+    pub(crate) fn load_cached_keys(&self, function_name: &Identifier<N>) -> Result<bool> {
+        // Retrieve the key cache, if one is used.
+        let key_cache = match &self.key_cache {
+            Some(key_cache) => key_cache,
+            None => return Ok(false),
+        };
+        // Compute the checksum of the function circuit.
+        let checksum = self.to_key_checksum(function_name)?;
+        // Retrieve the proving and verifying key.
+        match (key_cache.get_proving_key(&checksum)?, key_cache.get_verifying_key(&checksum)?) {
+            (Some(proving_key), Some(verifying_key)) => {
+                // Insert the proving key.
+                self.insert_proving_key(function_name, proving_key)?;
+                // Insert the verifying key.
+                self.insert_verifying_key(function_name, verifying_key)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
 }
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Ensures the given program is a compatible upgrade of the previous program.
+    ///
+    /// An upgrade may add mappings, interfaces, records, closures, and functions, and may change the logic
+    /// of existing functions. However, it must retain every existing mapping with the same key and value types,
+    /// so that the mappings in storage remain valid, and it must retain every existing interface, record,
+    /// and function signature, so that dependent programs and existing records remain valid.
+    #[inline]
+    pub(crate) fn check_upgrade(previous: &Program<N>, program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program IDs match.
+        ensure!(previous.id() == program_id, "Cannot upgrade '{}' with program '{program_id}'", previous.id());
+
+        // Ensure the existing mappings are retained, with the same key and value types.
+        for (name, mapping) in previous.mappings() {
+            let candidate = match program.mappings().get(name) {
+                Some(candidate) => candidate,
+                None => bail!("Cannot upgrade '{program_id}': it removes mapping '{name}'"),
+            };
+            // Ensure the key type is unchanged.
+            let (expected, found) = (mapping.key().finalize_type(), candidate.key().finalize_type());
+            if expected != found {
+                bail!("Cannot upgrade '{program_id}': mapping '{name}' changes its key from '{expected}' to '{found}'")
+            }
+            // Ensure the value type is unchanged.
+            let (expected, found) = (mapping.value().finalize_type(), candidate.value().finalize_type());
+            if expected != found {
+                bail!(
+                    "Cannot upgrade '{program_id}': mapping '{name}' changes its value from '{expected}' to '{found}'"
+                )
+            }
+        }
+
+        // Ensure the existing interfaces are retained, without changes.
+        for (name, interface) in previous.interfaces() {
+            if program.interfaces().get(name) != Some(interface) {
+                bail!("Cannot upgrade '{program_id}': it removes or changes interface '{name}'")
+            }
+        }
+
+        // Ensure the existing records are retained, without changes.
+        for (name, record) in previous.records() {
+            if program.records().get(name) != Some(record) {
+                bail!("Cannot upgrade '{program_id}': it removes or changes record '{name}'")
+            }
+        }
+
+        // Ensure the existing functions are retained, with the same input and output types.
+        for (name, function) in previous.functions() {
+            let candidate = match program.functions().get(name) {
+                Some(candidate) => candidate,
+                None => bail!("Cannot upgrade '{program_id}': it removes function '{name}'"),
+            };
+            // Ensure the input types are unchanged.
+            if function.input_types() != candidate.input_types() {
+                bail!("Cannot upgrade '{program_id}': function '{name}' changes its input types")
+            }
+            // Ensure the output types are unchanged.
+            if function.output_types() != candidate.output_types() {
+                bail!("Cannot upgrade '{program_id}': function '{name}' changes its output types")
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -29,12 +29,18 @@ pub use finalize_registers::*;
 mod finalize_types;
 pub use finalize_types::*;
 
+mod profile;
+pub use profile::*;
+
 mod register_types;
 pub use register_types::*;
 
 mod registers;
 pub use registers::*;
 
+mod trace;
+pub use trace::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
@@ -47,6 +53,7 @@ use crate::{
     Closure,
     Function,
     Instruction,
+    KeyCache,
     Operand,
     Process,
     Program,
@@ -92,7 +99,7 @@ pub enum CallStack<N: Network> {
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
     Evaluate(Authorization<N>),
-    Execute(Authorization<N>, Arc<RwLock<Execution<N>>>),
+    Execute(Authorization<N>, Arc<RwLock<Execution<N>>>, Assignments<N>),
 }
 
 impl<N: Network> CallStack<N> {
@@ -102,8 +109,14 @@ impl<N: Network> CallStack<N> {
     }
 
     /// Initializes a call stack as `Execute`.
-    pub fn execute(authorization: Authorization<N>, execution: Arc<RwLock<Execution<N>>>) -> Result<Self> {
-        Ok(CallStack::Execute(authorization, execution))
+    /// The circuit assignment of each transition is saved in `assignments`, in the order of the transitions,
+    /// so that the transitions can be proven together once the execution is complete.
+    pub fn execute(
+        authorization: Authorization<N>,
+        execution: Arc<RwLock<Execution<N>>>,
+        assignments: Assignments<N>,
+    ) -> Result<Self> {
+        Ok(CallStack::Execute(authorization, execution, assignments))
     }
 }
 
@@ -123,9 +136,11 @@ impl<N: Network> CallStack<N> {
                 Arc::new(RwLock::new(assignments.read().clone())),
             ),
             CallStack::Evaluate(authorization) => CallStack::Evaluate(authorization.replicate()),
-            CallStack::Execute(authorization, execution) => {
-                CallStack::Execute(authorization.replicate(), Arc::new(RwLock::new(execution.read().clone())))
-            }
+            CallStack::Execute(authorization, execution, assignments) => CallStack::Execute(
+                authorization.replicate(),
+                Arc::new(RwLock::new(execution.read().clone())),
+                Arc::new(RwLock::new(assignments.read().clone())),
+            ),
         }
     }
 
@@ -172,6 +187,8 @@ impl<N: Network> CallStack<N> {
 pub struct Stack<N: Network> {
     /// The program (record types, interfaces, functions).
     program: Program<N>,
+    /// The edition of the program.
+    edition: u16,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Stack<N>>,
     /// The mapping of closure and function names to their register types.
@@ -184,6 +201,8 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The cache of circuit keys on disk, if one is used.
+    key_cache: Option<KeyCache<N>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -194,6 +213,36 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure the program is well-formed.
+        Self::check_program(program)?;
+        // Return the stack.
+        Stack::initialize(process, program, 0)
+    }
+
+    /// Initializes the stack for the next edition of an existing program, given the process and the program.
+    /// The upgraded program must remain compatible with the current edition in the process.
+    #[inline]
+    pub fn upgrade(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the stack of the current edition.
+        let previous = process.get_stack(program.id())?;
+        // Ensure the program is well-formed.
+        Self::check_program(program)?;
+        // Ensure the program is compatible with the current edition.
+        Self::check_upgrade(previous.program(), program)?;
+        // Compute the next edition.
+        let edition = previous
+            .edition()
+            .checked_add(1)
+            .ok_or_else(|| anyhow!("Program '{}' has exceeded the maximum number of editions", program.id()))?;
+        // Return the stack.
+        Stack::initialize(process, program, edition)
+    }
+
+    /// Ensures the given program is well-formed.
+    #[inline]
+    fn check_program(program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program network-level domain (NLD) is correct.
         ensure!(program_id.is_aleo(), "Program '{program_id}' has an incorrect network-level domain (NLD)");
         // Ensure the program contains functions.
@@ -208,9 +257,7 @@ impl<N: Network> Stack<N> {
         let program_string = program.to_string();
         // Ensure the program deserializes from a string correctly.
         ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
-
-        // Return the stack.
-        Stack::initialize(process, program)
+        Ok(())
     }
 
     /// Returns the program.
@@ -219,6 +266,12 @@ impl<N: Network> Stack<N> {
         &self.program
     }
 
+    /// Returns the edition of the program.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
     /// Returns the program ID.
     #[inline]
     pub const fn program_id(&self) -> &ProgramID<N> {
@@ -350,6 +403,12 @@ impl<N: Network> Stack<N> {
             "Function '{function_name}' does not exist in program '{}'.",
             self.program.id()
         );
+        // Store the proving key in the key cache, if one is used.
+        if let Some(key_cache) = &self.key_cache {
+            if let Err(error) = key_cache.insert_proving_key(&self.to_key_checksum(function_name)?, &proving_key) {
+                warn!("Failed to cache the proving key for '{}/{function_name}': {error}", self.program.id());
+            }
+        }
         // Insert the proving key.
         self.proving_keys.write().insert(*function_name, proving_key);
         Ok(())
@@ -364,6 +423,12 @@ impl<N: Network> Stack<N> {
             "Function '{function_name}' does not exist in program '{}'.",
             self.program.id()
         );
+        // Store the verifying key in the key cache, if one is used.
+        if let Some(key_cache) = &self.key_cache {
+            if let Err(error) = key_cache.insert_verifying_key(&self.to_key_checksum(function_name)?, &verifying_key) {
+                warn!("Failed to cache the verifying key for '{}/{function_name}': {error}", self.program.id());
+            }
+        }
         // Insert the verifying key.
         self.verifying_keys.write().insert(*function_name, verifying_key);
         Ok(())
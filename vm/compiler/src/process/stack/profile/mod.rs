@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod serialize;
+mod string;
+
+use crate::Instruction;
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+use core::{any::Any, marker::PhantomData, ops::Add};
+use std::cell::RefCell;
+
+/// The number of constants, public variables, private variables, constraints, and gates in a circuit.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CircuitCount {
+    /// The number of constants.
+    num_constants: u64,
+    /// The number of public variables.
+    num_public: u64,
+    /// The number of private variables.
+    num_private: u64,
+    /// The number of constraints.
+    num_constraints: u64,
+    /// The number of gates.
+    num_gates: u64,
+}
+
+impl CircuitCount {
+    /// Initializes a new circuit count.
+    pub const fn new(
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        num_gates: u64,
+    ) -> Self {
+        Self { num_constants, num_public, num_private, num_constraints, num_gates }
+    }
+
+    /// Returns the circuit count of the circuit in the current environment.
+    pub fn current<A: circuit::Environment>() -> Self {
+        let (num_constants, num_public, num_private, num_constraints, num_gates) = A::count();
+        Self::new(num_constants, num_public, num_private, num_constraints, num_gates)
+    }
+
+    /// Returns the circuit count that was added since the given (earlier) circuit count.
+    pub const fn since(&self, earlier: &Self) -> Self {
+        Self::new(
+            self.num_constants.saturating_sub(earlier.num_constants),
+            self.num_public.saturating_sub(earlier.num_public),
+            self.num_private.saturating_sub(earlier.num_private),
+            self.num_constraints.saturating_sub(earlier.num_constraints),
+            self.num_gates.saturating_sub(earlier.num_gates),
+        )
+    }
+
+    /// Returns the number of constants.
+    pub const fn num_constants(&self) -> u64 {
+        self.num_constants
+    }
+
+    /// Returns the number of public variables.
+    pub const fn num_public(&self) -> u64 {
+        self.num_public
+    }
+
+    /// Returns the number of private variables.
+    pub const fn num_private(&self) -> u64 {
+        self.num_private
+    }
+
+    /// Returns the number of constraints.
+    pub const fn num_constraints(&self) -> u64 {
+        self.num_constraints
+    }
+
+    /// Returns the number of gates.
+    pub const fn num_gates(&self) -> u64 {
+        self.num_gates
+    }
+}
+
+impl Add for CircuitCount {
+    type Output = Self;
+
+    /// Returns the sum of the circuit counts.
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.num_constants.saturating_add(other.num_constants),
+            self.num_public.saturating_add(other.num_public),
+            self.num_private.saturating_add(other.num_private),
+            self.num_constraints.saturating_add(other.num_constraints),
+            self.num_gates.saturating_add(other.num_gates),
+        )
+    }
+}
+
+/// The kind of a profiled block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProfileKind {
+    /// A closure, which is synthesized in the circuit of its caller.
+    Closure,
+    /// A function, which is synthesized in a circuit of its own.
+    Function,
+}
+
+/// The circuit count of an instruction, along with the profile of the closure or function it calls, if any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionProfile<N: Network> {
+    /// The instruction.
+    instruction: String,
+    /// The circuit count of the instruction.
+    count: CircuitCount,
+    /// The profile of the closure or function that is called by the instruction, if any.
+    call: Option<Profile<N>>,
+}
+
+impl<N: Network> InstructionProfile<N> {
+    /// Returns the instruction.
+    pub fn instruction(&self) -> &str {
+        &self.instruction
+    }
+
+    /// Returns the circuit count of the instruction.
+    /// Note: A call to a closure counts the closure, whereas a call to a function does not,
+    /// as the function is synthesized in a circuit of its own.
+    pub const fn count(&self) -> &CircuitCount {
+        &self.count
+    }
+
+    /// Returns the profile of the closure or function that is called by the instruction, if any.
+    pub const fn call(&self) -> Option<&Profile<N>> {
+        self.call.as_ref()
+    }
+}
+
+/// The circuit count of each instruction in an executed closure or function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Profile<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The name of the closure or function.
+    name: Identifier<N>,
+    /// The kind of the profiled block.
+    kind: ProfileKind,
+    /// The profile of each instruction, in order.
+    instructions: Vec<InstructionProfile<N>>,
+    /// The circuit count of the closure or function.
+    total: CircuitCount,
+}
+
+impl<N: Network> Profile<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the closure or function.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the kind of the profiled block.
+    pub const fn kind(&self) -> ProfileKind {
+        self.kind
+    }
+
+    /// Returns the profile of each instruction, in order.
+    pub fn instructions(&self) -> &[InstructionProfile<N>] {
+        &self.instructions
+    }
+
+    /// Returns the circuit count of the closure or function.
+    /// Note: For a function, this is the count of its entire circuit, which includes
+    /// the verification of its request and the construction of its response.
+    pub const fn total(&self) -> &CircuitCount {
+        &self.total
+    }
+}
+
+thread_local! {
+    /// The state of the profiler on this thread, if it is enabled.
+    static PROFILER: RefCell<Option<ProfilerState>> = const { RefCell::new(None) };
+}
+
+/// The state of the profiler.
+#[derive(Default)]
+struct ProfilerState {
+    /// The profiles in progress, from outermost to innermost.
+    frames: Vec<Box<dyn Any>>,
+    /// The most recently completed profile, which is attached to the next recorded instruction of its caller.
+    completed: Option<Box<dyn Any>>,
+}
+
+/// A profiler of the circuit count of each instruction that is executed on this thread.
+pub(crate) struct Profiler<N: Network>(PhantomData<N>);
+
+impl<N: Network> Profiler<N> {
+    /// Enables the profiler on this thread.
+    pub(crate) fn enable() {
+        PROFILER.with(|profiler| *profiler.borrow_mut() = Some(ProfilerState::default()));
+    }
+
+    /// Disables the profiler on this thread, and returns the profile of the outermost function, if it completed.
+    pub(crate) fn disable() -> Option<Profile<N>> {
+        let state = PROFILER.with(|profiler| profiler.borrow_mut().take())?;
+        match state.frames.is_empty() {
+            true => state.completed?.downcast::<Profile<N>>().ok().map(|profile| *profile),
+            false => None,
+        }
+    }
+
+    /// Starts the profile of the given closure or function, if the profiler is enabled.
+    pub(crate) fn enter(program_id: &ProgramID<N>, name: &Identifier<N>, kind: ProfileKind) {
+        Self::with_state(|state| {
+            let profile = Profile::<N> {
+                program_id: *program_id,
+                name: *name,
+                kind,
+                instructions: vec![],
+                total: CircuitCount::default(),
+            };
+            state.frames.push(Box::new(profile));
+        });
+    }
+
+    /// Records the circuit count of the given instruction, if the profiler is enabled.
+    pub(crate) fn record(instruction: &Instruction<N>, count: CircuitCount) {
+        Self::with_state(|state| {
+            // Retrieve the profile of the closure or function that was called by the instruction, if any.
+            let call = state.completed.take().and_then(|profile| profile.downcast::<Profile<N>>().ok());
+            if let Some(profile) = state.frames.last_mut().and_then(|frame| frame.downcast_mut::<Profile<N>>()) {
+                profile.instructions.push(InstructionProfile {
+                    instruction: instruction.to_string(),
+                    count,
+                    call: call.map(|profile| *profile),
+                });
+            }
+        });
+    }
+
+    /// Completes the profile of the innermost closure or function, if the profiler is enabled.
+    /// If the total circuit count is not given, it is the sum of the circuit count of each instruction.
+    pub(crate) fn exit(total: Option<CircuitCount>) {
+        Self::with_state(|state| {
+            if let Some(mut frame) = state.frames.pop() {
+                if let Some(profile) = frame.downcast_mut::<Profile<N>>() {
+                    profile.total = match total {
+                        Some(total) => total,
+                        None => profile.instructions.iter().fold(CircuitCount::default(), |sum, instruction| {
+                            sum + instruction.count
+                        }),
+                    };
+                }
+                state.completed = Some(frame);
+            }
+        });
+    }
+
+    /// Applies the given closure to the state of the profiler, if it is enabled.
+    fn with_state(closure: impl FnOnce(&mut ProfilerState)) {
+        PROFILER.with(|profiler| {
+            if let Some(state) = profiler.borrow_mut().as_mut() {
+                closure(state)
+            }
+        });
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Serialize for CircuitCount {
+    /// Serializes the circuit count into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut count = serializer.serialize_struct("CircuitCount", 5)?;
+        count.serialize_field("constants", &self.num_constants)?;
+        count.serialize_field("public", &self.num_public)?;
+        count.serialize_field("private", &self.num_private)?;
+        count.serialize_field("constraints", &self.num_constraints)?;
+        count.serialize_field("gates", &self.num_gates)?;
+        count.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CircuitCount {
+    /// Deserializes the circuit count from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the circuit count from a string into a value.
+        let count = serde_json::Value::deserialize(deserializer)?;
+        // Recover the circuit count.
+        Ok(Self::new(
+            serde_json::from_value(count["constants"].clone()).map_err(de::Error::custom)?,
+            serde_json::from_value(count["public"].clone()).map_err(de::Error::custom)?,
+            serde_json::from_value(count["private"].clone()).map_err(de::Error::custom)?,
+            serde_json::from_value(count["constraints"].clone()).map_err(de::Error::custom)?,
+            serde_json::from_value(count["gates"].clone()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl<N: Network> Serialize for InstructionProfile<N> {
+    /// Serializes the instruction profile into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut profile = serializer.serialize_struct("InstructionProfile", 3)?;
+        profile.serialize_field("instruction", &self.instruction)?;
+        profile.serialize_field("count", &self.count)?;
+        profile.serialize_field("call", &self.call)?;
+        profile.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for InstructionProfile<N> {
+    /// Deserializes the instruction profile from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the instruction profile from a string into a value.
+        let profile = serde_json::Value::deserialize(deserializer)?;
+        // Recover the instruction profile.
+        Ok(Self {
+            instruction: serde_json::from_value(profile["instruction"].clone()).map_err(de::Error::custom)?,
+            count: serde_json::from_value(profile["count"].clone()).map_err(de::Error::custom)?,
+            call: serde_json::from_value(profile["call"].clone()).map_err(de::Error::custom)?,
+        })
+    }
+}
+
+impl<N: Network> Serialize for Profile<N> {
+    /// Serializes the profile into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut profile = serializer.serialize_struct("Profile", 5)?;
+        profile.serialize_field("program_id", &self.program_id)?;
+        profile.serialize_field("name", &self.name)?;
+        profile.serialize_field("kind", &self.kind.to_string())?;
+        profile.serialize_field("instructions", &self.instructions)?;
+        profile.serialize_field("total", &self.total)?;
+        profile.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Profile<N> {
+    /// Deserializes the profile from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the profile from a string into a value.
+        let profile = serde_json::Value::deserialize(deserializer)?;
+        // Recover the profile.
+        Ok(Self {
+            program_id: serde_json::from_value(profile["program_id"].clone()).map_err(de::Error::custom)?,
+            name: serde_json::from_value(profile["name"].clone()).map_err(de::Error::custom)?,
+            kind: match profile["kind"].as_str() {
+                Some("closure") => ProfileKind::Closure,
+                Some("function") => ProfileKind::Function,
+                _ => return Err(de::Error::custom("Invalid profile kind")),
+            },
+            instructions: serde_json::from_value(profile["instructions"].clone()).map_err(de::Error::custom)?,
+            total: serde_json::from_value(profile["total"].clone()).map_err(de::Error::custom)?,
+        })
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Display for ProfileKind {
+    /// Prints the profile kind as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Closure => write!(f, "closure"),
+            Self::Function => write!(f, "function"),
+        }
+    }
+}
+
+impl<N: Network> Display for Profile<N> {
+    /// Prints the profile as a table, with the profile of each call nested under its instruction.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_with_depth(f, 0)
+    }
+}
+
+impl<N: Network> Profile<N> {
+    /// Prints the profile as a table, indented by the given depth.
+    fn fmt_with_depth(&self, f: &mut Formatter, depth: usize) -> fmt::Result {
+        // Prepare the indentation.
+        let indent = "    ".repeat(depth);
+        // Compute the width of the instruction column.
+        let width = self.instructions.iter().map(|instruction| instruction.instruction.len()).fold(11, usize::max);
+
+        // Print the header.
+        writeln!(f, "{indent}{} '{}/{}'", self.kind, self.program_id, self.name)?;
+        writeln!(
+            f,
+            "{indent}{:>5}  {:<width$}  {:>10}  {:>10}  {:>10}  {:>12}  {:>10}",
+            "#", "Instruction", "Constants", "Public", "Private", "Constraints", "Gates"
+        )?;
+        // Print each instruction, followed by the profile of its call, if any.
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            Self::fmt_row(f, &indent, &index.to_string(), &instruction.instruction, &instruction.count, width)?;
+            if let Some(call) = &instruction.call {
+                call.fmt_with_depth(f, depth + 1)?;
+            }
+        }
+        // Print the total.
+        Self::fmt_row(f, &indent, "", "Total", &self.total, width)
+    }
+
+    /// Prints a row of the table.
+    fn fmt_row(
+        f: &mut Formatter,
+        indent: &str,
+        index: &str,
+        label: &str,
+        count: &CircuitCount,
+        width: usize,
+    ) -> fmt::Result {
+        writeln!(
+            f,
+            "{indent}{index:>5}  {label:<width$}  {:>10}  {:>10}  {:>10}  {:>12}  {:>10}",
+            count.num_constants, count.num_public, count.num_private, count.num_constraints, count.num_gates
+        )
+    }
+}
@@ -0,0 +1,357 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod serialize;
+mod string;
+
+use super::{Registers, Stack};
+use crate::{Instruction, Operand};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID, Register, Value},
+};
+
+use core::{any::Any, marker::PhantomData};
+use std::cell::RefCell;
+
+/// The kind of a traced block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TraceKind {
+    /// A closure.
+    Closure,
+    /// A function.
+    Function,
+}
+
+/// An evaluated instruction, along with the values of its operands and destinations,
+/// and the trace of the closure or function it calls, if any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep<N: Network> {
+    /// The index of the instruction in its closure or function.
+    index: usize,
+    /// The instruction.
+    instruction: String,
+    /// The value of each operand, before the instruction is evaluated.
+    operands: Vec<(Operand<N>, Value<N>)>,
+    /// The value of each destination register, after the instruction is evaluated.
+    /// Note: This is empty if the instruction failed to evaluate.
+    destinations: Vec<(Register<N>, Value<N>)>,
+    /// The trace of the closure or function that is called by the instruction, if any.
+    call: Option<Trace<N>>,
+}
+
+impl<N: Network> TraceStep<N> {
+    /// Returns the index of the instruction in its closure or function.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the instruction.
+    pub fn instruction(&self) -> &str {
+        &self.instruction
+    }
+
+    /// Returns the value of each operand, before the instruction is evaluated.
+    pub fn operands(&self) -> &[(Operand<N>, Value<N>)] {
+        &self.operands
+    }
+
+    /// Returns the value of each destination register, after the instruction is evaluated.
+    /// Note: This is empty if the instruction failed to evaluate.
+    pub fn destinations(&self) -> &[(Register<N>, Value<N>)] {
+        &self.destinations
+    }
+
+    /// Returns the trace of the closure or function that is called by the instruction, if any.
+    pub const fn call(&self) -> Option<&Trace<N>> {
+        self.call.as_ref()
+    }
+}
+
+/// The instructions that were evaluated in a closure or function, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The name of the closure or function.
+    name: Identifier<N>,
+    /// The kind of the traced block.
+    kind: TraceKind,
+    /// The value of each input register.
+    inputs: Vec<(Register<N>, Value<N>)>,
+    /// The evaluated instructions, in order.
+    steps: Vec<TraceStep<N>>,
+    /// The value of each output register.
+    /// Note: This is empty if the closure or function failed to evaluate.
+    outputs: Vec<(Register<N>, Value<N>)>,
+}
+
+impl<N: Network> Trace<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the closure or function.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the kind of the traced block.
+    pub const fn kind(&self) -> TraceKind {
+        self.kind
+    }
+
+    /// Returns the value of each input register.
+    pub fn inputs(&self) -> &[(Register<N>, Value<N>)] {
+        &self.inputs
+    }
+
+    /// Returns the evaluated instructions, in order.
+    pub fn steps(&self) -> &[TraceStep<N>] {
+        &self.steps
+    }
+
+    /// Returns the value of each output register.
+    /// Note: This is empty if the closure or function failed to evaluate.
+    pub fn outputs(&self) -> &[(Register<N>, Value<N>)] {
+        &self.outputs
+    }
+
+    /// Returns a stepper over the evaluated instructions, starting before the first instruction.
+    pub fn stepper(&self) -> Stepper<'_, N> {
+        Stepper { frames: vec![(self, 0)], current: None }
+    }
+}
+
+/// A cursor that steps through a trace in the order the instructions were evaluated.
+/// Stepping into a `call` instruction continues with the first instruction of the callee.
+pub struct Stepper<'a, N: Network> {
+    /// The traces in progress, from outermost to innermost, with the index of their next step.
+    frames: Vec<(&'a Trace<N>, usize)>,
+    /// The current step.
+    current: Option<&'a TraceStep<N>>,
+}
+
+impl<'a, N: Network> Stepper<'a, N> {
+    /// Returns the current step, or `None` if the stepper has not started or has finished.
+    pub const fn current(&self) -> Option<&'a TraceStep<N>> {
+        self.current
+    }
+
+    /// Returns the trace of the closure or function of the current step.
+    pub fn trace(&self) -> Option<&'a Trace<N>> {
+        self.current.and(self.frames.last().map(|(trace, _)| *trace))
+    }
+
+    /// Returns the call depth of the current step, where the outermost function has a depth of zero.
+    pub fn depth(&self) -> usize {
+        self.frames.len().saturating_sub(1)
+    }
+
+    /// Advances to the next evaluated instruction, entering the callee of the current step, if any.
+    pub fn step_into(&mut self) -> Option<&'a TraceStep<N>> {
+        if let Some(call) = self.current.and_then(|step| step.call.as_ref()) {
+            self.frames.push((call, 0));
+        }
+        self.advance()
+    }
+
+    /// Advances to the next instruction of the current closure or function, skipping over the callee
+    /// of the current step, if any. If the current step is the last, this returns to the caller.
+    pub fn step_over(&mut self) -> Option<&'a TraceStep<N>> {
+        self.advance()
+    }
+
+    /// Advances to the instruction after the `call` instruction of the caller.
+    pub fn step_out(&mut self) -> Option<&'a TraceStep<N>> {
+        self.frames.pop();
+        self.advance()
+    }
+
+    /// Advances to the next step of the innermost trace, returning to the caller as each trace is exhausted.
+    fn advance(&mut self) -> Option<&'a TraceStep<N>> {
+        self.current = None;
+        while let Some((trace, next)) = self.frames.last_mut() {
+            match trace.steps.get(*next) {
+                Some(step) => {
+                    *next += 1;
+                    self.current = Some(step);
+                    break;
+                }
+                None => {
+                    self.frames.pop();
+                }
+            }
+        }
+        self.current
+    }
+}
+
+impl<'a, N: Network> Iterator for Stepper<'a, N> {
+    type Item = &'a TraceStep<N>;
+
+    /// Steps into the next evaluated instruction.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.step_into()
+    }
+}
+
+thread_local! {
+    /// The state of the tracer on this thread, if it is enabled.
+    static TRACER: RefCell<Option<TracerState>> = const { RefCell::new(None) };
+}
+
+/// The state of the tracer.
+#[derive(Default)]
+struct TracerState {
+    /// The traces in progress, from outermost to innermost.
+    frames: Vec<Box<dyn Any>>,
+    /// The most recently completed trace, which is attached to the last step of its caller.
+    completed: Option<Box<dyn Any>>,
+}
+
+/// A tracer of each instruction that is evaluated on this thread.
+pub(crate) struct Tracer<N: Network>(PhantomData<N>);
+
+impl<N: Network> Tracer<N> {
+    /// Enables the tracer on this thread.
+    pub(crate) fn enable() {
+        TRACER.with(|tracer| *tracer.borrow_mut() = Some(TracerState::default()));
+    }
+
+    /// Disables the tracer on this thread, and returns the trace of the outermost function, if it started.
+    /// If the evaluation failed, the trace ends at the instruction that failed, in each closure or function.
+    pub(crate) fn disable() -> Option<Trace<N>> {
+        let state = TRACER.with(|tracer| tracer.borrow_mut().take())?;
+        // Attach each trace in progress to the last step of its caller.
+        let mut trace = state.completed.and_then(|trace| trace.downcast::<Trace<N>>().ok());
+        for frame in state.frames.into_iter().rev() {
+            let mut frame = frame.downcast::<Trace<N>>().ok()?;
+            if let Some(step) = frame.steps.last_mut() {
+                step.call = trace.map(|trace| *trace);
+            }
+            trace = Some(frame);
+        }
+        trace.map(|trace| *trace)
+    }
+
+    /// Returns `true` if the tracer is enabled on this thread.
+    pub(crate) fn is_enabled() -> bool {
+        TRACER.with(|tracer| tracer.borrow().is_some())
+    }
+
+    /// Starts the trace of the given closure or function, if the tracer is enabled.
+    pub(crate) fn enter(
+        program_id: &ProgramID<N>,
+        name: &Identifier<N>,
+        kind: TraceKind,
+        registers: &[&Register<N>],
+        inputs: &[Value<N>],
+    ) {
+        Self::with_state(|state| {
+            let trace = Trace::<N> {
+                program_id: *program_id,
+                name: *name,
+                kind,
+                inputs: registers.iter().map(|register| (*register).clone()).zip(inputs.iter().cloned()).collect(),
+                steps: vec![],
+                outputs: vec![],
+            };
+            state.frames.push(Box::new(trace));
+        });
+    }
+
+    /// Starts the step of the given instruction, if the tracer is enabled.
+    pub(crate) fn step<A: circuit::Aleo<Network = N>>(
+        stack: &Stack<N>,
+        registers: &Registers<N, A>,
+        index: usize,
+        instruction: &Instruction<N>,
+    ) {
+        if !Self::is_enabled() {
+            return;
+        }
+        // Load the value of each operand. An operand that fails to load is left for the instruction to report.
+        let operands = instruction
+            .operands()
+            .iter()
+            .filter_map(|operand| Some((operand.clone(), registers.load(stack, operand).ok()?)))
+            .collect();
+        Self::with_state(|state| {
+            if let Some(trace) = state.frames.last_mut().and_then(|frame| frame.downcast_mut::<Trace<N>>()) {
+                trace.steps.push(TraceStep {
+                    index,
+                    instruction: instruction.to_string(),
+                    operands,
+                    destinations: vec![],
+                    call: None,
+                });
+            }
+        });
+    }
+
+    /// Completes the step of the given instruction, if the tracer is enabled.
+    pub(crate) fn complete<A: circuit::Aleo<Network = N>>(
+        stack: &Stack<N>,
+        registers: &Registers<N, A>,
+        instruction: &Instruction<N>,
+    ) {
+        if !Self::is_enabled() {
+            return;
+        }
+        // Load the value of each destination register.
+        let destinations = instruction
+            .destinations()
+            .into_iter()
+            .filter_map(|register| {
+                let value = registers.load(stack, &Operand::Register(register.clone())).ok()?;
+                Some((register, value))
+            })
+            .collect();
+        Self::with_state(|state| {
+            // Retrieve the trace of the closure or function that was called by the instruction, if any.
+            let call = state.completed.take().and_then(|trace| trace.downcast::<Trace<N>>().ok());
+            if let Some(trace) = state.frames.last_mut().and_then(|frame| frame.downcast_mut::<Trace<N>>()) {
+                if let Some(step) = trace.steps.last_mut() {
+                    step.destinations = destinations;
+                    step.call = call.map(|trace| *trace);
+                }
+            }
+        });
+    }
+
+    /// Completes the trace of the innermost closure or function, if the tracer is enabled.
+    pub(crate) fn exit(registers: &[Register<N>], outputs: &[Value<N>]) {
+        Self::with_state(|state| {
+            if let Some(mut frame) = state.frames.pop() {
+                if let Some(trace) = frame.downcast_mut::<Trace<N>>() {
+                    trace.outputs = registers.iter().cloned().zip(outputs.iter().cloned()).collect();
+                }
+                state.completed = Some(frame);
+            }
+        });
+    }
+
+    /// Applies the given closure to the state of the tracer, if it is enabled.
+    fn with_state(closure: impl FnOnce(&mut TracerState)) {
+        TRACER.with(|tracer| {
+            if let Some(state) = tracer.borrow_mut().as_mut() {
+                closure(state)
+            }
+        });
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Serialize for TraceStep<N> {
+    /// Serializes the trace step into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Prepare the operands, as the operand is not serializable.
+        let operands = self.operands.iter().map(|(operand, value)| (operand.to_string(), value)).collect::<Vec<_>>();
+
+        let mut step = serializer.serialize_struct("TraceStep", 5)?;
+        step.serialize_field("index", &self.index)?;
+        step.serialize_field("instruction", &self.instruction)?;
+        step.serialize_field("operands", &operands)?;
+        step.serialize_field("destinations", &self.destinations)?;
+        step.serialize_field("call", &self.call)?;
+        step.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for TraceStep<N> {
+    /// Deserializes the trace step from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the trace step from a string into a value.
+        let step = serde_json::Value::deserialize(deserializer)?;
+        // Recover the operands.
+        let operands: Vec<(String, Value<N>)> =
+            serde_json::from_value(step["operands"].clone()).map_err(de::Error::custom)?;
+        let operands = operands
+            .into_iter()
+            .map(|(operand, value)| Ok((Operand::from_str(&operand)?, value)))
+            .collect::<Result<Vec<_>>>()
+            .map_err(de::Error::custom)?;
+        // Recover the trace step.
+        Ok(Self {
+            index: serde_json::from_value(step["index"].clone()).map_err(de::Error::custom)?,
+            instruction: serde_json::from_value(step["instruction"].clone()).map_err(de::Error::custom)?,
+            operands,
+            destinations: serde_json::from_value(step["destinations"].clone()).map_err(de::Error::custom)?,
+            call: serde_json::from_value(step["call"].clone()).map_err(de::Error::custom)?,
+        })
+    }
+}
+
+impl<N: Network> Serialize for Trace<N> {
+    /// Serializes the trace into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut trace = serializer.serialize_struct("Trace", 6)?;
+        trace.serialize_field("program_id", &self.program_id)?;
+        trace.serialize_field("name", &self.name)?;
+        trace.serialize_field("kind", &self.kind.to_string())?;
+        trace.serialize_field("inputs", &self.inputs)?;
+        trace.serialize_field("steps", &self.steps)?;
+        trace.serialize_field("outputs", &self.outputs)?;
+        trace.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Trace<N> {
+    /// Deserializes the trace from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the trace from a string into a value.
+        let trace = serde_json::Value::deserialize(deserializer)?;
+        // Recover the trace.
+        Ok(Self {
+            program_id: serde_json::from_value(trace["program_id"].clone()).map_err(de::Error::custom)?,
+            name: serde_json::from_value(trace["name"].clone()).map_err(de::Error::custom)?,
+            kind: match trace["kind"].as_str() {
+                Some("closure") => TraceKind::Closure,
+                Some("function") => TraceKind::Function,
+                _ => return Err(de::Error::custom("Invalid trace kind")),
+            },
+            inputs: serde_json::from_value(trace["inputs"].clone()).map_err(de::Error::custom)?,
+            steps: serde_json::from_value(trace["steps"].clone()).map_err(de::Error::custom)?,
+            outputs: serde_json::from_value(trace["outputs"].clone()).map_err(de::Error::custom)?,
+        })
+    }
+}
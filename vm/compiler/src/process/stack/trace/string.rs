@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Display for TraceKind {
+    /// Prints the trace kind as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Closure => write!(f, "closure"),
+            Self::Function => write!(f, "function"),
+        }
+    }
+}
+
+impl<N: Network> Display for Trace<N> {
+    /// Prints the trace as a listing, with the trace of each call nested under its instruction.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_with_depth(f, 0)
+    }
+}
+
+impl<N: Network> Trace<N> {
+    /// Prints the trace as a listing, indented by the given depth.
+    fn fmt_with_depth(&self, f: &mut Formatter, depth: usize) -> fmt::Result {
+        // Prepare the indentation.
+        let indent = "    ".repeat(depth);
+
+        // Print the header.
+        writeln!(f, "{indent}{} '{}/{}'", self.kind, self.program_id, self.name)?;
+        // Print the inputs.
+        for (register, value) in &self.inputs {
+            Self::fmt_value(f, &format!("{indent}    input {register}"), value, &indent)?;
+        }
+        // Print each step, followed by its operands, its destinations, and the trace of its call, if any.
+        for step in &self.steps {
+            writeln!(f, "{indent}{:>5}  {}", step.index, step.instruction)?;
+            for (operand, value) in &step.operands {
+                Self::fmt_value(f, &format!("{indent}           {operand}"), value, &indent)?;
+            }
+            if let Some(call) = &step.call {
+                call.fmt_with_depth(f, depth + 2)?;
+            }
+            for (register, value) in &step.destinations {
+                Self::fmt_value(f, &format!("{indent}        => {register}"), value, &indent)?;
+            }
+        }
+        // Print the outputs.
+        for (register, value) in &self.outputs {
+            Self::fmt_value(f, &format!("{indent}    output {register}"), value, &indent)?;
+        }
+        Ok(())
+    }
+
+    /// Prints the given value with the given label, indenting each subsequent line of the value.
+    fn fmt_value(f: &mut Formatter, label: &str, value: &Value<N>, indent: &str) -> fmt::Result {
+        let value = value.to_string().replace('\n', &format!("\n{indent}           "));
+        writeln!(f, "{label} = {value}")
+    }
+}
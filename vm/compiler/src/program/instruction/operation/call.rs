@@ -282,8 +282,7 @@ impl<N: Network> Call<N> {
             let num_public = A::num_public();
 
             use circuit::Eject;
-            // Eject the existing circuit.
-            let r1cs = A::eject_r1cs_and_reset();
+            // Note: The function is synthesized in its own circuit session, which leaves this circuit intact.
             let (request, response) = {
                 // Eject the circuit inputs.
                 let inputs = inputs.eject_value();
@@ -314,7 +313,7 @@ impl<N: Network> Call<N> {
                         authorization.push(request.clone());
 
                         // Execute the request.
-                        let response = substack.execute_function::<A, _>(call_stack, rng)?;
+                        let response = substack.execute_function::<A>(call_stack)?;
 
                         // Return the request and response.
                         (request, response)
@@ -336,7 +335,7 @@ impl<N: Network> Call<N> {
                         call_stack.push(request.clone())?;
 
                         // Execute the request.
-                        let response = substack.execute_function::<A, _>(call_stack, rng)?;
+                        let response = substack.execute_function::<A>(call_stack)?;
                         // Return the request and response.
                         (request, response)
                     }
@@ -357,7 +356,7 @@ impl<N: Network> Call<N> {
                         // Evaluate the function, and load the outputs.
                         let console_response = substack.evaluate_function::<A>(registers.call_stack().replicate())?;
                         // Execute the request.
-                        let response = substack.execute_function::<A, _>(registers.call_stack(), rng)?;
+                        let response = substack.execute_function::<A>(registers.call_stack())?;
                         // Ensure the values are equal.
                         if console_response.outputs() != response.outputs() {
                             #[cfg(debug_assertions)]
@@ -369,8 +368,6 @@ impl<N: Network> Call<N> {
                     }
                 }
             };
-            // Inject the existing circuit.
-            A::inject_r1cs(r1cs);
 
             use circuit::Inject;
 
@@ -0,0 +1,559 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::finalize::Command;
+use console::program::{Register, RegisterType};
+
+use std::collections::HashSet;
+
+/// The opcodes of the operations that may halt, e.g. on an overflow, a division by zero, a shift past the boundary,
+/// or a cast into an invalid record. Removing one of these operations removes its checks, so it is never dead.
+const HALTING_OPCODES: [&str; 16] = [
+    "abs", "add", "cast", "div", "div.w", "inv", "mod", "mul", "neg", "pow", "rem", "rem.w", "shl", "shr", "sqrt",
+    "sub",
+];
+
+/// The kind of a lint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LintKind {
+    /// An input register that is never read.
+    UnusedInput,
+    /// An instruction (or command) whose destination registers are never read, and which cannot halt.
+    DeadInstruction,
+    /// A closure that is never called within the program.
+    UnreachableClosure,
+    /// A cast that copies an interface into a register of the same interface.
+    RedundantCast,
+    /// An operation whose operands are all literals, which could be replaced by its result.
+    ConstantOperation,
+}
+
+impl Display for LintKind {
+    /// Prints the lint kind as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::UnusedInput => write!(f, "unused-input"),
+            Self::DeadInstruction => write!(f, "dead-instruction"),
+            Self::UnreachableClosure => write!(f, "unreachable-closure"),
+            Self::RedundantCast => write!(f, "redundant-cast"),
+            Self::ConstantOperation => write!(f, "constant-operation"),
+        }
+    }
+}
+
+/// The closure, function, or finalize block of a program that a lint refers to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LintScope<N: Network> {
+    /// The closure with the given name.
+    Closure(Identifier<N>),
+    /// The function with the given name.
+    Function(Identifier<N>),
+    /// The finalize block of the function with the given name.
+    Finalize(Identifier<N>),
+}
+
+impl<N: Network> Display for LintScope<N> {
+    /// Prints the lint scope as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Closure(name) => write!(f, "closure '{name}'"),
+            Self::Function(name) => write!(f, "function '{name}'"),
+            Self::Finalize(name) => write!(f, "finalize '{name}'"),
+        }
+    }
+}
+
+/// A warning about a program, which does not prevent it from being deployed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Lint<N: Network> {
+    /// The kind of the lint.
+    kind: LintKind,
+    /// The block that the lint refers to.
+    scope: LintScope<N>,
+    /// The index of the instruction (or command) that the lint refers to, if any.
+    index: Option<usize>,
+    /// The description of the lint.
+    message: String,
+}
+
+impl<N: Network> Lint<N> {
+    /// Returns the kind of the lint.
+    pub const fn kind(&self) -> LintKind {
+        self.kind
+    }
+
+    /// Returns the block that the lint refers to.
+    pub const fn scope(&self) -> &LintScope<N> {
+        &self.scope
+    }
+
+    /// Returns the index of the instruction (or command) that the lint refers to, if any.
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Returns the description of the lint.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl<N: Network> Display for Lint<N> {
+    /// Prints the lint as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "warning[{}]: {}", self.kind, self.scope)?;
+        match (&self.scope, self.index) {
+            (LintScope::Finalize(..), Some(index)) => write!(f, ", command {index}")?,
+            (_, Some(index)) => write!(f, ", instruction {index}")?,
+            (_, None) => (),
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// An instruction or command, as seen by the analysis.
+struct Statement<N: Network> {
+    /// The statement, as written in the program.
+    text: String,
+    /// The operands of the statement.
+    operands: Vec<Operand<N>>,
+    /// The destination registers of the statement.
+    destinations: Vec<Register<N>>,
+    /// Whether the statement has an effect beyond its destination registers (e.g. an assertion or a halting check).
+    has_side_effects: bool,
+    /// The instruction, if the statement is an instruction.
+    instruction: Option<Instruction<N>>,
+}
+
+impl<N: Network> Program<N> {
+    /// Returns the lints for the program, which flag unused inputs, dead instructions,
+    /// unreachable closures, redundant casts, and constant operations.
+    pub fn lint(&self) -> Vec<Lint<N>> {
+        let mut lints = Vec::new();
+
+        // Lint each closure.
+        for closure in self.closures.values() {
+            self.lint_block(
+                LintScope::Closure(*closure.name()),
+                closure.inputs().iter().map(|input| (input.register().clone(), *input.register_type())),
+                closure.instructions().iter().map(|instruction| self.to_statement(instruction)).collect(),
+                closure.outputs().iter().map(|output| output.register().clone()).collect(),
+                &mut lints,
+            );
+        }
+
+        // Lint each function, along with its finalize block.
+        for function in self.functions.values() {
+            // The operands of the finalize command are read by the function.
+            let mut outputs = function.outputs().iter().map(|output| output.register().clone()).collect::<Vec<_>>();
+            if let Some(command) = function.finalize_command() {
+                outputs.extend(command.operands().iter().filter_map(|operand| match operand {
+                    Operand::Register(register) => Some(register.clone()),
+                    _ => None,
+                }));
+            }
+            self.lint_block(
+                LintScope::Function(*function.name()),
+                function.inputs().iter().map(|input| (input.register().clone(), (*input.value_type()).into())),
+                function.instructions().iter().map(|instruction| self.to_statement(instruction)).collect(),
+                outputs,
+                &mut lints,
+            );
+
+            if let Some(finalize) = function.finalize_logic() {
+                self.lint_block(
+                    LintScope::Finalize(*finalize.name()),
+                    finalize.inputs().iter().map(|input| (input.register().clone(), (*input.finalize_type()).into())),
+                    finalize.commands().iter().map(|command| self.to_command_statement(command)).collect(),
+                    finalize.outputs().iter().map(|output| output.register().clone()).collect(),
+                    &mut lints,
+                );
+            }
+        }
+
+        // Lint the closures that are never called.
+        self.lint_unreachable_closures(&mut lints);
+
+        lints
+    }
+}
+
+impl<N: Network> Program<N> {
+    /// Lints the given closure, function, or finalize block.
+    fn lint_block(
+        &self,
+        scope: LintScope<N>,
+        inputs: impl Iterator<Item = (Register<N>, RegisterType<N>)>,
+        statements: Vec<Statement<N>>,
+        outputs: Vec<Register<N>>,
+        lints: &mut Vec<Lint<N>>,
+    ) {
+        let inputs = inputs.collect::<Vec<_>>();
+
+        // Lint the inputs that are never read.
+        let reads = statements
+            .iter()
+            .flat_map(|statement| statement.operands.iter())
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register.locator()),
+                _ => None,
+            })
+            .chain(outputs.iter().map(Register::locator))
+            .collect::<HashSet<_>>();
+        for (register, register_type) in &inputs {
+            // Note: A record input of a function is spent, even if it is never read.
+            let is_spent = matches!(scope, LintScope::Function(..))
+                && matches!(register_type, RegisterType::Record(..) | RegisterType::ExternalRecord(..));
+            if !is_spent && !reads.contains(&register.locator()) {
+                lints.push(Lint {
+                    kind: LintKind::UnusedInput,
+                    scope: scope.clone(),
+                    index: None,
+                    message: format!("Input '{register}' is never read"),
+                });
+            }
+        }
+
+        // Lint the statements, in order.
+        let mut statement_lints = vec![Vec::new(); statements.len()];
+
+        // Lint the statements whose destinations are never read, starting from the last statement.
+        // Note: The operands of a dead statement are not live, so a chain of dead statements is flagged in full.
+        let mut live = outputs.iter().map(Register::locator).collect::<HashSet<_>>();
+        for (index, statement) in statements.iter().enumerate().rev() {
+            match statement.has_side_effects
+                || statement.destinations.iter().any(|destination| live.contains(&destination.locator()))
+            {
+                true => live.extend(statement.operands.iter().filter_map(|operand| match operand {
+                    Operand::Register(register) => Some(register.locator()),
+                    _ => None,
+                })),
+                false => statement_lints[index].push(Lint {
+                    kind: LintKind::DeadInstruction,
+                    scope: scope.clone(),
+                    index: Some(index),
+                    message: format!("'{}' is dead, as its destination is never read", statement.text),
+                }),
+            }
+        }
+
+        // Track the interface of each register, to find the casts that copy an interface.
+        let mut interfaces = inputs
+            .iter()
+            .filter_map(|(register, register_type)| match register_type {
+                RegisterType::Plaintext(PlaintextType::Interface(name)) => Some((register.locator(), *name)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        for (index, statement) in statements.iter().enumerate() {
+            let instruction = match &statement.instruction {
+                Some(instruction) => instruction,
+                None => continue,
+            };
+
+            // Lint the operations whose operands are all literals.
+            let is_constant = !instruction.operands().is_empty()
+                && instruction.operands().iter().all(|operand| matches!(operand, Operand::Literal(..)));
+            let is_operation = matches!(
+                instruction.opcode(),
+                Opcode::Literal(..) | Opcode::Hash(..) | Opcode::Commit(..) | Opcode::Is(..)
+            );
+            if is_constant && is_operation {
+                statement_lints[index].push(Lint {
+                    kind: LintKind::ConstantOperation,
+                    scope: scope.clone(),
+                    index: Some(index),
+                    message: format!("'{}' only has literal operands, so it can be precomputed", statement.text),
+                });
+            }
+
+            // Lint the casts that copy an interface, member by member, into the same interface.
+            if let Instruction::Cast(cast) = instruction {
+                if let RegisterType::Plaintext(PlaintextType::Interface(name)) = cast.register_type() {
+                    if let Some(source) = self.to_copied_interface(name, cast.operands(), &interfaces) {
+                        statement_lints[index].push(Lint {
+                            kind: LintKind::RedundantCast,
+                            scope: scope.clone(),
+                            index: Some(index),
+                            message: format!("'{}' copies 'r{source}', which is already a '{name}'", statement.text),
+                        });
+                    }
+                    interfaces.extend(cast.destinations().iter().map(|destination| (destination.locator(), *name)));
+                }
+            }
+        }
+
+        lints.extend(statement_lints.into_iter().flatten());
+    }
+
+    /// Returns the locator of the register that the given cast operands copy, if the operands are
+    /// exactly the members of a register of the given interface, in order.
+    fn to_copied_interface(
+        &self,
+        name: &Identifier<N>,
+        operands: &[Operand<N>],
+        interfaces: &[(u64, Identifier<N>)],
+    ) -> Option<u64> {
+        // Retrieve the members of the interface.
+        let interface = self.get_interface(name).ok()?;
+        let members = interface.members().keys().collect::<Vec<_>>();
+
+        // Retrieve the register of the first operand.
+        let source = match operands.first()? {
+            Operand::Register(Register::Member(locator, _)) => *locator,
+            _ => return None,
+        };
+        // Ensure the register is of the given interface.
+        if !interfaces.iter().any(|(locator, interface)| *locator == source && interface == name) {
+            return None;
+        }
+        // Ensure the operands are the members of the register, in order.
+        match operands.len() == members.len()
+            && operands.iter().zip_eq(&members).all(|(operand, member)| match operand {
+                Operand::Register(Register::Member(locator, identifiers)) => {
+                    *locator == source && identifiers.len() == 1 && identifiers[0] == **member
+                }
+                _ => false,
+            }) {
+            true => Some(source),
+            false => None,
+        }
+    }
+
+    /// Lints the closures that are never called, directly or indirectly, by a function of the program.
+    fn lint_unreachable_closures(&self, lints: &mut Vec<Lint<N>>) {
+        // Initialize the closures that are called by the functions.
+        let mut reachable = self
+            .functions
+            .values()
+            .flat_map(|function| self.to_called_closures(function.instructions()))
+            .collect::<HashSet<_>>();
+        // Add the closures that are called by the reachable closures, until there are no more.
+        let mut queue = reachable.iter().copied().collect::<Vec<_>>();
+        while let Some(name) = queue.pop() {
+            if let Some(closure) = self.closures.get(&name) {
+                for callee in self.to_called_closures(closure.instructions()) {
+                    if reachable.insert(callee) {
+                        queue.push(callee);
+                    }
+                }
+            }
+        }
+
+        for name in self.closures.keys().filter(|name| !reachable.contains(*name)) {
+            lints.push(Lint {
+                kind: LintKind::UnreachableClosure,
+                scope: LintScope::Closure(*name),
+                index: None,
+                message: format!("Closure '{name}' is never called within '{}'", self.id),
+            });
+        }
+    }
+
+    /// Returns the names of the local closures that are called by the given instructions.
+    fn to_called_closures<'a>(
+        &'a self,
+        instructions: &'a [Instruction<N>],
+    ) -> impl 'a + Iterator<Item = Identifier<N>> {
+        instructions.iter().filter_map(|instruction| match instruction {
+            Instruction::Call(call) => match call.operator() {
+                CallOperator::Resource(name) if self.contains_closure(name) => Some(*name),
+                _ => None,
+            },
+            _ => None,
+        })
+    }
+
+    /// Returns the statement for the given instruction.
+    fn to_statement(&self, instruction: &Instruction<N>) -> Statement<N> {
+        let has_side_effects = match instruction {
+            // Note: A call to a function produces a transition, whereas a call to a local closure
+            // only has side effects if one of the instructions of the closure does.
+            Instruction::Call(call) => match call.operator() {
+                CallOperator::Resource(name) => match self.get_closure(name) {
+                    Ok(closure) => closure
+                        .instructions()
+                        .iter()
+                        .any(|instruction| self.to_statement(instruction).has_side_effects),
+                    Err(_) => true,
+                },
+                CallOperator::Locator(..) => true,
+            },
+            _ => match instruction.opcode() {
+                Opcode::Assert(..) => true,
+                opcode => HALTING_OPCODES.contains(&*opcode),
+            },
+        };
+        Statement {
+            text: instruction.to_string(),
+            operands: instruction.operands().to_vec(),
+            destinations: instruction.destinations(),
+            has_side_effects,
+            instruction: Some(instruction.clone()),
+        }
+    }
+
+    /// Returns the statement for the given finalize command.
+    fn to_command_statement(&self, command: &Command<N>) -> Statement<N> {
+        match command {
+            Command::Instruction(instruction) => self.to_statement(instruction),
+            // Note: An increment or decrement writes to a mapping.
+            Command::Decrement(decrement) => Statement {
+                text: command.to_string(),
+                operands: decrement.operands(),
+                destinations: vec![],
+                has_side_effects: true,
+                instruction: None,
+            },
+            Command::Increment(increment) => Statement {
+                text: command.to_string(),
+                operands: increment.operands(),
+                destinations: vec![],
+                has_side_effects: true,
+                instruction: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_lint_credits() {
+        // Ensure the credits program has no lints.
+        assert!(Program::<CurrentNetwork>::credits().unwrap().lint().is_empty());
+    }
+
+    #[test]
+    fn test_lint() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program lint.aleo;
+
+interface point:
+    x as field;
+    y as field;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+closure twice:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+closure thrice:
+    input r0 as field;
+    input r1 as field;
+    mul r0 3field into r2;
+    output r2 as field;
+
+function compute:
+    input r0 as point.private;
+    input r1 as u32.private;
+    input r2 as u32.public;
+    add 1u32 2u32 into r3;
+    mul.w r2 r2 into r4;
+    add.w r4 r3 into r5;
+    cast r0.x r0.y into r6 as point;
+    call twice r0.x into r7;
+    output r3 as u32.private;
+    output r6 as point.private;
+    output r7 as field.private;
+
+function bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;",
+        )
+        .unwrap();
+
+        // Ensure the lints are as expected, in order.
+        let lints = program.lint();
+        let summary =
+            lints.iter().map(|lint| (lint.kind(), lint.scope().to_string(), lint.index())).collect::<Vec<_>>();
+        assert_eq!(summary, vec![
+            (LintKind::UnusedInput, "closure 'thrice'".to_string(), None),
+            (LintKind::UnusedInput, "function 'compute'".to_string(), None),
+            (LintKind::ConstantOperation, "function 'compute'".to_string(), Some(0)),
+            (LintKind::DeadInstruction, "function 'compute'".to_string(), Some(1)),
+            (LintKind::DeadInstruction, "function 'compute'".to_string(), Some(2)),
+            (LintKind::RedundantCast, "function 'compute'".to_string(), Some(3)),
+            (LintKind::UnreachableClosure, "closure 'thrice'".to_string(), None),
+        ]);
+
+        // Ensure the lints are printed with their locations.
+        assert_eq!(lints[1].to_string(), "warning[unused-input]: function 'compute': Input 'r1' is never read");
+        assert_eq!(
+            lints[3].to_string(),
+            "warning[dead-instruction]: function 'compute', instruction 1: \
+             'mul.w r2 r2 into r4;' is dead, as its destination is never read"
+        );
+    }
+
+    #[test]
+    fn test_lint_halting_instructions() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program halting.aleo;
+
+interface pair:
+    first as u8;
+    second as u8;
+
+closure check:
+    input r0 as u8;
+    sub r0 1u8 into r1;
+    output r1 as u8;
+
+closure wrap:
+    input r0 as u8;
+    sub.w r0 1u8 into r1;
+    output r1 as u8;
+
+function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    div r0 r1 into r3;
+    shl r0 r1 into r4;
+    call check r0 into r5;
+    call wrap r0 into r6;
+    cast r0 r1 into r7 as pair;
+    output r0 as u8.private;",
+        )
+        .unwrap();
+
+        // Ensure only the call to the closure that cannot halt is dead, as removing the others drops their checks.
+        let lints = program.lint();
+        let summary =
+            lints.iter().map(|lint| (lint.kind(), lint.scope().to_string(), lint.index())).collect::<Vec<_>>();
+        assert_eq!(summary, vec![(LintKind::DeadInstruction, "function 'compute'".to_string(), Some(4))]);
+    }
+}
@@ -28,6 +28,9 @@ pub use import::*;
 mod instruction;
 pub use instruction::*;
 
+mod lint;
+pub use lint::*;
+
 mod mapping;
 pub use mapping::*;
 
@@ -171,6 +174,16 @@ function fee:
         &self.mappings
     }
 
+    /// Returns the interfaces in the program.
+    pub const fn interfaces(&self) -> &IndexMap<Identifier<N>, Interface<N>> {
+        &self.interfaces
+    }
+
+    /// Returns the records in the program.
+    pub const fn records(&self) -> &IndexMap<Identifier<N>, RecordType<N>> {
+        &self.records
+    }
+
     /// Returns the closures in the program.
     pub const fn closures(&self) -> &IndexMap<Identifier<N>, Closure<N>> {
         &self.closures
@@ -639,7 +652,7 @@ impl<N: Network> TypeName for Program<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{CallStack, Execution};
+    use crate::{Assignments, CallStack, Execution};
     use circuit::network::AleoV0;
     use console::{
         account::{Address, PrivateKey},
@@ -1119,9 +1132,9 @@ function compute:
 
         // Re-run to ensure state continues to work.
         let execution = Arc::new(RwLock::new(Execution::new()));
-        let response = stack
-            .execute_function::<CurrentAleo, _>(CallStack::execute(authorization, execution).unwrap(), rng)
-            .unwrap();
+        let assignments = Assignments::<CurrentNetwork>::default();
+        let call_stack = CallStack::execute(authorization, execution, assignments).unwrap();
+        let response = stack.execute_function::<CurrentAleo>(call_stack).unwrap();
         let candidate = response.outputs();
         assert_eq!(3, candidate.len());
         assert_eq!(r2, candidate[0]);
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+mod bytes;
+mod parse;
+mod serialize;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct AggregatedProof<N: Network> {
+    /// The aggregated proof.
+    proof: marlin::AggregatedProof<N::PairingCurve>,
+}
+
+impl<N: Network> AggregatedProof<N> {
+    /// Initializes a new aggregated proof.
+    pub(super) const fn new(proof: marlin::AggregatedProof<N::PairingCurve>) -> Self {
+        Self { proof }
+    }
+}
+
+impl<N: Network> Deref for AggregatedProof<N> {
+    type Target = marlin::AggregatedProof<N::PairingCurve>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.proof
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    pub(super) fn sample_aggregated_proof() -> AggregatedProof<CurrentNetwork> {
+        // Sample an execution.
+        let execution = crate::process::test_helpers::sample_execution();
+        // Return the aggregated proof.
+        execution.proof().clone().unwrap()
+    }
+}
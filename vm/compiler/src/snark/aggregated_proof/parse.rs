@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+static AGGREGATED_PROOF_PREFIX: &str = "aggproof";
+
+impl<N: Network> Parser for AggregatedProof<N> {
+    /// Parses a string into an aggregated proof.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Prepare a parser for the aggregated proof.
+        let parse_proof = recognize(pair(
+            pair(tag(AGGREGATED_PROOF_PREFIX), tag("1")),
+            many1(terminated(one_of("qpzry9x8gf2tvdw0s3jn54khce6mua7l"), many0(char('_')))),
+        ));
+
+        // Parse the aggregated proof from the string.
+        map_res(parse_proof, |proof: &str| -> Result<_, Error> { Self::from_str(&proof.replace('_', "")) })(string)
+    }
+}
+
+impl<N: Network> FromStr for AggregatedProof<N> {
+    type Err = Error;
+
+    /// Reads in the aggregated proof string.
+    fn from_str(proof: &str) -> Result<Self, Self::Err> {
+        // Decode the aggregated proof string from bech32m.
+        let (hrp, data, variant) = bech32::decode(proof)?;
+        if hrp != AGGREGATED_PROOF_PREFIX {
+            bail!("Failed to decode aggregated proof: '{hrp}' is an invalid prefix")
+        } else if data.is_empty() {
+            bail!("Failed to decode aggregated proof: data field is empty")
+        } else if variant != bech32::Variant::Bech32m {
+            bail!("Found an aggregated proof that is not bech32m encoded: {proof}");
+        }
+        // Decode the aggregated proof data from u5 to u8, and into the aggregated proof.
+        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+    }
+}
+
+impl<N: Network> Debug for AggregatedProof<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for AggregatedProof<N> {
+    /// Writes the aggregated proof as a bech32m string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Convert the aggregated proof to bytes.
+        let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
+        // Encode the bytes into bech32m.
+        let string = bech32::encode(AGGREGATED_PROOF_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m)
+            .map_err(|_| fmt::Error)?;
+        // Output the string.
+        Display::fmt(&string, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        // Ensure type and empty value fails.
+        assert!(AggregatedProof::<CurrentNetwork>::parse(&format!("{AGGREGATED_PROOF_PREFIX}1")).is_err());
+        assert!(AggregatedProof::<CurrentNetwork>::parse("").is_err());
+
+        // Sample the aggregated proof.
+        let proof = aggregated_proof::tests::sample_aggregated_proof();
+
+        // Check the aggregated proof parsing.
+        let expected = format!("{proof}");
+        let (remainder, candidate) = AggregatedProof::<CurrentNetwork>::parse(&expected).unwrap();
+        assert_eq!(format!("{expected}"), candidate.to_string());
+        assert_eq!(AGGREGATED_PROOF_PREFIX, candidate.to_string().split('1').next().unwrap());
+        assert_eq!("", remainder);
+        Ok(())
+    }
+
+    #[test]
+    fn test_string() -> Result<()> {
+        // Sample the aggregated proof.
+        let expected = aggregated_proof::tests::sample_aggregated_proof();
+
+        // Check the string representation.
+        let candidate = format!("{expected}");
+        assert_eq!(expected, AggregatedProof::from_str(&candidate)?);
+        assert_eq!(AGGREGATED_PROOF_PREFIX, candidate.split('1').next().unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        // Sample the aggregated proof.
+        let expected = aggregated_proof::tests::sample_aggregated_proof();
+
+        let candidate = expected.to_string();
+        assert_eq!(format!("{expected}"), candidate);
+        assert_eq!(AGGREGATED_PROOF_PREFIX, candidate.split('1').next().unwrap());
+
+        let candidate_recovered = AggregatedProof::<CurrentNetwork>::from_str(&candidate)?;
+        assert_eq!(expected, candidate_recovered);
+
+        Ok(())
+    }
+}
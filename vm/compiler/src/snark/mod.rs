@@ -33,6 +33,9 @@ type Fr<N> = <N as Environment>::Field;
 type Marlin<N> =
     marlin::MarlinSNARK<<N as Environment>::PairingCurve, FiatShamir<N>, marlin::MarlinHidingMode, [Fr<N>]>;
 
+mod aggregated_proof;
+pub use aggregated_proof::AggregatedProof;
+
 mod certificate;
 pub use certificate::Certificate;
 
@@ -47,3 +50,6 @@ pub use universal_srs::UniversalSRS;
 
 mod verifying_key;
 pub use verifying_key::VerifyingKey;
+
+/// A proof whose check is deferred, along with its verifying key and public inputs.
+pub type DeferredProof<N> = (VerifyingKey<N>, Vec<Fr<N>>, Proof<N>);
@@ -54,10 +54,10 @@ mod tests {
         static INSTANCE: OnceCell<Proof<CurrentNetwork>> = OnceCell::new();
         INSTANCE
             .get_or_init(|| {
-                // Sample a transition.
-                let transition = crate::process::test_helpers::sample_transition();
+                // Sample a transition that carries its own proof.
+                let transition = crate::process::test_helpers::sample_proven_transition();
                 // Return the proof.
-                transition.proof().clone()
+                transition.proof().clone().unwrap()
             })
             .clone()
     }
@@ -72,6 +72,35 @@ impl<N: Network> ProvingKey<N> {
         println!("{}", format!(" • Executed '{function_name}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
         Ok(batch_proof)
     }
+
+    /// Returns one aggregated proof for the given assignments, each on the circuit of its proving key.
+    pub fn prove_aggregate<R: Rng + CryptoRng>(
+        keys_to_assignments: &[(&Self, &circuit::Assignment<N::Field>)],
+        rng: &mut R,
+    ) -> Result<AggregatedProof<N>> {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Prepare the circuits.
+        let keys_to_circuits = keys_to_assignments
+            .iter()
+            .map(|(proving_key, assignment)| (&***proving_key, std::slice::from_ref(*assignment)))
+            .collect::<Vec<_>>();
+
+        // Compute the aggregated proof.
+        let aggregated_proof = AggregatedProof::new(Marlin::<N>::prove_batch_aggregate(
+            N::marlin_fs_parameters(),
+            &keys_to_circuits,
+            rng,
+        )?);
+
+        #[cfg(feature = "aleo-cli")]
+        {
+            let elapsed = timer.elapsed().as_millis();
+            println!("{}", format!(" • Aggregated {} proofs (in {elapsed} ms)", keys_to_assignments.len()).dimmed());
+        }
+        Ok(aggregated_proof)
+    }
 }
 
 impl<N: Network> Deref for ProvingKey<N> {
@@ -16,6 +16,8 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+
 #[derive(Clone)]
 pub struct UniversalSRS<N: Network> {
     /// The universal SRS parameter.
@@ -70,15 +72,16 @@ impl<N: Network> Deref for UniversalSRS<N> {
             #[cfg(feature = "aleo-cli")]
             let timer = std::time::Instant::now();
 
-            // Load the universal SRS bytes.
-            static UNIVERSAL_SRS: OnceCell<Vec<u8>> = OnceCell::new();
-            let srs = UNIVERSAL_SRS
-                .get_or_try_init(snarkvm_parameters::testnet3::TrialSRS::load_bytes)
-                .expect("Failed to load universal SRS bytes");
-
-            // Recover the universal SRS.
-            let universal_srs = CanonicalDeserialize::deserialize_with_mode(&**srs, Compress::No, Validate::No)
-                .expect("Failed to initialize universal SRS");
+            // Load the universal SRS bytes, or sample the universal SRS locally if the network does not provide it.
+            crate::supply_network_bytes();
+            let universal_srs = match N::universal_srs_bytes().expect("Failed to load universal SRS bytes") {
+                // Recover the universal SRS.
+                Some(srs) => CanonicalDeserialize::deserialize_with_mode(srs, Compress::No, Validate::No)
+                    .expect("Failed to initialize universal SRS"),
+                // Note: A locally-sampled universal SRS is **insecure**, and is only suitable for local development.
+                None => Marlin::<N>::universal_setup(&N::LOCAL_SRS_DEGREE, &mut StdRng::seed_from_u64(N::ID as u64))
+                    .expect("Failed to sample universal SRS"),
+            };
 
             #[cfg(feature = "aleo-cli")]
             println!("{}", format!(" • Loaded universal setup (in {} ms)", timer.elapsed().as_millis()).dimmed());
@@ -81,6 +81,74 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Returns `true` if the aggregated proof is valid for the given verifying keys and public inputs.
+    /// The verifying keys must be given in the same order as their circuits were proven.
+    pub fn verify_aggregate(keys_to_inputs: &[(Self, Vec<N::Field>)], proof: &AggregatedProof<N>) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Prepare the instances.
+        let keys_to_inputs = keys_to_inputs
+            .iter()
+            .map(|(verifying_key, inputs)| (&**verifying_key, std::slice::from_ref(inputs)))
+            .collect::<Vec<_>>();
+
+        // Verify the aggregated proof.
+        match Marlin::<N>::verify_batch_aggregate(N::marlin_fs_parameters(), &keys_to_inputs, proof) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                {
+                    let elapsed = timer.elapsed().as_millis();
+                    let num_proofs = keys_to_inputs.len();
+                    println!("{}", format!(" • Verified {num_proofs} aggregated proofs (in {elapsed} ms)").dimmed());
+                }
+
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if each deferred proof is valid for its verifying key and public inputs.
+    /// The proofs are checked together with a single pairing check.
+    pub fn verify_many(proofs: &[DeferredProof<N>]) -> bool {
+        // If there are no proofs, there is nothing to verify.
+        if proofs.is_empty() {
+            return true;
+        }
+
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Prepare the instances.
+        let instances = proofs
+            .iter()
+            .map(|(verifying_key, inputs, proof)| (&**verifying_key, std::slice::from_ref(inputs), &**proof))
+            .collect::<Vec<_>>();
+
+        // Verify the proofs.
+        match Marlin::<N>::verify_many(N::marlin_fs_parameters(), &instances, &mut rand::thread_rng()) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                {
+                    let elapsed = timer.elapsed().as_millis();
+                    println!("{}", format!(" • Verified {} proofs (in {} ms)", proofs.len(), elapsed).dimmed());
+                }
+
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::{Network, ProgramID};
+
+use anyhow::{anyhow, ensure, Result};
+use core::str::FromStr;
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+const LOCK_FILE_NAME: &str = "program.lock";
+
+/// A resolved program dependency, as recorded in the lock file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockedDependency<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The resolved version.
+    version: String,
+    /// The location the dependency was resolved from.
+    source: String,
+    /// The checksum of the program.
+    checksum: String,
+}
+
+impl<N: Network> LockedDependency<N> {
+    /// Initializes a new locked dependency.
+    pub const fn new(program_id: ProgramID<N>, version: String, source: String, checksum: String) -> Self {
+        Self { program_id, version, source, checksum }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the resolved version.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the location the dependency was resolved from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the checksum of the program.
+    pub fn checksum(&self) -> &str {
+        &self.checksum
+    }
+}
+
+pub struct LockFile<N: Network> {
+    /// The file path.
+    path: PathBuf,
+    /// The resolved dependencies, in the order they are added to a process.
+    dependencies: Vec<LockedDependency<N>>,
+}
+
+impl<N: Network> LockFile<N> {
+    /// Creates a lock file with the given directory path and resolved dependencies, replacing any existing lock file.
+    pub fn create(directory: &Path, dependencies: Vec<LockedDependency<N>>) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The program directory does not exist: '{}'", directory.display());
+
+        // Construct the lock file string.
+        let json = serde_json::json!({
+            "dependencies": dependencies
+                .iter()
+                .map(|dependency| {
+                    serde_json::json!({
+                        "program": dependency.program_id.to_string(),
+                        "version": dependency.version,
+                        "source": dependency.source,
+                        "checksum": dependency.checksum,
+                    })
+                })
+                .collect::<Vec<_>>(),
+        });
+        let lock_string = serde_json::to_string_pretty(&json)? + "\n";
+
+        // Construct the file path.
+        let path = directory.join(LOCK_FILE_NAME);
+        // Write the file.
+        File::create(&path)?.write_all(lock_string.as_bytes())?;
+
+        // Return the lock file.
+        Ok(Self { path, dependencies })
+    }
+
+    /// Opens the lock file for reading.
+    pub fn open(directory: &Path) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The program directory does not exist: '{}'", directory.display());
+
+        // Construct the file path.
+        let path = directory.join(LOCK_FILE_NAME);
+        // Ensure the file path exists.
+        ensure!(path.exists(), "Lock file is missing: '{}'", path.display());
+
+        // Read the file to a string.
+        let lock_string = fs::read_to_string(&path)?;
+        let json: serde_json::Value = serde_json::from_str(&lock_string)?;
+
+        // Retrieve the resolved dependencies.
+        let dependencies = json["dependencies"]
+            .as_array()
+            .ok_or_else(|| anyhow!("Locked dependencies not found."))?
+            .iter()
+            .map(|dependency| {
+                // Retrieves the given field of the locked dependency.
+                let field = |name: &str| {
+                    dependency[name]
+                        .as_str()
+                        .map(|value| value.to_string())
+                        .ok_or_else(|| anyhow!("Locked dependency is missing its '{name}'."))
+                };
+                Ok(LockedDependency::new(
+                    ProgramID::from_str(&field("program")?)?,
+                    field("version")?,
+                    field("source")?,
+                    field("checksum")?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Return the lock file.
+        Ok(Self { path, dependencies })
+    }
+
+    /// Returns `true` if the lock file exists at the given path.
+    pub fn exists_at(directory: &Path) -> bool {
+        // Construct the file path.
+        let path = directory.join(LOCK_FILE_NAME);
+        // Return the result.
+        path.is_file() && path.exists()
+    }
+
+    /// Returns the lock file name.
+    pub const fn file_name() -> &'static str {
+        LOCK_FILE_NAME
+    }
+
+    /// Returns the file path.
+    pub const fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Returns the resolved dependencies.
+    pub fn dependencies(&self) -> &[LockedDependency<N>] {
+        &self.dependencies
+    }
+
+    /// Returns the resolved dependency for the given program ID, if it exists.
+    pub fn get(&self, program_id: &ProgramID<N>) -> Option<&LockedDependency<N>> {
+        self.dependencies.iter().find(|dependency| dependency.program_id() == program_id)
+    }
+}
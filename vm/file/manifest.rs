@@ -17,8 +17,9 @@
 use crate::prelude::{Address, Network, PrivateKey, ProgramID};
 use snarkvm_compiler::Program;
 
-use anyhow::{anyhow, ensure, Result};
-use core::str::FromStr;
+use anyhow::{anyhow, bail, ensure, Result};
+use core::{fmt, str::FromStr};
+use indexmap::IndexMap;
 use std::{
     fs::{self, File},
     io::Write,
@@ -27,11 +28,60 @@ use std::{
 
 const MANIFEST_FILE_NAME: &str = "program.json";
 
+/// The location of a program dependency.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DependencySource {
+    /// A package directory, relative to the package that declares the dependency.
+    Path(PathBuf),
+    /// A registry directory, which contains packages at `{registry}/{program_id}/{version}`.
+    Registry(PathBuf),
+}
+
+impl fmt::Display for DependencySource {
+    /// Prints the dependency source as `path+{directory}` or `registry+{directory}`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "path+{}", path.display()),
+            Self::Registry(path) => write!(f, "registry+{}", path.display()),
+        }
+    }
+}
+
+/// A program dependency, as declared in the manifest.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Dependency {
+    /// The required version.
+    version: String,
+    /// The location of the dependency.
+    source: DependencySource,
+}
+
+impl Dependency {
+    /// Initializes a new dependency with the given version and source.
+    pub const fn new(version: String, source: DependencySource) -> Self {
+        Self { version, source }
+    }
+
+    /// Returns the required version.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the location of the dependency.
+    pub const fn source(&self) -> &DependencySource {
+        &self.source
+    }
+}
+
 pub struct Manifest<N: Network> {
     /// The file path.
     path: PathBuf,
     /// The program ID.
     program_id: ProgramID<N>,
+    /// The program version.
+    version: String,
+    /// The program dependencies.
+    dependencies: IndexMap<ProgramID<N>, Dependency>,
     /// The development private key.
     development_private_key: PrivateKey<N>,
     /// The development address.
@@ -77,7 +127,14 @@ impl<N: Network> Manifest<N> {
         File::create(&path)?.write_all(manifest_string.as_bytes())?;
 
         // Return the manifest file.
-        Ok(Self { path, program_id: *id, development_private_key: private_key, development_address: address })
+        Ok(Self {
+            path,
+            program_id: *id,
+            version: "0.0.0".to_string(),
+            dependencies: IndexMap::new(),
+            development_private_key: private_key,
+            development_address: address,
+        })
     }
 
     /// Opens the manifest file for reading.
@@ -100,6 +157,24 @@ impl<N: Network> Manifest<N> {
         // Ensure the program name is valid.
         ensure!(!Program::is_reserved_keyword(id.name()), "Program name is invalid (reserved): {id}");
 
+        // Retrieve the program version.
+        let version = json["version"].as_str().ok_or_else(|| anyhow!("Program version not found."))?.to_string();
+
+        // Retrieve the program dependencies, if they exist.
+        let dependencies = match &json["dependencies"] {
+            serde_json::Value::Null => IndexMap::new(),
+            serde_json::Value::Object(dependencies) => dependencies
+                .iter()
+                .map(|(dependency_id, dependency)| {
+                    let dependency_id = ProgramID::from_str(dependency_id)?;
+                    Ok((dependency_id, Self::parse_dependency(&dependency_id, dependency)?))
+                })
+                .collect::<Result<IndexMap<_, _>>>()?,
+            _ => bail!("Program dependencies must be an object."),
+        };
+        // Ensure the program does not depend on itself.
+        ensure!(!dependencies.contains_key(&id), "Program '{id}' cannot depend on itself.");
+
         // Retrieve the development private key.
         let development_private_key_string =
             json["development"]["private_key"].as_str().ok_or_else(|| anyhow!("Development private key not found."))?;
@@ -116,7 +191,7 @@ impl<N: Network> Manifest<N> {
         );
 
         // Return the manifest file.
-        Ok(Self { path, program_id: id, development_private_key, development_address })
+        Ok(Self { path, program_id: id, version, dependencies, development_private_key, development_address })
     }
 
     /// Returns `true` if the manifest file exists at the given path.
@@ -142,6 +217,16 @@ impl<N: Network> Manifest<N> {
         &self.program_id
     }
 
+    /// Returns the program version.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the program dependencies.
+    pub const fn dependencies(&self) -> &IndexMap<ProgramID<N>, Dependency> {
+        &self.dependencies
+    }
+
     /// Returns the development private key.
     pub const fn development_private_key(&self) -> &PrivateKey<N> {
         &self.development_private_key
@@ -152,3 +237,19 @@ impl<N: Network> Manifest<N> {
         &self.development_address
     }
 }
+
+impl<N: Network> Manifest<N> {
+    /// Parses a dependency of the form `{ "version": "...", "path": "..." }`
+    /// or `{ "version": "...", "registry": "..." }`.
+    fn parse_dependency(id: &ProgramID<N>, json: &serde_json::Value) -> Result<Dependency> {
+        // Retrieve the required version.
+        let version = json["version"].as_str().ok_or_else(|| anyhow!("Version not found for dependency '{id}'."))?;
+        // Retrieve the location of the dependency.
+        let source = match (json["path"].as_str(), json["registry"].as_str()) {
+            (Some(path), None) => DependencySource::Path(PathBuf::from(path)),
+            (None, Some(registry)) => DependencySource::Registry(PathBuf::from(registry)),
+            _ => bail!("Dependency '{id}' must specify exactly one of 'path' or 'registry'."),
+        };
+        Ok(Dependency::new(version.to_string(), source))
+    }
+}
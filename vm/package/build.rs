@@ -153,6 +153,11 @@ impl<N: Network> Package<N> {
         &self,
         endpoint: Option<String>,
     ) -> Result<()> {
+        // Resolve the dependencies into the imports directory, if the manifest declares any.
+        if !self.manifest_file.dependencies().is_empty() {
+            self.resolve_dependencies()?;
+        }
+
         // Skip the 'build' if the program is already built.
         if !self.is_build_required::<A>() {
             return Ok(());
@@ -18,13 +18,25 @@ mod build;
 mod clean;
 mod deploy;
 mod is_build_required;
+mod resolve;
 mod run;
 
 pub use build::{BuildRequest, BuildResponse};
 pub use deploy::{DeployRequest, DeployResponse};
 
 use crate::{
-    file::{AVMFile, AleoFile, Manifest, ProverFile, VerifierFile, README},
+    file::{
+        AVMFile,
+        AleoFile,
+        Dependency,
+        DependencySource,
+        LockFile,
+        LockedDependency,
+        Manifest,
+        ProverFile,
+        VerifierFile,
+        README,
+    },
     prelude::{
         de,
         Deserialize,
@@ -45,6 +57,7 @@ use snarkvm_compiler::{CallOperator, Execution, Instruction, Process, Program, P
 
 use anyhow::{bail, ensure, Error, Result};
 use core::str::FromStr;
+use indexmap::IndexMap;
 use rand::{CryptoRng, Rng};
 use std::path::{Path, PathBuf};
 
@@ -154,25 +167,49 @@ impl<N: Network> Package<N> {
 
     /// Returns a new process for the package.
     pub fn get_process(&self) -> Result<Process<N>> {
-        // Create the process.
-        let mut process = Process::load()?;
+        // Create the process, which shares its circuit keys across packages through the key cache.
+        #[cfg(not(test))]
+        let mut process = Process::load_with_default_key_cache()?;
+        // Note: Tests use a key cache in the temporary directory, so that they never write to the home directory.
+        #[cfg(test)]
+        let mut process = Process::load_with_key_cache(snarkvm_compiler::KeyCache::open(
+            std::env::temp_dir().join("snarkvm_package_key_cache"),
+            snarkvm_compiler::KeyCache::<N>::DEFAULT_MAX_SIZE_IN_BYTES,
+        )?)?;
+
+        // Add all import programs (in order) to the process.
+        self.add_imports(&mut process, self.program(), &mut vec![self.program_id])?;
+
+        // Add the program to the process.
+        process.add_program(self.program())?;
+
+        Ok(process)
+    }
 
+    /// Adds the imports of the given program to the process, such that each import is added after its own imports.
+    /// The `path` contains the program IDs from the main program to the given program, to detect cycles.
+    fn add_imports(&self, process: &mut Process<N>, program: &Program<N>, path: &mut Vec<ProgramID<N>>) -> Result<()> {
         // Prepare the imports directory.
         let imports_directory = self.imports_directory();
 
-        // Add all import programs (in order) to the process.
-        self.program().imports().keys().try_for_each(|program_id| {
+        program.imports().keys().try_for_each(|program_id| {
+            // Ensure the import does not form a cycle.
+            ensure!(!path.contains(program_id), "Found an import cycle at '{program_id}'");
+            // Skip the import, if it has already been added.
+            if process.contains_program(program_id) {
+                return Ok(());
+            }
+
             // Open the Aleo program file.
             let import_program_file = AleoFile::open(&imports_directory, program_id, false)?;
+            // Add the imports of the import program.
+            path.push(*program_id);
+            self.add_imports(process, import_program_file.program(), path)?;
+            path.pop();
             // Add the import program.
             process.add_program(import_program_file.program())?;
             Ok::<_, Error>(())
-        })?;
-
-        // Add the program to the process.
-        process.add_program(self.program())?;
-
-        Ok(process)
+        })
     }
 }
 
@@ -0,0 +1,301 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console::prelude::*;
+
+/// A dependency that has been located and loaded.
+struct ResolvedDependency<N: Network> {
+    /// The dependency, as declared in the manifest.
+    dependency: Dependency,
+    /// The program file of the dependency.
+    program_file: AleoFile<N>,
+}
+
+impl<N: Network> Package<N> {
+    /// Resolves the dependencies declared in the manifest (including their own dependencies),
+    /// copies them into the imports directory, and records them in the lock file.
+    /// Returns the program IDs of the dependencies, in the order they must be added to a process.
+    pub fn resolve_dependencies(&self) -> Result<Vec<ProgramID<N>>> {
+        // Resolve the dependencies, such that every dependency is ordered after its own dependencies.
+        let mut resolved = IndexMap::new();
+        let mut path = vec![self.program_id];
+        for (program_id, dependency) in self.manifest_file.dependencies() {
+            Self::resolve_dependency(&self.directory, program_id, dependency, &mut path, &mut resolved)?;
+        }
+
+        // Compute the locked dependencies.
+        let locked_dependencies = resolved
+            .iter()
+            .map(|(program_id, resolved)| {
+                // Compute the checksum of the program.
+                let checksum = N::hash_bhp1024(&resolved.program_file.program_string().as_bytes().to_bits_le())?;
+                Ok(LockedDependency::new(
+                    *program_id,
+                    resolved.dependency.version().to_string(),
+                    resolved.dependency.source().to_string(),
+                    checksum.to_string(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Ensure the dependencies have not changed, for the versions recorded in the lock file.
+        if LockFile::<N>::exists_at(&self.directory) {
+            let lock_file = LockFile::<N>::open(&self.directory)?;
+            for dependency in &locked_dependencies {
+                if let Some(locked) = lock_file.get(dependency.program_id()) {
+                    ensure!(
+                        locked.version() != dependency.version() || locked.checksum() == dependency.checksum(),
+                        "Dependency '{}' (version '{}') does not match the checksum in '{}'",
+                        dependency.program_id(),
+                        dependency.version(),
+                        LockFile::<N>::file_name()
+                    );
+                }
+            }
+        }
+
+        // Copy the dependencies into the imports directory.
+        if !resolved.is_empty() {
+            let imports_directory = self.imports_directory();
+            if !imports_directory.exists() {
+                std::fs::create_dir_all(&imports_directory)?;
+            }
+            for (program_id, resolved) in &resolved {
+                let file_name = match program_id.is_aleo() {
+                    true => program_id.to_string(),
+                    false => format!("{program_id}.aleo"),
+                };
+                std::fs::write(imports_directory.join(file_name), resolved.program_file.program_string())?;
+            }
+        }
+
+        // Write the lock file.
+        LockFile::create(&self.directory, locked_dependencies)?;
+
+        Ok(resolved.into_keys().collect())
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Resolves the given dependency of the package at the given directory, along with its own dependencies.
+    /// The `path` contains the program IDs from the main program to the dependent program, to detect cycles.
+    fn resolve_dependency(
+        directory: &Path,
+        program_id: &ProgramID<N>,
+        dependency: &Dependency,
+        path: &mut Vec<ProgramID<N>>,
+        resolved: &mut IndexMap<ProgramID<N>, ResolvedDependency<N>>,
+    ) -> Result<()> {
+        // Ensure the dependency does not form a cycle.
+        if let Some(index) = path.iter().position(|id| id == program_id) {
+            let cycle = path[index..].iter().chain([program_id]).map(|id| id.to_string()).collect::<Vec<_>>();
+            bail!("Found a dependency cycle: {}", cycle.join(" -> "))
+        }
+
+        // If the dependency is already resolved, ensure the required versions match.
+        if let Some(existing) = resolved.get(program_id) {
+            ensure!(
+                existing.dependency.version() == dependency.version(),
+                "Dependency '{program_id}' is required at mismatched versions: '{}' and '{}'",
+                existing.dependency.version(),
+                dependency.version()
+            );
+            return Ok(());
+        }
+
+        // Locate the package of the dependency.
+        let package_directory = match dependency.source() {
+            DependencySource::Path(package_path) => directory.join(package_path),
+            DependencySource::Registry(registry) => {
+                directory.join(registry).join(program_id.to_string()).join(dependency.version())
+            }
+        };
+        ensure!(
+            package_directory.exists(),
+            "Dependency '{program_id}' is not found at '{}'",
+            package_directory.display()
+        );
+
+        // Open the package of the dependency.
+        let package = Package::<N>::open(&package_directory)?;
+        // Ensure the program ID matches.
+        ensure!(
+            package.program_id() == program_id,
+            "Expected dependency '{program_id}' at '{}', found '{}'",
+            package_directory.display(),
+            package.program_id()
+        );
+        // Ensure the version matches.
+        ensure!(
+            package.manifest_file().version() == dependency.version(),
+            "Dependency '{program_id}' at '{}' is version '{}', but version '{}' is required",
+            package_directory.display(),
+            package.manifest_file().version(),
+            dependency.version()
+        );
+        // Ensure the imports of the dependency are declared as its own dependencies.
+        for import_id in package.program().imports().keys() {
+            ensure!(
+                package.manifest_file().dependencies().contains_key(import_id),
+                "Dependency '{program_id}' imports '{import_id}', which is not declared in its '{}'",
+                Manifest::<N>::file_name()
+            );
+        }
+
+        // Resolve the dependencies of the dependency.
+        path.push(*program_id);
+        for (dependency_id, dependency) in package.manifest_file().dependencies() {
+            Self::resolve_dependency(&package_directory, dependency_id, dependency, path, resolved)?;
+        }
+        path.pop();
+
+        // Add the dependency, after its own dependencies.
+        let program_file = package.program_file;
+        resolved.insert(*program_id, ResolvedDependency { dependency: dependency.clone(), program_file });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Writes a package with the given program, version, and dependencies (as a JSON object) at the given directory.
+    fn write_package(directory: &Path, program_string: &str, version: &str, dependencies: &str) {
+        std::fs::create_dir_all(directory).unwrap();
+        std::fs::write(directory.join("main.aleo"), program_string).unwrap();
+
+        // Create the manifest file, and declare the version and dependencies.
+        let program = Program::<CurrentNetwork>::from_str(program_string).unwrap();
+        let manifest = Manifest::create(directory, program.id()).unwrap();
+        let manifest_string = std::fs::read_to_string(manifest.path()).unwrap();
+        let mut json: serde_json::Value = serde_json::from_str(&manifest_string).unwrap();
+        json["version"] = serde_json::Value::from(version);
+        json["dependencies"] = serde_json::from_str(dependencies).unwrap();
+        std::fs::write(manifest.path(), serde_json::to_string_pretty(&json).unwrap()).unwrap();
+    }
+
+    /// Returns a program with the given program ID and imports.
+    fn program_with_imports(program_id: &str, imports: &[&str]) -> String {
+        let imports = imports.iter().map(|import| format!("import {import};\n")).collect::<String>();
+        format!(
+            "{imports}
+program {program_id};
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;"
+        )
+    }
+
+    #[test]
+    fn test_resolve_dependencies() {
+        let directory = tempfile::tempdir().unwrap().into_path();
+
+        // Publish 'token.aleo' to a registry, and declare 'wallet.aleo' as a local package depending on it.
+        let registry = directory.join("registry");
+        let token_directory = registry.join("token.aleo").join("1.0.0");
+        write_package(&token_directory, &program_with_imports("token.aleo", &[]), "1.0.0", "{}");
+        write_package(
+            &directory.join("wallet"),
+            &program_with_imports("wallet.aleo", &["token.aleo"]),
+            "0.1.0",
+            r#"{ "token.aleo": { "version": "1.0.0", "registry": "../registry" } }"#,
+        );
+        write_package(
+            &directory.join("main"),
+            &program_with_imports("main.aleo", &["wallet.aleo", "token.aleo"]),
+            "0.0.0",
+            r#"{
+                "wallet.aleo": { "version": "0.1.0", "path": "../wallet" },
+                "token.aleo": { "version": "1.0.0", "registry": "../registry" }
+            }"#,
+        );
+
+        // Resolve the dependencies of the main package.
+        let package = Package::<CurrentNetwork>::open(&directory.join("main")).unwrap();
+        let resolved = package.resolve_dependencies().unwrap();
+        assert_eq!(resolved, [ProgramID::from_str("token.aleo").unwrap(), ProgramID::from_str("wallet.aleo").unwrap()]);
+
+        // Ensure the dependencies are copied into the imports directory.
+        for program_id in &resolved {
+            assert!(AleoFile::open(&package.imports_directory(), program_id, false).is_ok());
+        }
+
+        // Ensure the lock file records the dependencies, and that resolving again is stable.
+        let lock_file = LockFile::<CurrentNetwork>::open(package.directory()).unwrap();
+        assert_eq!(lock_file.dependencies().len(), 2);
+        assert_eq!(lock_file.dependencies()[0].source(), "registry+../registry");
+        assert_eq!(package.resolve_dependencies().unwrap(), resolved);
+
+        // Ensure a dependency that changes without a new version is rejected.
+        std::fs::write(token_directory.join("main.aleo"), program_with_imports("token.aleo", &[]) + "\n").unwrap();
+        assert!(package.resolve_dependencies().is_err());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_dependencies_fails() {
+        let directory = tempfile::tempdir().unwrap().into_path();
+
+        // Declare a cycle between 'a.aleo' and 'b.aleo'.
+        write_package(
+            &directory.join("a"),
+            &program_with_imports("a.aleo", &[]),
+            "0.0.0",
+            r#"{ "b.aleo": { "version": "0.0.0", "path": "../b" } }"#,
+        );
+        write_package(
+            &directory.join("b"),
+            &program_with_imports("b.aleo", &[]),
+            "0.0.0",
+            r#"{ "a.aleo": { "version": "0.0.0", "path": "../a" } }"#,
+        );
+        let package = Package::<CurrentNetwork>::open(&directory.join("a")).unwrap();
+        let error = package.resolve_dependencies().unwrap_err();
+        assert_eq!(error.to_string(), "Found a dependency cycle: a.aleo -> b.aleo -> a.aleo");
+
+        // Require 'token.aleo' at two different versions.
+        write_package(&directory.join("token-1"), &program_with_imports("token.aleo", &[]), "1.0.0", "{}");
+        write_package(&directory.join("token-2"), &program_with_imports("token.aleo", &[]), "2.0.0", "{}");
+        write_package(
+            &directory.join("wallet"),
+            &program_with_imports("wallet.aleo", &[]),
+            "0.0.0",
+            r#"{ "token.aleo": { "version": "1.0.0", "path": "../token-1" } }"#,
+        );
+        write_package(
+            &directory.join("main"),
+            &program_with_imports("main.aleo", &[]),
+            "0.0.0",
+            r#"{
+                "wallet.aleo": { "version": "0.0.0", "path": "../wallet" },
+                "token.aleo": { "version": "2.0.0", "path": "../token-2" }
+            }"#,
+        );
+        let package = Package::<CurrentNetwork>::open(&directory.join("main")).unwrap();
+        let error = package.resolve_dependencies().unwrap_err();
+        assert!(error.to_string().starts_with("Dependency 'token.aleo' is required at mismatched versions"), "{error}");
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}